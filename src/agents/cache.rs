@@ -0,0 +1,448 @@
+//! Response caching for LLM agents.
+//!
+//! This module provides an opt-in on-disk cache for agent responses so that
+//! re-running the same deterministic prompt does not spend another API call.
+
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{Agent, AgentConfig, Generation, TextStream, TokenUsage};
+use crate::error::Result;
+use crate::utils::file::{atomic_write, ensure_directory};
+
+/// Configuration for agent response caching.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheConfig {
+    /// Directory holding one JSON file per cached response.
+    #[serde(default = "default_dir")]
+    pub dir: PathBuf,
+
+    /// Time-to-live for cache entries, in seconds.
+    #[serde(default = "default_ttl_secs")]
+    pub ttl_secs: u64,
+
+    /// Cache responses even when the agent's temperature is above zero.
+    #[serde(default)]
+    pub cache_nondeterministic: bool,
+}
+
+fn default_dir() -> PathBuf {
+    PathBuf::from("workspace/cache/agents")
+}
+
+fn default_ttl_secs() -> u64 {
+    86_400 // 1 day
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            dir: default_dir(),
+            ttl_secs: default_ttl_secs(),
+            cache_nondeterministic: false,
+        }
+    }
+}
+
+/// A cached agent response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    response: serde_json::Value,
+    timestamp_secs: u64,
+}
+
+impl CacheEntry {
+    fn new(response: serde_json::Value) -> Self {
+        Self {
+            response,
+            timestamp_secs: now_secs(),
+        }
+    }
+
+    fn is_expired(&self, ttl: Duration) -> bool {
+        now_secs().saturating_sub(self.timestamp_secs) > ttl.as_secs()
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Wrapper that adds response caching to any `Agent` implementation.
+///
+/// Responses are keyed on a hash of the provider, model name, prompt and
+/// temperature (plus the system prompt and whether text or JSON was
+/// requested). Only agents with a temperature of zero are cached unless
+/// [`CacheConfig::cache_nondeterministic`] is set.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ats_checker::agents::cache::{CacheConfig, CachingAgent};
+/// use ats_checker::agents::{AgentConfig, GeminiAgent};
+///
+/// let config = AgentConfig::builder().name("enhancer").temperature(0.0).build();
+/// let agent = GeminiAgent::from_env(config).unwrap();
+/// let cached = CachingAgent::new(agent, CacheConfig::default());
+/// ```
+pub struct CachingAgent<A: Agent> {
+    inner: A,
+    config: CacheConfig,
+}
+
+impl<A: Agent> CachingAgent<A> {
+    /// Create a new caching wrapper around an agent.
+    pub fn new(agent: A, config: CacheConfig) -> Self {
+        Self {
+            inner: agent,
+            config,
+        }
+    }
+
+    /// Create a caching wrapper with default configuration.
+    pub fn with_defaults(agent: A) -> Self {
+        Self::new(agent, CacheConfig::default())
+    }
+
+    /// The wrapped agent.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Whether responses from the wrapped agent may be cached.
+    fn is_cacheable(&self) -> bool {
+        self.config.cache_nondeterministic || self.inner.config().temperature == 0.0
+    }
+
+    /// Generate a cache key for a prompt and response kind.
+    fn cache_key(&self, kind: &str, prompt: &str) -> String {
+        let agent = self.inner.config();
+        let mut hasher = Sha256::new();
+        hasher.update(agent.provider.as_bytes());
+        hasher.update([0]);
+        hasher.update(agent.model_name.as_bytes());
+        hasher.update([0]);
+        hasher.update(agent.temperature.to_bits().to_le_bytes());
+        hasher.update(agent.system_prompt.as_deref().unwrap_or("").as_bytes());
+        hasher.update([0]);
+        hasher.update(kind.as_bytes());
+        hasher.update([0]);
+        hasher.update(prompt.as_bytes());
+        hex::encode(&hasher.finalize()[..16])
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.config.dir.join(format!("{key}.json"))
+    }
+
+    /// Get cached response if available and not expired.
+    fn get_cached(&self, key: &str) -> Option<serde_json::Value> {
+        let content = std::fs::read_to_string(self.entry_path(key)).ok()?;
+        let entry: CacheEntry = match serde_json::from_str(&content) {
+            Ok(entry) => entry,
+            Err(e) => {
+                log::warn!("Ignoring unreadable agent cache entry {key}: {e}");
+                return None;
+            }
+        };
+
+        if entry.is_expired(Duration::from_secs(self.config.ttl_secs)) {
+            log::debug!("Agent cache entry expired for key: {key}");
+            return None;
+        }
+
+        log::debug!("Agent cache hit for key: {key}");
+        Some(entry.response)
+    }
+
+    /// Store response in cache.
+    fn put_cached(&self, key: &str, response: serde_json::Value) {
+        let result = ensure_directory(&self.config.dir).and_then(|()| {
+            let json = serde_json::to_string_pretty(&CacheEntry::new(response))?;
+            atomic_write(self.entry_path(key), &json)
+        });
+
+        match result {
+            Ok(()) => log::debug!("Cached agent response for key: {key}"),
+            Err(e) => log::warn!("Failed to write agent cache entry: {e}"),
+        }
+    }
+
+    /// Remove all cached responses from the cache directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory cannot be read or an entry
+    /// cannot be removed.
+    pub fn clear_cache(&self) -> Result<()> {
+        if !self.config.dir.exists() {
+            return Ok(());
+        }
+
+        for entry in std::fs::read_dir(&self.config.dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                std::fs::remove_file(path)?;
+            }
+        }
+
+        log::info!("Agent cache cleared for: {}", self.inner.config().name);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<A: Agent> Agent for CachingAgent<A> {
+    fn config(&self) -> &AgentConfig {
+        self.inner.config()
+    }
+
+    fn last_usage(&self) -> Option<TokenUsage> {
+        self.inner.last_usage()
+    }
+
+    async fn generate_text(&self, prompt: &str) -> Result<String> {
+        self.generate_text_traced(prompt).await.map(|g| g.value)
+    }
+
+    async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+        self.generate_json_traced(prompt).await.map(|g| g.value)
+    }
+
+    /// Cache hits report this agent with no usage, since no tokens were spent.
+    async fn generate_text_traced(&self, prompt: &str) -> Result<Generation<String>> {
+        if !self.is_cacheable() {
+            return self.inner.generate_text_traced(prompt).await;
+        }
+
+        let key = self.cache_key("text", prompt);
+        if let Some(serde_json::Value::String(text)) = self.get_cached(&key) {
+            return Ok(Generation::new(text, self.config(), None));
+        }
+
+        log::debug!("Agent cache miss for key: {key}");
+        let generation = self.inner.generate_text_traced(prompt).await?;
+        self.put_cached(&key, serde_json::Value::String(generation.value.clone()));
+
+        Ok(generation)
+    }
+
+    /// Cache hits report this agent with no usage, since no tokens were spent.
+    async fn generate_json_traced(&self, prompt: &str) -> Result<Generation<serde_json::Value>> {
+        if !self.is_cacheable() {
+            return self.inner.generate_json_traced(prompt).await;
+        }
+
+        let key = self.cache_key("json", prompt);
+        if let Some(value) = self.get_cached(&key) {
+            return Ok(Generation::new(value, self.config(), None));
+        }
+
+        log::debug!("Agent cache miss for key: {key}");
+        let generation = self.inner.generate_json_traced(prompt).await?;
+        self.put_cached(&key, generation.value.clone());
+
+        Ok(generation)
+    }
+
+    /// Streams are passed through uncached.
+    async fn generate_stream(&self, prompt: &str) -> Result<TextStream> {
+        self.inner.generate_stream(prompt).await
+    }
+
+    fn batch_concurrency(&self) -> usize {
+        self.inner.batch_concurrency()
+    }
+
+    /// Always reaches the provider; a cached answer would say nothing about
+    /// whether it is still reachable.
+    async fn ping(&self) -> Result<()> {
+        self.inner.ping().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use tempfile::tempdir;
+
+    // Mock agent that tracks call count
+    struct MockAgent {
+        config: AgentConfig,
+        call_count: Arc<AtomicU32>,
+    }
+
+    impl MockAgent {
+        fn new(temperature: f64, call_count: &Arc<AtomicU32>) -> Self {
+            Self {
+                config: AgentConfig::builder()
+                    .name("mock")
+                    .temperature(temperature)
+                    .build(),
+                call_count: Arc::clone(call_count),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Agent for MockAgent {
+        fn config(&self) -> &AgentConfig {
+            &self.config
+        }
+
+        async fn generate_text(&self, prompt: &str) -> Result<String> {
+            let n = self.call_count.fetch_add(1, Ordering::SeqCst);
+            Ok(format!("{prompt} #{n}"))
+        }
+
+        async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+            let n = self.call_count.fetch_add(1, Ordering::SeqCst);
+            Ok(serde_json::json!({ "prompt": prompt, "call": n }))
+        }
+
+        async fn generate_text_traced(&self, prompt: &str) -> Result<Generation<String>> {
+            let text = self.generate_text(prompt).await?;
+            let usage = TokenUsage {
+                prompt_tokens: 3,
+                completion_tokens: 2,
+                total_tokens: 5,
+            };
+            Ok(Generation::new(text, &self.config, Some(usage)))
+        }
+
+        async fn generate_stream(&self, prompt: &str) -> Result<TextStream> {
+            let chunks = vec![Ok(prompt.to_string()), Ok(" streamed".to_string())];
+            Ok(Box::pin(futures::stream::iter(chunks)))
+        }
+
+        async fn ping(&self) -> Result<()> {
+            self.call_count.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn cache_config(dir: &std::path::Path) -> CacheConfig {
+        CacheConfig {
+            dir: dir.to_path_buf(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_second_identical_call_hits_cache() {
+        let dir = tempdir().unwrap();
+        let calls = Arc::new(AtomicU32::new(0));
+        let agent = CachingAgent::new(MockAgent::new(0.0, &calls), cache_config(dir.path()));
+
+        let first = agent.generate_json("enhance").await.unwrap();
+        let second = agent.generate_json("enhance").await.unwrap();
+        assert_eq!(first, second);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let text = agent.generate_text("enhance").await.unwrap();
+        assert_eq!(agent.generate_text("enhance").await.unwrap(), text);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        agent.generate_json("different prompt").await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_ping_stream_and_usage_reach_the_inner_agent() {
+        use futures::StreamExt;
+
+        let dir = tempdir().unwrap();
+        let calls = Arc::new(AtomicU32::new(0));
+        let agent = CachingAgent::new(MockAgent::new(0.0, &calls), cache_config(dir.path()));
+
+        agent.ping().await.unwrap();
+        agent.ping().await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let miss = agent.generate_text_traced("enhance").await.unwrap();
+        assert_eq!(miss.usage.map(|u| u.total_tokens), Some(5));
+        let hit = agent.generate_text_traced("enhance").await.unwrap();
+        assert_eq!(hit.value, miss.value);
+        assert_eq!(hit.usage, None);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        let chunks: Vec<String> = agent
+            .generate_stream("enhance")
+            .await
+            .unwrap()
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+        assert_eq!(chunks, ["enhance", " streamed"]);
+    }
+
+    #[tokio::test]
+    async fn test_cache_persists_across_wrappers() {
+        let dir = tempdir().unwrap();
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let first = CachingAgent::new(MockAgent::new(0.0, &calls), cache_config(dir.path()));
+        first.generate_text("enhance").await.unwrap();
+
+        let second = CachingAgent::new(MockAgent::new(0.0, &calls), cache_config(dir.path()));
+        second.generate_text("enhance").await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        second.clear_cache().unwrap();
+        second.generate_text("enhance").await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_nondeterministic_agents_not_cached_by_default() {
+        let dir = tempdir().unwrap();
+        let calls = Arc::new(AtomicU32::new(0));
+        let agent = CachingAgent::new(MockAgent::new(0.7, &calls), cache_config(dir.path()));
+
+        agent.generate_text("enhance").await.unwrap();
+        agent.generate_text("enhance").await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let opted_in = CachingAgent::new(
+            MockAgent::new(0.7, &calls),
+            CacheConfig {
+                cache_nondeterministic: true,
+                ..cache_config(dir.path())
+            },
+        );
+        opted_in.generate_text("enhance").await.unwrap();
+        opted_in.generate_text("enhance").await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_refreshed() {
+        let dir = tempdir().unwrap();
+        let calls = Arc::new(AtomicU32::new(0));
+        let agent = CachingAgent::new(MockAgent::new(0.0, &calls), cache_config(dir.path()));
+
+        let key = agent.cache_key("text", "enhance");
+        let stale = CacheEntry {
+            response: serde_json::Value::String("stale".to_string()),
+            timestamp_secs: now_secs() - default_ttl_secs() - 10,
+        };
+        std::fs::write(
+            agent.entry_path(&key),
+            serde_json::to_string(&stale).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(agent.generate_text("enhance").await.unwrap(), "enhance #0");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
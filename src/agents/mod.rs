@@ -11,6 +11,9 @@
 //! - **scorer**: Scores resumes (currently deterministic, not LLM-based)
 //! - **reviser**: Iteratively improves resumes based on feedback
 //!
+//! Responses for deterministic prompts can be cached on disk by wrapping an
+//! agent in [`cache::CachingAgent`].
+//!
 //! # Example
 //!
 //! ```no_run
@@ -31,19 +34,48 @@
 //! }
 //! ```
 
+pub mod cache;
+
 use crate::anthropic::{AnthropicClient, GenerationConfig as AnthropicGenerationConfig};
 use crate::error::{AtsError, Result};
 use crate::gemini::{GeminiClient, GenerationConfig as GeminiGenerationConfig};
 use crate::llama::{GenerationConfig as LlamaGenerationConfig, LlamaClient};
 use crate::openai::{GenerationConfig as OpenAiGenerationConfig, OpenAiClient};
+use crate::utils::http::ClientOptions;
+use crate::utils::text::strip_markdown_fences;
 use async_trait::async_trait;
+use futures::stream::{self, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Duration;
 
 // -------------------------
 // Agent Configuration
 // -------------------------
 
+/// Provider names accepted by [`AgentRegistry::from_config`], including aliases.
+pub const SUPPORTED_PROVIDERS: &[&str] = &[
+    "gemini",
+    "openai",
+    "azure",
+    "anthropic",
+    "claude",
+    "llama",
+    "ollama",
+];
+
+/// Default number of requests [`Agent::generate_json_batch`] keeps in flight.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
+/// Default number of probes [`AgentRegistry::verify_all`] runs at once.
+pub const DEFAULT_VERIFY_CONCURRENCY: usize = 4;
+
+/// Per-agent time limit for [`AgentRegistry::verify_all_with`] when neither
+/// the client options nor the agent configure a timeout.
+pub const DEFAULT_VERIFY_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Configuration for an LLM agent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentConfig {
@@ -85,10 +117,24 @@ pub struct AgentConfig {
     #[serde(default = "default_true")]
     pub retry_on_empty: bool,
 
-    /// Require JSON output.
+    /// Backoff policy between retry attempts.
+    #[serde(default)]
+    pub retry_policy: RetryPolicy,
+
+    /// Require JSON output. `OpenAI` and Gemini use their native JSON
+    /// response modes for [`Agent::generate_json`]; other providers rely on
+    /// prompt instructions.
     #[serde(default)]
     pub require_json: bool,
 
+    /// System prompt sent with every request (e.g. persistent instructions).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_prompt: Option<String>,
+
+    /// HTTP request timeout in seconds (provider default when unset).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_timeout_secs: Option<u64>,
+
     /// Extra provider-specific options.
     #[serde(default)]
     pub extras: HashMap<String, serde_json::Value>,
@@ -135,7 +181,10 @@ impl Default for AgentConfig {
             max_output_tokens: default_max_output_tokens(),
             max_retries: 0,
             retry_on_empty: true,
+            retry_policy: RetryPolicy::default(),
             require_json: false,
+            system_prompt: None,
+            request_timeout_secs: None,
             extras: HashMap::new(),
         }
     }
@@ -146,6 +195,13 @@ impl AgentConfig {
     pub fn builder() -> AgentConfigBuilder {
         AgentConfigBuilder::default()
     }
+
+    /// Configured request timeout, if any (zero is treated as unset).
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout_secs
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+    }
 }
 
 /// Builder for `AgentConfig`.
@@ -161,7 +217,10 @@ pub struct AgentConfigBuilder {
     max_output_tokens: Option<i32>,
     max_retries: Option<i32>,
     retry_on_empty: Option<bool>,
+    retry_policy: Option<RetryPolicy>,
     require_json: Option<bool>,
+    system_prompt: Option<String>,
+    request_timeout_secs: Option<u64>,
 }
 
 impl AgentConfigBuilder {
@@ -235,6 +294,13 @@ impl AgentConfigBuilder {
         self
     }
 
+    /// Set the backoff policy used between retry attempts.
+    #[must_use]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
     /// Set whether JSON output is required.
     #[must_use]
     pub fn require_json(mut self, require: bool) -> Self {
@@ -242,6 +308,20 @@ impl AgentConfigBuilder {
         self
     }
 
+    /// Set the system prompt sent with every request.
+    #[must_use]
+    pub fn system_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.system_prompt = Some(prompt.into());
+        self
+    }
+
+    /// Set the HTTP request timeout in seconds.
+    #[must_use]
+    pub fn request_timeout_secs(mut self, secs: u64) -> Self {
+        self.request_timeout_secs = Some(secs);
+        self
+    }
+
     /// Build the `AgentConfig`.
     pub fn build(self) -> AgentConfig {
         let defaults = AgentConfig::default();
@@ -257,16 +337,243 @@ impl AgentConfigBuilder {
             max_output_tokens: self.max_output_tokens.unwrap_or(defaults.max_output_tokens),
             max_retries: self.max_retries.unwrap_or(defaults.max_retries),
             retry_on_empty: self.retry_on_empty.unwrap_or(defaults.retry_on_empty),
+            retry_policy: self.retry_policy.unwrap_or(defaults.retry_policy),
             require_json: self.require_json.unwrap_or(defaults.require_json),
+            system_prompt: self.system_prompt.or(defaults.system_prompt),
+            request_timeout_secs: self.request_timeout_secs.or(defaults.request_timeout_secs),
             extras: HashMap::new(),
         }
     }
 }
 
+// -------------------------
+// Retry Policy
+// -------------------------
+
+/// Exponential backoff policy for agent retries.
+///
+/// The delay before retry `n` (0-based) is
+/// `min(max_delay_ms, base_delay_ms * multiplier^n)` plus a random jitter of
+/// up to `jitter` times that delay. A rate-limit error carrying a
+/// `retry_after` hint overrides the computed delay.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Delay before the first retry, in milliseconds.
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+
+    /// Upper bound for the exponential delay, in milliseconds.
+    #[serde(default = "default_max_delay_ms")]
+    pub max_delay_ms: u64,
+
+    /// Growth factor applied per attempt.
+    #[serde(default = "default_multiplier")]
+    pub multiplier: f64,
+
+    /// Maximum random jitter as a fraction of the delay (0.0 disables jitter).
+    #[serde(default = "default_jitter")]
+    pub jitter: f64,
+}
+
+fn default_base_delay_ms() -> u64 {
+    500
+}
+
+fn default_max_delay_ms() -> u64 {
+    30_000
+}
+
+fn default_multiplier() -> f64 {
+    2.0
+}
+
+fn default_jitter() -> f64 {
+    0.2
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay_ms: default_base_delay_ms(),
+            max_delay_ms: default_max_delay_ms(),
+            multiplier: default_multiplier(),
+            jitter: default_jitter(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential delay (without jitter) before retry `attempt` (0-based).
+    pub fn backoff(&self, attempt: u32) -> std::time::Duration {
+        let exp = self.base_delay_ms as f64 * self.multiplier.max(1.0).powi(attempt as i32);
+        let capped = exp.min(self.max_delay_ms as f64).max(0.0);
+        std::time::Duration::from_millis(capped as u64)
+    }
+
+    /// Delay before retry `attempt` after `error`, including jitter.
+    ///
+    /// A rate limit's `Retry-After` is used as is, unless it exceeds
+    /// `max_delay_ms`: then `None` is returned and the error should be given
+    /// up on rather than stalling the caller.
+    pub(crate) fn delay_for(
+        &self,
+        attempt: u32,
+        error: &AtsError,
+        rng: &mut fastrand::Rng,
+    ) -> Option<std::time::Duration> {
+        if let AtsError::ApiRateLimit {
+            retry_after: Some(secs),
+            ..
+        } = error
+        {
+            let delay = std::time::Duration::from_secs(*secs);
+            return (delay <= std::time::Duration::from_millis(self.max_delay_ms)).then_some(delay);
+        }
+
+        let backoff = self.backoff(attempt);
+        let jitter = backoff.mul_f64(self.jitter.clamp(0.0, 1.0) * rng.f64());
+        Some(backoff + jitter)
+    }
+}
+
+/// Run a generation request with the agent's retry settings.
+///
+/// Retries up to `max_retries` times on errors (except authentication
+/// failures), including [`AtsError::Timeout`], and, when `retry_on_empty` is
+/// set, on blank responses. A rate limit asking to wait longer than the
+/// policy's `max_delay_ms` is returned without retrying.
+async fn retry_generation<F, Fut>(
+    config: &AgentConfig,
+    mut request: F,
+) -> Result<(String, Option<TokenUsage>)>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<(String, Option<TokenUsage>)>>,
+{
+    let max_attempts = 1 + config.max_retries.max(0) as u32;
+    let mut rng = fastrand::Rng::new();
+    let mut last_error = None;
+
+    for attempt in 0..max_attempts {
+        let error = match request().await {
+            Ok((text, _)) if config.retry_on_empty && text.trim().is_empty() => {
+                AtsError::ApiResponse {
+                    message: "Empty response from API".to_string(),
+                    status_code: None,
+                }
+            }
+            Ok(result) => return Ok(result),
+            Err(e) => e,
+        };
+
+        // Don't retry on auth errors
+        if matches!(error, AtsError::ApiAuth { .. }) {
+            return Err(error);
+        }
+
+        if attempt + 1 < max_attempts {
+            let Some(delay) = config.retry_policy.delay_for(attempt, &error, &mut rng) else {
+                log::warn!(
+                    "Agent '{}' was asked to wait longer than {}ms, giving up: {}",
+                    config.name,
+                    config.retry_policy.max_delay_ms,
+                    error
+                );
+                return Err(error);
+            };
+            log::warn!(
+                "Agent '{}' attempt {}/{} failed, retrying in {:?}: {}",
+                config.name,
+                attempt + 1,
+                max_attempts,
+                delay,
+                error
+            );
+            tokio::time::sleep(delay).await;
+        }
+
+        last_error = Some(error);
+    }
+
+    Err(last_error.unwrap_or_else(|| AtsError::ApiRequest {
+        message: "All retry attempts failed".to_string(),
+        source: None,
+    }))
+}
+
+// -------------------------
+// Token Usage
+// -------------------------
+
+/// Token counts reported by an LLM provider for a single request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    /// Tokens consumed by the prompt.
+    pub prompt_tokens: u64,
+
+    /// Tokens generated in the completion.
+    pub completion_tokens: u64,
+
+    /// Total tokens billed for the request.
+    pub total_tokens: u64,
+}
+
+impl TokenUsage {
+    /// Create a usage record, deriving the total from prompt and completion tokens.
+    pub fn new(prompt_tokens: u64, completion_tokens: u64) -> Self {
+        Self {
+            prompt_tokens,
+            completion_tokens,
+            total_tokens: prompt_tokens + completion_tokens,
+        }
+    }
+}
+
+impl std::ops::AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.total_tokens += other.total_tokens;
+    }
+}
+
 // -------------------------
 // Agent Trait
 // -------------------------
 
+/// A generated value together with details of the request that produced it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generation<T> {
+    /// The generated text or JSON.
+    pub value: T,
+    /// Name of the agent that answered.
+    pub agent: String,
+    /// Provider of that agent.
+    pub provider: String,
+    /// Model of that agent.
+    pub model: String,
+    /// Token usage the provider reported for the request, if any.
+    pub usage: Option<TokenUsage>,
+}
+
+impl<T> Generation<T> {
+    /// Record `value` as answered by the agent configured by `config`.
+    pub fn new(value: T, config: &AgentConfig, usage: Option<TokenUsage>) -> Self {
+        Self {
+            value,
+            agent: config.name.clone(),
+            provider: config.provider.clone(),
+            model: config.model_name.clone(),
+            usage,
+        }
+    }
+}
+
+/// A stream of generated text chunks.
+///
+/// Concatenating every `Ok` chunk yields the full response text.
+pub type TextStream = Pin<Box<dyn Stream<Item = Result<String>> + Send>>;
+
 /// Agent trait for LLM providers.
 #[async_trait]
 pub trait Agent: Send + Sync {
@@ -278,6 +585,98 @@ pub trait Agent: Send + Sync {
 
     /// Generate JSON from a prompt.
     async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value>;
+
+    /// Generate text from a prompt, also reporting the agent that answered
+    /// and the token usage of this request.
+    ///
+    /// Unlike reading [`Agent::last_usage`] afterwards, the details belong to
+    /// this request even while the agent serves others concurrently. The
+    /// default implementation calls [`Agent::generate_text`] and reports this
+    /// agent with no usage.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`Agent::generate_text`].
+    async fn generate_text_traced(&self, prompt: &str) -> Result<Generation<String>> {
+        let text = self.generate_text(prompt).await?;
+        Ok(Generation::new(text, self.config(), None))
+    }
+
+    /// Generate JSON from a prompt, also reporting the agent that answered
+    /// and the token usage of this request; see
+    /// [`Agent::generate_text_traced`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`Agent::generate_json`].
+    async fn generate_json_traced(&self, prompt: &str) -> Result<Generation<serde_json::Value>> {
+        let value = self.generate_json(prompt).await?;
+        Ok(Generation::new(value, self.config(), None))
+    }
+
+    /// Generate text from a prompt as a stream of chunks.
+    ///
+    /// The default implementation calls [`Agent::generate_text`] and yields the
+    /// full text as a single chunk, so providers without a streaming endpoint
+    /// still work. Streamed responses are not retried and are not validated
+    /// against `require_json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be started.
+    async fn generate_stream(&self, prompt: &str) -> Result<TextStream> {
+        let text = self.generate_text(prompt).await?;
+        Ok(Box::pin(stream::once(async move { Ok(text) })))
+    }
+
+    /// Generate JSON for several prompts concurrently.
+    ///
+    /// At most [`Agent::batch_concurrency`] requests are in flight at once.
+    /// Results are returned in prompt order, one per prompt, and a failed
+    /// prompt doesn't affect the others.
+    async fn generate_json_batch(&self, prompts: &[String]) -> Vec<Result<serde_json::Value>> {
+        let limit = self.batch_concurrency().max(1);
+        // Futures are lazy, so creating them all up front sends nothing yet
+        let requests: Vec<_> = prompts
+            .iter()
+            .map(|prompt| self.generate_json(prompt))
+            .collect();
+        stream::iter(requests).buffered(limit).collect().await
+    }
+
+    /// Most requests [`Agent::generate_json_batch`] sends concurrently.
+    ///
+    /// Defaults to [`DEFAULT_BATCH_CONCURRENCY`]; override it to match a
+    /// provider's rate limits.
+    fn batch_concurrency(&self) -> usize {
+        DEFAULT_BATCH_CONCURRENCY
+    }
+
+    /// Token usage reported for the most recent request.
+    ///
+    /// Returns `None` when the provider does not report token counts (e.g.
+    /// Ollama), no request has completed yet, or the most recent one failed.
+    /// With concurrent requests this may belong to any of them; use
+    /// [`Agent::generate_text_traced`] or [`Agent::generate_json_traced`] to
+    /// attribute usage to a request.
+    fn last_usage(&self) -> Option<TokenUsage> {
+        None
+    }
+
+    /// Check that the provider is reachable and the configured model is
+    /// usable, before committing to a long run.
+    ///
+    /// The default implementation sends a one-word prompt through
+    /// [`Agent::generate_text`]; the built-in agents override it with a
+    /// cheaper provider-specific probe.
+    ///
+    /// # Errors
+    ///
+    /// Returns the provider error (e.g. [`AtsError::ApiAuth`] for a rejected
+    /// key).
+    async fn ping(&self) -> Result<()> {
+        self.generate_text("ping").await.map(|_| ())
+    }
 }
 
 // -------------------------
@@ -288,6 +687,7 @@ pub trait Agent: Send + Sync {
 pub struct GeminiAgent {
     config: AgentConfig,
     client: GeminiClient,
+    last_usage: Mutex<Option<TokenUsage>>,
 }
 
 impl GeminiAgent {
@@ -304,10 +704,19 @@ impl GeminiAgent {
             max_output_tokens: Some(config.max_output_tokens),
         };
 
-        let client = GeminiClient::from_env_with_model(&config.model_name)?
-            .with_generation_config(generation_config);
+        let mut client = GeminiClient::from_env_with_model(&config.model_name)?
+            .with_generation_config(generation_config)
+            .with_system_prompt(config.system_prompt.clone())
+            .with_json_mode(config.require_json);
+        if let Some(timeout) = config.request_timeout() {
+            client = client.with_timeout(timeout);
+        }
 
-        Ok(Self { config, client })
+        Ok(Self {
+            config,
+            client,
+            last_usage: Mutex::new(None),
+        })
     }
 
     /// Create a new Gemini agent with explicit API key.
@@ -323,50 +732,47 @@ impl GeminiAgent {
             max_output_tokens: Some(config.max_output_tokens),
         };
 
-        let client = GeminiClient::new(api_key, &config.model_name)?
-            .with_generation_config(generation_config);
+        let mut client = GeminiClient::new(api_key, &config.model_name)?
+            .with_generation_config(generation_config)
+            .with_system_prompt(config.system_prompt.clone())
+            .with_json_mode(config.require_json);
+        if let Some(timeout) = config.request_timeout() {
+            client = client.with_timeout(timeout);
+        }
 
-        Ok(Self { config, client })
+        Ok(Self {
+            config,
+            client,
+            last_usage: Mutex::new(None),
+        })
     }
 
-    /// Generate text with retry logic.
-    async fn generate_with_retry(&self, prompt: &str) -> Result<String> {
-        let mut last_error = None;
-        let max_attempts = 1 + self.config.max_retries.max(0);
-
-        for attempt in 0..max_attempts {
-            match self.client.generate_content(prompt).await {
-                Ok(text) => {
-                    if self.config.retry_on_empty && text.trim().is_empty() {
-                        last_error = Some(AtsError::ApiResponse {
-                            message: "Empty response from API".to_string(),
-                            status_code: None,
-                        });
-                        continue;
-                    }
-
-                    return Ok(text);
-                }
-                Err(e) => {
-                    last_error = Some(e);
-
-                    // Don't retry on auth errors
-                    if matches!(last_error, Some(AtsError::ApiAuth { .. })) {
-                        break;
-                    }
+    /// Override the Gemini API base URL (the `.../models` collection URL).
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.client = self.client.with_base_url(base_url);
+        self
+    }
 
-                    // Add small delay before retry
-                    if attempt < max_attempts - 1 {
-                        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    }
-                }
+    /// Generate text with retry logic. With `json` set, the request uses the
+    /// native JSON mode when the agent requires JSON.
+    async fn generate_with_retry(
+        &self,
+        prompt: &str,
+        json: bool,
+    ) -> Result<(String, Option<TokenUsage>)> {
+        let result = retry_generation(&self.config, || async move {
+            if json {
+                self.client.generate_json_with_usage(prompt).await
+            } else {
+                self.client.generate_content_with_usage(prompt).await
             }
-        }
+        })
+        .await;
 
-        Err(last_error.unwrap_or_else(|| AtsError::ApiRequest {
-            message: "All retry attempts failed".to_string(),
-            source: None,
-        }))
+        // Cleared on failure so a failed request isn't billed the previous usage
+        *self.last_usage.lock().unwrap() = result.as_ref().ok().and_then(|(_, usage)| *usage);
+        result
     }
 }
 
@@ -376,29 +782,43 @@ impl Agent for GeminiAgent {
         &self.config
     }
 
+    fn last_usage(&self) -> Option<TokenUsage> {
+        *self.last_usage.lock().unwrap()
+    }
+
     async fn generate_text(&self, prompt: &str) -> Result<String> {
-        let text = self.generate_with_retry(prompt).await?;
+        self.generate_text_traced(prompt).await.map(|g| g.value)
+    }
+
+    async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+        self.generate_json_traced(prompt).await.map(|g| g.value)
+    }
+
+    async fn generate_text_traced(&self, prompt: &str) -> Result<Generation<String>> {
+        let (text, usage) = self.generate_with_retry(prompt, false).await?;
 
         // If require_json is set, validate it's valid JSON
         if self.config.require_json {
             let cleaned = strip_markdown_fences(&text);
             serde_json::from_str::<serde_json::Value>(&cleaned).map_err(|e| {
-                AtsError::ApiResponse {
+                AtsError::JsonParse {
                     message: format!("Response is not valid JSON: {e}"),
-                    status_code: None,
+                    source: Some(e),
                 }
             })?;
 
             // Return cleaned JSON
-            return Ok(cleaned);
+            return Ok(Generation::new(cleaned, &self.config, usage));
         }
 
-        Ok(text)
+        Ok(Generation::new(text, &self.config, usage))
     }
 
-    async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
-        // Add JSON instruction to prompt if not already present
-        let enhanced_prompt = if prompt.to_lowercase().contains("json") {
+    async fn generate_json_traced(&self, prompt: &str) -> Result<Generation<serde_json::Value>> {
+        // Native JSON mode (require_json) makes the prompt instruction redundant;
+        // otherwise add it if not already present
+        let enhanced_prompt = if self.config.require_json || prompt.to_lowercase().contains("json")
+        {
             prompt.to_string()
         } else {
             format!(
@@ -406,13 +826,23 @@ impl Agent for GeminiAgent {
             )
         };
 
-        let text = self.generate_with_retry(&enhanced_prompt).await?;
+        let (text, usage) = self.generate_with_retry(&enhanced_prompt, true).await?;
         let cleaned = strip_markdown_fences(&text);
 
-        serde_json::from_str(&cleaned).map_err(|e| AtsError::ApiResponse {
+        let value = serde_json::from_str(&cleaned).map_err(|e| AtsError::JsonParse {
             message: format!("Failed to parse JSON: {e}"),
-            status_code: None,
-        })
+            source: Some(e),
+        })?;
+        Ok(Generation::new(value, &self.config, usage))
+    }
+
+    async fn generate_stream(&self, prompt: &str) -> Result<TextStream> {
+        let chunks = self.client.generate_content_stream(prompt).await?;
+        Ok(Box::pin(chunks))
+    }
+
+    async fn ping(&self) -> Result<()> {
+        self.client.ping().await
     }
 }
 
@@ -424,6 +854,7 @@ impl Agent for GeminiAgent {
 pub struct OpenAiAgent {
     config: AgentConfig,
     client: OpenAiClient,
+    last_usage: Mutex<Option<TokenUsage>>,
 }
 
 impl OpenAiAgent {
@@ -433,16 +864,22 @@ impl OpenAiAgent {
     ///
     /// Returns an error if the `OPENAI_API_KEY` environment variable is not set.
     pub fn from_env(config: AgentConfig) -> Result<Self> {
-        let generation_config = OpenAiGenerationConfig {
-            temperature: Some(config.temperature),
-            top_p: Some(config.top_p),
-            max_tokens: Some(config.max_output_tokens),
-        };
-
-        let client = OpenAiClient::from_env_with_model(&config.model_name)?
-            .with_generation_config(generation_config);
+        let client = OpenAiClient::from_env_with_model(&config.model_name)?;
+        Ok(Self::with_client(config, client))
+    }
 
-        Ok(Self { config, client })
+    /// Create an agent for an Azure `OpenAI` deployment from environment.
+    ///
+    /// The agent's `model_name` is the deployment name; see
+    /// [`OpenAiClient::azure_from_env`] for the variables read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `AZURE_OPENAI_ENDPOINT` or `AZURE_OPENAI_API_KEY`
+    /// is not set.
+    pub fn azure_from_env(config: AgentConfig) -> Result<Self> {
+        let client = OpenAiClient::azure_from_env(&config.model_name)?;
+        Ok(Self::with_client(config, client))
     }
 
     /// Create a new `OpenAI` agent with explicit API key.
@@ -451,56 +888,59 @@ impl OpenAiAgent {
     ///
     /// Returns an error if the API key is invalid.
     pub fn new(api_key: impl Into<String>, config: AgentConfig) -> Result<Self> {
+        let client = OpenAiClient::new(api_key, &config.model_name)?;
+        Ok(Self::with_client(config, client))
+    }
+
+    /// Apply the agent config's generation settings to `client`.
+    fn with_client(config: AgentConfig, client: OpenAiClient) -> Self {
         let generation_config = OpenAiGenerationConfig {
             temperature: Some(config.temperature),
             top_p: Some(config.top_p),
             max_tokens: Some(config.max_output_tokens),
         };
 
-        let client = OpenAiClient::new(api_key, &config.model_name)?
-            .with_generation_config(generation_config);
+        let mut client = client
+            .with_generation_config(generation_config)
+            .with_system_prompt(config.system_prompt.clone())
+            .with_json_mode(config.require_json);
+        if let Some(timeout) = config.request_timeout() {
+            client = client.with_timeout(timeout);
+        }
 
-        Ok(Self { config, client })
+        Self {
+            config,
+            client,
+            last_usage: Mutex::new(None),
+        }
     }
 
-    /// Generate text with retry logic.
-    async fn generate_with_retry(&self, prompt: &str) -> Result<String> {
-        let mut last_error = None;
-        let max_attempts = 1 + self.config.max_retries.max(0);
-
-        for attempt in 0..max_attempts {
-            match self.client.generate_content(prompt).await {
-                Ok(text) => {
-                    if self.config.retry_on_empty && text.trim().is_empty() {
-                        last_error = Some(AtsError::ApiResponse {
-                            message: "Empty response from API".to_string(),
-                            status_code: None,
-                        });
-                        continue;
-                    }
-
-                    return Ok(text);
-                }
-                Err(e) => {
-                    last_error = Some(e);
-
-                    // Don't retry on auth errors
-                    if matches!(last_error, Some(AtsError::ApiAuth { .. })) {
-                        break;
-                    }
+    /// Override the `OpenAI` API base URL (e.g. an OpenAI-compatible server).
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.client = self.client.with_base_url(base_url);
+        self
+    }
 
-                    // Add small delay before retry
-                    if attempt < max_attempts - 1 {
-                        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    }
-                }
+    /// Generate text with retry logic. With `json` set, the request uses the
+    /// native JSON mode when the agent requires JSON.
+    async fn generate_with_retry(
+        &self,
+        prompt: &str,
+        json: bool,
+    ) -> Result<(String, Option<TokenUsage>)> {
+        let result = retry_generation(&self.config, || async move {
+            if json {
+                self.client.generate_json_with_usage(prompt).await
+            } else {
+                self.client.generate_content_with_usage(prompt).await
             }
-        }
+        })
+        .await;
 
-        Err(last_error.unwrap_or_else(|| AtsError::ApiRequest {
-            message: "All retry attempts failed".to_string(),
-            source: None,
-        }))
+        // Cleared on failure so a failed request isn't billed the previous usage
+        *self.last_usage.lock().unwrap() = result.as_ref().ok().and_then(|(_, usage)| *usage);
+        result
     }
 }
 
@@ -510,28 +950,41 @@ impl Agent for OpenAiAgent {
         &self.config
     }
 
+    fn last_usage(&self) -> Option<TokenUsage> {
+        *self.last_usage.lock().unwrap()
+    }
+
     async fn generate_text(&self, prompt: &str) -> Result<String> {
-        let text = self.generate_with_retry(prompt).await?;
+        self.generate_text_traced(prompt).await.map(|g| g.value)
+    }
+
+    async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+        self.generate_json_traced(prompt).await.map(|g| g.value)
+    }
+
+    async fn generate_text_traced(&self, prompt: &str) -> Result<Generation<String>> {
+        let (text, usage) = self.generate_with_retry(prompt, false).await?;
 
         // If require_json is set, validate it's valid JSON
         if self.config.require_json {
             let cleaned = strip_markdown_fences(&text);
             serde_json::from_str::<serde_json::Value>(&cleaned).map_err(|e| {
-                AtsError::ApiResponse {
+                AtsError::JsonParse {
                     message: format!("Response is not valid JSON: {e}"),
-                    status_code: None,
+                    source: Some(e),
                 }
             })?;
 
             // Return cleaned JSON
-            return Ok(cleaned);
+            return Ok(Generation::new(cleaned, &self.config, usage));
         }
 
-        Ok(text)
+        Ok(Generation::new(text, &self.config, usage))
     }
 
-    async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
-        // Add JSON instruction to prompt if not already present
+    async fn generate_json_traced(&self, prompt: &str) -> Result<Generation<serde_json::Value>> {
+        // Add JSON instruction to prompt if not already present (JSON mode
+        // also requires the messages to mention JSON)
         let enhanced_prompt = if prompt.to_lowercase().contains("json") {
             prompt.to_string()
         } else {
@@ -540,13 +993,23 @@ impl Agent for OpenAiAgent {
             )
         };
 
-        let text = self.generate_with_retry(&enhanced_prompt).await?;
+        let (text, usage) = self.generate_with_retry(&enhanced_prompt, true).await?;
         let cleaned = strip_markdown_fences(&text);
 
-        serde_json::from_str(&cleaned).map_err(|e| AtsError::ApiResponse {
+        let value = serde_json::from_str(&cleaned).map_err(|e| AtsError::JsonParse {
             message: format!("Failed to parse JSON: {e}"),
-            status_code: None,
-        })
+            source: Some(e),
+        })?;
+        Ok(Generation::new(value, &self.config, usage))
+    }
+
+    async fn generate_stream(&self, prompt: &str) -> Result<TextStream> {
+        let chunks = self.client.generate_content_stream(prompt).await?;
+        Ok(Box::pin(chunks))
+    }
+
+    async fn ping(&self) -> Result<()> {
+        self.client.ping().await
     }
 }
 
@@ -558,6 +1021,7 @@ impl Agent for OpenAiAgent {
 pub struct AnthropicAgent {
     config: AgentConfig,
     client: AnthropicClient,
+    last_usage: Mutex<Option<TokenUsage>>,
 }
 
 impl AnthropicAgent {
@@ -574,10 +1038,18 @@ impl AnthropicAgent {
             max_tokens: Some(config.max_output_tokens),
         };
 
-        let client = AnthropicClient::from_env_with_model(&config.model_name)?
-            .with_generation_config(generation_config);
+        let mut client = AnthropicClient::from_env_with_model(&config.model_name)?
+            .with_generation_config(generation_config)
+            .with_system_prompt(config.system_prompt.clone());
+        if let Some(timeout) = config.request_timeout() {
+            client = client.with_timeout(timeout);
+        }
 
-        Ok(Self { config, client })
+        Ok(Self {
+            config,
+            client,
+            last_usage: Mutex::new(None),
+        })
     }
 
     /// Create a new Anthropic agent with explicit API key.
@@ -593,50 +1065,37 @@ impl AnthropicAgent {
             max_tokens: Some(config.max_output_tokens),
         };
 
-        let client = AnthropicClient::new(api_key, &config.model_name)?
-            .with_generation_config(generation_config);
+        let mut client = AnthropicClient::new(api_key, &config.model_name)?
+            .with_generation_config(generation_config)
+            .with_system_prompt(config.system_prompt.clone());
+        if let Some(timeout) = config.request_timeout() {
+            client = client.with_timeout(timeout);
+        }
 
-        Ok(Self { config, client })
+        Ok(Self {
+            config,
+            client,
+            last_usage: Mutex::new(None),
+        })
     }
 
-    /// Generate text with retry logic.
-    async fn generate_with_retry(&self, prompt: &str) -> Result<String> {
-        let mut last_error = None;
-        let max_attempts = 1 + self.config.max_retries.max(0);
-
-        for attempt in 0..max_attempts {
-            match self.client.generate_content(prompt).await {
-                Ok(text) => {
-                    if self.config.retry_on_empty && text.trim().is_empty() {
-                        last_error = Some(AtsError::ApiResponse {
-                            message: "Empty response from API".to_string(),
-                            status_code: None,
-                        });
-                        continue;
-                    }
-
-                    return Ok(text);
-                }
-                Err(e) => {
-                    last_error = Some(e);
-
-                    // Don't retry on auth errors
-                    if matches!(last_error, Some(AtsError::ApiAuth { .. })) {
-                        break;
-                    }
+    /// Override the Anthropic API base URL (e.g. for a proxy).
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.client = self.client.with_base_url(base_url);
+        self
+    }
 
-                    // Add small delay before retry
-                    if attempt < max_attempts - 1 {
-                        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    }
-                }
-            }
-        }
+    /// Generate text with retry logic.
+    async fn generate_with_retry(&self, prompt: &str) -> Result<(String, Option<TokenUsage>)> {
+        let result = retry_generation(&self.config, || {
+            self.client.generate_content_with_usage(prompt)
+        })
+        .await;
 
-        Err(last_error.unwrap_or_else(|| AtsError::ApiRequest {
-            message: "All retry attempts failed".to_string(),
-            source: None,
-        }))
+        // Cleared on failure so a failed request isn't billed the previous usage
+        *self.last_usage.lock().unwrap() = result.as_ref().ok().and_then(|(_, usage)| *usage);
+        result
     }
 }
 
@@ -646,27 +1105,39 @@ impl Agent for AnthropicAgent {
         &self.config
     }
 
+    fn last_usage(&self) -> Option<TokenUsage> {
+        *self.last_usage.lock().unwrap()
+    }
+
     async fn generate_text(&self, prompt: &str) -> Result<String> {
-        let text = self.generate_with_retry(prompt).await?;
+        self.generate_text_traced(prompt).await.map(|g| g.value)
+    }
+
+    async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+        self.generate_json_traced(prompt).await.map(|g| g.value)
+    }
+
+    async fn generate_text_traced(&self, prompt: &str) -> Result<Generation<String>> {
+        let (text, usage) = self.generate_with_retry(prompt).await?;
 
         // If require_json is set, validate it's valid JSON
         if self.config.require_json {
             let cleaned = strip_markdown_fences(&text);
             serde_json::from_str::<serde_json::Value>(&cleaned).map_err(|e| {
-                AtsError::ApiResponse {
+                AtsError::JsonParse {
                     message: format!("Response is not valid JSON: {e}"),
-                    status_code: None,
+                    source: Some(e),
                 }
             })?;
 
             // Return cleaned JSON
-            return Ok(cleaned);
+            return Ok(Generation::new(cleaned, &self.config, usage));
         }
 
-        Ok(text)
+        Ok(Generation::new(text, &self.config, usage))
     }
 
-    async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+    async fn generate_json_traced(&self, prompt: &str) -> Result<Generation<serde_json::Value>> {
         // Add JSON instruction to prompt if not already present
         let enhanced_prompt = if prompt.to_lowercase().contains("json") {
             prompt.to_string()
@@ -676,13 +1147,18 @@ impl Agent for AnthropicAgent {
             )
         };
 
-        let text = self.generate_with_retry(&enhanced_prompt).await?;
+        let (text, usage) = self.generate_with_retry(&enhanced_prompt).await?;
         let cleaned = strip_markdown_fences(&text);
 
-        serde_json::from_str(&cleaned).map_err(|e| AtsError::ApiResponse {
+        let value = serde_json::from_str(&cleaned).map_err(|e| AtsError::JsonParse {
             message: format!("Failed to parse JSON: {e}"),
-            status_code: None,
-        })
+            source: Some(e),
+        })?;
+        Ok(Generation::new(value, &self.config, usage))
+    }
+
+    async fn ping(&self) -> Result<()> {
+        self.client.ping().await
     }
 }
 
@@ -691,9 +1167,13 @@ impl Agent for AnthropicAgent {
 // -------------------------
 
 /// Llama (Ollama) based agent implementation.
+///
+/// Set `auto_pull = true` in the agent's `extras` to pull a missing model
+/// before the first request (see [`LlamaClient::ensure_model`]).
 pub struct LlamaAgent {
     config: AgentConfig,
     client: LlamaClient,
+    model_ready: tokio::sync::OnceCell<()>,
 }
 
 impl LlamaAgent {
@@ -710,10 +1190,15 @@ impl LlamaAgent {
             num_predict: Some(config.max_output_tokens),
         };
 
-        let client =
-            LlamaClient::new(&config.model_name)?.with_generation_config(generation_config);
+        let mut client = LlamaClient::new(&config.model_name)?
+            .with_generation_config(generation_config)
+            .with_system_prompt(config.system_prompt.clone())
+            .with_auto_pull(Self::auto_pull(&config));
+        if let Some(timeout) = config.request_timeout() {
+            client = client.with_timeout(timeout);
+        }
 
-        Ok(Self { config, client })
+        Ok(Self::from_client(config, client))
     }
 
     /// Create a new Llama agent with a specific host.
@@ -729,46 +1214,57 @@ impl LlamaAgent {
             num_predict: Some(config.max_output_tokens),
         };
 
-        let client = LlamaClient::new(&config.model_name)?
+        let mut client = LlamaClient::new(&config.model_name)?
             .with_host(host)
-            .with_generation_config(generation_config);
+            .with_generation_config(generation_config)
+            .with_system_prompt(config.system_prompt.clone())
+            .with_auto_pull(Self::auto_pull(&config));
+        if let Some(timeout) = config.request_timeout() {
+            client = client.with_timeout(timeout);
+        }
 
-        Ok(Self { config, client })
+        Ok(Self::from_client(config, client))
     }
 
-    /// Generate text with retry logic.
-    async fn generate_with_retry(&self, prompt: &str) -> Result<String> {
-        let mut last_error = None;
-        let max_attempts = 1 + self.config.max_retries.max(0);
-
-        for attempt in 0..max_attempts {
-            match self.client.generate_content(prompt).await {
-                Ok(text) => {
-                    if self.config.retry_on_empty && text.trim().is_empty() {
-                        last_error = Some(AtsError::ApiResponse {
-                            message: "Empty response from API".to_string(),
-                            status_code: None,
-                        });
-                        continue;
-                    }
-
-                    return Ok(text);
-                }
-                Err(e) => {
-                    last_error = Some(e);
+    fn from_client(config: AgentConfig, client: LlamaClient) -> Self {
+        Self {
+            config,
+            client,
+            model_ready: tokio::sync::OnceCell::new(),
+        }
+    }
 
-                    // Add small delay before retry
-                    if attempt < max_attempts - 1 {
-                        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    }
-                }
-            }
+    /// Whether `extras.auto_pull` is enabled.
+    fn auto_pull(config: &AgentConfig) -> bool {
+        config
+            .extras
+            .get("auto_pull")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+    }
+
+    /// Pull the model on first use when auto-pull is enabled.
+    async fn ensure_model(&self) -> Result<()> {
+        if !Self::auto_pull(&self.config) {
+            return Ok(());
         }
+        self.model_ready
+            .get_or_try_init(|| self.client.ensure_model())
+            .await?;
+        Ok(())
+    }
 
-        Err(last_error.unwrap_or_else(|| AtsError::ApiRequest {
-            message: "All retry attempts failed".to_string(),
-            source: None,
-        }))
+    /// Generate text with retry logic.
+    async fn generate_with_retry(&self, prompt: &str) -> Result<(String, Option<TokenUsage>)> {
+        self.ensure_model().await?;
+
+        retry_generation(&self.config, || async {
+            self.client
+                .generate_content(prompt)
+                .await
+                .map(|text| (text, None))
+        })
+        .await
     }
 }
 
@@ -779,26 +1275,34 @@ impl Agent for LlamaAgent {
     }
 
     async fn generate_text(&self, prompt: &str) -> Result<String> {
-        let text = self.generate_with_retry(prompt).await?;
+        self.generate_text_traced(prompt).await.map(|g| g.value)
+    }
+
+    async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+        self.generate_json_traced(prompt).await.map(|g| g.value)
+    }
+
+    async fn generate_text_traced(&self, prompt: &str) -> Result<Generation<String>> {
+        let (text, usage) = self.generate_with_retry(prompt).await?;
 
         // If require_json is set, validate it's valid JSON
         if self.config.require_json {
             let cleaned = strip_markdown_fences(&text);
             serde_json::from_str::<serde_json::Value>(&cleaned).map_err(|e| {
-                AtsError::ApiResponse {
+                AtsError::JsonParse {
                     message: format!("Response is not valid JSON: {e}"),
-                    status_code: None,
+                    source: Some(e),
                 }
             })?;
 
             // Return cleaned JSON
-            return Ok(cleaned);
+            return Ok(Generation::new(cleaned, &self.config, usage));
         }
 
-        Ok(text)
+        Ok(Generation::new(text, &self.config, usage))
     }
 
-    async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+    async fn generate_json_traced(&self, prompt: &str) -> Result<Generation<serde_json::Value>> {
         // Add JSON instruction to prompt if not already present
         let enhanced_prompt = if prompt.to_lowercase().contains("json") {
             prompt.to_string()
@@ -808,14 +1312,190 @@ impl Agent for LlamaAgent {
             )
         };
 
-        let text = self.generate_with_retry(&enhanced_prompt).await?;
+        let (text, usage) = self.generate_with_retry(&enhanced_prompt).await?;
         let cleaned = strip_markdown_fences(&text);
 
-        serde_json::from_str(&cleaned).map_err(|e| AtsError::ApiResponse {
+        let value = serde_json::from_str(&cleaned).map_err(|e| AtsError::JsonParse {
             message: format!("Failed to parse JSON: {e}"),
-            status_code: None,
+            source: Some(e),
+        })?;
+        Ok(Generation::new(value, &self.config, usage))
+    }
+
+    async fn generate_stream(&self, prompt: &str) -> Result<TextStream> {
+        self.ensure_model().await?;
+        let chunks = self.client.generate_content_stream(prompt).await?;
+        Ok(Box::pin(chunks))
+    }
+
+    async fn ping(&self) -> Result<()> {
+        self.client.ping().await
+    }
+}
+
+// -------------------------
+// Fallback Agent
+// -------------------------
+
+/// Agent that tries an ordered chain of backing agents.
+///
+/// Each request goes to the first agent; on a rate-limit, API response,
+/// timeout or connection error (quota exhausted, 5xx, empty output, provider
+/// down or hung) it moves on to the next agent.
+/// Authentication and JSON parse errors are returned immediately, since a
+/// different provider is unlikely to fix a malformed prompt.
+///
+/// The `*_traced` methods report the agent that answered each request. Only
+/// for callers without them, [`Agent::config`] reports the agent that served
+/// the most recent successful request (initially the primary agent); under
+/// concurrent requests that may be another request's agent.
+pub struct FallbackAgent {
+    agents: Vec<Box<dyn Agent>>,
+    active: std::sync::atomic::AtomicUsize,
+}
+
+impl FallbackAgent {
+    /// Create a fallback chain from agents in priority order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `agents` is empty.
+    pub fn new(agents: Vec<Box<dyn Agent>>) -> Result<Self> {
+        if agents.is_empty() {
+            return Err(AtsError::AgentConfig {
+                message: "Fallback chain requires at least one agent".to_string(),
+            });
+        }
+
+        Ok(Self {
+            agents,
+            active: std::sync::atomic::AtomicUsize::new(0),
         })
     }
+
+    /// Number of agents in the chain.
+    pub fn len(&self) -> usize {
+        self.agents.len()
+    }
+
+    /// Whether the chain is empty (never true for a constructed chain).
+    pub fn is_empty(&self) -> bool {
+        self.agents.is_empty()
+    }
+
+    /// Whether an error should move the request on to the next agent.
+    fn should_fall_back(error: &AtsError) -> bool {
+        matches!(
+            error,
+            AtsError::ApiRateLimit { .. }
+                | AtsError::ApiResponse { .. }
+                | AtsError::ApiRequest { .. }
+                | AtsError::Network { .. }
+                | AtsError::Timeout { .. }
+        )
+    }
+
+    /// Record which agent served a request, logging when it was not the primary.
+    fn mark_active(&self, index: usize) {
+        self.active
+            .store(index, std::sync::atomic::Ordering::Relaxed);
+        if index > 0 {
+            let config = self.agents[index].config();
+            log::info!(
+                "Fallback agent '{}' ({}) handled request after {} failure(s)",
+                config.name,
+                config.provider,
+                index
+            );
+        }
+    }
+
+    fn active_agent(&self) -> &dyn Agent {
+        let index = self.active.load(std::sync::atomic::Ordering::Relaxed);
+        self.agents[index].as_ref()
+    }
+}
+
+#[async_trait]
+impl Agent for FallbackAgent {
+    fn config(&self) -> &AgentConfig {
+        self.active_agent().config()
+    }
+
+    fn last_usage(&self) -> Option<TokenUsage> {
+        self.active_agent().last_usage()
+    }
+
+    async fn generate_text(&self, prompt: &str) -> Result<String> {
+        Ok(self.generate_text_traced(prompt).await?.value)
+    }
+
+    async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+        Ok(self.generate_json_traced(prompt).await?.value)
+    }
+
+    async fn generate_text_traced(&self, prompt: &str) -> Result<Generation<String>> {
+        let mut last_error = None;
+        for (index, agent) in self.agents.iter().enumerate() {
+            match agent.generate_text_traced(prompt).await {
+                Ok(generation) => {
+                    self.mark_active(index);
+                    return Ok(generation);
+                }
+                Err(e) if Self::should_fall_back(&e) => {
+                    log::warn!("Agent '{}' failed, trying next: {e}", agent.config().name);
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| AtsError::internal("Fallback chain is empty")))
+    }
+
+    async fn generate_json_traced(&self, prompt: &str) -> Result<Generation<serde_json::Value>> {
+        let mut last_error = None;
+        for (index, agent) in self.agents.iter().enumerate() {
+            match agent.generate_json_traced(prompt).await {
+                Ok(generation) => {
+                    self.mark_active(index);
+                    return Ok(generation);
+                }
+                Err(e) if Self::should_fall_back(&e) => {
+                    log::warn!("Agent '{}' failed, trying next: {e}", agent.config().name);
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| AtsError::internal("Fallback chain is empty")))
+    }
+
+    async fn generate_stream(&self, prompt: &str) -> Result<TextStream> {
+        let mut last_error = None;
+        for (index, agent) in self.agents.iter().enumerate() {
+            match agent.generate_stream(prompt).await {
+                Ok(stream) => {
+                    self.mark_active(index);
+                    return Ok(stream);
+                }
+                Err(e) if Self::should_fall_back(&e) => {
+                    log::warn!("Agent '{}' failed, trying next: {e}", agent.config().name);
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| AtsError::internal("Fallback chain is empty")))
+    }
+
+    /// Ping every agent in the chain, so a broken fallback is reported even
+    /// while the primary works. Returns the first failure.
+    async fn ping(&self) -> Result<()> {
+        for agent in &self.agents {
+            agent.ping().await?;
+        }
+        Ok(())
+    }
 }
 
 // -------------------------
@@ -840,6 +1520,41 @@ impl AgentRegistry {
         self.agents.insert(name.into(), agent);
     }
 
+    /// Register a fallback chain under a single name.
+    ///
+    /// Requests to `name` go to the first agent and move down the list on
+    /// rate-limit or API response errors. See [`FallbackAgent`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `agents` is empty.
+    pub fn register_with_fallbacks(
+        &mut self,
+        name: impl Into<String>,
+        agents: Vec<Box<dyn Agent>>,
+    ) -> Result<()> {
+        let chain = FallbackAgent::new(agents)?;
+        self.register(name, Box::new(chain));
+        Ok(())
+    }
+
+    /// Generate JSON with the named agent, falling back along its chain.
+    ///
+    /// Returns the parsed JSON together with the provider that produced it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the agent is not registered or every agent in the
+    /// chain fails.
+    pub async fn generate_json_with_fallback(
+        &self,
+        name: &str,
+        prompt: &str,
+    ) -> Result<(serde_json::Value, String)> {
+        let generation = self.get(name)?.generate_json_traced(prompt).await?;
+        Ok((generation.value, generation.provider))
+    }
+
     /// Get an agent by name.
     ///
     /// # Errors
@@ -854,6 +1569,48 @@ impl AgentRegistry {
             })
     }
 
+    /// Ping every registered agent concurrently.
+    ///
+    /// Uses the environment's [`ClientOptions`] and at most
+    /// [`DEFAULT_VERIFY_CONCURRENCY`] probes in flight; see
+    /// [`AgentRegistry::verify_all_with`].
+    pub async fn verify_all(&self) -> Vec<(String, Result<()>)> {
+        self.verify_all_with(&ClientOptions::from_env(), DEFAULT_VERIFY_CONCURRENCY)
+            .await
+    }
+
+    /// Ping every registered agent, at most `concurrency` at a time.
+    ///
+    /// Each probe is limited to `options.timeout`, falling back to the
+    /// agent's own request timeout and then [`DEFAULT_VERIFY_TIMEOUT`]; a
+    /// probe that runs over reports [`AtsError::Timeout`] without holding up
+    /// the others. Returns each agent's name with its result, sorted by name.
+    /// See [`Agent::ping`].
+    pub async fn verify_all_with(
+        &self,
+        options: &ClientOptions,
+        concurrency: usize,
+    ) -> Vec<(String, Result<()>)> {
+        let mut names: Vec<&String> = self.agents.keys().collect();
+        names.sort();
+
+        let checks = names.into_iter().map(|name| async move {
+            let agent = &self.agents[name];
+            let limit = options
+                .timeout
+                .or_else(|| agent.config().request_timeout())
+                .unwrap_or(DEFAULT_VERIFY_TIMEOUT);
+            let result = tokio::time::timeout(limit, agent.ping())
+                .await
+                .unwrap_or_else(|_| Err(AtsError::timeout(&agent.config().provider, limit)));
+            (name.clone(), result)
+        });
+        stream::iter(checks)
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
     /// List all agent names.
     pub fn list(&self) -> Vec<&str> {
         self.agents
@@ -895,24 +1652,37 @@ impl AgentRegistry {
         let mut registry = Self::new();
 
         for (name, config) in agents_config {
-            let agent: Box<dyn Agent> = match config.provider.as_str() {
-                "gemini" => Box::new(GeminiAgent::from_env(config.clone())?),
-                "openai" => Box::new(OpenAiAgent::from_env(config.clone())?),
-                "anthropic" | "claude" => Box::new(AnthropicAgent::from_env(config.clone())?),
-                "llama" | "ollama" => Box::new(LlamaAgent::new(config.clone())?),
-                other => {
-                    return Err(AtsError::NotSupported {
-                        message: format!("Provider '{other}' not supported"),
-                    })
-                }
-            };
-
-            registry.register(name.clone(), agent);
+            registry.register(name.clone(), build_agent(config)?);
         }
 
         Ok(registry)
     }
 
+    /// Create a registry from a config map, skipping agents that fail to
+    /// initialize.
+    ///
+    /// Unlike [`AgentRegistry::from_config`], one bad agent (e.g. a provider
+    /// whose API key isn't set) doesn't prevent the others from being
+    /// registered. Each failure is returned with the agent's name, sorted by
+    /// name.
+    #[must_use]
+    pub fn from_config_lenient(
+        agents_config: &HashMap<String, AgentConfig>,
+    ) -> (Self, Vec<(String, AtsError)>) {
+        let mut registry = Self::new();
+        let mut failures = Vec::new();
+
+        for (name, config) in agents_config {
+            match build_agent(config) {
+                Ok(agent) => registry.register(name.clone(), agent),
+                Err(e) => failures.push((name.clone(), e)),
+            }
+        }
+        failures.sort_by(|a, b| a.0.cmp(&b.0));
+
+        (registry, failures)
+    }
+
     /// Save agent configurations to a TOML file.
     ///
     /// # Arguments
@@ -989,6 +1759,22 @@ impl Default for AgentRegistry {
     }
 }
 
+/// Construct the agent for a config's provider.
+fn build_agent(config: &AgentConfig) -> Result<Box<dyn Agent>> {
+    Ok(match config.provider.as_str() {
+        "gemini" => Box::new(GeminiAgent::from_env(config.clone())?),
+        "openai" => Box::new(OpenAiAgent::from_env(config.clone())?),
+        "azure" => Box::new(OpenAiAgent::azure_from_env(config.clone())?),
+        "anthropic" | "claude" => Box::new(AnthropicAgent::from_env(config.clone())?),
+        "llama" | "ollama" => Box::new(LlamaAgent::new(config.clone())?),
+        other => {
+            return Err(AtsError::NotSupported {
+                message: format!("Provider '{other}' not supported"),
+            })
+        }
+    })
+}
+
 // -------------------------
 // Agent Defaults
 // -------------------------
@@ -1130,27 +1916,6 @@ impl Default for SyncAgentRegistry {
 // Utilities
 // -------------------------
 
-/// Strip markdown code fences from text.
-fn strip_markdown_fences(text: &str) -> String {
-    let trimmed = text.trim();
-
-    if trimmed.starts_with("```") && trimmed.ends_with("```") {
-        if let Some(first_newline) = trimmed.find('\n') {
-            let header = &trimmed[..first_newline];
-            if header.starts_with("```") {
-                let body_start = first_newline + 1;
-                let body_end = trimmed.len() - 3;
-                if body_start < body_end {
-                    return trimmed[body_start..body_end].trim().to_string();
-                }
-            }
-        }
-        return trimmed[3..trimmed.len() - 3].trim().to_string();
-    }
-
-    trimmed.to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1178,6 +1943,27 @@ mod tests {
         assert!(!config.require_json);
     }
 
+    #[test]
+    fn test_from_config_lenient_keeps_working_agents() {
+        let mut configs = HashMap::new();
+        configs.insert(
+            "local".to_string(),
+            AgentConfig::builder().provider("ollama").build(),
+        );
+        configs.insert(
+            "broken".to_string(),
+            AgentConfig::builder().provider("carrier_pigeon").build(),
+        );
+
+        assert!(AgentRegistry::from_config(&configs).is_err());
+
+        let (registry, failures) = AgentRegistry::from_config_lenient(&configs);
+        assert_eq!(registry.list(), vec!["local"]);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, "broken");
+        assert!(matches!(failures[0].1, AtsError::NotSupported { .. }));
+    }
+
     #[test]
     fn test_agent_registry_operations() {
         let registry = AgentRegistry::new();
@@ -1188,12 +1974,241 @@ mod tests {
         assert!(registry.get("nonexistent").is_err());
     }
 
+    struct EchoAgent {
+        config: AgentConfig,
+    }
+
+    #[async_trait]
+    impl Agent for EchoAgent {
+        fn config(&self) -> &AgentConfig {
+            &self.config
+        }
+
+        async fn generate_text(&self, prompt: &str) -> Result<String> {
+            Ok(format!("echo: {prompt}"))
+        }
+
+        async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+            Ok(serde_json::json!({ "echo": prompt }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_generate_stream_yields_single_chunk() {
+        use futures::StreamExt;
+
+        let agent = EchoAgent {
+            config: AgentConfig::default(),
+        };
+        let chunks: Vec<String> = agent
+            .generate_stream("hi")
+            .await
+            .unwrap()
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(chunks, vec!["echo: hi".to_string()]);
+    }
+
+    struct FlakyBatchAgent {
+        config: AgentConfig,
+    }
+
+    #[allow(deprecated)]
+    #[async_trait]
+    impl Agent for FlakyBatchAgent {
+        fn config(&self) -> &AgentConfig {
+            &self.config
+        }
+
+        async fn generate_text(&self, prompt: &str) -> Result<String> {
+            Ok(prompt.to_string())
+        }
+
+        async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+            // Earlier prompts finish last, so ordering can't come from timing
+            let delay = match prompt {
+                "first" => 30,
+                "second" => 20,
+                _ => 10,
+            };
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            if prompt == "second" {
+                return Err(AtsError::ApiTimeout {
+                    message: "timeout".to_string(),
+                });
+            }
+            Ok(serde_json::json!({ "prompt": prompt }))
+        }
+
+        fn batch_concurrency(&self) -> usize {
+            2
+        }
+    }
+
+    #[allow(deprecated)]
+    #[tokio::test]
+    async fn test_generate_json_batch_preserves_order() {
+        let agent = FlakyBatchAgent {
+            config: AgentConfig::default(),
+        };
+        let prompts = vec![
+            "first".to_string(),
+            "second".to_string(),
+            "third".to_string(),
+        ];
+
+        let results = agent.generate_json_batch(&prompts).await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap()["prompt"], "first");
+        assert!(matches!(results[1], Err(AtsError::ApiTimeout { .. })));
+        assert_eq!(results[2].as_ref().unwrap()["prompt"], "third");
+    }
+
+    #[test]
+    fn test_retry_policy_backoff_schedule() {
+        let policy = RetryPolicy {
+            base_delay_ms: 500,
+            max_delay_ms: 3_000,
+            multiplier: 2.0,
+            jitter: 0.0,
+        };
+
+        let schedule: Vec<u128> = (0..5).map(|n| policy.backoff(n).as_millis()).collect();
+        assert_eq!(schedule, vec![500, 1000, 2000, 3000, 3000]);
+    }
+
+    #[test]
+    fn test_retry_policy_jitter_with_fixed_seed() {
+        let policy = RetryPolicy::default();
+        let error = AtsError::ApiResponse {
+            message: "boom".to_string(),
+            status_code: Some(500),
+        };
+
+        let schedule = |seed| {
+            let mut rng = fastrand::Rng::with_seed(seed);
+            (0..6)
+                .map(|n| policy.delay_for(n, &error, &mut rng).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        let first = schedule(42);
+        assert_eq!(first, schedule(42));
+        assert_ne!(first, schedule(7));
+
+        for (n, delay) in (0u32..).zip(&first) {
+            let backoff = policy.backoff(n);
+            assert!(*delay >= backoff);
+            assert!(*delay <= backoff.mul_f64(1.0 + policy.jitter));
+        }
+    }
+
     #[test]
-    fn test_strip_markdown_fences() {
-        let input = "```json\n{\"key\": \"value\"}\n```";
-        assert_eq!(strip_markdown_fences(input), "{\"key\": \"value\"}");
+    fn test_retry_policy_honors_retry_after() {
+        let policy = RetryPolicy::default();
+        let mut rng = fastrand::Rng::with_seed(1);
+        let error = AtsError::ApiRateLimit {
+            message: "slow down".to_string(),
+            retry_after: Some(7),
+        };
+
+        assert_eq!(
+            policy.delay_for(0, &error, &mut rng),
+            Some(std::time::Duration::from_secs(7))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_beyond_max_delay_is_not_waited_for() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let policy = RetryPolicy::default();
+        let mut rng = fastrand::Rng::with_seed(1);
+        let rate_limit = || AtsError::ApiRateLimit {
+            message: "slow down".to_string(),
+            retry_after: Some(3600),
+        };
+        assert_eq!(policy.delay_for(0, &rate_limit(), &mut rng), None);
+
+        let config = AgentConfig::builder().max_retries(3).build();
+        let calls = AtomicU32::new(0);
+        let started = std::time::Instant::now();
+
+        let result = retry_generation(&config, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(rate_limit())
+        })
+        .await;
+
+        assert!(matches!(result, Err(AtsError::ApiRateLimit { .. })));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(started.elapsed() < std::time::Duration::from_secs(1));
+    }
+
+    #[allow(deprecated)]
+    #[tokio::test]
+    async fn test_retry_generation_retries_then_succeeds() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let config = AgentConfig::builder()
+            .max_retries(2)
+            .retry_policy(RetryPolicy {
+                base_delay_ms: 1,
+                max_delay_ms: 1,
+                multiplier: 1.0,
+                jitter: 0.0,
+            })
+            .build();
+        let calls = AtomicU32::new(0);
+
+        let result = retry_generation(&config, || async {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(AtsError::ApiTimeout {
+                    message: "timeout".to_string(),
+                })
+            } else {
+                Ok(("done".to_string(), None))
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(result.0, "done");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let auth_calls = AtomicU32::new(0);
+        let result = retry_generation(&config, || async {
+            auth_calls.fetch_add(1, Ordering::SeqCst);
+            Err(AtsError::ApiAuth {
+                message: "bad key".to_string(),
+            })
+        })
+        .await;
+
+        assert!(matches!(result, Err(AtsError::ApiAuth { .. })));
+        assert_eq!(auth_calls.load(Ordering::SeqCst), 1);
+    }
 
-        let input2 = "plain text";
-        assert_eq!(strip_markdown_fences(input2), "plain text");
+    #[test]
+    fn test_token_usage_accumulates() {
+        let mut total = TokenUsage::default();
+        total += TokenUsage::new(10, 5);
+        total += TokenUsage {
+            prompt_tokens: 2,
+            completion_tokens: 1,
+            total_tokens: 4,
+        };
+
+        assert_eq!(total.prompt_tokens, 12);
+        assert_eq!(total.completion_tokens, 6);
+        assert_eq!(total.total_tokens, 19);
+        assert!(EchoAgent {
+            config: AgentConfig::default()
+        }
+        .last_usage()
+        .is_none());
     }
 }
@@ -5,6 +5,7 @@
 //! # Environment Variables
 //!
 //! - `ANTHROPIC_API_KEY`: Required API key for authentication
+//! - `ANTHROPIC_BASE_URL`: Optional base URL override for Anthropic-compatible servers
 //!
 //! # Example
 //!
@@ -20,10 +21,13 @@
 //! }
 //! ```
 
+use crate::agents::TokenUsage;
 use crate::error::{AtsError, Result};
+use crate::utils::http::{base_url_from_env, build_client, retry_after, ClientOptions};
+use crate::utils::text::strip_markdown_fences;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Anthropic API base URL.
 const ANTHROPIC_API_BASE: &str = "https://api.anthropic.com/v1";
@@ -69,6 +73,8 @@ impl Default for GenerationConfig {
 #[derive(Debug, Serialize)]
 struct MessagesRequest {
     model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<String>,
     messages: Vec<Message>,
     #[serde(skip_serializing_if = "Option::is_none")]
     max_tokens: Option<i32>,
@@ -91,6 +97,23 @@ struct Message {
 #[derive(Debug, Deserialize)]
 struct MessagesResponse {
     content: Vec<ContentBlock>,
+    #[serde(default)]
+    usage: Option<UsageResponse>,
+}
+
+/// Token usage reported by the messages API.
+#[derive(Debug, Deserialize)]
+struct UsageResponse {
+    #[serde(default)]
+    input_tokens: u64,
+    #[serde(default)]
+    output_tokens: u64,
+}
+
+impl From<UsageResponse> for TokenUsage {
+    fn from(usage: UsageResponse) -> Self {
+        Self::new(usage.input_tokens, usage.output_tokens)
+    }
 }
 
 /// Content block in the response.
@@ -106,8 +129,11 @@ struct ContentBlock {
 #[derive(Debug)]
 pub struct AnthropicClient {
     api_key: String,
+    base_url: String,
     model_name: String,
     generation_config: GenerationConfig,
+    system_prompt: Option<String>,
+    timeout: Duration,
     client: Client,
 }
 
@@ -131,18 +157,15 @@ impl AnthropicClient {
             });
         }
 
-        let client = Client::builder()
-            .timeout(DEFAULT_TIMEOUT)
-            .build()
-            .map_err(|e| AtsError::ApiRequest {
-                message: format!("Failed to build HTTP client: {e}"),
-                source: Some(e),
-            })?;
+        let client = build_client(&ClientOptions::from_env(), DEFAULT_TIMEOUT)?;
 
         Ok(Self {
             api_key,
+            base_url: ANTHROPIC_API_BASE.to_string(),
             model_name: model_name.into(),
             generation_config: GenerationConfig::default(),
+            system_prompt: None,
+            timeout: DEFAULT_TIMEOUT,
             client,
         })
     }
@@ -151,26 +174,55 @@ impl AnthropicClient {
     ///
     /// # Errors
     ///
-    /// Returns an error if the `ANTHROPIC_API_KEY` environment variable is not set.
+    /// Returns an error if the `ANTHROPIC_API_KEY` environment variable is not set
+    /// or `ANTHROPIC_BASE_URL` is not a valid URL.
     pub fn from_env() -> Result<Self> {
-        let api_key = std::env::var("ANTHROPIC_API_KEY").map_err(|_| AtsError::ApiAuth {
-            message: "ANTHROPIC_API_KEY environment variable not set".to_string(),
-        })?;
-
-        Self::new(api_key, "claude-3-sonnet-20240229")
+        Self::from_env_with_model("claude-3-sonnet-20240229")
     }
 
     /// Create a client with a specific model from environment.
     ///
     /// # Errors
     ///
-    /// Returns an error if the `ANTHROPIC_API_KEY` environment variable is not set.
+    /// Returns an error if the `ANTHROPIC_API_KEY` environment variable is not set
+    /// or `ANTHROPIC_BASE_URL` is not a valid URL.
     pub fn from_env_with_model(model_name: impl Into<String>) -> Result<Self> {
         let api_key = std::env::var("ANTHROPIC_API_KEY").map_err(|_| AtsError::ApiAuth {
             message: "ANTHROPIC_API_KEY environment variable not set".to_string(),
         })?;
 
-        Self::new(api_key, model_name)
+        let client = Self::new(api_key, model_name)?;
+        Ok(match base_url_from_env("ANTHROPIC_BASE_URL")? {
+            Some(base_url) => client.with_base_url(base_url),
+            None => client,
+        })
+    }
+
+    /// Rebuild the HTTP client with explicit options (e.g. a proxy).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy URL is invalid or the client cannot be built.
+    pub fn with_client_options(mut self, options: &ClientOptions) -> Result<Self> {
+        self.client = build_client(options, DEFAULT_TIMEOUT)?;
+        if let Some(timeout) = options.timeout {
+            self.timeout = timeout;
+        }
+        Ok(self)
+    }
+
+    /// Set the request timeout.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Override the API base URL (e.g. for a proxy).
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
     }
 
     /// Set the generation configuration.
@@ -180,6 +232,13 @@ impl AnthropicClient {
         self
     }
 
+    /// Set the system prompt sent with every request.
+    #[must_use]
+    pub fn with_system_prompt(mut self, system_prompt: Option<String>) -> Self {
+        self.system_prompt = system_prompt.filter(|p| !p.trim().is_empty());
+        self
+    }
+
     /// Set the temperature.
     #[must_use]
     pub fn with_temperature(mut self, temperature: f64) -> Self {
@@ -216,6 +275,23 @@ impl AnthropicClient {
     ///
     /// Returns an error if the API request fails or the response cannot be parsed.
     pub async fn generate_content(&self, prompt: &str) -> Result<String> {
+        self.generate_content_with_usage(prompt)
+            .await
+            .map(|(text, _)| text)
+    }
+
+    /// Generate content from a text prompt, also returning token usage.
+    ///
+    /// Token counts come from the `usage` object (`input_tokens` / `output_tokens`)
+    /// of the response, when present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn generate_content_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, Option<TokenUsage>)> {
         if prompt.trim().is_empty() {
             return Err(AtsError::ApiRequest {
                 message: "Prompt cannot be empty".to_string(),
@@ -225,6 +301,7 @@ impl AnthropicClient {
 
         let request = MessagesRequest {
             model: self.model_name.clone(),
+            system: self.system_prompt.clone(),
             messages: vec![Message {
                 role: "user".to_string(),
                 content: prompt.to_string(),
@@ -235,25 +312,95 @@ impl AnthropicClient {
             top_k: self.generation_config.top_k,
         };
 
-        let url = format!("{ANTHROPIC_API_BASE}/messages");
+        let started = Instant::now();
+        let response = self.send(&request).await?;
+
+        let response_data: MessagesResponse = response.json().await.map_err(|e| {
+            if e.is_timeout() {
+                AtsError::timeout("Anthropic", started.elapsed())
+            } else {
+                AtsError::ApiResponse {
+                    message: format!("Failed to parse API response: {e}"),
+                    status_code: None,
+                }
+            }
+        })?;
+
+        // Extract text from the first content block
+        let text = response_data
+            .content
+            .first()
+            .and_then(|c| c.text.clone())
+            .ok_or_else(|| AtsError::ApiResponse {
+                message: "No text in API response".to_string(),
+                status_code: None,
+            })?;
 
+        if text.trim().is_empty() {
+            return Err(AtsError::ApiResponse {
+                message: "API returned empty response".to_string(),
+                status_code: None,
+            });
+        }
+
+        Ok((text, response_data.usage.map(TokenUsage::from)))
+    }
+
+    /// Check that the API key is accepted and the model exists.
+    ///
+    /// Sends a one-token message, so the check costs next to nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AtsError::ApiAuth`] if the key is rejected, or the API error
+    /// (e.g. a 404 for an unknown model) if the request fails.
+    pub async fn ping(&self) -> Result<()> {
+        let request = MessagesRequest {
+            model: self.model_name.clone(),
+            system: None,
+            messages: vec![Message {
+                role: "user".to_string(),
+                content: "ping".to_string(),
+            }],
+            max_tokens: Some(1),
+            temperature: None,
+            top_p: None,
+            top_k: None,
+        };
+
+        self.send(&request).await.map(|_| ())
+    }
+
+    /// Send a messages request and map HTTP error statuses.
+    async fn send(&self, request: &MessagesRequest) -> Result<reqwest::Response> {
+        let url = format!("{}/messages", self.base_url);
+
+        let started = Instant::now();
         let response = self
             .client
             .post(&url)
+            .timeout(self.timeout)
             .header("x-api-key", &self.api_key)
             .header("anthropic-version", ANTHROPIC_VERSION)
             .header("content-type", "application/json")
-            .json(&request)
+            .json(request)
             .send()
             .await
-            .map_err(|e| AtsError::ApiRequest {
-                message: format!("Failed to send request to Anthropic API: {e}"),
-                source: Some(e),
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AtsError::timeout("Anthropic", started.elapsed())
+                } else {
+                    AtsError::ApiRequest {
+                        message: format!("Failed to send request to Anthropic API: {e}"),
+                        source: Some(e),
+                    }
+                }
             })?;
 
         let status = response.status();
 
         if !status.is_success() {
+            let retry_after = retry_after(response.headers());
             let error_text = response
                 .text()
                 .await
@@ -265,7 +412,7 @@ impl AnthropicClient {
                 },
                 429 => AtsError::ApiRateLimit {
                     message: format!("Rate limit exceeded: {error_text}"),
-                    retry_after: None,
+                    retry_after,
                 },
                 _ => AtsError::ApiResponse {
                     message: format!("API error ({status}): {error_text}"),
@@ -274,30 +421,7 @@ impl AnthropicClient {
             });
         }
 
-        let response_data: MessagesResponse =
-            response.json().await.map_err(|e| AtsError::ApiResponse {
-                message: format!("Failed to parse API response: {e}"),
-                status_code: None,
-            })?;
-
-        // Extract text from the first content block
-        let text = response_data
-            .content
-            .first()
-            .and_then(|c| c.text.clone())
-            .ok_or_else(|| AtsError::ApiResponse {
-                message: "No text in API response".to_string(),
-                status_code: None,
-            })?;
-
-        if text.trim().is_empty() {
-            return Err(AtsError::ApiResponse {
-                message: "API returned empty response".to_string(),
-                status_code: None,
-            });
-        }
-
-        Ok(text)
+        Ok(response)
     }
 
     /// Generate JSON content from a prompt.
@@ -318,55 +442,10 @@ impl AnthropicClient {
     }
 }
 
-/// Strip markdown code fences from text.
-///
-/// Handles patterns like:
-/// - ` ```json\n{...}\n``` `
-/// - ` ```\n{...}\n``` `
-fn strip_markdown_fences(text: &str) -> String {
-    let trimmed = text.trim();
-
-    if trimmed.starts_with("```") && trimmed.ends_with("```") {
-        // Find the first newline after ```
-        if let Some(first_newline) = trimmed.find('\n') {
-            let header = &trimmed[..first_newline];
-
-            // Check if it's ```json or just ```
-            if header.starts_with("```") {
-                // Extract body between first newline and final ```
-                let body_start = first_newline + 1;
-                let body_end = trimmed.len() - 3;
-
-                if body_start < body_end {
-                    return trimmed[body_start..body_end].trim().to_string();
-                }
-            }
-        }
-
-        // Fallback: just strip ``` from both ends
-        return trimmed[3..trimmed.len() - 3].trim().to_string();
-    }
-
-    trimmed.to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_strip_markdown_fences() {
-        let input = "```json\n{\"key\": \"value\"}\n```";
-        let expected = "{\"key\": \"value\"}";
-        assert_eq!(strip_markdown_fences(input), expected);
-
-        let input2 = "```\n{\"key\": \"value\"}\n```";
-        assert_eq!(strip_markdown_fences(input2), expected);
-
-        let input3 = "{\"key\": \"value\"}";
-        assert_eq!(strip_markdown_fences(input3), expected);
-    }
-
     #[test]
     fn test_generation_config_default() {
         let config = GenerationConfig::default();
@@ -376,6 +455,26 @@ mod tests {
         assert_eq!(config.max_tokens, Some(4096));
     }
 
+    #[test]
+    fn test_usage_deserialization() {
+        let payload = r#"{
+            "id": "msg_01XFDUDYJgAACzvnptvVoYEL",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-3-sonnet-20240229",
+            "content": [{"type": "text", "text": "Hello!"}],
+            "stop_reason": "end_turn",
+            "stop_sequence": null,
+            "usage": {"input_tokens": 10, "output_tokens": 25}
+        }"#;
+
+        let response: MessagesResponse = serde_json::from_str(payload).unwrap();
+        let usage = response.usage.map(TokenUsage::from).unwrap();
+        assert_eq!(usage.prompt_tokens, 10);
+        assert_eq!(usage.completion_tokens, 25);
+        assert_eq!(usage.total_tokens, 35);
+    }
+
     #[test]
     fn test_anthropic_client_new_validation() {
         let result = AnthropicClient::new("", "claude-3-sonnet-20240229");
@@ -40,7 +40,7 @@ async fn main() {
     let exit_code = match cli.command {
         // Interactive mode (default)
         Some(ats_checker::cli::Commands::Interactive) | None => {
-            match interactive::run_interactive_menu(config).await {
+            match interactive::run_interactive_menu(config, cli.force).await {
                 Ok(()) => 0,
                 Err(e) => {
                     eprintln!("Error in interactive mode: {}", e);
@@ -51,7 +51,7 @@ async fn main() {
 
         // Score resume subcommand
         Some(ats_checker::cli::Commands::ScoreResume { resume, weights }) => {
-            match handlers::handle_score_resume(&resume, weights.as_deref(), &config) {
+            match handlers::handle_score_resume(&resume, weights.as_deref(), cli.format, &config) {
                 Ok(code) => code,
                 Err(e) => {
                     eprintln!("Error scoring resume: {}", e);
@@ -65,7 +65,13 @@ async fn main() {
             resume,
             job,
             weights,
-        }) => match handlers::handle_score_match(&resume, &job, weights.as_deref(), &config) {
+        }) => match handlers::handle_score_match(
+            &resume,
+            &job,
+            weights.as_deref(),
+            cli.format,
+            &config,
+        ) {
             Ok(code) => code,
             Err(e) => {
                 eprintln!("Error scoring match: {}", e);
@@ -73,9 +79,20 @@ async fn main() {
             }
         },
 
+        // Score job subcommand
+        Some(ats_checker::cli::Commands::ScoreJob { job, weights }) => {
+            match handlers::handle_score_job(&job, weights.as_deref(), cli.format, &config) {
+                Ok(code) => code,
+                Err(e) => {
+                    eprintln!("Error scoring job: {}", e);
+                    1
+                }
+            }
+        }
+
         // Rank jobs subcommand
         Some(ats_checker::cli::Commands::RankJobs { results, top }) => {
-            match handlers::handle_rank_jobs(&results, top, &config) {
+            match handlers::handle_rank_jobs(&results, top, cli.format, &config) {
                 Ok(code) => code,
                 Err(e) => {
                     eprintln!("Error ranking jobs: {}", e);
@@ -91,14 +108,16 @@ async fn main() {
             sources,
             max_results,
             remote,
+            since,
             output,
         }) => {
             match handlers::handle_job_search(
-                &keywords,
+                keywords.as_deref(),
                 location.as_deref(),
                 &sources,
                 max_results,
                 remote,
+                since,
                 output.as_deref(),
                 &config,
             )
@@ -111,6 +130,17 @@ async fn main() {
                 }
             }
         }
+
+        // Run saved searches subcommand
+        Some(ats_checker::cli::Commands::RunSavedSearches) => {
+            match handlers::handle_run_saved_searches(&config).await {
+                Ok(code) => code,
+                Err(e) => {
+                    eprintln!("Error running saved searches: {}", e);
+                    1
+                }
+            }
+        }
     };
 
     process::exit(exit_code);
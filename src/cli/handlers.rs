@@ -1,9 +1,13 @@
 //! CLI command handlers.
 
-use crate::cli::table;
+use crate::cli::{table, OutputFormat};
 use crate::config::Config;
 use crate::error::{AtsError, Result};
-use crate::scoring::{score_job, score_match, score_resume};
+use crate::scoring::{
+    compute_iteration_score, score_job_with_options, score_match_with_options,
+    score_resume_with_options, ScoreReport,
+};
+use crate::scraper::{load_search_results, DatePosted};
 use std::path::Path;
 
 // -------------------------
@@ -21,6 +25,7 @@ use std::path::Path;
 pub fn handle_score_resume(
     resume_path: &str,
     weights_path: Option<&str>,
+    format: OutputFormat,
     config: &Config,
 ) -> Result<i32> {
     log::info!("Scoring resume: {resume_path}");
@@ -56,7 +61,13 @@ pub fn handle_score_resume(
     };
 
     // Score the resume
-    let score_report = score_resume(&resume, weights.to_str())?;
+    let score_report =
+        score_resume_with_options(&resume, weights.to_str(), &config.scoring_options())?;
+
+    if format == OutputFormat::Json {
+        print_json(&score_report.as_dict())?;
+        return Ok(0);
+    }
 
     // Convert score report to JSON for table formatting
     let mut categories = serde_json::Map::new();
@@ -80,6 +91,9 @@ pub fn handle_score_resume(
     println!("RESUME SCORE REPORT");
     println!("{}", "=".repeat(60));
     println!("\n{}", table::format_resume_scores(&scores_json));
+    for sentence in score_report.explain() {
+        println!("  - {sentence}");
+    }
     println!("{}", "=".repeat(60));
 
     Ok(0)
@@ -101,6 +115,7 @@ pub fn handle_score_match(
     resume_path: &str,
     job_path: &str,
     weights_path: Option<&str>,
+    format: OutputFormat,
     config: &Config,
 ) -> Result<i32> {
     log::info!("Scoring match: {resume_path} vs {job_path}");
@@ -146,13 +161,29 @@ pub fn handle_score_match(
     });
 
     // Score resume quality
-    let resume_score = score_resume(&resume, weights.to_str())?;
+    let resume_score =
+        score_resume_with_options(&resume, weights.to_str(), &config.scoring_options())?;
 
     // Score resume-job match
-    let match_score = score_match(&resume, &job_json, weights.to_str())?;
-
-    // Calculate combined score
-    let combined_score = f64::midpoint(resume_score.total, match_score.total);
+    let match_score = score_match_with_options(
+        &resume,
+        &job_json,
+        weights.to_str(),
+        &config.scoring_options(),
+    )?;
+
+    // Calculate combined score using the configured overall weights
+    let (combined_score, _) =
+        compute_iteration_score(&resume_score, &match_score, weights.to_str());
+
+    if format == OutputFormat::Json {
+        print_json(&serde_json::json!({
+            "total": combined_score,
+            "resume": resume_score.as_dict(),
+            "match": match_score.as_dict(),
+        }))?;
+        return Ok(0);
+    }
 
     // Convert resume score to JSON
     let mut resume_categories = serde_json::Map::new();
@@ -200,6 +231,109 @@ pub fn handle_score_match(
     Ok(0)
 }
 
+// -------------------------
+// Score Job Command
+// -------------------------
+
+/// Handle the score-job subcommand.
+///
+/// JSON and TOML files are scored as structured postings. Any other file is
+/// treated as plain text: its first non-empty line is used as the title and
+/// the whole text as the description.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The job file cannot be read or parsed
+/// - The weights file cannot be loaded
+/// - Scoring fails
+pub fn handle_score_job(
+    job_path: &str,
+    weights_path: Option<&str>,
+    format: OutputFormat,
+    config: &Config,
+) -> Result<i32> {
+    log::info!("Scoring job: {job_path}");
+
+    // Determine weights file path
+    let weights = if let Some(w) = weights_path {
+        Path::new(w)
+    } else {
+        &config.scoring_weights_file
+    };
+
+    let job = load_job_value(job_path)?;
+
+    // Score the posting
+    let score_report = score_job_with_options(&job, weights.to_str(), &config.scoring_options())?;
+
+    if format == OutputFormat::Json {
+        print_json(&score_report.as_dict())?;
+        return Ok(0);
+    }
+
+    // Convert score report to JSON for table formatting
+    let mut categories = serde_json::Map::new();
+    for category in &score_report.categories {
+        categories.insert(
+            category.name.to_lowercase().replace(' ', "_"),
+            serde_json::json!({
+                "score": category.score,
+                "weight": category.weight
+            }),
+        );
+    }
+
+    let scores_json = serde_json::json!({
+        "overall": score_report.total,
+        "categories": categories
+    });
+
+    // Print results using table formatting
+    println!("\n{}", "=".repeat(60));
+    println!("JOB SCORE REPORT");
+    println!("{}", "=".repeat(60));
+    if let Some(title) = job.get("title").and_then(|v| v.as_str()) {
+        println!("\nJob: {title}");
+    }
+    println!("\n{}", table::format_resume_scores(&scores_json));
+    println!("{}", "=".repeat(60));
+
+    Ok(0)
+}
+
+/// Load a job posting as JSON from a JSON, TOML or plain text file.
+fn load_job_value(job_path: &str) -> Result<serde_json::Value> {
+    let content = std::fs::read_to_string(job_path)
+        .map_err(|e| AtsError::io("Failed to read job file".to_string(), e))?;
+
+    let extension = Path::new(job_path)
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase());
+
+    match extension.as_deref() {
+        Some("json") => serde_json::from_str(&content)
+            .map_err(|e| AtsError::internal(format!("Invalid JSON: {e}"))),
+        Some("toml") => {
+            let toml_value: toml::Value = toml::from_str(&content)
+                .map_err(|e| AtsError::config_parse(format!("Invalid TOML: {e}")))?;
+            serde_json::to_value(toml_value)
+                .map_err(|e| AtsError::internal(format!("Failed to convert TOML to JSON: {e}")))
+        }
+        _ => {
+            let title = content
+                .lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .unwrap_or_default();
+            Ok(serde_json::json!({
+                "title": title,
+                "description": content,
+            }))
+        }
+    }
+}
+
 // -------------------------
 // Rank Jobs Command
 // -------------------------
@@ -211,42 +345,51 @@ pub fn handle_score_match(
 /// Returns an error if:
 /// - The results file cannot be read or parsed
 /// - Job score calculation fails
-pub fn handle_rank_jobs(results_path: &str, top: i32, config: &Config) -> Result<i32> {
+pub fn handle_rank_jobs(
+    results_path: &str,
+    top: i32,
+    format: OutputFormat,
+    config: &Config,
+) -> Result<i32> {
     log::info!("Ranking jobs from: {results_path} (top {top})");
 
-    // Load results file
-    let results_content = std::fs::read_to_string(results_path)
-        .map_err(|e| AtsError::io("Failed to read results file".to_string(), e))?;
-
-    // Parse as TOML
-    let results_toml: toml::Value = toml::from_str(&results_content)
-        .map_err(|e| AtsError::config_parse(format!("Invalid TOML: {e}")))?;
-
-    // Extract jobs array
-    let jobs = results_toml
-        .get("jobs")
-        .and_then(|v| v.as_array())
-        .ok_or_else(|| AtsError::internal("No 'jobs' array in results file"))?;
+    // Load results file (TOML or JSON, by extension)
+    let jobs = load_search_results(results_path)?.jobs;
 
     if jobs.is_empty() {
-        println!("No jobs found in results file.");
+        if format == OutputFormat::Json {
+            print_json(&serde_json::json!([]))?;
+        } else {
+            println!("No jobs found in results file.");
+        }
         return Ok(0);
     }
 
-    // Convert to JobPosting structs and score them
-    let mut scored_jobs: Vec<(serde_json::Value, f64)> = Vec::new();
-
-    for job_value in jobs {
-        let job_json = serde_json::to_value(job_value)
-            .map_err(|e| AtsError::internal(format!("Failed to convert job: {e}")))?;
-
-        // Score the job posting
-        let score = score_job(&job_json, config.scoring_weights_file.to_str())?;
-        scored_jobs.push((job_json, score.total));
+    // Score each job posting
+    let mut scored_jobs: Vec<(serde_json::Value, ScoreReport)> = Vec::new();
+
+    for job in &jobs {
+        let job_json = serde_json::to_value(job)?;
+        let score = score_job_with_options(
+            &job_json,
+            config.scoring_weights_file.to_str(),
+            &config.scoring_options(),
+        )?;
+        scored_jobs.push((job_json, score));
     }
 
     // Sort by score descending
-    scored_jobs.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    scored_jobs.sort_by(|a, b| b.1.total.total_cmp(&a.1.total));
+
+    if format == OutputFormat::Json {
+        let ranked: Vec<serde_json::Value> = scored_jobs
+            .iter()
+            .take(usize::try_from(top).unwrap_or(0))
+            .map(|(job, score)| serde_json::json!({"job": job, "score": score.as_dict()}))
+            .collect();
+        print_json(&serde_json::json!(ranked))?;
+        return Ok(0);
+    }
 
     // Convert to format expected by table formatter
     let jobs_for_table: Vec<serde_json::Value> = scored_jobs
@@ -254,7 +397,7 @@ pub fn handle_rank_jobs(results_path: &str, top: i32, config: &Config) -> Result
         .map(|(job, score)| {
             let mut job_with_score = job.clone();
             if let Some(obj) = job_with_score.as_object_mut() {
-                obj.insert("score".to_string(), serde_json::json!(score));
+                obj.insert("score".to_string(), serde_json::json!(score.total));
             }
             job_with_score
         })
@@ -284,26 +427,62 @@ pub fn handle_rank_jobs(results_path: &str, top: i32, config: &Config) -> Result
 
 /// Handle the job search subcommand.
 ///
+/// Without `keywords`, the filters are seeded from the candidate profile in
+/// the configured `profile_file` (see [`SearchFilters::from_profile`]);
+/// `location` and `remote_only` override the profile's preferences.
+///
+/// [`SearchFilters::from_profile`]: crate::scraper::SearchFilters::from_profile
+///
 /// # Errors
 ///
 /// Returns an error if:
+/// - No keywords are given and the candidate profile is missing or names no
+///   titles or skills
 /// - `JobSpy` is not installed or Python is not available
 /// - Job search fails
 /// - Results cannot be saved
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_job_search(
-    keywords: &str,
+    keywords: Option<&str>,
     location: Option<&str>,
     sources: &[String],
     max_results: i32,
     remote_only: bool,
+    since: Option<DatePosted>,
     output_file: Option<&str>,
     config: &Config,
 ) -> Result<i32> {
-    use crate::scraper::{
-        jobspy::JobSpyScraper, CacheConfig, CacheWrapper, JobScraperManager, RetryConfig,
-        RetryWrapper, SearchFilters,
+    use crate::scraper::{JobScraperManager, ResultsFormat, SearchFilters, SearchMetadata};
+
+    // Build search filters, starting from the candidate profile if no
+    // keywords were given
+    let mut filters = if let Some(keywords) = keywords {
+        SearchFilters::builder().keywords(keywords).build()
+    } else {
+        let profile = config
+            .candidate_profile()?
+            .ok_or_else(|| AtsError::InputValidation {
+                message: "no --keywords given and no candidate profile configured \
+                          (set profile_file to a file with a [candidate] table)"
+                    .to_string(),
+            })?;
+        SearchFilters::from_profile(&profile)
+    };
+    let Some(keywords) = filters.keywords.clone() else {
+        return Err(AtsError::InputValidation {
+            message: "the candidate profile lists no desired titles or skills".to_string(),
+        });
     };
-    use std::time::Duration;
+
+    if let Some(loc) = location {
+        filters.location = Some(loc.to_string());
+    }
+    if remote_only {
+        filters.remote_only = true;
+    }
+    if let Some(since) = since {
+        filters.date_posted = Some(since.to_string());
+    }
 
     log::info!("Searching for jobs: {keywords}");
 
@@ -312,64 +491,20 @@ pub async fn handle_job_search(
     let saved_searches_path = config.output_folder.join("saved_searches.toml");
     let mut manager = JobScraperManager::new(&results_folder, &saved_searches_path)?;
 
-    // Register scrapers for each source
-    for source in sources {
-        match JobSpyScraper::new(source) {
-            Ok(scraper) => {
-                // Check dependencies
-                if let Err(e) = scraper.check_dependencies() {
-                    eprintln!("⚠️  Warning: {source} scraper unavailable: {e}");
-                    continue;
-                }
-
-                // Add retry wrapper
-                let retry_config = RetryConfig {
-                    max_retries: 3,
-                    initial_backoff: Duration::from_secs(2),
-                    max_backoff: Duration::from_secs(30),
-                    backoff_multiplier: 2.0,
-                };
-                let retry_scraper = RetryWrapper::new(scraper, retry_config);
-
-                // Add cache wrapper
-                let cache_config = CacheConfig {
-                    ttl: Duration::from_secs(1800), // 30 minutes
-                    cache_dir: Some(config.output_folder.join("cache")),
-                    persistent: true,
-                };
-                let cached_scraper = CacheWrapper::new(retry_scraper, cache_config);
-
-                manager.register_scraper(Box::new(cached_scraper));
-                println!("✓ Registered {source} scraper");
-            }
-            Err(e) => {
-                eprintln!("⚠️  Warning: Invalid source '{source}': {e}");
-            }
-        }
-    }
-
-    // Build search filters
-    let mut filters = SearchFilters::builder().keywords(keywords);
-
-    if let Some(loc) = location {
-        filters = filters.location(loc);
-    }
-
-    if remote_only {
-        filters = filters.remote_only(true);
-    }
-
-    let filters = filters.build();
+    register_job_scrapers(&mut manager, sources, config);
 
     // Search jobs
     println!("\n🔍 Searching for jobs...");
     println!("   Keywords: {keywords}");
-    if let Some(loc) = location {
+    if let Some(loc) = &filters.location {
         println!("   Location: {loc}");
     }
-    if remote_only {
+    if filters.remote_only {
         println!("   Remote only: Yes");
     }
+    if let Some(since) = since {
+        println!("   Posted: {since}");
+    }
     println!("   Sources: {}", sources.join(", "));
     println!("   Max results per source: {max_results}\n");
 
@@ -386,8 +521,9 @@ pub async fn handle_job_search(
     println!("✓ Found {} jobs\n", jobs.len());
 
     // Save results if output file specified
+    let metadata = SearchMetadata::new(filters, sources.to_vec());
     let saved_path = if let Some(output) = output_file {
-        manager.save_results(&jobs, output)?
+        manager.save_results(&jobs, output, ResultsFormat::from_path(output), &metadata)?
     } else {
         // Default filename based on keywords and timestamp
         let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
@@ -397,7 +533,7 @@ pub async fn handle_job_search(
             .collect::<String>()
             .replace(' ', "_");
         let filename = format!("jobs_{safe_keywords}_{timestamp}.toml");
-        manager.save_results(&jobs, &filename)?
+        manager.save_results(&jobs, &filename, ResultsFormat::Toml, &metadata)?
     };
 
     println!("✓ Results saved to: {}\n", saved_path.display());
@@ -428,6 +564,127 @@ pub async fn handle_job_search(
     Ok(0)
 }
 
+/// Register a retrying, caching `JobSpy` scraper for each source.
+///
+/// Sources whose scraper is unavailable are reported and skipped.
+fn register_job_scrapers(
+    manager: &mut crate::scraper::JobScraperManager,
+    sources: &[String],
+    config: &Config,
+) {
+    use crate::scraper::{
+        jobspy::JobSpyScraper, CacheConfig, CacheWrapper, RetryConfig, RetryWrapper,
+    };
+    use std::time::Duration;
+
+    for source in sources {
+        match JobSpyScraper::new(source) {
+            Ok(scraper) => {
+                // Check dependencies
+                if let Err(e) = scraper.check_dependencies() {
+                    eprintln!("⚠️  Warning: {source} scraper unavailable: {e}");
+                    continue;
+                }
+
+                // Add retry wrapper
+                let retry_config = RetryConfig {
+                    max_retries: 3,
+                    initial_backoff: Duration::from_secs(2),
+                    max_backoff: Duration::from_secs(30),
+                    backoff_multiplier: 2.0,
+                };
+                let retry_scraper = RetryWrapper::new(scraper, retry_config);
+
+                // Add cache wrapper
+                let cache_config = CacheConfig {
+                    ttl: Duration::from_secs(1800), // 30 minutes
+                    cache_dir: Some(config.output_folder.join("cache")),
+                    persistent: true,
+                };
+                let cached_scraper = CacheWrapper::new(retry_scraper, cache_config);
+
+                manager.register_scraper(Box::new(cached_scraper));
+                println!("✓ Registered {source} scraper");
+            }
+            Err(e) => {
+                eprintln!("⚠️  Warning: Invalid source '{source}': {e}");
+            }
+        }
+    }
+}
+
+/// Handle the run-saved-searches command.
+///
+/// Runs every saved search, saves each search's results to its own file and
+/// reports how many postings are new since the previous run.
+///
+/// # Errors
+///
+/// Returns an error if the saved searches cannot be loaded or results cannot be saved.
+pub async fn handle_run_saved_searches(config: &Config) -> Result<i32> {
+    use crate::scraper::{JobScraperManager, ResultsFormat, SavedSearchManager, SearchMetadata};
+
+    let results_folder = config.output_folder.join("job_searches");
+    let saved_searches_path = config.output_folder.join("saved_searches.toml");
+    let mut saved = SavedSearchManager::new(&saved_searches_path)?;
+
+    if saved.count() == 0 {
+        println!("No saved searches found.");
+        return Ok(0);
+    }
+
+    let mut manager = JobScraperManager::new(&results_folder, &saved_searches_path)?;
+    let mut sources: Vec<String> = saved
+        .list()
+        .into_iter()
+        .filter_map(|name| saved.get(name))
+        .flat_map(|search| search.sources.iter().cloned())
+        .collect();
+    sources.sort_unstable();
+    sources.dedup();
+    register_job_scrapers(&mut manager, &sources, config);
+
+    println!("\n🔍 Running {} saved searches...\n", saved.count());
+    let results = manager.run_saved_searches(&saved).await?;
+
+    let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+    for (search, jobs) in &results {
+        let new_count = jobs.iter().filter(|job| job.is_new()).count();
+        println!("{}: {} jobs ({new_count} new)", search.name, jobs.len());
+
+        if !jobs.is_empty() {
+            let safe_name = search
+                .name
+                .chars()
+                .filter(|c| c.is_alphanumeric() || *c == ' ')
+                .collect::<String>()
+                .replace(' ', "_");
+            let metadata = SearchMetadata::new(search.filters.clone(), search.sources.clone());
+            let path = manager.save_results(
+                jobs,
+                &format!("saved_{safe_name}_{timestamp}.toml"),
+                ResultsFormat::Toml,
+                &metadata,
+            )?;
+            println!("   Saved to: {}", path.display());
+        }
+
+        saved.update_last_run(&search.name)?;
+    }
+
+    Ok(0)
+}
+
+// -------------------------
+// Output Helpers
+// -------------------------
+
+/// Print a value as pretty JSON on stdout (`--format json`).
+fn print_json(value: &serde_json::Value) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
 // -------------------------
 // Tests
 // -------------------------
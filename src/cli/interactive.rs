@@ -3,16 +3,17 @@
 use crate::config::Config;
 use crate::error::Result;
 use crate::input::InputHandler;
+use crate::output::OutputIndex;
 
 use crate::processor::ResumeProcessor;
 use crate::scraper::{cache::CacheConfig, retry::RetryConfig};
 use crate::scraper::{
-    jobspy::JobSpyScraper, CacheWrapper, JobScraperManager, RetryWrapper, SavedSearch,
-    SavedSearchManager, SearchFilters,
+    jobspy::JobSpyScraper, CacheWrapper, JobScraperManager, ResultsFormat, RetryWrapper,
+    SavedSearch, SavedSearchManager, SearchFilters, SearchMetadata,
 };
 use crate::state::StateManager;
 use crate::utils::file::sanitize_filename;
-use crate::utils::ocr::{check_tesseract_installed, extract_text_from_image};
+use crate::utils::ocr::{check_tesseract_installed, extract_text_with_options, OcrOptions};
 use std::collections::VecDeque;
 use std::io::{self, Write};
 use std::path::PathBuf;
@@ -70,10 +71,13 @@ impl Default for OperationHistory {
 
 /// Run the interactive menu.
 ///
+/// With `force`, resume processing reruns resumes already recorded in the
+/// state file.
+///
 /// # Errors
 ///
 /// Returns an error if any menu operation fails (e.g., file I/O, processing errors).
-pub async fn run_interactive_menu(config: Config) -> Result<()> {
+pub async fn run_interactive_menu(config: Config, force: bool) -> Result<()> {
     // Set up Ctrl+C handler
     let running = Arc::new(AtomicBool::new(true));
     let r = running.clone();
@@ -149,7 +153,7 @@ pub async fn run_interactive_menu(config: Config) -> Result<()> {
 
         match choice {
             "1" => {
-                if let Err(e) = process_resumes_menu(&config).await {
+                if let Err(e) = process_resumes_menu(&config, force).await {
                     eprintln!("Error: {e}");
                 } else {
                     history.add("Process resumes");
@@ -186,14 +190,14 @@ pub async fn run_interactive_menu(config: Config) -> Result<()> {
                 history.add("Settings");
             }
             "8" => {
-                test_ocr_menu();
+                test_ocr_menu(&config);
                 history.add("Test OCR");
             }
             "9" => {
                 view_history(&history);
             }
             "10" => {
-                check_api_keys_menu();
+                check_api_keys_menu(&config).await;
                 history.add("Check API keys");
             }
             "11" => {
@@ -213,7 +217,7 @@ pub async fn run_interactive_menu(config: Config) -> Result<()> {
 // Process Resumes Menu
 // -------------------------
 
-async fn process_resumes_menu(config: &Config) -> Result<()> {
+async fn process_resumes_menu(config: &Config, force: bool) -> Result<()> {
     println!("\n{}", "-".repeat(60));
     println!("PROCESS RESUMES");
     println!("{}", "-".repeat(60));
@@ -231,7 +235,7 @@ async fn process_resumes_menu(config: &Config) -> Result<()> {
     io::stdin().read_line(&mut input).unwrap();
     let choice = input.trim();
 
-    let mut processor = ResumeProcessor::new(config.clone())?;
+    let mut processor = ResumeProcessor::new(config.clone())?.with_force(force);
 
     match choice {
         "1" => {
@@ -327,7 +331,8 @@ async fn select_resume_interactive(config: &Config, processor: &mut ResumeProces
     let input_handler = InputHandler::new(
         config.input_resumes_folder.clone(),
         config.job_descriptions_folder.clone(),
-    );
+    )
+    .with_recursive(config.input_recursive);
 
     let resumes = input_handler.list_resumes()?;
 
@@ -512,7 +517,12 @@ async fn new_job_search(results_folder: &PathBuf, saved_searches_path: &PathBuf)
                 // Save results
                 let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
                 let filename = format!("job_search_{timestamp}.toml");
-                let path = manager.save_results(&jobs, &filename)?;
+                let metadata = SearchMetadata::new(
+                    filters.clone(),
+                    available_scrapers.iter().map(ToString::to_string).collect(),
+                );
+                let path =
+                    manager.save_results(&jobs, &filename, ResultsFormat::Toml, &metadata)?;
                 println!("\n✓ Results saved to: {}", path.display());
 
                 // Ask if user wants to export to job descriptions
@@ -778,7 +788,8 @@ fn view_files_menu(config: &Config) {
     let input_handler = InputHandler::new(
         config.input_resumes_folder.clone(),
         config.job_descriptions_folder.clone(),
-    );
+    )
+    .with_recursive(config.input_recursive);
 
     // List resumes
     println!("\nResumes in {}:", config.input_resumes_folder.display());
@@ -895,7 +906,12 @@ fn view_state(config: &Config) -> Result<()> {
 // View Outputs Menu
 // -------------------------
 
+/// Most recent runs listed from the output index.
+const RUN_HISTORY_LIMIT: usize = 20;
+
 fn view_outputs_menu(config: &Config) {
+    print_run_history(config);
+
     println!("\n{}", "-".repeat(60));
     println!("OUTPUT DIRECTORIES");
     println!("{}", "-".repeat(60));
@@ -938,6 +954,36 @@ fn view_outputs_menu(config: &Config) {
     }
 }
 
+fn print_run_history(config: &Config) {
+    let index = match OutputIndex::load(&config.output_folder) {
+        Ok(index) => index,
+        Err(e) => {
+            eprintln!("Error reading output index: {e}");
+            return;
+        }
+    };
+    if index.runs.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "-".repeat(60));
+    println!("RUN HISTORY (most recent first)");
+    println!("{}", "-".repeat(60));
+    for run in index.runs.iter().rev().take(RUN_HISTORY_LIMIT) {
+        let score = run
+            .score
+            .map_or_else(|| "-".to_string(), |s| format!("{s:.1}"));
+        println!(
+            "  {}  {} -> {}  score {}  ({})",
+            run.timestamp,
+            run.resume_name,
+            run.job_title.as_deref().unwrap_or("no job"),
+            score,
+            run.path.display()
+        );
+    }
+}
+
 fn count_files_in_dir(path: &PathBuf) -> usize {
     std::fs::read_dir(path)
         .map(|entries| entries.filter_map(std::result::Result::ok).count())
@@ -1098,7 +1144,7 @@ fn settings_menu(config: &Config) {
 // Test OCR Menu
 // -------------------------
 
-fn test_ocr_menu() {
+fn test_ocr_menu(config: &Config) {
     println!("\n{}", "-".repeat(60));
     println!("TEST OCR (Optical Character Recognition)");
     println!("{}", "-".repeat(60));
@@ -1136,19 +1182,21 @@ fn test_ocr_menu() {
         return;
     }
 
-    print!("Enter language code (default: eng): ");
+    let mut options = OcrOptions::from_config(config);
+    print!(
+        "Enter language code, e.g. eng+deu (default: {}): ",
+        options.language.as_deref().unwrap_or("eng")
+    );
     io::stdout().flush().unwrap();
 
     let mut lang = String::new();
     io::stdin().read_line(&mut lang).unwrap();
-    let lang = if lang.trim().is_empty() {
-        None
-    } else {
-        Some(lang.trim())
-    };
+    if !lang.trim().is_empty() {
+        options = options.with_language(lang.trim());
+    }
 
     println!("\nExtracting text from image...");
-    match extract_text_from_image(&path, lang) {
+    match extract_text_with_options(&path, &options) {
         Ok(text) => {
             println!("\n{}", "=".repeat(60));
             println!("EXTRACTED TEXT");
@@ -1190,8 +1238,9 @@ fn view_history(history: &OperationHistory) {
 // Check API Keys Menu
 // -------------------------
 
-/// Check and display status of all API keys.
-fn check_api_keys_menu() {
+/// Check and display status of all API keys, optionally verifying the
+/// configured agents with a live request.
+async fn check_api_keys_menu(config: &Config) {
     println!("\n{}", "-".repeat(60));
     println!("API KEYS STATUS");
     println!("{}", "-".repeat(60));
@@ -1239,6 +1288,40 @@ fn check_api_keys_menu() {
         println!("  PowerShell:     $env:KEY_NAME=\"your_key_value\"");
         println!("\nFor permanent setup, use System Properties → Environment Variables");
     }
+
+    print!("\nVerify configured agents with a live request? (y/n): ");
+    io::stdout().flush().unwrap();
+    let mut verify = String::new();
+    io::stdin().read_line(&mut verify).unwrap();
+
+    if verify.trim().to_lowercase() == "y" {
+        verify_agents(config).await;
+    }
+}
+
+/// Ping every configured agent and report which are usable.
+async fn verify_agents(config: &Config) {
+    let processor = match ResumeProcessor::new(config.clone()) {
+        Ok(processor) => processor,
+        Err(e) => {
+            eprintln!("\n✗ Could not set up agents: {e}");
+            return;
+        }
+    };
+
+    let results = processor.agent_registry().verify_all().await;
+    if results.is_empty() {
+        println!("\nNo agents configured.");
+        return;
+    }
+
+    println!();
+    for (name, result) in &results {
+        match result {
+            Ok(()) => println!("  [✓] {name}: OK"),
+            Err(e) => println!("  [✗] {name}: {e}"),
+        }
+    }
 }
 
 // -------------------------
@@ -4,7 +4,8 @@ pub mod handlers;
 pub mod interactive;
 pub mod table;
 
-use clap::{Parser, Subcommand};
+use crate::scraper::DatePosted;
+use clap::{Parser, Subcommand, ValueEnum};
 
 /// ATS Resume Checker CLI.
 #[derive(Parser, Debug)]
@@ -26,6 +27,8 @@ use clap::{Parser, Subcommand};
     ats-checker score-resume --resume output/john_doe.json\n\n  \
     # Score resume-job match\n  \
     ats-checker score-match --resume output/resume.json --job jobs/software_engineer.txt\n\n  \
+    # Score a job posting\n  \
+    ats-checker score-job --job jobs/software_engineer.json\n\n  \
     # Rank jobs by score\n  \
     ats-checker rank-jobs --results workspace/search_results.toml --top 10\n\n\
     For more information, visit: https://github.com/your-repo/ats-checker")]
@@ -49,11 +52,41 @@ pub struct Cli {
     #[arg(help = "Suppress all non-essential output")]
     pub quiet: bool,
 
+    /// Output format of the scoring commands
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    #[arg(help = "Print scoring results as text tables or JSON")]
+    #[arg(
+        long_help = "Output format for score-resume, score-match, score-job and rank-jobs. \
+        `json` prints the score reports as JSON on stdout (for piping into jq); logs and \
+        errors still go to stderr."
+    )]
+    pub format: OutputFormat,
+
+    /// Reprocess resumes already recorded in the state file
+    #[arg(long, global = true)]
+    #[arg(help = "Reprocess resumes even if they were already processed")]
+    #[arg(
+        long_help = "Bypass the processed-resume check so resumes processed before are enhanced \
+        and scored again. State is still updated afterwards. Applies to resume processing in \
+        interactive mode."
+    )]
+    pub force: bool,
+
     /// Subcommand to run
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
 
+/// How the scoring commands print their results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable tables.
+    #[default]
+    Text,
+    /// Score reports as pretty-printed JSON.
+    Json,
+}
+
 /// Available commands.
 #[derive(Subcommand, Debug)]
 pub enum Commands {
@@ -121,6 +154,33 @@ pub enum Commands {
         weights: Option<String>,
     },
 
+    /// Score a job posting on its own
+    #[command(name = "score-job")]
+    #[command(about = "Score the quality of a single job posting")]
+    #[command(
+        long_about = "Scores a job posting independently of any resume. Provides detailed scores \
+        for completeness, clarity, compensation transparency, link quality and recency, which \
+        helps decide whether a posting is worth applying to."
+    )]
+    #[command(after_help = "EXAMPLE:\n  \
+        ats-checker score-job --job jobs/senior_engineer.json")]
+    ScoreJob {
+        /// Path to the job posting file
+        #[arg(long)]
+        #[arg(help = "Path to job posting file (JSON, TOML or text)")]
+        #[arg(
+            long_help = "Path to the job posting. JSON and TOML files are read as structured \
+            postings (title, company, description, salary, url, ...). Other files are read as \
+            plain text, using the first line as the title."
+        )]
+        job: String,
+
+        /// Path to scoring weights configuration (optional)
+        #[arg(long)]
+        #[arg(help = "Custom scoring weights file")]
+        weights: Option<String>,
+    },
+
     /// Rank and display job postings by score
     #[command(name = "rank-jobs")]
     #[command(about = "Rank jobs from a search results file by quality score")]
@@ -132,11 +192,13 @@ pub enum Commands {
     #[command(after_help = "EXAMPLE:\n  \
         ats-checker rank-jobs --results workspace/search_results.toml --top 10")]
     RankJobs {
-        /// Path to job search results file (TOML format)
+        /// Path to job search results file (TOML or JSON format)
         #[arg(long)]
-        #[arg(help = "Path to TOML file with job search results")]
-        #[arg(long_help = "Path to a TOML file containing job search results. \
-            This file should have a 'jobs' array with job posting data.")]
+        #[arg(help = "Path to TOML or JSON file with job search results")]
+        #[arg(
+            long_help = "Path to a TOML or JSON file containing job search results, \
+            as saved by job-search. The format is inferred from the file extension."
+        )]
         results: String,
 
         /// Number of top-ranked jobs to display
@@ -166,7 +228,11 @@ pub enum Commands {
         /// Search keywords (job title, skills, etc.)
         #[arg(long)]
         #[arg(help = "Keywords to search for (e.g., \"software engineer\", \"rust developer\")")]
-        keywords: String,
+        #[arg(
+            long_help = "Keywords to search for. If omitted, keywords, location and the remote \
+            preference are taken from the [candidate] table of the configured profile_file."
+        )]
+        keywords: Option<String>,
 
         /// Location filter
         #[arg(long)]
@@ -196,6 +262,13 @@ pub enum Commands {
         #[arg(help = "Filter to only remote positions")]
         remote: bool,
 
+        /// Only show jobs posted recently
+        #[arg(long)]
+        #[arg(
+            help = "Only include jobs posted within 24h, 7d or 30d, or since a date (YYYY-MM-DD)"
+        )]
+        since: Option<DatePosted>,
+
         /// Output filename (optional)
         #[arg(long)]
         #[arg(help = "Custom output filename (default: auto-generated)")]
@@ -203,4 +276,10 @@ pub enum Commands {
             If not specified, a filename will be generated based on keywords and timestamp.")]
         output: Option<String>,
     },
+
+    /// Run all saved job searches
+    #[command(after_help = "EXAMPLES:\n  \
+        # Run every saved search and flag postings not seen before\n  \
+        ats-checker run-saved-searches")]
+    RunSavedSearches,
 }
@@ -3,12 +3,18 @@
 //! This module handles loading, validating, and managing configuration
 //! from TOML files with support for profile overlays.
 
+mod profile;
+
+pub use profile::Profile;
+
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use crate::agents::RetryPolicy;
 use crate::error::{AtsError, Result};
+use crate::scoring::ScoringOptions;
 
 /// Main configuration struct for the ATS Checker.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +30,10 @@ pub struct Config {
     #[serde(default = "default_job_descriptions_folder")]
     pub job_descriptions_folder: PathBuf,
 
+    /// Scan the input folders recursively, including subfolders.
+    #[serde(default)]
+    pub input_recursive: bool,
+
     /// Folder for generated outputs.
     #[serde(default = "default_output_folder")]
     pub output_folder: PathBuf,
@@ -36,6 +46,18 @@ pub struct Config {
     #[serde(default = "default_scoring_weights_file")]
     pub scoring_weights_file: PathBuf,
 
+    /// Named profile in the scoring weights file to score with (e.g.
+    /// `"tech"` for `[profiles.tech.resume.weights]`). Unset uses the
+    /// top-level weights.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scoring_profile: Option<String>,
+
+    /// ISO 639-1 code of the scoring lexicons in use (stopwords, synonyms,
+    /// action verbs). Unset means the built-in English ones; resumes in any
+    /// other language get a warning that their scores may be inaccurate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lexicon_language: Option<String>,
+
     /// Path to saved searches file.
     #[serde(default = "default_saved_searches_file")]
     pub saved_searches_file: PathBuf,
@@ -48,6 +70,15 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tesseract_cmd: Option<String>,
 
+    /// Tesseract OCR languages, joined with `+` (e.g. `"eng+deu"`).
+    /// Tesseract's default (`eng`) is used when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tesseract_lang: Option<String>,
+
+    /// Clean up images (grayscale, upscaling, binarization) before OCR.
+    #[serde(default)]
+    pub ocr_preprocess: bool,
+
     // -------------------------
     // AI Settings
     // -------------------------
@@ -75,6 +106,19 @@ pub struct Config {
     #[serde(default = "default_max_output_tokens")]
     pub default_max_output_tokens: i32,
 
+    /// Maximum prompt length, in characters, for enhancement and revision
+    /// prompts. Over-long job descriptions and resumes are trimmed to fit;
+    /// 0 disables the limit.
+    #[serde(default = "default_max_prompt_chars")]
+    pub max_prompt_chars: usize,
+
+    /// How resumes are turned into structured JSON (`ai`, heuristic).
+    ///
+    /// `heuristic` parses the resume text locally without any API calls;
+    /// iteration is skipped in that mode.
+    #[serde(default = "default_enhancement_mode")]
+    pub enhancement_mode: String,
+
     // -------------------------
     // Processing Settings
     // -------------------------
@@ -102,6 +146,12 @@ pub struct Config {
     #[serde(default = "default_max_regressions")]
     pub max_regressions: i32,
 
+    /// Points an iteration must gain over the best score to count as an
+    /// improvement. Smaller gains still keep the better candidate but count
+    /// as no progress for the `patience` strategy. 0 counts any gain.
+    #[serde(default)]
+    pub min_improvement_delta: f64,
+
     /// Maximum concurrent API requests.
     #[serde(default = "default_max_concurrent_requests")]
     pub max_concurrent_requests: i32,
@@ -110,7 +160,7 @@ pub struct Config {
     #[serde(default)]
     pub score_cache_enabled: bool,
 
-    /// Output format (json, toml, both).
+    /// Output format (json, toml, both, pdf, docx, md, html).
     #[serde(default = "default_structured_output_format")]
     pub structured_output_format: String,
 
@@ -118,10 +168,27 @@ pub struct Config {
     #[serde(default)]
     pub schema_validation_enabled: bool,
 
+    /// How many times to re-prompt the enhancer when its output fails
+    /// schema validation.
+    #[serde(default = "default_schema_validation_max_retries")]
+    pub schema_validation_max_retries: i32,
+
+    /// Minimum enhancement confidence (0-1) required to write outputs. A
+    /// resume scoring below it is reported as failed and nothing is written.
+    /// 0 writes everything.
+    #[serde(default)]
+    pub min_enhancement_confidence: f64,
+
     /// Path to resume JSON schema.
     #[serde(default = "default_resume_schema_path")]
     pub resume_schema_path: PathBuf,
 
+    /// Optional TOML file overriding the enhancer, reviser and job summarizer
+    /// prompts (see [`PromptTemplates`](crate::processor::PromptTemplates)).
+    /// Unset uses the built-in prompts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_templates_file: Option<PathBuf>,
+
     /// Whether recommendations are enabled.
     #[serde(default = "default_recommendations_enabled")]
     pub recommendations_enabled: bool,
@@ -130,6 +197,19 @@ pub struct Config {
     #[serde(default = "default_recommendations_max_items")]
     pub recommendations_max_items: i32,
 
+    /// Whether to generate a cover letter when a job description is given.
+    #[serde(default)]
+    pub cover_letter_enabled: bool,
+
+    /// Replace contact details with placeholders before writing outputs.
+    ///
+    /// Covers the enhanced resume, the cover letter and score details, and
+    /// replaces the resume's file name with an alias in output paths, the
+    /// manifest, the run report and `index.toml`. See
+    /// [`utils::redact`](crate::utils::redact) for what stays unredacted.
+    #[serde(default)]
+    pub redact_pii: bool,
+
     /// Output subdirectory pattern.
     #[serde(default = "default_output_subdir_pattern")]
     pub output_subdir_pattern: String,
@@ -175,7 +255,8 @@ pub struct Config {
     // -------------------------
     // Profile
     // -------------------------
-    /// Optional profile file path.
+    /// Optional profile file path. The file is overlaid onto this config and
+    /// its `[candidate]` table describes the candidate (see [`Profile`]).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub profile_file: Option<PathBuf>,
 }
@@ -229,12 +310,21 @@ fn default_target_score() -> f64 {
 fn default_max_iterations() -> i32 {
     3
 }
+fn default_max_prompt_chars() -> usize {
+    100_000
+}
+fn default_enhancement_mode() -> String {
+    "ai".to_string()
+}
 fn default_iteration_strategy() -> String {
     "best_of".to_string()
 }
 fn default_max_regressions() -> i32 {
     2
 }
+fn default_schema_validation_max_retries() -> i32 {
+    2
+}
 fn default_max_concurrent_requests() -> i32 {
     1
 }
@@ -262,31 +352,44 @@ impl Default for Config {
         Self {
             input_resumes_folder: default_input_resumes_folder(),
             job_descriptions_folder: default_job_descriptions_folder(),
+            input_recursive: false,
             output_folder: default_output_folder(),
             state_file: default_state_file(),
             scoring_weights_file: default_scoring_weights_file(),
+            scoring_profile: None,
+            lexicon_language: None,
             saved_searches_file: default_saved_searches_file(),
             job_search_results_folder: default_job_search_results_folder(),
             tesseract_cmd: None,
+            tesseract_lang: None,
+            ocr_preprocess: false,
             gemini_api_key_env: default_gemini_api_key_env(),
             default_model_name: default_model_name(),
             default_temperature: default_temperature(),
             default_top_p: default_top_p(),
             default_top_k: default_top_k(),
             default_max_output_tokens: default_max_output_tokens(),
+            max_prompt_chars: default_max_prompt_chars(),
+            enhancement_mode: default_enhancement_mode(),
             num_versions_per_job: default_num_versions_per_job(),
             iterate_until_score_reached: false,
             target_score: default_target_score(),
             max_iterations: default_max_iterations(),
             iteration_strategy: default_iteration_strategy(),
             max_regressions: default_max_regressions(),
+            min_improvement_delta: 0.0,
             max_concurrent_requests: default_max_concurrent_requests(),
             score_cache_enabled: false,
             structured_output_format: default_structured_output_format(),
             schema_validation_enabled: false,
+            schema_validation_max_retries: default_schema_validation_max_retries(),
+            min_enhancement_confidence: 0.0,
             resume_schema_path: default_resume_schema_path(),
+            prompt_templates_file: None,
             recommendations_enabled: default_recommendations_enabled(),
             recommendations_max_items: default_recommendations_max_items(),
+            cover_letter_enabled: false,
+            redact_pii: false,
             output_subdir_pattern: default_output_subdir_pattern(),
             ai_agents: HashMap::new(),
             job_portals: HashMap::new(),
@@ -304,6 +407,10 @@ impl Default for Config {
 impl Config {
     /// Load configuration from a TOML file.
     ///
+    /// Both the flat layout and the sectioned one with `[paths]`,
+    /// `[processing]` and `[ai]` tables are accepted; see
+    /// [`flatten_sections`] for how table keys map onto fields.
+    ///
     /// # Errors
     ///
     /// Returns an error if the file cannot be read, parsed, or validated.
@@ -317,13 +424,13 @@ impl Config {
         }
 
         let content = std::fs::read_to_string(path)?;
-        let mut config: Config = toml::from_str(&content)?;
+        let mut config = parse_config(&content)?;
 
         // Load profile overlay if specified
         if let Some(ref profile_path) = config.profile_file {
             if profile_path.exists() {
                 let profile_content = std::fs::read_to_string(profile_path)?;
-                let profile: Config = toml::from_str(&profile_content)?;
+                let profile = parse_config(&profile_content)?;
                 config = config.merge(profile);
             }
         }
@@ -334,22 +441,95 @@ impl Config {
         Ok(config)
     }
 
+    /// Save the configuration to a TOML file.
+    ///
+    /// Paths under the file's directory are written relative to it, undoing
+    /// the expansion done by [`load`](Self::load), so the saved file stays
+    /// portable and loading it again yields an equivalent config. Other
+    /// relative paths are made absolute so they still resolve after reload.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be serialized or the file cannot
+    /// be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut config = self.clone();
+        config.relativize_paths(path.parent());
+        crate::toml_io::dump_as(&config, path)
+    }
+
     /// Merge another config into this one (overlay pattern).
+    ///
+    /// Every overlay field that differs from its default replaces the base
+    /// value. The `ai_agents` and `job_portals` maps are merged key-by-key:
+    /// within each entry only the fields the overlay changed from that entry
+    /// type's defaults are applied, so a profile can tweak one agent's
+    /// temperature without resetting its model or provider.
     #[must_use]
-    pub fn merge(mut self, overlay: Config) -> Self {
-        // Only merge non-default values
-        // This is a simplified merge - a real implementation would be more sophisticated
-        if overlay.iterate_until_score_reached {
-            self.iterate_until_score_reached = overlay.iterate_until_score_reached;
+    pub fn merge(self, overlay: Config) -> Self {
+        let to_value = |config: &Config| serde_json::to_value(config).unwrap_or_default();
+
+        let base = to_value(&self);
+        let overlay = to_value(&overlay);
+        let defaults = to_value(&Config::default());
+
+        let mut changes = overlay_changes(&overlay, &defaults).unwrap_or_default();
+        if !changes.is_object() {
+            return self;
+        }
+
+        // Keyed maps are diffed per entry against the entry type's defaults
+        let entry_defaults = [
+            ("ai_agents", entry_default::<AgentConfig>()),
+            ("job_portals", entry_default::<PortalConfig>()),
+        ];
+        for (field, entry_default) in entry_defaults {
+            let Some(entries) = overlay.get(field).and_then(|v| v.as_object()) else {
+                continue;
+            };
+            let entry_changes: serde_json::Map<String, serde_json::Value> = entries
+                .iter()
+                .map(|(name, entry)| {
+                    let diff = overlay_changes(entry, &entry_default)
+                        .unwrap_or_else(|| serde_json::json!({}));
+                    (name.clone(), diff)
+                })
+                .collect();
+            changes[field] = serde_json::Value::Object(entry_changes);
         }
-        if overlay.target_score != default_target_score() {
-            self.target_score = overlay.target_score;
+
+        let merged = crate::toml_io::merge_toml(&base, &changes);
+        match serde_json::from_value(merged) {
+            Ok(config) => config,
+            Err(e) => {
+                log::warn!("Failed to merge config overlay, keeping base config: {e}");
+                self
+            }
+        }
+    }
+
+    /// Load the candidate profile from `profile_file`.
+    ///
+    /// Returns `None` if no profile file is configured or it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile file cannot be read or parsed.
+    pub fn candidate_profile(&self) -> Result<Option<Profile>> {
+        match &self.profile_file {
+            Some(path) if path.exists() => Profile::load(path).map(Some),
+            _ => Ok(None),
         }
-        if overlay.max_iterations != default_max_iterations() {
-            self.max_iterations = overlay.max_iterations;
+    }
+
+    /// Scoring options for the configured `scoring_profile`.
+    #[must_use]
+    pub fn scoring_options(&self) -> ScoringOptions {
+        ScoringOptions {
+            profile: self.scoring_profile.clone(),
+            ..ScoringOptions::default()
         }
-        // ... additional field merging would go here
-        self
     }
 
     /// Expand relative paths to absolute paths.
@@ -370,6 +550,41 @@ impl Config {
         expand(&mut self.saved_searches_file);
         expand(&mut self.job_search_results_folder);
         expand(&mut self.resume_schema_path);
+        if let Some(path) = self.prompt_templates_file.as_mut() {
+            expand(path);
+        }
+    }
+
+    /// Rewrite paths relative to `base`, the inverse of [`expand_paths`](Self::expand_paths).
+    fn relativize_paths(&mut self, base: Option<&Path>) {
+        let base = base.unwrap_or_else(|| Path::new("."));
+        let absolute_base = std::path::absolute(base).ok();
+
+        let relativize = |p: &mut PathBuf| {
+            if let Ok(relative) = p.strip_prefix(base) {
+                *p = relative.to_path_buf();
+            } else if let Ok(absolute) = std::path::absolute(&p) {
+                match absolute_base
+                    .as_deref()
+                    .and_then(|b| absolute.strip_prefix(b).ok())
+                {
+                    Some(relative) => *p = relative.to_path_buf(),
+                    None => *p = absolute,
+                }
+            }
+        };
+
+        relativize(&mut self.input_resumes_folder);
+        relativize(&mut self.job_descriptions_folder);
+        relativize(&mut self.output_folder);
+        relativize(&mut self.state_file);
+        relativize(&mut self.scoring_weights_file);
+        relativize(&mut self.saved_searches_file);
+        relativize(&mut self.job_search_results_folder);
+        relativize(&mut self.resume_schema_path);
+        if let Some(path) = self.prompt_templates_file.as_mut() {
+            relativize(path);
+        }
     }
 
     /// Ensure all required directories exist.
@@ -411,6 +626,9 @@ impl Config {
     /// # Errors
     ///
     /// Returns an error if configuration values are invalid (e.g., invalid iteration strategy, negative values).
+    /// Problems with `ai_agents` entries (unsupported provider, empty or
+    /// mismatched model, out-of-range temperature, too few output tokens for
+    /// JSON) are collected into a single [`AtsError::ConfigInvalidValue`].
     pub fn validate(&self) -> Result<()> {
         // Validate iteration strategy
         let valid_strategies = ["best_of", "first_hit", "patience"];
@@ -421,6 +639,15 @@ impl Config {
             });
         }
 
+        // Validate enhancement mode
+        let valid_modes = ["ai", "heuristic"];
+        if !valid_modes.contains(&self.enhancement_mode.as_str()) {
+            return Err(AtsError::ConfigInvalidValue {
+                field: "enhancement_mode".to_string(),
+                message: format!("Must be one of: {}", valid_modes.join(", ")),
+            });
+        }
+
         // Validate temperature
         if !(0.0..=2.0).contains(&self.default_temperature) {
             return Err(AtsError::ConfigInvalidValue {
@@ -437,10 +664,134 @@ impl Config {
             });
         }
 
+        // Validate improvement threshold
+        if !(0.0..=100.0).contains(&self.min_improvement_delta) {
+            return Err(AtsError::ConfigInvalidValue {
+                field: "min_improvement_delta".to_string(),
+                message: "Must be between 0.0 and 100.0".to_string(),
+            });
+        }
+
+        // Validate confidence gate
+        if !(0.0..=1.0).contains(&self.min_enhancement_confidence) {
+            return Err(AtsError::ConfigInvalidValue {
+                field: "min_enhancement_confidence".to_string(),
+                message: "Must be between 0.0 and 1.0".to_string(),
+            });
+        }
+
+        // Validate OCR languages
+        if let Some(lang) = &self.tesseract_lang {
+            if crate::utils::ocr::language_codes(lang).is_none() {
+                return Err(AtsError::ConfigInvalidValue {
+                    field: "tesseract_lang".to_string(),
+                    message: "Must be Tesseract language codes joined with '+', e.g. \"eng+deu\""
+                        .to_string(),
+                });
+            }
+        }
+
+        // Validate agents, reporting every problem at once
+        let mut names: Vec<&String> = self.ai_agents.keys().collect();
+        names.sort();
+        let problems: Vec<String> = names
+            .into_iter()
+            .flat_map(|name| {
+                self.ai_agents[name]
+                    .problems()
+                    .into_iter()
+                    .map(move |problem| format!("{name}: {problem}"))
+            })
+            .collect();
+        if !problems.is_empty() {
+            return Err(AtsError::ConfigInvalidValue {
+                field: "ai_agents".to_string(),
+                message: problems.join("; "),
+            });
+        }
+
         Ok(())
     }
 }
 
+/// Tables of the sectioned config layout whose keys are [`Config`] fields.
+const SECTION_TABLES: [&str; 3] = ["paths", "processing", "ai"];
+
+/// `[ai]` keys whose [`Config`] field has a different name.
+const AI_KEY_ALIASES: [(&str, &str); 6] = [
+    ("agents", "ai_agents"),
+    ("model_name", "default_model_name"),
+    ("temperature", "default_temperature"),
+    ("top_p", "default_top_p"),
+    ("top_k", "default_top_k"),
+    ("max_output_tokens", "default_max_output_tokens"),
+];
+
+/// Parse a config file in either the flat or the sectioned layout.
+fn parse_config(content: &str) -> Result<Config> {
+    let mut doc: toml::Table = toml::from_str(content)?;
+    flatten_sections(&mut doc);
+    Ok(toml::Value::Table(doc).try_into()?)
+}
+
+/// Hoist the keys of the `[paths]`, `[processing]` and `[ai]` tables to the
+/// top level, so `[paths].output_folder` becomes `output_folder`.
+///
+/// `[ai]` keys are renamed per [`AI_KEY_ALIASES`] (e.g. `agents` becomes
+/// `ai_agents`). Keys already set at the top level take precedence over
+/// those found in a table.
+fn flatten_sections(doc: &mut toml::Table) {
+    for section in SECTION_TABLES {
+        if !doc.get(section).is_some_and(toml::Value::is_table) {
+            continue;
+        }
+        let Some(toml::Value::Table(table)) = doc.remove(section) else {
+            continue;
+        };
+        for (key, value) in table {
+            let field = if section == "ai" {
+                AI_KEY_ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == key)
+                    .map_or(key, |(_, field)| (*field).to_string())
+            } else {
+                key
+            };
+            doc.entry(field).or_insert(value);
+        }
+    }
+}
+
+/// Values in `overlay` that differ from `defaults`, recursing into objects.
+///
+/// Returns `None` when the overlay matches the defaults entirely.
+fn overlay_changes(
+    overlay: &serde_json::Value,
+    defaults: &serde_json::Value,
+) -> Option<serde_json::Value> {
+    match (overlay, defaults) {
+        (serde_json::Value::Object(overlay_map), serde_json::Value::Object(default_map)) => {
+            let changed: serde_json::Map<String, serde_json::Value> = overlay_map
+                .iter()
+                .filter_map(|(key, value)| match default_map.get(key) {
+                    Some(default) => overlay_changes(value, default).map(|v| (key.clone(), v)),
+                    None => Some((key.clone(), value.clone())),
+                })
+                .collect();
+            (!changed.is_empty()).then_some(serde_json::Value::Object(changed))
+        }
+        _ => (overlay != defaults).then(|| overlay.clone()),
+    }
+}
+
+/// Serialized defaults for a map entry type, as filled in by serde.
+fn entry_default<T: serde::de::DeserializeOwned + Serialize>() -> serde_json::Value {
+    serde_json::from_value::<T>(serde_json::json!({}))
+        .ok()
+        .and_then(|entry| serde_json::to_value(entry).ok())
+        .unwrap_or_default()
+}
+
 /// Agent configuration.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AgentConfig {
@@ -480,15 +831,97 @@ pub struct AgentConfig {
     #[serde(default = "default_true")]
     pub retry_on_empty: bool,
 
+    /// Backoff policy between retries.
+    #[serde(default)]
+    pub retry_policy: RetryPolicy,
+
     /// Require JSON output.
     #[serde(default)]
     pub require_json: bool,
 
+    /// System prompt sent with every request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_prompt: Option<String>,
+
+    /// HTTP request timeout in seconds (provider default when unset).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_timeout_secs: Option<u64>,
+
     /// Extra provider-specific options.
     #[serde(default)]
     pub extras: HashMap<String, serde_json::Value>,
 }
 
+/// Smallest `max_output_tokens` that fits a structured resume as JSON.
+const MIN_JSON_OUTPUT_TOKENS: i32 = 256;
+
+/// Model name prefixes that identify a hosted provider's models.
+const MODEL_FAMILIES: [(&str, &str); 3] = [
+    ("gemini", "gemini"),
+    ("gpt-", "openai"),
+    ("claude", "anthropic"),
+];
+
+impl AgentConfig {
+    /// Configuration problems for this agent, as human-readable messages.
+    ///
+    /// Checks that the provider is supported, the model is set and belongs to
+    /// that provider (for the hosted gemini, openai and anthropic providers),
+    /// the temperature is within the provider's range, and JSON agents have
+    /// room for a structured response.
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let provider = self.provider.to_lowercase();
+        let provider = match provider.as_str() {
+            "claude" => "anthropic",
+            "ollama" => "llama",
+            other => other,
+        };
+
+        if !crate::agents::SUPPORTED_PROVIDERS.contains(&provider) {
+            problems.push(format!(
+                "unsupported provider '{}' (expected one of: {})",
+                self.provider,
+                crate::agents::SUPPORTED_PROVIDERS.join(", ")
+            ));
+        }
+
+        let model = self.model_name.trim().to_lowercase();
+        if model.is_empty() {
+            problems.push("model_name must not be empty".to_string());
+        } else if matches!(provider, "gemini" | "openai" | "anthropic") {
+            if let Some((_, family)) = MODEL_FAMILIES
+                .iter()
+                .find(|(prefix, _)| model.starts_with(prefix))
+            {
+                if *family != provider {
+                    problems.push(format!(
+                        "model '{}' is a {family} model but provider is '{}'",
+                        self.model_name, self.provider
+                    ));
+                }
+            }
+        }
+
+        let max_temperature = if provider == "anthropic" { 1.0 } else { 2.0 };
+        if !(0.0..=max_temperature).contains(&self.temperature) {
+            problems.push(format!(
+                "temperature {} is outside 0.0-{max_temperature:.1} for provider '{}'",
+                self.temperature, self.provider
+            ));
+        }
+
+        if self.require_json && self.max_output_tokens < MIN_JSON_OUTPUT_TOKENS {
+            problems.push(format!(
+                "max_output_tokens {} is too small for JSON output (minimum {MIN_JSON_OUTPUT_TOKENS})",
+                self.max_output_tokens
+            ));
+        }
+
+        problems
+    }
+}
+
 fn default_provider() -> String {
     "gemini".to_string()
 }
@@ -540,6 +973,93 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    fn agent(provider: &str, model_name: &str, temperature: f64) -> AgentConfig {
+        AgentConfig {
+            provider: provider.to_string(),
+            model_name: model_name.to_string(),
+            temperature,
+            max_output_tokens: 4096,
+            ..AgentConfig::default()
+        }
+    }
+
+    fn agent_validation_message(agents: Vec<(&str, AgentConfig)>) -> Option<String> {
+        let config = Config {
+            ai_agents: agents
+                .into_iter()
+                .map(|(name, agent)| (name.to_string(), agent))
+                .collect(),
+            ..Config::default()
+        };
+        match config.validate() {
+            Ok(()) => None,
+            Err(AtsError::ConfigInvalidValue { field, message }) => {
+                assert_eq!(field, "ai_agents");
+                Some(message)
+            }
+            Err(other) => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn test_agent_validation_accepts_consistent_agents() {
+        assert_eq!(
+            agent_validation_message(vec![
+                ("enhancer", agent("gemini", "gemini-1.5-flash", 1.5)),
+                ("reviser", agent("claude", "claude-3-5-sonnet-latest", 1.0)),
+                ("local", agent("ollama", "llama3.2", 0.2)),
+            ]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_agent_validation_unsupported_provider() {
+        let message =
+            agent_validation_message(vec![("enhancer", agent("groq", "llama-3.3-70b", 0.7))])
+                .unwrap();
+        assert!(message.contains("enhancer: unsupported provider 'groq'"));
+    }
+
+    #[test]
+    fn test_agent_validation_anthropic_temperature_and_collects_all() {
+        let mut json_agent = agent("openai", "gemini-1.5-flash", 0.5);
+        json_agent.require_json = true;
+        json_agent.max_output_tokens = 64;
+
+        let message = agent_validation_message(vec![
+            (
+                "reviser",
+                agent("anthropic", "claude-3-5-sonnet-latest", 1.5),
+            ),
+            ("enhancer", json_agent),
+            ("summarizer", agent("openai", " ", 0.5)),
+        ])
+        .unwrap();
+
+        assert!(message.contains("reviser: temperature 1.5 is outside 0.0-1.0"));
+        assert!(message.contains("enhancer: model 'gemini-1.5-flash' is a gemini model"));
+        assert!(message.contains("enhancer: max_output_tokens 64 is too small"));
+        assert!(message.contains("summarizer: model_name must not be empty"));
+        // Sorted by agent name
+        assert!(message.starts_with("enhancer:"));
+    }
+
+    #[test]
+    fn test_tesseract_lang_validation() {
+        let mut config = Config {
+            tesseract_lang: Some("eng+deu".to_string()),
+            ..Config::default()
+        };
+        assert!(config.validate().is_ok());
+
+        config.tesseract_lang = Some("eng+".to_string());
+        match config.validate() {
+            Err(AtsError::ConfigInvalidValue { field, .. }) => assert_eq!(field, "tesseract_lang"),
+            other => panic!("Expected ConfigInvalidValue, got: {other:?}"),
+        }
+    }
+
     #[test]
     fn test_config_load_not_found() {
         let result = Config::load("/nonexistent/config.toml");
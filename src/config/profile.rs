@@ -0,0 +1,63 @@
+//! Candidate profile used to seed job searches.
+//!
+//! The profile lives in the `[candidate]` table of the configured
+//! `profile_file`, alongside any config overlay settings:
+//!
+//! ```toml
+//! [candidate]
+//! name = "Jane Doe"
+//! location = "Austin, TX"
+//! desired_titles = ["Backend Engineer", "Platform Engineer"]
+//! skills = ["Rust", "Kubernetes", "PostgreSQL"]
+//! remote_only = true
+//! ```
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AtsError, Result};
+
+/// Who the candidate is and what they are looking for.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    /// Candidate name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Preferred job location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    /// Job titles the candidate is targeting, most wanted first.
+    #[serde(default)]
+    pub desired_titles: Vec<String>,
+
+    /// Key skills, most relevant first.
+    #[serde(default)]
+    pub skills: Vec<String>,
+
+    /// Remote preference: only consider remote positions.
+    #[serde(default)]
+    pub remote_only: bool,
+}
+
+impl Profile {
+    /// Load a profile from the `[candidate]` table of a TOML file, or from
+    /// its top level if there is no such table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| AtsError::io(format!("Failed to read profile {}", path.display()), e))?;
+        let doc: toml::Value = toml::from_str(&content)?;
+
+        let table = doc.get("candidate").cloned().unwrap_or(doc);
+        table
+            .try_into()
+            .map_err(|e| AtsError::config_parse(format!("Invalid profile {}: {e}", path.display())))
+    }
+}
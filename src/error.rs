@@ -25,6 +25,7 @@
 //! ```
 
 use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type alias for ATS Checker operations.
@@ -116,6 +117,26 @@ pub enum AtsError {
         source: std::io::Error,
     },
 
+    /// Downloading a remote input file failed.
+    #[error("Failed to download {url}: {message}")]
+    Download {
+        /// URL that was being downloaded.
+        url: String,
+        /// Description of the failure.
+        message: String,
+        /// HTTP status code if the server responded.
+        status_code: Option<u16>,
+    },
+
+    /// Downloading a remote input file timed out.
+    #[error("Timed out downloading {url} after {timeout_secs}s")]
+    DownloadTimeout {
+        /// URL that was being downloaded.
+        url: String,
+        /// Timeout that elapsed, in seconds.
+        timeout_secs: u64,
+    },
+
     // -------------------------
     // Parsing Errors
     // -------------------------
@@ -218,12 +239,25 @@ pub enum AtsError {
     },
 
     /// API timeout error.
+    ///
+    /// Only produced by the generic `From<reqwest::Error>` conversion; the
+    /// provider clients report [`AtsError::Timeout`] instead.
+    #[deprecated(note = "provider clients report `AtsError::Timeout`")]
     #[error("API request timed out: {message}")]
     ApiTimeout {
         /// Description of the timeout.
         message: String,
     },
 
+    /// A request to an LLM provider exceeded its client timeout.
+    #[error("{provider} request timed out after {:.1}s", elapsed.as_secs_f64())]
+    Timeout {
+        /// Provider the request was sent to (e.g. `"OpenAI"`).
+        provider: String,
+        /// Time spent waiting before the request was abandoned.
+        elapsed: Duration,
+    },
+
     // -------------------------
     // Agent Errors
     // -------------------------
@@ -452,6 +486,14 @@ impl AtsError {
         }
     }
 
+    /// Create a new provider timeout error.
+    pub fn timeout(provider: impl Into<String>, elapsed: Duration) -> Self {
+        Self::Timeout {
+            provider: provider.into(),
+            elapsed,
+        }
+    }
+
     /// Create a new internal error.
     pub fn internal(message: impl Into<String>) -> Self {
         Self::Internal {
@@ -460,20 +502,25 @@ impl AtsError {
     }
 
     /// Check if this is a retryable error.
+    #[allow(deprecated)]
     pub fn is_retryable(&self) -> bool {
         matches!(
             self,
             Self::ApiRateLimit { .. }
                 | Self::ApiTimeout { .. }
+                | Self::Timeout { .. }
+                | Self::DownloadTimeout { .. }
                 | Self::Network { .. }
                 | Self::ScraperError { .. }
         )
     }
 
-    /// Get the HTTP status code if this is an API error.
+    /// Get the HTTP status code if this is an API or download error.
     pub fn status_code(&self) -> Option<u16> {
         match self {
-            Self::ApiResponse { status_code, .. } => *status_code,
+            Self::ApiResponse { status_code, .. } | Self::Download { status_code, .. } => {
+                *status_code
+            }
             _ => None,
         }
     }
@@ -509,6 +556,7 @@ impl From<serde_json::Error> for AtsError {
 }
 
 impl From<reqwest::Error> for AtsError {
+    #[allow(deprecated)]
     fn from(err: reqwest::Error) -> Self {
         if err.is_timeout() {
             Self::ApiTimeout {
@@ -20,10 +20,15 @@
 //! }
 //! ```
 
+use crate::agents::TokenUsage;
 use crate::error::{AtsError, Result};
+use crate::utils::http::{build_client, retry_after, ClientOptions};
+use crate::utils::stream::{response_lines, sse_data};
+use crate::utils::text::strip_markdown_fences;
+use futures::stream::{Stream, StreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Gemini API base URL.
 const GEMINI_API_BASE: &str = "https://generativelanguage.googleapis.com/v1beta/models";
@@ -67,7 +72,16 @@ impl Default for GenerationConfig {
 struct GenerateContentRequest {
     contents: Vec<Content>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    generation_config: Option<GenerationConfig>,
+    generation_config: Option<RequestGenerationConfig>,
+}
+
+/// Generation config as sent, with the optional JSON response mode.
+#[derive(Debug, Serialize)]
+struct RequestGenerationConfig {
+    #[serde(flatten)]
+    config: GenerationConfig,
+    #[serde(rename = "responseMimeType", skip_serializing_if = "Option::is_none")]
+    response_mime_type: Option<&'static str>,
 }
 
 /// Content part in a request.
@@ -86,6 +100,31 @@ struct Part {
 #[derive(Debug, Deserialize)]
 struct GenerateContentResponse {
     candidates: Vec<Candidate>,
+    #[serde(default, rename = "usageMetadata")]
+    usage: Option<UsageMetadata>,
+}
+
+/// Token usage reported in `usageMetadata`.
+#[derive(Debug, Deserialize)]
+#[allow(clippy::struct_field_names)] // mirrors the API payload
+#[serde(rename_all = "camelCase")]
+struct UsageMetadata {
+    #[serde(default)]
+    prompt_token_count: u64,
+    #[serde(default)]
+    candidates_token_count: u64,
+    #[serde(default)]
+    total_token_count: u64,
+}
+
+impl From<UsageMetadata> for TokenUsage {
+    fn from(usage: UsageMetadata) -> Self {
+        Self {
+            prompt_tokens: usage.prompt_token_count,
+            completion_tokens: usage.candidates_token_count,
+            total_tokens: usage.total_token_count,
+        }
+    }
 }
 
 /// A response candidate.
@@ -113,8 +152,12 @@ struct PartResponse {
 #[derive(Debug)]
 pub struct GeminiClient {
     api_key: String,
+    base_url: String,
     model_name: String,
     generation_config: GenerationConfig,
+    system_prompt: Option<String>,
+    json_mode: bool,
+    timeout: Duration,
     client: Client,
 }
 
@@ -138,18 +181,16 @@ impl GeminiClient {
             });
         }
 
-        let client = Client::builder()
-            .timeout(DEFAULT_TIMEOUT)
-            .build()
-            .map_err(|e| AtsError::ApiRequest {
-                message: format!("Failed to build HTTP client: {e}"),
-                source: Some(e),
-            })?;
+        let client = build_client(&ClientOptions::from_env(), DEFAULT_TIMEOUT)?;
 
         Ok(Self {
             api_key,
+            base_url: GEMINI_API_BASE.to_string(),
             model_name: model_name.into(),
             generation_config: GenerationConfig::default(),
+            system_prompt: None,
+            json_mode: false,
+            timeout: DEFAULT_TIMEOUT,
             client,
         })
     }
@@ -180,6 +221,33 @@ impl GeminiClient {
         Self::new(api_key, model_name)
     }
 
+    /// Rebuild the HTTP client with explicit options (e.g. a proxy).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy URL is invalid or the client cannot be built.
+    pub fn with_client_options(mut self, options: &ClientOptions) -> Result<Self> {
+        self.client = build_client(options, DEFAULT_TIMEOUT)?;
+        if let Some(timeout) = options.timeout {
+            self.timeout = timeout;
+        }
+        Ok(self)
+    }
+
+    /// Set the request timeout.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Override the API base URL (the `.../models` collection URL).
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
     /// Set the generation configuration.
     #[must_use]
     pub fn with_generation_config(mut self, config: GenerationConfig) -> Self {
@@ -187,6 +255,23 @@ impl GeminiClient {
         self
     }
 
+    /// Set the system prompt sent with every request.
+    #[must_use]
+    pub fn with_system_prompt(mut self, system_prompt: Option<String>) -> Self {
+        self.system_prompt = system_prompt.filter(|p| !p.trim().is_empty());
+        self
+    }
+
+    /// Ask the API for JSON output (`responseMimeType: application/json`) in
+    /// [`generate_json`](Self::generate_json) and
+    /// [`generate_json_with_usage`](Self::generate_json_with_usage); plain
+    /// text requests never use it.
+    #[must_use]
+    pub fn with_json_mode(mut self, enabled: bool) -> Self {
+        self.json_mode = enabled;
+        self
+    }
+
     /// Set the temperature.
     #[must_use]
     pub fn with_temperature(mut self, temperature: f64) -> Self {
@@ -223,6 +308,43 @@ impl GeminiClient {
     ///
     /// Returns an error if the API request fails or the response cannot be parsed.
     pub async fn generate_content(&self, prompt: &str) -> Result<String> {
+        self.generate_content_with_usage(prompt)
+            .await
+            .map(|(text, _)| text)
+    }
+
+    /// Generate content from a text prompt, also returning token usage.
+    ///
+    /// Token counts come from the `usageMetadata` object of the response, when present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn generate_content_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, Option<TokenUsage>)> {
+        self.complete(prompt, false).await
+    }
+
+    /// Generate JSON text from a prompt, also returning token usage.
+    ///
+    /// Like [`generate_content_with_usage`](Self::generate_content_with_usage),
+    /// but requests JSON output when JSON mode is enabled. The text is
+    /// returned as is, without parsing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn generate_json_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, Option<TokenUsage>)> {
+        self.complete(prompt, self.json_mode).await
+    }
+
+    /// Call `generateContent` and return the response text and usage.
+    async fn complete(&self, prompt: &str, json: bool) -> Result<(String, Option<TokenUsage>)> {
         if prompt.trim().is_empty() {
             return Err(AtsError::ApiRequest {
                 message: "Prompt cannot be empty".to_string(),
@@ -230,34 +352,182 @@ impl GeminiClient {
             });
         }
 
+        let started = Instant::now();
+        let response = self.send(prompt, "generateContent", json).await?;
+
+        let response_data: GenerateContentResponse = response.json().await.map_err(|e| {
+            if e.is_timeout() {
+                AtsError::timeout("Gemini", started.elapsed())
+            } else {
+                AtsError::ApiResponse {
+                    message: format!("Failed to parse API response: {e}"),
+                    status_code: None,
+                }
+            }
+        })?;
+
+        // Extract text from the first candidate
+        let text = response_data
+            .candidates
+            .first()
+            .and_then(|c| c.content.parts.first())
+            .map(|p| p.text.clone())
+            .ok_or_else(|| AtsError::ApiResponse {
+                message: "No text in API response".to_string(),
+                status_code: None,
+            })?;
+
+        if text.trim().is_empty() {
+            return Err(AtsError::ApiResponse {
+                message: "API returned empty response".to_string(),
+                status_code: None,
+            });
+        }
+
+        Ok((text, response_data.usage.map(TokenUsage::from)))
+    }
+
+    /// Stream generated content from a text prompt.
+    ///
+    /// Uses the `streamGenerateContent` endpoint with server-sent events and
+    /// yields the text of each candidate part as it arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the prompt is empty or the request is rejected.
+    /// Errors while reading or parsing the stream are yielded as stream items.
+    pub async fn generate_content_stream(
+        &self,
+        prompt: &str,
+    ) -> Result<impl Stream<Item = Result<String>> + Send + 'static> {
+        if prompt.trim().is_empty() {
+            return Err(AtsError::ApiRequest {
+                message: "Prompt cannot be empty".to_string(),
+                source: None,
+            });
+        }
+
+        let response = self.send(prompt, "streamGenerateContent", false).await?;
+
+        Ok(response_lines(response).filter_map(|line| async move {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            let data = sse_data(&line)?;
+            match serde_json::from_str::<GenerateContentResponse>(data) {
+                Ok(chunk) => chunk
+                    .candidates
+                    .into_iter()
+                    .next()
+                    .map(|c| {
+                        c.content
+                            .parts
+                            .into_iter()
+                            .map(|p| p.text)
+                            .collect::<String>()
+                    })
+                    .filter(|text| !text.is_empty())
+                    .map(Ok),
+                Err(e) => Some(Err(AtsError::ApiResponse {
+                    message: format!("Failed to parse streamed chunk: {e}"),
+                    status_code: None,
+                })),
+            }
+        }))
+    }
+
+    /// Check that the API key is accepted and the model exists.
+    ///
+    /// Sends a one-token `generateContent` request, so the check costs next to
+    /// nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AtsError::ApiAuth`] if the key is rejected, or the API error
+    /// (e.g. a 404 for an unknown model) if the request fails.
+    pub async fn ping(&self) -> Result<()> {
         let request = GenerateContentRequest {
             contents: vec![Content {
                 parts: vec![Part {
-                    text: prompt.to_string(),
+                    text: "ping".to_string(),
                 }],
             }],
-            generation_config: Some(self.generation_config.clone()),
+            generation_config: Some(RequestGenerationConfig {
+                config: GenerationConfig {
+                    temperature: None,
+                    top_p: None,
+                    top_k: None,
+                    max_output_tokens: Some(1),
+                },
+                response_mime_type: None,
+            }),
+        };
+
+        self.post(&request, "generateContent").await.map(|_| ())
+    }
+
+    /// Build a request for `prompt` and send it to the given model method,
+    /// asking for JSON output when `json` is set.
+    async fn send(&self, prompt: &str, method: &str, json: bool) -> Result<reqwest::Response> {
+        // Gemini gets the system prompt as a prepended instruction
+        let text = match &self.system_prompt {
+            Some(system) => format!("{system}\n\n{prompt}"),
+            None => prompt.to_string(),
+        };
+
+        let request = GenerateContentRequest {
+            contents: vec![Content {
+                parts: vec![Part { text }],
+            }],
+            generation_config: Some(RequestGenerationConfig {
+                config: self.generation_config.clone(),
+                response_mime_type: json.then_some("application/json"),
+            }),
         };
 
+        self.post(&request, method).await
+    }
+
+    /// POST a request to the given model method and map HTTP error statuses.
+    async fn post(
+        &self,
+        request: &GenerateContentRequest,
+        method: &str,
+    ) -> Result<reqwest::Response> {
+        let sse = if method == "streamGenerateContent" {
+            "alt=sse&"
+        } else {
+            ""
+        };
         let url = format!(
-            "{}/{}:generateContent?key={}",
-            GEMINI_API_BASE, self.model_name, self.api_key
+            "{}/{}:{method}?{sse}key={}",
+            self.base_url, self.model_name, self.api_key
         );
 
+        let started = Instant::now();
         let response = self
             .client
             .post(&url)
-            .json(&request)
+            .timeout(self.timeout)
+            .json(request)
             .send()
             .await
-            .map_err(|e| AtsError::ApiRequest {
-                message: format!("Failed to send request to Gemini API: {e}"),
-                source: Some(e),
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AtsError::timeout("Gemini", started.elapsed())
+                } else {
+                    AtsError::ApiRequest {
+                        message: format!("Failed to send request to Gemini API: {e}"),
+                        source: Some(e),
+                    }
+                }
             })?;
 
         let status = response.status();
 
         if !status.is_success() {
+            let retry_after = retry_after(response.headers());
             let error_text = response
                 .text()
                 .await
@@ -269,7 +539,7 @@ impl GeminiClient {
                 },
                 429 => AtsError::ApiRateLimit {
                     message: format!("Rate limit exceeded: {error_text}"),
-                    retry_after: None,
+                    retry_after,
                 },
                 _ => AtsError::ApiResponse {
                     message: format!("API error ({status}): {error_text}"),
@@ -278,31 +548,7 @@ impl GeminiClient {
             });
         }
 
-        let response_data: GenerateContentResponse =
-            response.json().await.map_err(|e| AtsError::ApiResponse {
-                message: format!("Failed to parse API response: {e}"),
-                status_code: None,
-            })?;
-
-        // Extract text from the first candidate
-        let text = response_data
-            .candidates
-            .first()
-            .and_then(|c| c.content.parts.first())
-            .map(|p| p.text.clone())
-            .ok_or_else(|| AtsError::ApiResponse {
-                message: "No text in API response".to_string(),
-                status_code: None,
-            })?;
-
-        if text.trim().is_empty() {
-            return Err(AtsError::ApiResponse {
-                message: "API returned empty response".to_string(),
-                status_code: None,
-            });
-        }
-
-        Ok(text)
+        Ok(response)
     }
 
     /// Generate JSON content from a prompt.
@@ -313,7 +559,7 @@ impl GeminiClient {
     ///
     /// Returns an error if the API request fails or the response is not valid JSON.
     pub async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
-        let text = self.generate_content(prompt).await?;
+        let (text, _) = self.generate_json_with_usage(prompt).await?;
         let cleaned = strip_markdown_fences(&text);
 
         serde_json::from_str(&cleaned).map_err(|e| AtsError::ApiResponse {
@@ -323,55 +569,10 @@ impl GeminiClient {
     }
 }
 
-/// Strip markdown code fences from text.
-///
-/// Handles patterns like:
-/// - ` ```json\n{...}\n``` `
-/// - ` ```\n{...}\n``` `
-fn strip_markdown_fences(text: &str) -> String {
-    let trimmed = text.trim();
-
-    if trimmed.starts_with("```") && trimmed.ends_with("```") {
-        // Find the first newline after ```
-        if let Some(first_newline) = trimmed.find('\n') {
-            let header = &trimmed[..first_newline];
-
-            // Check if it's ```json or just ```
-            if header.starts_with("```") {
-                // Extract body between first newline and final ```
-                let body_start = first_newline + 1;
-                let body_end = trimmed.len() - 3;
-
-                if body_start < body_end {
-                    return trimmed[body_start..body_end].trim().to_string();
-                }
-            }
-        }
-
-        // Fallback: just strip ``` from both ends
-        return trimmed[3..trimmed.len() - 3].trim().to_string();
-    }
-
-    trimmed.to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_strip_markdown_fences() {
-        let input = "```json\n{\"key\": \"value\"}\n```";
-        let expected = "{\"key\": \"value\"}";
-        assert_eq!(strip_markdown_fences(input), expected);
-
-        let input2 = "```\n{\"key\": \"value\"}\n```";
-        assert_eq!(strip_markdown_fences(input2), expected);
-
-        let input3 = "{\"key\": \"value\"}";
-        assert_eq!(strip_markdown_fences(input3), expected);
-    }
-
     #[test]
     fn test_generation_config_default() {
         let config = GenerationConfig::default();
@@ -381,6 +582,29 @@ mod tests {
         assert_eq!(config.max_output_tokens, Some(8192));
     }
 
+    #[test]
+    fn test_usage_deserialization() {
+        let payload = r#"{
+            "candidates": [{
+                "content": {"parts": [{"text": "Hello!"}], "role": "model"},
+                "finishReason": "STOP",
+                "index": 0
+            }],
+            "usageMetadata": {
+                "promptTokenCount": 8,
+                "candidatesTokenCount": 4,
+                "totalTokenCount": 12
+            },
+            "modelVersion": "gemini-1.5-flash"
+        }"#;
+
+        let response: GenerateContentResponse = serde_json::from_str(payload).unwrap();
+        let usage = response.usage.map(TokenUsage::from).unwrap();
+        assert_eq!(usage.prompt_tokens, 8);
+        assert_eq!(usage.completion_tokens, 4);
+        assert_eq!(usage.total_tokens, 12);
+    }
+
     #[test]
     fn test_gemini_client_new_validation() {
         let result = GeminiClient::new("", "gemini-1.5-flash");
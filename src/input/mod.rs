@@ -8,12 +8,15 @@ use crate::error::{AtsError, Result};
 use crate::state::StateManager;
 use crate::utils::extract::extract_text_from_file;
 use crate::utils::hash::calculate_file_hash;
+use crate::utils::http::{build_client, ClientOptions};
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use walkdir::WalkDir;
 
 /// Supported resume file extensions (including OCR image formats).
 const RESUME_EXTENSIONS: &[&str] = &[
-    "txt", "pdf", "docx", "md", "tex", "png", "jpg", "jpeg", "tiff", "tif", "bmp",
+    "txt", "pdf", "docx", "rtf", "md", "tex", "png", "jpg", "jpeg", "tiff", "tif", "bmp",
 ];
 
 /// Supported job description file extensions.
@@ -22,10 +25,15 @@ const JOB_EXTENSIONS: &[&str] = &["txt", "md"];
 /// OCR image file extensions.
 const OCR_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "tiff", "tif", "bmp"];
 
+/// Default timeout for downloading a resume from a URL.
+const DEFAULT_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Input handler for resumes and job descriptions.
 pub struct InputHandler {
     resumes_folder: PathBuf,
     jobs_folder: PathBuf,
+    download_timeout: Duration,
+    recursive: bool,
 }
 
 impl InputHandler {
@@ -39,9 +47,28 @@ impl InputHandler {
         Self {
             resumes_folder: resumes_folder.into(),
             jobs_folder: jobs_folder.into(),
+            download_timeout: DEFAULT_DOWNLOAD_TIMEOUT,
+            recursive: false,
         }
     }
 
+    /// Scan input folders recursively instead of only their top level.
+    ///
+    /// Hidden directories are skipped and symlinked directories are followed
+    /// with loop detection.
+    #[must_use]
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Set the timeout used when downloading resumes from a URL.
+    #[must_use]
+    pub fn with_download_timeout(mut self, timeout: Duration) -> Self {
+        self.download_timeout = timeout;
+        self
+    }
+
     /// List all resume files in the resumes folder.
     ///
     /// Returns paths to all files with supported resume extensions.
@@ -102,6 +129,80 @@ impl InputHandler {
         })
     }
 
+    /// Download a resume from an `http://` or `https://` URL and extract its text.
+    ///
+    /// The file format is taken from the response `Content-Type` when it is
+    /// recognised, otherwise from the URL's extension, falling back to plain
+    /// text. The body is written to a temporary file so that it goes through
+    /// the same extraction pipeline as local resumes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AtsError::DownloadTimeout`] if the download exceeds the
+    /// configured timeout, [`AtsError::Download`] if the request fails or the
+    /// server does not respond with a success status, and an extraction error
+    /// if text cannot be extracted from the downloaded file.
+    pub async fn load_resume_from_url(&self, url: &str) -> Result<String> {
+        let timeout_error = || AtsError::DownloadTimeout {
+            url: url.to_string(),
+            timeout_secs: self.download_timeout.as_secs(),
+        };
+        let request_error = |e: reqwest::Error| {
+            if e.is_timeout() {
+                timeout_error()
+            } else {
+                AtsError::Download {
+                    url: url.to_string(),
+                    message: e.to_string(),
+                    status_code: e.status().map(|s| s.as_u16()),
+                }
+            }
+        };
+
+        log::info!("Downloading resume from: {url}");
+        let client = build_client(&ClientOptions::from_env(), self.download_timeout)?;
+        let response = client.get(url).send().await.map_err(request_error)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(AtsError::Download {
+                url: url.to_string(),
+                message: format!("server responded with {status}"),
+                status_code: Some(status.as_u16()),
+            });
+        }
+
+        let extension = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(extension_for_content_type)
+            .or_else(|| extension_for_url_path(response.url().path()))
+            .unwrap_or("txt");
+        let bytes = response.bytes().await.map_err(request_error)?;
+
+        let mut file = tempfile::Builder::new()
+            .prefix("resume-")
+            .suffix(&format!(".{extension}"))
+            .tempfile()?;
+        file.write_all(&bytes)?;
+        file.flush()?;
+
+        extract_text_from_file(file.path()).map_err(|e| AtsError::TextExtraction {
+            message: format!("Failed to extract text from {url}: {e}"),
+        })
+    }
+
+    /// Check whether a resume input refers to a remote URL rather than a file.
+    pub fn is_url(input: &str) -> bool {
+        let input = input.trim_start();
+        ["http://", "https://"].iter().any(|scheme| {
+            input
+                .get(..scheme.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+        })
+    }
+
     /// Load the content of a job description file.
     ///
     /// Extracts text from the file.
@@ -397,11 +498,24 @@ impl InputHandler {
 
         let mut files = Vec::new();
 
-        for entry in WalkDir::new(dir)
-            .max_depth(1)
+        let walker = if self.recursive {
+            WalkDir::new(dir).follow_links(true)
+        } else {
+            WalkDir::new(dir).max_depth(1)
+        };
+        let entries = walker
             .into_iter()
-            .filter_map(std::result::Result::ok)
-        {
+            .filter_entry(|entry| entry.depth() == 0 || !is_hidden_dir(entry));
+
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    // Symlink loops and unreadable entries are skipped, not fatal
+                    log::warn!("Skipping entry in {}: {e}", dir.display());
+                    continue;
+                }
+            };
             let path = entry.path();
 
             if !path.is_file() {
@@ -428,6 +542,43 @@ impl Default for InputHandler {
     }
 }
 
+/// Whether a directory entry is a hidden (dot-prefixed) directory.
+fn is_hidden_dir(entry: &walkdir::DirEntry) -> bool {
+    entry.file_type().is_dir()
+        && entry
+            .file_name()
+            .to_str()
+            .is_some_and(|n| n.starts_with('.'))
+}
+
+/// Map a `Content-Type` header to a supported resume extension.
+fn extension_for_content_type(content_type: &str) -> Option<&'static str> {
+    let mime = content_type.split(';').next()?.trim().to_ascii_lowercase();
+    let extension = match mime.as_str() {
+        "text/plain" => "txt",
+        "text/markdown" | "text/x-markdown" => "md",
+        "application/x-tex" | "text/x-tex" => "tex",
+        "application/pdf" => "pdf",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => "docx",
+        "application/rtf" | "text/rtf" => "rtf",
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/tiff" => "tiff",
+        "image/bmp" => "bmp",
+        _ => return None,
+    };
+    Some(extension)
+}
+
+/// Take a supported resume extension from the last segment of a URL path.
+fn extension_for_url_path(path: &str) -> Option<&'static str> {
+    let extension = Path::new(path).extension()?.to_str()?.to_ascii_lowercase();
+    RESUME_EXTENSIONS
+        .iter()
+        .find(|supported| **supported == extension)
+        .copied()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -464,6 +615,59 @@ mod tests {
         assert!(!files.iter().any(|f| f.ends_with("ignored.csv")));
     }
 
+    #[test]
+    fn test_list_resumes_recursive() {
+        let temp_dir = TempDir::new().unwrap();
+        let resumes_path = temp_dir.path();
+        fs::create_dir_all(resumes_path.join("2024")).unwrap();
+        fs::create_dir_all(resumes_path.join(".archive")).unwrap();
+
+        fs::write(resumes_path.join("top.txt"), "Top").unwrap();
+        fs::write(resumes_path.join("2024").join("nested.pdf"), "Nested").unwrap();
+        fs::write(resumes_path.join(".archive").join("old.txt"), "Old").unwrap();
+
+        // A symlink back to the root must not cause an endless walk
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(resumes_path, resumes_path.join("2024").join("loop")).unwrap();
+
+        let flat = InputHandler::new(resumes_path, resumes_path);
+        assert_eq!(
+            flat.list_resumes().unwrap(),
+            vec![resumes_path.join("top.txt")]
+        );
+
+        let recursive = InputHandler::new(resumes_path, resumes_path).with_recursive(true);
+        assert_eq!(
+            recursive.list_resumes().unwrap(),
+            vec![
+                resumes_path.join("2024").join("nested.pdf"),
+                resumes_path.join("top.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_url() {
+        assert!(InputHandler::is_url("https://example.com/resume.pdf"));
+        assert!(InputHandler::is_url("HTTP://example.com/cv"));
+        assert!(!InputHandler::is_url("ftp://example.com/resume.pdf"));
+        assert!(!InputHandler::is_url("resumes/https.txt"));
+    }
+
+    #[test]
+    fn test_download_extension_inference() {
+        assert_eq!(
+            extension_for_content_type("application/pdf; charset=binary"),
+            Some("pdf")
+        );
+        assert_eq!(extension_for_content_type("text/plain"), Some("txt"));
+        assert_eq!(extension_for_content_type("application/octet-stream"), None);
+
+        assert_eq!(extension_for_url_path("/files/Resume.DOCX"), Some("docx"));
+        assert_eq!(extension_for_url_path("/files/resume.exe"), None);
+        assert_eq!(extension_for_url_path("/profile/resume"), None);
+    }
+
     #[test]
     fn test_get_resume_basename() {
         let handler = InputHandler::default();
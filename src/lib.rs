@@ -100,7 +100,7 @@ pub use processor::ResumeProcessor;
 pub use recommendations::Recommendation;
 pub use scoring::{score_job, score_match, score_resume, ScoreReport};
 pub use scraper::{JobPosting, JobScraperManager, SearchFilters};
-pub use state::StateManager;
+pub use state::{StateManager, SyncStateManager};
 pub use validation::ValidationResult;
 
 /// Library version
@@ -21,9 +21,13 @@
 //! ```
 
 use crate::error::{AtsError, Result};
+use crate::utils::http::{build_client, retry_after, ClientOptions};
+use crate::utils::stream::response_lines;
+use crate::utils::text::strip_markdown_fences;
+use futures::stream::{Stream, StreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Default Ollama host.
 const DEFAULT_OLLAMA_HOST: &str = "http://localhost:11434";
@@ -31,6 +35,9 @@ const DEFAULT_OLLAMA_HOST: &str = "http://localhost:11434";
 /// Default timeout for API requests (60 seconds - longer for local models).
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
 
+/// Timeout for pulling a model, which downloads several gigabytes.
+const PULL_TIMEOUT: Duration = Duration::from_hours(1);
+
 /// Generation configuration for Llama/Ollama API.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenerationConfig {
@@ -86,6 +93,9 @@ struct GenerateOptions {
 }
 
 /// Response from Ollama API.
+///
+/// With `stream: true` Ollama sends one of these per line (NDJSON), each
+/// carrying the next fragment of `response`.
 #[derive(Debug, Deserialize)]
 struct GenerateResponse {
     response: String,
@@ -93,12 +103,55 @@ struct GenerateResponse {
     done: bool,
 }
 
+/// Response from the Ollama tags API (locally pulled models).
+#[derive(Debug, Deserialize)]
+struct TagsResponse {
+    #[serde(default)]
+    models: Vec<ModelTag>,
+}
+
+/// A pulled model, named `model:tag`.
+#[derive(Debug, Deserialize)]
+struct ModelTag {
+    name: String,
+}
+
+/// Request payload for the Ollama pull API.
+#[derive(Debug, Serialize)]
+struct PullRequest {
+    model: String,
+    stream: bool,
+}
+
+/// A progress line streamed by the pull API.
+#[derive(Debug, Deserialize)]
+struct PullProgress {
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    total: Option<u64>,
+    #[serde(default)]
+    completed: Option<u64>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// Whether a pulled model named `name` satisfies `model`.
+///
+/// Ollama treats an untagged model name as `:latest`.
+fn model_matches(name: &str, model: &str) -> bool {
+    name == model || (!model.contains(':') && name == format!("{model}:latest"))
+}
+
 /// Llama/Ollama API client.
 #[derive(Debug)]
 pub struct LlamaClient {
     host: String,
     model_name: String,
     generation_config: GenerationConfig,
+    system_prompt: Option<String>,
+    timeout: Duration,
+    auto_pull: bool,
     client: Client,
 }
 
@@ -123,22 +176,39 @@ impl LlamaClient {
 
         let host = std::env::var("OLLAMA_HOST").unwrap_or_else(|_| DEFAULT_OLLAMA_HOST.to_string());
 
-        let client = Client::builder()
-            .timeout(DEFAULT_TIMEOUT)
-            .build()
-            .map_err(|e| AtsError::ApiRequest {
-                message: format!("Failed to build HTTP client: {e}"),
-                source: Some(e),
-            })?;
+        let client = build_client(&ClientOptions::from_env(), DEFAULT_TIMEOUT)?;
 
         Ok(Self {
             host,
             model_name,
             generation_config: GenerationConfig::default(),
+            system_prompt: None,
+            timeout: DEFAULT_TIMEOUT,
+            auto_pull: false,
             client,
         })
     }
 
+    /// Rebuild the HTTP client with explicit options (e.g. a proxy).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy URL is invalid or the client cannot be built.
+    pub fn with_client_options(mut self, options: &ClientOptions) -> Result<Self> {
+        self.client = build_client(options, DEFAULT_TIMEOUT)?;
+        if let Some(timeout) = options.timeout {
+            self.timeout = timeout;
+        }
+        Ok(self)
+    }
+
+    /// Set the request timeout.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     /// Create a client with a specific host.
     #[must_use]
     pub fn with_host(mut self, host: impl Into<String>) -> Self {
@@ -146,6 +216,14 @@ impl LlamaClient {
         self
     }
 
+    /// Pull the model from the Ollama library in [`LlamaClient::ensure_model`]
+    /// when it is not available locally.
+    #[must_use]
+    pub fn with_auto_pull(mut self, enabled: bool) -> Self {
+        self.auto_pull = enabled;
+        self
+    }
+
     /// Set the generation configuration.
     #[must_use]
     pub fn with_generation_config(mut self, config: GenerationConfig) -> Self {
@@ -153,6 +231,13 @@ impl LlamaClient {
         self
     }
 
+    /// Set the system prompt sent with every request.
+    #[must_use]
+    pub fn with_system_prompt(mut self, system_prompt: Option<String>) -> Self {
+        self.system_prompt = system_prompt.filter(|p| !p.trim().is_empty());
+        self
+    }
+
     /// Set the temperature.
     #[must_use]
     pub fn with_temperature(mut self, temperature: f64) -> Self {
@@ -196,6 +281,75 @@ impl LlamaClient {
             });
         }
 
+        let started = Instant::now();
+        let response = self.send(prompt, false).await?;
+
+        let response_data: GenerateResponse = response.json().await.map_err(|e| {
+            if e.is_timeout() {
+                AtsError::timeout("Ollama", started.elapsed())
+            } else {
+                AtsError::ApiResponse {
+                    message: format!("Failed to parse API response: {e}"),
+                    status_code: None,
+                }
+            }
+        })?;
+
+        let text = response_data.response;
+
+        if text.trim().is_empty() {
+            return Err(AtsError::ApiResponse {
+                message: "API returned empty response".to_string(),
+                status_code: None,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Stream generated content from a text prompt.
+    ///
+    /// Sends the request with `stream: true` and yields the `response`
+    /// fragment of each newline-delimited JSON object as it arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the prompt is empty or the request is rejected.
+    /// Errors while reading or parsing the stream are yielded as stream items.
+    pub async fn generate_content_stream(
+        &self,
+        prompt: &str,
+    ) -> Result<impl Stream<Item = Result<String>> + Send + 'static> {
+        if prompt.trim().is_empty() {
+            return Err(AtsError::ApiRequest {
+                message: "Prompt cannot be empty".to_string(),
+                source: None,
+            });
+        }
+
+        let response = self.send(prompt, true).await?;
+
+        Ok(response_lines(response).filter_map(|line| async move {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            if line.trim().is_empty() {
+                return None;
+            }
+            match serde_json::from_str::<GenerateResponse>(&line) {
+                Ok(chunk) if chunk.response.is_empty() => None,
+                Ok(chunk) => Some(Ok(chunk.response)),
+                Err(e) => Some(Err(AtsError::ApiResponse {
+                    message: format!("Failed to parse streamed chunk: {e}"),
+                    status_code: None,
+                })),
+            }
+        }))
+    }
+
+    /// Send a generate request and map HTTP error statuses.
+    async fn send(&self, prompt: &str, stream: bool) -> Result<reqwest::Response> {
         let options = if self.generation_config.temperature.is_some()
             || self.generation_config.top_p.is_some()
             || self.generation_config.top_k.is_some()
@@ -211,56 +365,238 @@ impl LlamaClient {
             None
         };
 
+        // Ollama gets the system prompt as a prepended instruction
+        let prompt = match &self.system_prompt {
+            Some(system) => format!("{system}\n\n{prompt}"),
+            None => prompt.to_string(),
+        };
+
         let request = GenerateRequest {
             model: self.model_name.clone(),
-            prompt: prompt.to_string(),
+            prompt,
             options,
-            stream: false,
+            stream,
         };
 
         let url = format!("{}/api/generate", self.host);
 
+        let started = Instant::now();
         let response = self
             .client
             .post(&url)
+            .timeout(self.timeout)
             .json(&request)
             .send()
             .await
-            .map_err(|e| AtsError::ApiRequest {
-                message: format!("Failed to send request to Ollama API: {e}"),
-                source: Some(e),
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AtsError::timeout("Ollama", started.elapsed())
+                } else {
+                    AtsError::ApiRequest {
+                        message: format!("Failed to send request to Ollama API: {e}"),
+                        source: Some(e),
+                    }
+                }
             })?;
 
         let status = response.status();
 
         if !status.is_success() {
+            let retry_after = retry_after(response.headers());
             let error_text = response
                 .text()
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
 
+            return Err(match status.as_u16() {
+                429 => AtsError::ApiRateLimit {
+                    message: format!("Rate limit exceeded: {error_text}"),
+                    retry_after,
+                },
+                _ => AtsError::ApiResponse {
+                    message: format!("API error ({status}): {error_text}"),
+                    status_code: Some(status.as_u16()),
+                },
+            });
+        }
+
+        Ok(response)
+    }
+
+    /// Check that the Ollama server is reachable and the model is pulled.
+    ///
+    /// Lists local models via `/api/tags` rather than generating, so the
+    /// model is not loaded into memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be reached, or
+    /// [`AtsError::AgentConfig`] if the model has not been pulled.
+    pub async fn ping(&self) -> Result<()> {
+        if self.has_model().await? {
+            Ok(())
+        } else {
+            Err(self.model_missing_error())
+        }
+    }
+
+    /// Make sure the model is available locally, pulling it if it is missing
+    /// and auto-pull is enabled (see [`LlamaClient::with_auto_pull`]).
+    ///
+    /// Pull progress is logged at info level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be reached, the pull fails, or
+    /// the model is missing and auto-pull is disabled.
+    pub async fn ensure_model(&self) -> Result<()> {
+        if self.has_model().await? {
+            return Ok(());
+        }
+        if !self.auto_pull {
+            return Err(self.model_missing_error());
+        }
+
+        self.pull_model().await
+    }
+
+    /// Whether the model is among the locally pulled models.
+    async fn has_model(&self) -> Result<bool> {
+        let url = format!("{}/api/tags", self.host);
+
+        let started = Instant::now();
+        let response = self
+            .client
+            .get(&url)
+            .timeout(self.timeout)
+            .send()
+            .await
+            .map_err(|e| self.unreachable_error(e, started))?;
+
+        let status = response.status();
+        if !status.is_success() {
             return Err(AtsError::ApiResponse {
-                message: format!("API error ({status}): {error_text}"),
+                message: format!("Failed to list Ollama models ({status})"),
                 status_code: Some(status.as_u16()),
             });
         }
 
-        let response_data: GenerateResponse =
-            response.json().await.map_err(|e| AtsError::ApiResponse {
-                message: format!("Failed to parse API response: {e}"),
-                status_code: None,
-            })?;
+        let tags: TagsResponse = response.json().await.map_err(|e| {
+            if e.is_timeout() {
+                AtsError::timeout("Ollama", started.elapsed())
+            } else {
+                AtsError::ApiResponse {
+                    message: format!("Failed to parse Ollama model list: {e}"),
+                    status_code: None,
+                }
+            }
+        })?;
 
-        let text = response_data.response;
+        Ok(tags
+            .models
+            .iter()
+            .any(|m| model_matches(&m.name, &self.model_name)))
+    }
 
-        if text.trim().is_empty() {
+    /// Pull the model via `/api/pull`, following the streamed progress.
+    async fn pull_model(&self) -> Result<()> {
+        log::info!("Pulling Ollama model '{}'", self.model_name);
+
+        let url = format!("{}/api/pull", self.host);
+        let request = PullRequest {
+            model: self.model_name.clone(),
+            stream: true,
+        };
+
+        let started = Instant::now();
+        // Pulls download gigabytes, so the generate timeout would cut them off
+        let response = self
+            .client
+            .post(&url)
+            .timeout(PULL_TIMEOUT)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| self.unreachable_error(e, started))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
             return Err(AtsError::ApiResponse {
-                message: "API returned empty response".to_string(),
-                status_code: None,
+                message: format!(
+                    "Failed to pull model '{}' ({status}): {error_text}",
+                    self.model_name
+                ),
+                status_code: Some(status.as_u16()),
             });
         }
 
-        Ok(text)
+        let mut lines = Box::pin(response_lines(response));
+        let mut last_status = String::new();
+        while let Some(line) = lines.next().await {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let progress: PullProgress =
+                serde_json::from_str(&line).map_err(|e| AtsError::ApiResponse {
+                    message: format!("Failed to parse pull progress: {e}"),
+                    status_code: None,
+                })?;
+
+            if let Some(error) = progress.error {
+                return Err(AtsError::ApiResponse {
+                    message: format!("Failed to pull model '{}': {error}", self.model_name),
+                    status_code: None,
+                });
+            }
+            if let (Some(completed), Some(total)) = (progress.completed, progress.total) {
+                if let Some(percent) = completed.saturating_mul(100).checked_div(total) {
+                    log::debug!("{}: {percent}%", progress.status);
+                }
+            }
+            if progress.status != last_status {
+                log::info!("Ollama pull '{}': {}", self.model_name, progress.status);
+                last_status = progress.status;
+            }
+        }
+
+        if last_status == "success" {
+            Ok(())
+        } else {
+            Err(AtsError::ApiResponse {
+                message: format!(
+                    "Pull of model '{}' ended without success (last status: '{last_status}')",
+                    self.model_name
+                ),
+                status_code: None,
+            })
+        }
+    }
+
+    fn model_missing_error(&self) -> AtsError {
+        AtsError::AgentConfig {
+            message: format!(
+                "Model '{0}' is not pulled; run `ollama pull {0}` or enable auto_pull",
+                self.model_name
+            ),
+        }
+    }
+
+    fn unreachable_error(&self, e: reqwest::Error, started: Instant) -> AtsError {
+        if e.is_timeout() {
+            return AtsError::timeout("Ollama", started.elapsed());
+        }
+        AtsError::ApiRequest {
+            message: format!(
+                "Ollama is not reachable at {} (is `ollama serve` running?): {e}",
+                self.host
+            ),
+            source: Some(e),
+        }
     }
 
     /// Generate JSON content from a prompt.
@@ -281,55 +617,10 @@ impl LlamaClient {
     }
 }
 
-/// Strip markdown code fences from text.
-///
-/// Handles patterns like:
-/// - ` ```json\n{...}\n``` `
-/// - ` ```\n{...}\n``` `
-fn strip_markdown_fences(text: &str) -> String {
-    let trimmed = text.trim();
-
-    if trimmed.starts_with("```") && trimmed.ends_with("```") {
-        // Find the first newline after ```
-        if let Some(first_newline) = trimmed.find('\n') {
-            let header = &trimmed[..first_newline];
-
-            // Check if it's ```json or just ```
-            if header.starts_with("```") {
-                // Extract body between first newline and final ```
-                let body_start = first_newline + 1;
-                let body_end = trimmed.len() - 3;
-
-                if body_start < body_end {
-                    return trimmed[body_start..body_end].trim().to_string();
-                }
-            }
-        }
-
-        // Fallback: just strip ``` from both ends
-        return trimmed[3..trimmed.len() - 3].trim().to_string();
-    }
-
-    trimmed.to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_strip_markdown_fences() {
-        let input = "```json\n{\"key\": \"value\"}\n```";
-        let expected = "{\"key\": \"value\"}";
-        assert_eq!(strip_markdown_fences(input), expected);
-
-        let input2 = "```\n{\"key\": \"value\"}\n```";
-        assert_eq!(strip_markdown_fences(input2), expected);
-
-        let input3 = "{\"key\": \"value\"}";
-        assert_eq!(strip_markdown_fences(input3), expected);
-    }
-
     #[test]
     fn test_generation_config_default() {
         let config = GenerationConfig::default();
@@ -339,6 +630,14 @@ mod tests {
         assert_eq!(config.num_predict, Some(4096));
     }
 
+    #[test]
+    fn test_model_matches() {
+        assert!(model_matches("llama3.2:latest", "llama3.2"));
+        assert!(model_matches("llama3.2:1b", "llama3.2:1b"));
+        assert!(!model_matches("llama3.2:1b", "llama3.2"));
+        assert!(!model_matches("llama3.2:latest", "llama3"));
+    }
+
     #[test]
     fn test_llama_client_new_validation() {
         let result = LlamaClient::new("");
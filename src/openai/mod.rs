@@ -1,10 +1,16 @@
 //! `OpenAI` API integration module.
 //!
-//! This module provides a client for the `OpenAI` API.
+//! This module provides a client for the `OpenAI` API, including Azure
+//! `OpenAI` deployments (see [`OpenAiClient::azure`]).
 //!
 //! # Environment Variables
 //!
 //! - `OPENAI_API_KEY`: Required API key for authentication
+//! - `OPENAI_BASE_URL`: Optional base URL override for OpenAI-compatible servers
+//! - `AZURE_OPENAI_ENDPOINT`, `AZURE_OPENAI_API_KEY`: Azure resource endpoint
+//!   and key, used by [`OpenAiClient::azure_from_env`]
+//! - `AZURE_OPENAI_API_VERSION`: Optional Azure API version (defaults to
+//!   [`DEFAULT_AZURE_API_VERSION`])
 //!
 //! # Example
 //!
@@ -20,14 +26,22 @@
 //! }
 //! ```
 
+use crate::agents::TokenUsage;
 use crate::error::{AtsError, Result};
+use crate::utils::http::{base_url_from_env, build_client, retry_after, ClientOptions};
+use crate::utils::stream::{response_lines, sse_data};
+use crate::utils::text::strip_markdown_fences;
+use futures::stream::{Stream, StreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// `OpenAI` API base URL.
 const OPENAI_API_BASE: &str = "https://api.openai.com/v1";
 
+/// Azure `OpenAI` API version used when none is configured.
+pub const DEFAULT_AZURE_API_VERSION: &str = "2024-10-21";
+
 /// Default timeout for API requests (30 seconds).
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 
@@ -68,6 +82,17 @@ struct ChatCompletionRequest {
     top_p: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     max_tokens: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<ResponseFormat>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
+}
+
+/// Requested response format (`{"type": "json_object"}` for JSON mode).
+#[derive(Debug, Serialize)]
+struct ResponseFormat {
+    #[serde(rename = "type")]
+    kind: &'static str,
 }
 
 /// Message in the conversation.
@@ -81,6 +106,30 @@ struct Message {
 #[derive(Debug, Deserialize)]
 struct ChatCompletionResponse {
     choices: Vec<Choice>,
+    #[serde(default)]
+    usage: Option<UsageResponse>,
+}
+
+/// Token usage reported by the chat completions API.
+#[derive(Debug, Deserialize)]
+#[allow(clippy::struct_field_names)] // mirrors the API payload
+struct UsageResponse {
+    #[serde(default)]
+    prompt_tokens: u64,
+    #[serde(default)]
+    completion_tokens: u64,
+    #[serde(default)]
+    total_tokens: u64,
+}
+
+impl From<UsageResponse> for TokenUsage {
+    fn from(usage: UsageResponse) -> Self {
+        Self {
+            prompt_tokens: usage.prompt_tokens,
+            completion_tokens: usage.completion_tokens,
+            total_tokens: usage.total_tokens,
+        }
+    }
 }
 
 /// A choice in the response.
@@ -95,12 +144,38 @@ struct MessageResponse {
     content: String,
 }
 
+/// A server-sent chunk from a streaming chat completion.
+#[derive(Debug, Deserialize)]
+struct ChatCompletionChunk {
+    choices: Vec<ChunkChoice>,
+}
+
+/// A choice in a streamed chunk.
+#[derive(Debug, Deserialize)]
+struct ChunkChoice {
+    delta: ChunkDelta,
+}
+
+/// Incremental message content in a streamed chunk.
+#[derive(Debug, Deserialize)]
+struct ChunkDelta {
+    #[serde(default)]
+    content: Option<String>,
+}
+
 /// `OpenAI` API client.
 #[derive(Debug)]
 pub struct OpenAiClient {
     api_key: String,
+    base_url: String,
+    /// Azure API version; set in Azure mode, where `base_url` is the
+    /// deployment URL and the key goes in an `api-key` header.
+    azure_api_version: Option<String>,
     model_name: String,
     generation_config: GenerationConfig,
+    system_prompt: Option<String>,
+    json_mode: bool,
+    timeout: Duration,
     client: Client,
 }
 
@@ -124,18 +199,17 @@ impl OpenAiClient {
             });
         }
 
-        let client = Client::builder()
-            .timeout(DEFAULT_TIMEOUT)
-            .build()
-            .map_err(|e| AtsError::ApiRequest {
-                message: format!("Failed to build HTTP client: {e}"),
-                source: Some(e),
-            })?;
+        let client = build_client(&ClientOptions::from_env(), DEFAULT_TIMEOUT)?;
 
         Ok(Self {
             api_key,
+            base_url: OPENAI_API_BASE.to_string(),
+            azure_api_version: None,
             model_name: model_name.into(),
             generation_config: GenerationConfig::default(),
+            system_prompt: None,
+            json_mode: false,
+            timeout: DEFAULT_TIMEOUT,
             client,
         })
     }
@@ -144,26 +218,112 @@ impl OpenAiClient {
     ///
     /// # Errors
     ///
-    /// Returns an error if the `OPENAI_API_KEY` environment variable is not set.
+    /// Returns an error if the `OPENAI_API_KEY` environment variable is not set
+    /// or `OPENAI_BASE_URL` is not a valid URL.
     pub fn from_env() -> Result<Self> {
-        let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| AtsError::ApiAuth {
-            message: "OPENAI_API_KEY environment variable not set".to_string(),
-        })?;
-
-        Self::new(api_key, "gpt-4")
+        Self::from_env_with_model("gpt-4")
     }
 
     /// Create a client with a specific model from environment.
     ///
     /// # Errors
     ///
-    /// Returns an error if the `OPENAI_API_KEY` environment variable is not set.
+    /// Returns an error if the `OPENAI_API_KEY` environment variable is not set
+    /// or `OPENAI_BASE_URL` is not a valid URL.
     pub fn from_env_with_model(model_name: impl Into<String>) -> Result<Self> {
         let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| AtsError::ApiAuth {
             message: "OPENAI_API_KEY environment variable not set".to_string(),
         })?;
 
-        Self::new(api_key, model_name)
+        let client = Self::new(api_key, model_name)?;
+        Ok(match base_url_from_env("OPENAI_BASE_URL")? {
+            Some(base_url) => client.with_base_url(base_url),
+            None => client,
+        })
+    }
+
+    /// Create a client for an Azure `OpenAI` deployment.
+    ///
+    /// Requests go to
+    /// `{endpoint}/openai/deployments/{deployment}/chat/completions?api-version={api_version}`
+    /// and authenticate with an `api-key` header instead of a bearer token.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Resource endpoint, e.g. `https://my-resource.openai.azure.com`
+    /// * `deployment` - Deployment name (also sent as the model)
+    /// * `api_version` - API version, e.g. [`DEFAULT_AZURE_API_VERSION`]
+    /// * `api_key` - Azure `OpenAI` API key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API key is empty.
+    pub fn azure(
+        endpoint: impl AsRef<str>,
+        deployment: impl Into<String>,
+        api_version: impl Into<String>,
+        api_key: impl Into<String>,
+    ) -> Result<Self> {
+        let deployment = deployment.into();
+        let base_url = format!(
+            "{}/openai/deployments/{deployment}",
+            endpoint.as_ref().trim_end_matches('/')
+        );
+
+        let mut client = Self::new(api_key, deployment)?.with_base_url(base_url);
+        client.azure_api_version = Some(api_version.into());
+        Ok(client)
+    }
+
+    /// Create an Azure `OpenAI` client for `deployment` from the
+    /// `AZURE_OPENAI_ENDPOINT`, `AZURE_OPENAI_API_KEY` and (optional)
+    /// `AZURE_OPENAI_API_VERSION` environment variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the endpoint or API key is not set, or the
+    /// endpoint is not a valid URL.
+    pub fn azure_from_env(deployment: impl Into<String>) -> Result<Self> {
+        let endpoint =
+            base_url_from_env("AZURE_OPENAI_ENDPOINT")?.ok_or_else(|| AtsError::ApiAuth {
+                message: "AZURE_OPENAI_ENDPOINT environment variable not set".to_string(),
+            })?;
+        let api_key = std::env::var("AZURE_OPENAI_API_KEY").map_err(|_| AtsError::ApiAuth {
+            message: "AZURE_OPENAI_API_KEY environment variable not set".to_string(),
+        })?;
+        let api_version = std::env::var("AZURE_OPENAI_API_VERSION")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_AZURE_API_VERSION.to_string());
+
+        Self::azure(endpoint, deployment, api_version, api_key)
+    }
+
+    /// Rebuild the HTTP client with explicit options (e.g. a proxy).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy URL is invalid or the client cannot be built.
+    pub fn with_client_options(mut self, options: &ClientOptions) -> Result<Self> {
+        self.client = build_client(options, DEFAULT_TIMEOUT)?;
+        if let Some(timeout) = options.timeout {
+            self.timeout = timeout;
+        }
+        Ok(self)
+    }
+
+    /// Set the request timeout.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Override the API base URL (e.g. for a proxy or an OpenAI-compatible server).
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
     }
 
     /// Set the generation configuration.
@@ -173,6 +333,25 @@ impl OpenAiClient {
         self
     }
 
+    /// Set the system prompt sent with every request.
+    #[must_use]
+    pub fn with_system_prompt(mut self, system_prompt: Option<String>) -> Self {
+        self.system_prompt = system_prompt.filter(|p| !p.trim().is_empty());
+        self
+    }
+
+    /// Ask the API for a JSON object (`response_format: {"type": "json_object"}`)
+    /// in [`generate_json`](Self::generate_json) and
+    /// [`generate_json_with_usage`](Self::generate_json_with_usage); plain
+    /// text requests never use it.
+    ///
+    /// The API rejects this mode unless the messages mention JSON.
+    #[must_use]
+    pub fn with_json_mode(mut self, enabled: bool) -> Self {
+        self.json_mode = enabled;
+        self
+    }
+
     /// Set the temperature.
     #[must_use]
     pub fn with_temperature(mut self, temperature: f64) -> Self {
@@ -202,6 +381,97 @@ impl OpenAiClient {
     ///
     /// Returns an error if the API request fails or the response cannot be parsed.
     pub async fn generate_content(&self, prompt: &str) -> Result<String> {
+        self.generate_content_with_usage(prompt)
+            .await
+            .map(|(text, _)| text)
+    }
+
+    /// Generate content from a text prompt, also returning token usage.
+    ///
+    /// Token counts come from the `usage` object of the response, when present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn generate_content_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, Option<TokenUsage>)> {
+        self.complete(prompt, false).await
+    }
+
+    /// Generate JSON text from a prompt, also returning token usage.
+    ///
+    /// Like [`generate_content_with_usage`](Self::generate_content_with_usage),
+    /// but requests a JSON object when JSON mode is enabled. The text is
+    /// returned as is, without parsing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn generate_json_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, Option<TokenUsage>)> {
+        self.complete(prompt, self.json_mode).await
+    }
+
+    /// Send a non-streaming chat completion and return its text and usage.
+    async fn complete(&self, prompt: &str, json: bool) -> Result<(String, Option<TokenUsage>)> {
+        if prompt.trim().is_empty() {
+            return Err(AtsError::ApiRequest {
+                message: "Prompt cannot be empty".to_string(),
+                source: None,
+            });
+        }
+
+        let started = Instant::now();
+        let response = self.send(prompt, false, json).await?;
+
+        let response_data: ChatCompletionResponse = response.json().await.map_err(|e| {
+            if e.is_timeout() {
+                AtsError::timeout("OpenAI", started.elapsed())
+            } else {
+                AtsError::ApiResponse {
+                    message: format!("Failed to parse API response: {e}"),
+                    status_code: None,
+                }
+            }
+        })?;
+
+        // Extract text from the first choice
+        let text = response_data
+            .choices
+            .first()
+            .map(|c| c.message.content.clone())
+            .ok_or_else(|| AtsError::ApiResponse {
+                message: "No text in API response".to_string(),
+                status_code: None,
+            })?;
+
+        if text.trim().is_empty() {
+            return Err(AtsError::ApiResponse {
+                message: "API returned empty response".to_string(),
+                status_code: None,
+            });
+        }
+
+        Ok((text, response_data.usage.map(TokenUsage::from)))
+    }
+
+    /// Stream generated content from a text prompt.
+    ///
+    /// Sends a `stream: true` chat completion request and yields each content
+    /// delta from the server-sent events as it arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the prompt is empty or the request is rejected.
+    /// Errors while reading or parsing the stream are yielded as stream items.
+    pub async fn generate_content_stream(
+        &self,
+        prompt: &str,
+    ) -> Result<impl Stream<Item = Result<String>> + Send + 'static> {
         if prompt.trim().is_empty() {
             return Err(AtsError::ApiRequest {
                 message: "Prompt cannot be empty".to_string(),
@@ -209,34 +479,113 @@ impl OpenAiClient {
             });
         }
 
+        let response = self.send(prompt, true, false).await?;
+
+        Ok(response_lines(response).filter_map(|line| async move {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            let data = sse_data(&line)?;
+            match serde_json::from_str::<ChatCompletionChunk>(data) {
+                Ok(chunk) => chunk
+                    .choices
+                    .into_iter()
+                    .next()
+                    .and_then(|c| c.delta.content)
+                    .filter(|text| !text.is_empty())
+                    .map(Ok),
+                Err(e) => Some(Err(AtsError::ApiResponse {
+                    message: format!("Failed to parse streamed chunk: {e}"),
+                    status_code: None,
+                })),
+            }
+        }))
+    }
+
+    /// Check that the API key is accepted and the model exists.
+    ///
+    /// Sends a one-token chat completion, so the check costs next to nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AtsError::ApiAuth`] if the key is rejected, or the API error
+    /// (e.g. a 404 for an unknown model) if the request fails.
+    pub async fn ping(&self) -> Result<()> {
         let request = ChatCompletionRequest {
             model: self.model_name.clone(),
             messages: vec![Message {
                 role: "user".to_string(),
-                content: prompt.to_string(),
+                content: "ping".to_string(),
             }],
+            temperature: None,
+            top_p: None,
+            max_tokens: Some(1),
+            response_format: None,
+            stream: false,
+        };
+
+        self.post(&request).await.map(|_| ())
+    }
+
+    /// Build a chat completion request for `prompt` and send it, asking for a
+    /// JSON object when `json` is set.
+    async fn send(&self, prompt: &str, stream: bool, json: bool) -> Result<reqwest::Response> {
+        let mut messages = Vec::with_capacity(2);
+        if let Some(system) = &self.system_prompt {
+            messages.push(Message {
+                role: "system".to_string(),
+                content: system.clone(),
+            });
+        }
+        messages.push(Message {
+            role: "user".to_string(),
+            content: prompt.to_string(),
+        });
+
+        let request = ChatCompletionRequest {
+            model: self.model_name.clone(),
+            messages,
             temperature: self.generation_config.temperature,
             top_p: self.generation_config.top_p,
             max_tokens: self.generation_config.max_tokens,
+            response_format: json.then_some(ResponseFormat {
+                kind: "json_object",
+            }),
+            stream,
         };
 
-        let url = format!("{OPENAI_API_BASE}/chat/completions");
+        self.post(&request).await
+    }
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .json(&request)
-            .send()
-            .await
-            .map_err(|e| AtsError::ApiRequest {
-                message: format!("Failed to send request to OpenAI API: {e}"),
-                source: Some(e),
-            })?;
+    /// POST a chat completion request and map HTTP error statuses.
+    async fn post(&self, request: &ChatCompletionRequest) -> Result<reqwest::Response> {
+        let url = format!("{}/chat/completions", self.base_url);
+
+        let builder = self.client.post(&url).timeout(self.timeout);
+        let builder = match &self.azure_api_version {
+            Some(api_version) => builder
+                .query(&[("api-version", api_version)])
+                .header("api-key", &self.api_key),
+            None => builder.header("Authorization", format!("Bearer {}", self.api_key)),
+        };
+
+        let started = Instant::now();
+        let response = builder.json(request).send().await.map_err(|e| {
+            if e.is_timeout() {
+                AtsError::timeout("OpenAI", started.elapsed())
+            } else {
+                AtsError::ApiRequest {
+                    message: format!("Failed to send request to OpenAI API: {e}"),
+                    source: Some(e),
+                }
+            }
+        })?;
 
         let status = response.status();
 
         if !status.is_success() {
+            let retry_after = retry_after(response.headers());
             let error_text = response
                 .text()
                 .await
@@ -248,7 +597,7 @@ impl OpenAiClient {
                 },
                 429 => AtsError::ApiRateLimit {
                     message: format!("Rate limit exceeded: {error_text}"),
-                    retry_after: None,
+                    retry_after,
                 },
                 _ => AtsError::ApiResponse {
                     message: format!("API error ({status}): {error_text}"),
@@ -257,30 +606,7 @@ impl OpenAiClient {
             });
         }
 
-        let response_data: ChatCompletionResponse =
-            response.json().await.map_err(|e| AtsError::ApiResponse {
-                message: format!("Failed to parse API response: {e}"),
-                status_code: None,
-            })?;
-
-        // Extract text from the first choice
-        let text = response_data
-            .choices
-            .first()
-            .map(|c| c.message.content.clone())
-            .ok_or_else(|| AtsError::ApiResponse {
-                message: "No text in API response".to_string(),
-                status_code: None,
-            })?;
-
-        if text.trim().is_empty() {
-            return Err(AtsError::ApiResponse {
-                message: "API returned empty response".to_string(),
-                status_code: None,
-            });
-        }
-
-        Ok(text)
+        Ok(response)
     }
 
     /// Generate JSON content from a prompt.
@@ -291,7 +617,7 @@ impl OpenAiClient {
     ///
     /// Returns an error if the API request fails or the response is not valid JSON.
     pub async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
-        let text = self.generate_content(prompt).await?;
+        let (text, _) = self.generate_json_with_usage(prompt).await?;
         let cleaned = strip_markdown_fences(&text);
 
         serde_json::from_str(&cleaned).map_err(|e| AtsError::ApiResponse {
@@ -301,55 +627,10 @@ impl OpenAiClient {
     }
 }
 
-/// Strip markdown code fences from text.
-///
-/// Handles patterns like:
-/// - ` ```json\n{...}\n``` `
-/// - ` ```\n{...}\n``` `
-fn strip_markdown_fences(text: &str) -> String {
-    let trimmed = text.trim();
-
-    if trimmed.starts_with("```") && trimmed.ends_with("```") {
-        // Find the first newline after ```
-        if let Some(first_newline) = trimmed.find('\n') {
-            let header = &trimmed[..first_newline];
-
-            // Check if it's ```json or just ```
-            if header.starts_with("```") {
-                // Extract body between first newline and final ```
-                let body_start = first_newline + 1;
-                let body_end = trimmed.len() - 3;
-
-                if body_start < body_end {
-                    return trimmed[body_start..body_end].trim().to_string();
-                }
-            }
-        }
-
-        // Fallback: just strip ``` from both ends
-        return trimmed[3..trimmed.len() - 3].trim().to_string();
-    }
-
-    trimmed.to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_strip_markdown_fences() {
-        let input = "```json\n{\"key\": \"value\"}\n```";
-        let expected = "{\"key\": \"value\"}";
-        assert_eq!(strip_markdown_fences(input), expected);
-
-        let input2 = "```\n{\"key\": \"value\"}\n```";
-        assert_eq!(strip_markdown_fences(input2), expected);
-
-        let input3 = "{\"key\": \"value\"}";
-        assert_eq!(strip_markdown_fences(input3), expected);
-    }
-
     #[test]
     fn test_generation_config_default() {
         let config = GenerationConfig::default();
@@ -358,6 +639,32 @@ mod tests {
         assert_eq!(config.max_tokens, Some(4096));
     }
 
+    #[test]
+    fn test_usage_deserialization() {
+        let payload = r#"{
+            "id": "chatcmpl-9abc",
+            "object": "chat.completion",
+            "created": 1718000000,
+            "model": "gpt-4o-mini",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "Hello!"},
+                "finish_reason": "stop"
+            }],
+            "usage": {"prompt_tokens": 12, "completion_tokens": 3, "total_tokens": 15}
+        }"#;
+
+        let response: ChatCompletionResponse = serde_json::from_str(payload).unwrap();
+        let usage = response.usage.map(TokenUsage::from).unwrap();
+        assert_eq!(usage.prompt_tokens, 12);
+        assert_eq!(usage.completion_tokens, 3);
+        assert_eq!(usage.total_tokens, 15);
+
+        let without_usage: ChatCompletionResponse =
+            serde_json::from_str(r#"{"choices": [{"message": {"content": "Hi"}}]}"#).unwrap();
+        assert!(without_usage.usage.is_none());
+    }
+
     #[test]
     fn test_openai_client_new_validation() {
         let result = OpenAiClient::new("", "gpt-4");
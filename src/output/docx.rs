@@ -0,0 +1,217 @@
+//! DOCX rendering for enhanced resumes.
+//!
+//! Builds a Word document that uses named paragraph styles (`Title`,
+//! `Heading1`, `Heading2`) and a real bullet-list numbering definition rather
+//! than direct formatting, so it opens cleanly in word processors and
+//! stays editable. Sections that are missing or empty in the resume are
+//! skipped.
+
+use super::{
+    skill_lines, summary_paragraphs, DocumentEntry, DocumentHeader, DocumentSection,
+    DOCUMENT_SECTIONS,
+};
+use crate::error::{AtsError, Result};
+use docx_rs::{
+    AbstractNumbering, Docx, IndentLevel, Level, LevelJc, LevelText, NumberFormat, Numbering,
+    NumberingId, Paragraph, Run, SpecialIndentType, Start, Style, StyleType,
+};
+use serde_json::Value;
+use std::io::Cursor;
+
+/// Numbering id shared by every bullet paragraph.
+const BULLET_NUMBERING: usize = 1;
+
+// Font sizes are in half-points, as Word stores them.
+const NAME_SIZE: usize = 40;
+const HEADING_SIZE: usize = 26;
+const ENTRY_SIZE: usize = 22;
+
+const TITLE_STYLE: &str = "Title";
+const HEADING_STYLE: &str = "Heading1";
+const ENTRY_STYLE: &str = "Heading2";
+
+/// Render a resume JSON value as a DOCX document.
+///
+/// # Errors
+///
+/// Returns an error if the document cannot be packaged.
+pub(crate) fn render_resume_docx(resume: &Value) -> Result<Vec<u8>> {
+    let mut doc = DocxLayout::new();
+
+    doc.header(&DocumentHeader::from_resume(resume));
+
+    for section in &DOCUMENT_SECTIONS {
+        match section {
+            DocumentSection::Summary => {
+                let paragraphs = summary_paragraphs(resume);
+                if !paragraphs.is_empty() {
+                    doc.heading("Summary");
+                    for paragraph in paragraphs {
+                        doc.text(&paragraph);
+                    }
+                }
+            }
+            DocumentSection::Skills => doc.skills(&skill_lines(resume)),
+            DocumentSection::Entries(section) => {
+                doc.entries(section.title, &section.entries(resume));
+            }
+        }
+    }
+
+    doc.finish()
+}
+
+/// Collects the document's paragraphs in order.
+struct DocxLayout {
+    paragraphs: Vec<Paragraph>,
+}
+
+impl DocxLayout {
+    fn new() -> Self {
+        Self {
+            paragraphs: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, paragraph: Paragraph) {
+        self.paragraphs.push(paragraph);
+    }
+
+    /// Name as the document title, then headline and contact line.
+    fn header(&mut self, header: &DocumentHeader) {
+        if let Some(name) = &header.name {
+            self.push(
+                Paragraph::new()
+                    .add_run(Run::new().add_text(name))
+                    .style(TITLE_STYLE),
+            );
+        }
+        if let Some(headline) = &header.headline {
+            self.text(headline);
+        }
+        if !header.contact.is_empty() {
+            self.text(&header.contact.join("  |  "));
+        }
+    }
+
+    fn heading(&mut self, title: &str) {
+        self.push(
+            Paragraph::new()
+                .add_run(Run::new().add_text(title))
+                .style(HEADING_STYLE),
+        );
+    }
+
+    fn text(&mut self, text: &str) {
+        self.push(Paragraph::new().add_run(Run::new().add_text(text)));
+    }
+
+    fn bullet(&mut self, text: &str) {
+        self.push(
+            Paragraph::new()
+                .add_run(Run::new().add_text(text))
+                .numbering(NumberingId::new(BULLET_NUMBERING), IndentLevel::new(0)),
+        );
+    }
+
+    /// A section of entries (experience, education, ...): the entry title as
+    /// a sub-heading, a details line led by the bold organisation (company,
+    /// institution, ...) and any bullets.
+    fn entries(&mut self, title: &str, entries: &[DocumentEntry]) {
+        if entries.is_empty() {
+            return;
+        }
+
+        self.heading(title);
+        for entry in entries {
+            if let Some(heading) = &entry.heading {
+                self.push(
+                    Paragraph::new()
+                        .add_run(Run::new().add_text(heading))
+                        .style(ENTRY_STYLE),
+                );
+            }
+            if let Some((organisation, rest)) = entry.details.split_first() {
+                let mut details =
+                    Paragraph::new().add_run(Run::new().add_text(organisation).bold());
+                if !rest.is_empty() {
+                    details = details
+                        .add_run(Run::new().add_text(format!("  |  {}", rest.join("  |  "))));
+                }
+                self.push(details);
+            }
+            for bullet in &entry.bullets {
+                self.bullet(bullet);
+            }
+        }
+    }
+
+    /// Skills as `Category: a, b, c` lines with a bold category, or a single
+    /// comma-separated list.
+    fn skills(&mut self, lines: &[(Option<String>, String)]) {
+        if lines.is_empty() {
+            return;
+        }
+
+        self.heading("Skills");
+        for (category, list) in lines {
+            let mut paragraph = Paragraph::new();
+            if let Some(category) = category {
+                paragraph = paragraph.add_run(Run::new().add_text(format!("{category}: ")).bold());
+            }
+            self.push(paragraph.add_run(Run::new().add_text(list)));
+        }
+    }
+
+    /// Register the heading styles and bullet numbering, then add the paragraphs.
+    fn into_docx(self) -> Docx {
+        let heading = |id: &str, name: &str, size: usize, outline: usize| {
+            Style::new(id, StyleType::Paragraph)
+                .name(name)
+                .based_on("Normal")
+                .next("Normal")
+                .size(size)
+                .bold()
+                .outline_lvl(outline)
+        };
+
+        let bullets = AbstractNumbering::new(BULLET_NUMBERING).add_level(
+            Level::new(
+                0,
+                Start::new(1),
+                NumberFormat::new("bullet"),
+                LevelText::new("•"),
+                LevelJc::new("left"),
+            )
+            .indent(Some(720), Some(SpecialIndentType::Hanging(360)), None, None),
+        );
+
+        let docx = Docx::new()
+            .add_style(
+                Style::new(TITLE_STYLE, StyleType::Paragraph)
+                    .name("Title")
+                    .based_on("Normal")
+                    .next("Normal")
+                    .size(NAME_SIZE)
+                    .bold(),
+            )
+            .add_style(heading(HEADING_STYLE, "heading 1", HEADING_SIZE, 0))
+            .add_style(heading(ENTRY_STYLE, "heading 2", ENTRY_SIZE, 1))
+            .add_abstract_numbering(bullets)
+            .add_numbering(Numbering::new(BULLET_NUMBERING, BULLET_NUMBERING));
+
+        self.paragraphs.into_iter().fold(docx, Docx::add_paragraph)
+    }
+
+    /// Package the document as DOCX (zip) bytes.
+    fn finish(self) -> Result<Vec<u8>> {
+        let mut bytes = Cursor::new(Vec::new());
+        self.into_docx()
+            .build()
+            .pack(&mut bytes)
+            .map_err(|e| AtsError::OutputGeneration {
+                message: format!("Failed to write DOCX: {e}"),
+            })?;
+        Ok(bytes.into_inner())
+    }
+}
@@ -0,0 +1,278 @@
+//! HTML rendering for enhanced resumes.
+//!
+//! Produces a single self-contained page (inline CSS, no external assets)
+//! with the rendered resume alongside an ATS-friendliness report: the score
+//! breakdown as labelled bars, the job keywords the resume is missing, and
+//! the recommendations as an ordered list.
+//! Markup is kept XML well-formed so the file can also be processed as XHTML.
+
+use super::{
+    capitalize, skill_lines, summary_paragraphs, DocumentEntry, DocumentHeader, DocumentSection,
+    OutputData, DOCUMENT_SECTIONS,
+};
+use crate::recommendations::Recommendation;
+use crate::scoring::{KeywordGap, ScoreReport};
+use std::fmt::Write;
+
+/// Missing keywords shown in the keyword gap section.
+const MAX_GAP_TERMS: usize = 15;
+
+const STYLE: &str = "
+body { margin: 0; background: #f4f5f7; color: #1f2328; font: 15px/1.5 -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; }
+.page { display: flex; flex-wrap: wrap; gap: 24px; max-width: 1100px; margin: 32px auto; padding: 0 16px; }
+.resume { flex: 2 1 560px; background: #fff; padding: 40px 48px; border-radius: 6px; box-shadow: 0 1px 3px rgba(0,0,0,.12); }
+.report { flex: 1 1 300px; background: #fff; padding: 24px; border-radius: 6px; box-shadow: 0 1px 3px rgba(0,0,0,.12); align-self: flex-start; }
+h1 { margin: 0; font-size: 28px; }
+h2 { font-size: 15px; text-transform: uppercase; letter-spacing: .06em; border-bottom: 1px solid #d0d7de; padding-bottom: 4px; margin: 24px 0 10px; }
+.headline, .contact { margin: 2px 0; color: #57606a; }
+.entry { margin-bottom: 12px; }
+.entry p { margin: 0; }
+.entry ul { margin: 4px 0 0; padding-left: 20px; }
+.total { font-size: 22px; margin: 4px 0 16px; }
+.bar-row { display: grid; grid-template-columns: 9em 1fr 3em; align-items: center; gap: 8px; margin: 6px 0; font-size: 13px; }
+.bar { background: #eaeef2; border-radius: 4px; height: 10px; overflow: hidden; }
+.bar-fill { height: 100%; }
+.bar-value { text-align: right; font-variant-numeric: tabular-nums; }
+.band-red { background: #cf222e; }
+.band-amber { background: #d4a72c; }
+.band-green { background: #2da44e; }
+.total.band-red, .total.band-amber, .total.band-green { background: none; }
+.total.band-red strong { color: #cf222e; }
+.total.band-amber strong { color: #9a6700; }
+.total.band-green strong { color: #1a7f37; }
+.keywords { list-style: none; padding: 0; margin: 0; display: flex; flex-wrap: wrap; gap: 6px; }
+.keywords li { background: #fff8c5; border: 1px solid #d4a72c; border-radius: 12px; padding: 1px 10px; font-size: 13px; }
+.keywords .weight { color: #57606a; margin-left: 4px; }
+.coverage { color: #57606a; font-size: 13px; }
+.recommendations li { margin-bottom: 8px; }
+.reason { display: block; color: #57606a; font-size: 13px; }
+";
+
+/// Render the enhanced resume and its score report as a standalone HTML page.
+pub(crate) fn render_resume_html(data: &OutputData) -> String {
+    let resume = &data.enhanced_resume;
+    let header = DocumentHeader::from_resume(resume);
+    let title = header.name.as_deref().unwrap_or(&data.resume_name);
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    out.push_str("<meta charset=\"utf-8\" />\n");
+    out.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\" />\n");
+    let _ = writeln!(out, "<title>{} - Resume</title>", escape(title));
+    let _ = writeln!(out, "<style>{STYLE}</style>");
+    out.push_str("</head>\n<body>\n<div class=\"page\">\n");
+
+    out.push_str("<main class=\"resume\">\n");
+    push_header(&mut out, &header);
+    for section in &DOCUMENT_SECTIONS {
+        match section {
+            DocumentSection::Summary => {
+                let paragraphs = summary_paragraphs(resume);
+                if !paragraphs.is_empty() {
+                    out.push_str("<section>\n<h2>Summary</h2>\n");
+                    for paragraph in paragraphs {
+                        let _ = writeln!(out, "<p>{}</p>", escape(&paragraph));
+                    }
+                    out.push_str("</section>\n");
+                }
+            }
+            DocumentSection::Skills => {
+                let lines = skill_lines(resume);
+                if !lines.is_empty() {
+                    out.push_str("<section>\n<h2>Skills</h2>\n<ul>\n");
+                    for (category, skills) in lines {
+                        match category {
+                            Some(category) => {
+                                let _ = writeln!(
+                                    out,
+                                    "<li><strong>{}:</strong> {}</li>",
+                                    escape(&category),
+                                    escape(&skills)
+                                );
+                            }
+                            None => {
+                                let _ = writeln!(out, "<li>{}</li>", escape(&skills));
+                            }
+                        }
+                    }
+                    out.push_str("</ul>\n</section>\n");
+                }
+            }
+            DocumentSection::Entries(section) => {
+                let entries = section.entries(resume);
+                if !entries.is_empty() {
+                    let _ = writeln!(out, "<section>\n<h2>{}</h2>", escape(section.title));
+                    for entry in &entries {
+                        push_entry(&mut out, entry);
+                    }
+                    out.push_str("</section>\n");
+                }
+            }
+        }
+    }
+    out.push_str("</main>\n");
+
+    if data.scores.is_some() || data.keyword_gap.is_some() || !data.recommendations.is_empty() {
+        out.push_str("<aside class=\"report\">\n");
+        if let Some(scores) = &data.scores {
+            push_score_report(&mut out, scores);
+        }
+        if let Some(gap) = &data.keyword_gap {
+            push_keyword_gap(&mut out, gap);
+        }
+        if !data.recommendations.is_empty() {
+            push_recommendations(&mut out, &data.recommendations);
+        }
+        out.push_str("</aside>\n");
+    }
+
+    out.push_str("</div>\n</body>\n</html>\n");
+    out
+}
+
+fn push_header(out: &mut String, header: &DocumentHeader) {
+    out.push_str("<header>\n");
+    if let Some(name) = &header.name {
+        let _ = writeln!(out, "<h1>{}</h1>", escape(name));
+    }
+    if let Some(headline) = &header.headline {
+        let _ = writeln!(out, "<p class=\"headline\">{}</p>", escape(headline));
+    }
+    if !header.contact.is_empty() {
+        let contact: Vec<String> = header.contact.iter().map(|c| escape(c)).collect();
+        let _ = writeln!(out, "<p class=\"contact\">{}</p>", contact.join(" | "));
+    }
+    out.push_str("</header>\n");
+}
+
+fn push_entry(out: &mut String, entry: &DocumentEntry) {
+    out.push_str("<div class=\"entry\">\n");
+
+    let title_line: Vec<String> = entry
+        .heading
+        .iter()
+        .map(|heading| format!("<strong>{}</strong>", escape(heading)))
+        .chain(entry.details.iter().map(|detail| escape(detail)))
+        .collect();
+    if !title_line.is_empty() {
+        let _ = writeln!(out, "<p>{}</p>", title_line.join(" | "));
+    }
+
+    if !entry.bullets.is_empty() {
+        out.push_str("<ul>\n");
+        for bullet in &entry.bullets {
+            let _ = writeln!(out, "<li>{}</li>", escape(bullet));
+        }
+        out.push_str("</ul>\n");
+    }
+
+    out.push_str("</div>\n");
+}
+
+fn push_score_report(out: &mut String, scores: &ScoreReport) {
+    out.push_str("<section>\n<h2>ATS Report</h2>\n");
+    let _ = writeln!(
+        out,
+        "<p class=\"total {}\">Total score: <strong>{:.1}</strong></p>",
+        score_band(scores.total),
+        scores.total
+    );
+
+    for category in &scores.categories {
+        let name = capitalize(&category.name.replace('_', " "));
+        let width = category.score.clamp(0.0, 100.0);
+        let _ = writeln!(
+            out,
+            "<div class=\"bar-row\"><span class=\"bar-label\">{}</span>\
+             <div class=\"bar\"><div class=\"bar-fill {}\" style=\"width: {width:.1}%\"></div></div>\
+             <span class=\"bar-value\">{:.1}</span></div>",
+            escape(&name),
+            score_band(category.score),
+            category.score
+        );
+    }
+    out.push_str("</section>\n");
+}
+
+fn push_keyword_gap(out: &mut String, gap: &KeywordGap) {
+    out.push_str("<section>\n<h2>Keyword Gap</h2>\n");
+    let total = gap.present.len() + gap.missing.len();
+    let _ = writeln!(
+        out,
+        "<p class=\"coverage\">{} of {total} job keywords found in the resume.</p>",
+        gap.present.len()
+    );
+    if !gap.missing_by_importance.is_empty() {
+        out.push_str("<ul class=\"keywords\">\n");
+        for (term, importance) in gap.missing_by_importance.iter().take(MAX_GAP_TERMS) {
+            let _ = writeln!(
+                out,
+                "<li>{}<span class=\"weight\">{:.0}%</span></li>",
+                escape(term),
+                importance * 100.0
+            );
+        }
+        out.push_str("</ul>\n");
+    }
+    out.push_str("</section>\n");
+}
+
+fn push_recommendations(out: &mut String, recommendations: &[Recommendation]) {
+    out.push_str("<section>\n<h2>Recommendations</h2>\n<ol class=\"recommendations\">\n");
+    for recommendation in recommendations {
+        let _ = write!(out, "<li>{}", escape(&recommendation.message));
+        if let Some(reason) = &recommendation.reason {
+            let _ = write!(out, "<span class=\"reason\">{}</span>", escape(reason));
+        }
+        out.push_str("</li>\n");
+    }
+    out.push_str("</ol>\n</section>\n");
+}
+
+/// CSS class for a score: red below 50, amber below 70, green otherwise.
+fn score_band(score: f64) -> &'static str {
+    if score < 50.0 {
+        "band-red"
+    } else if score < 70.0 {
+        "band-amber"
+    } else {
+        "band-green"
+    }
+}
+
+/// Escape text for use in HTML element content and quoted attributes.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape(r#"<script>alert("R&D's")</script>"#),
+            "&lt;script&gt;alert(&quot;R&amp;D&#39;s&quot;)&lt;/script&gt;"
+        );
+    }
+
+    #[test]
+    fn test_score_band() {
+        assert_eq!(score_band(49.9), "band-red");
+        assert_eq!(score_band(50.0), "band-amber");
+        assert_eq!(score_band(69.9), "band-amber");
+        assert_eq!(score_band(70.0), "band-green");
+    }
+}
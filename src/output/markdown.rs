@@ -0,0 +1,156 @@
+//! Markdown rendering for enhanced resumes.
+//!
+//! Produces a diff-friendly document with one heading per section, bold
+//! entry titles and `-` bullets, plus a score summary table when scores are
+//! available.
+
+use super::{
+    capitalize, skill_lines, summary_paragraphs, DocumentEntry, DocumentHeader, DocumentSection,
+    DOCUMENT_SECTIONS,
+};
+use crate::scoring::ScoreReport;
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Render a resume JSON value (and optional scores) as Markdown.
+pub(crate) fn render_resume_markdown(resume: &Value, scores: Option<&ScoreReport>) -> String {
+    let mut out = String::new();
+
+    let header = DocumentHeader::from_resume(resume);
+    if let Some(name) = &header.name {
+        push_block(&mut out, &format!("# {name}"));
+    }
+    if let Some(headline) = &header.headline {
+        push_block(&mut out, headline);
+    }
+    if !header.contact.is_empty() {
+        push_block(&mut out, &header.contact.join(" | "));
+    }
+
+    for section in &DOCUMENT_SECTIONS {
+        match section {
+            DocumentSection::Summary => {
+                let paragraphs = summary_paragraphs(resume);
+                if !paragraphs.is_empty() {
+                    push_block(&mut out, "## Summary");
+                    for paragraph in paragraphs {
+                        push_block(&mut out, &paragraph);
+                    }
+                }
+            }
+            DocumentSection::Skills => {
+                let lines = skill_lines(resume);
+                if !lines.is_empty() {
+                    push_block(&mut out, "## Skills");
+                    let list: Vec<String> = lines
+                        .into_iter()
+                        .map(|(category, skills)| match category {
+                            Some(category) => format!("- **{category}:** {skills}"),
+                            None => format!("- {skills}"),
+                        })
+                        .collect();
+                    push_block(&mut out, &list.join("\n"));
+                }
+            }
+            DocumentSection::Entries(section) => {
+                let entries = section.entries(resume);
+                if !entries.is_empty() {
+                    push_block(&mut out, &format!("## {}", section.title));
+                    for entry in &entries {
+                        push_entry(&mut out, entry);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(scores) = scores {
+        push_block(&mut out, "## Score Summary");
+        push_block(&mut out, &score_table(scores));
+    }
+
+    out
+}
+
+/// Append a block followed by a blank line.
+fn push_block(out: &mut String, block: &str) {
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out.push_str(block);
+    out.push('\n');
+}
+
+fn push_entry(out: &mut String, entry: &DocumentEntry) {
+    let title_line: Vec<String> = entry
+        .heading
+        .iter()
+        .map(|heading| format!("**{heading}**"))
+        .chain(entry.details.iter().cloned())
+        .collect();
+    if !title_line.is_empty() {
+        push_block(out, &title_line.join(" | "));
+    }
+
+    if !entry.bullets.is_empty() {
+        let bullets: Vec<String> = entry.bullets.iter().map(|b| format!("- {b}")).collect();
+        push_block(out, &bullets.join("\n"));
+    }
+}
+
+/// Compact `Category | Score | Weight` table with the total as the last row.
+fn score_table(scores: &ScoreReport) -> String {
+    let mut table = String::from("| Category | Score | Weight |\n| --- | ---: | ---: |\n");
+    for category in &scores.categories {
+        let name = capitalize(&category.name.replace('_', " ")).replace('|', "\\|");
+        let _ = writeln!(
+            table,
+            "| {name} | {:.1} | {:.2} |",
+            category.score, category.weight
+        );
+    }
+    let _ = write!(table, "| **Total** | **{:.1}** | |", scores.total);
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ScoreCategoryResult;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_description_string_and_array() {
+        let resume = json!({
+            "experience": [
+                {"title": "Engineer", "description": "Built APIs\nShipped features"},
+                {"title": "Intern", "description": ["Wrote tests", "Fixed bugs"]}
+            ]
+        });
+
+        let md = render_resume_markdown(&resume, None);
+        assert!(md.contains("**Engineer**\n\n- Built APIs\n- Shipped features\n"));
+        assert!(md.contains("**Intern**\n\n- Wrote tests\n- Fixed bugs\n"));
+        assert!(!md.contains("## Score Summary"));
+    }
+
+    #[test]
+    fn test_score_table() {
+        let scores = ScoreReport {
+            kind: "resume".to_string(),
+            total: 82.456,
+            categories: vec![ScoreCategoryResult {
+                name: "skills_quality".to_string(),
+                score: 90.0,
+                weight: 0.25,
+                details: HashMap::new(),
+            }],
+            meta: HashMap::new(),
+        };
+
+        let table = score_table(&scores);
+        assert!(table.contains("| Skills quality | 90.0 | 0.25 |"));
+        assert!(table.ends_with("| **Total** | **82.5** | |"));
+    }
+}
@@ -0,0 +1,1022 @@
+//! Output generation module.
+//!
+//! Writes the artifacts produced by the processing pipeline:
+//! - Enhanced resume as TOML, JSON, PDF, DOCX, Markdown or HTML (per
+//!   `structured_output_format`); the HTML page also embeds the score report,
+//!   keyword gap and recommendations
+//! - A plain-text rendering of the enhanced resume (always written)
+//! - `<base>_cover_letter.txt` with a tailored cover letter (when generated)
+//! - `scores.toml` with the score report (when scores are available)
+//! - `manifest.toml` describing the run, including the crate version and
+//!   hashes of the config and scoring weights it was generated with
+//!
+//! Every run is also appended to `index.toml` in the output folder root (see
+//! [`OutputIndex`]), so past runs can be browsed in one place.
+//!
+//! Output directories are derived from a configurable pattern supporting the
+//! `{resume_name}`, `{job_title}`, `{timestamp}`, `{date}`, `{score}` and
+//! `{provider}` placeholders (see [`render_subdir`]).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use ats_checker::output::{OutputData, OutputGenerator};
+//! use serde_json::json;
+//! use std::collections::HashMap;
+//! use std::path::PathBuf;
+//!
+//! let generator = OutputGenerator::new(
+//!     PathBuf::from("workspace/output"),
+//!     "json".to_string(),
+//!     "{resume_name}/{timestamp}".to_string(),
+//! );
+//!
+//! let data = OutputData {
+//!     resume_name: "john_doe".to_string(),
+//!     job_title: None,
+//!     enhanced_resume: json!({"personal_info": {"name": "John Doe"}}),
+//!     scores: None,
+//!     recommendations: vec![],
+//!     keyword_gap: None,
+//!     cover_letter: None,
+//!     metadata: HashMap::new(),
+//! };
+//!
+//! let output_dir = generator.generate(&data).unwrap();
+//! println!("Wrote outputs to {}", output_dir.display());
+//! ```
+
+mod docx;
+mod html;
+mod markdown;
+mod pdf;
+
+use crate::config::Config;
+use crate::error::{AtsError, Result};
+use crate::recommendations::Recommendation;
+use crate::scoring::{KeywordGap, ScoreReport};
+use crate::utils::file::{atomic_write, atomic_write_bytes, sanitize_filename};
+use crate::utils::hash::{calculate_file_hash, calculate_string_hash};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+/// Job name used in output file names when no job description was supplied.
+const NO_JOB_PLACEHOLDER: &str = "no_job";
+
+/// File name of the manifest written into every output directory.
+pub const MANIFEST_FILE: &str = "manifest.toml";
+
+/// File name of the score report written when scores are available.
+pub const SCORES_FILE: &str = "scores.toml";
+
+/// File name of the run history kept in the output folder root.
+pub const INDEX_FILE: &str = "index.toml";
+
+/// Serializes index updates within the process, so concurrent runs don't
+/// drop each other's entries.
+static INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+// -------------------------
+// Data Structures
+// -------------------------
+
+/// Data required to generate outputs for a single processed resume.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputData {
+    /// Base name of the resume (usually the input file stem).
+    pub resume_name: String,
+    /// Job title or job description name, if matched against a job.
+    pub job_title: Option<String>,
+    /// Enhanced resume JSON.
+    pub enhanced_resume: serde_json::Value,
+    /// Score report for the enhanced resume.
+    pub scores: Option<ScoreReport>,
+    /// Improvement recommendations.
+    pub recommendations: Vec<Recommendation>,
+    /// Job keywords present in and missing from the resume, when matched
+    /// against a job.
+    pub keyword_gap: Option<KeywordGap>,
+    /// Tailored cover letter, written as `<base>_cover_letter.txt`.
+    pub cover_letter: Option<String>,
+    /// Additional metadata to record in the manifest.
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+/// Manifest describing a single output run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputManifest {
+    /// Resume base name.
+    pub resume_name: String,
+    /// Job title, if any.
+    pub job_title: Option<String>,
+    /// Generation timestamp (RFC 3339).
+    pub generated_at: String,
+    /// Crate version that generated the outputs.
+    #[serde(default)]
+    pub version: String,
+    /// Hash of the configuration used (see [`OutputManifest::hash_config`]).
+    pub config_hash: Option<String>,
+    /// Hash of the scoring weights file used, if it could be read.
+    pub weights_hash: Option<String>,
+    /// Structured output format used ("json", "toml", "both", "pdf", "docx",
+    /// "md" or "html").
+    pub format: String,
+    /// File names written into the output directory.
+    pub files: Vec<String>,
+    /// Total score, if scored.
+    pub total_score: Option<f64>,
+    /// Number of recommendations generated.
+    pub recommendations_count: usize,
+    /// Additional metadata.
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+impl OutputManifest {
+    /// Load the manifest from an output directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be read or parsed.
+    pub fn load(output_dir: impl AsRef<Path>) -> Result<Self> {
+        crate::toml_io::load_as(output_dir.as_ref().join(MANIFEST_FILE))
+    }
+
+    /// Write the manifest into an output directory, replacing any existing
+    /// one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be serialized or written.
+    pub fn save(&self, output_dir: impl AsRef<Path>) -> Result<()> {
+        let value = serde_json::to_value(self)?;
+        let content = crate::toml_io::dumps(&strip_nulls(&value))?;
+        atomic_write(output_dir.as_ref().join(MANIFEST_FILE), &content)
+    }
+
+    /// File name of the structured (JSON or TOML) enhanced resume, if one was
+    /// written. JSON is preferred when both are present.
+    pub fn enhanced_resume_file(&self) -> Option<&str> {
+        let find = |suffix: &str| {
+            self.files
+                .iter()
+                .find(|f| f.ends_with(suffix))
+                .map(String::as_str)
+        };
+        find("_enhanced.json").or_else(|| find("_enhanced.toml"))
+    }
+
+    /// Hash identifying a configuration: SHA256 of its serialized form.
+    pub fn hash_config(config: &Config) -> String {
+        let value = serde_json::to_value(config).unwrap_or_default();
+        calculate_string_hash(&value.to_string())
+    }
+
+    /// SHA256 of the scoring weights file, or `None` if it cannot be read.
+    pub fn hash_weights_file(path: impl AsRef<Path>) -> Option<String> {
+        calculate_file_hash(path).ok()
+    }
+
+    /// Whether the outputs are out of date for `config` and the scoring
+    /// weights file at `weights_path`.
+    ///
+    /// Outputs are stale when they were generated by a different crate
+    /// version, or with a configuration or weights file whose content has
+    /// since changed. Manifests without a config hash are always stale.
+    pub fn is_stale(&self, config: &Config, weights_path: impl AsRef<Path>) -> bool {
+        self.version != crate::VERSION
+            || self.config_hash.as_deref() != Some(Self::hash_config(config).as_str())
+            || self.weights_hash != Self::hash_weights_file(weights_path)
+    }
+}
+
+/// One run recorded in the [`OutputIndex`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutputIndexEntry {
+    /// Resume base name.
+    pub resume_name: String,
+    /// Job title, if any.
+    pub job_title: Option<String>,
+    /// Total score, if scored.
+    pub score: Option<f64>,
+    /// Output directory, relative to the output folder.
+    pub path: PathBuf,
+    /// Generation timestamp (RFC 3339).
+    pub timestamp: String,
+}
+
+/// History of the runs written to an output folder, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OutputIndex {
+    /// Recorded runs.
+    #[serde(default)]
+    pub runs: Vec<OutputIndexEntry>,
+}
+
+impl OutputIndex {
+    /// Load the index from an output folder. A missing index is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index exists but cannot be read or parsed.
+    pub fn load(output_folder: impl AsRef<Path>) -> Result<Self> {
+        let path = output_folder.as_ref().join(INDEX_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        crate::toml_io::load_as(path)
+    }
+
+    /// Write the index into an output folder, replacing any existing one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be serialized or written.
+    pub fn save(&self, output_folder: impl AsRef<Path>) -> Result<()> {
+        let value = serde_json::to_value(self)?;
+        let content = crate::toml_io::dumps(&strip_nulls(&value))?;
+        atomic_write(output_folder.as_ref().join(INDEX_FILE), &content)
+    }
+
+    /// Append a run to the index in `output_folder`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the existing index cannot be loaded or the updated
+    /// one cannot be written.
+    pub fn append(output_folder: impl AsRef<Path>, entry: OutputIndexEntry) -> Result<()> {
+        let _guard = INDEX_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let output_folder = output_folder.as_ref();
+        let mut index = Self::load(output_folder)?;
+        index.runs.push(entry);
+        index.save(output_folder)
+    }
+}
+
+// -------------------------
+// OutputGenerator
+// -------------------------
+
+/// Generates output artifacts for processed resumes.
+#[derive(Debug, Clone)]
+pub struct OutputGenerator {
+    output_folder: PathBuf,
+    format: String,
+    subdir_pattern: String,
+    provider: Option<String>,
+    config_hash: Option<String>,
+    weights_hash: Option<String>,
+}
+
+impl OutputGenerator {
+    /// Create a new output generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_folder` - Root folder for all outputs
+    /// * `format` - Structured output format: "json", "toml", "both", "pdf",
+    ///   "docx", "md" (alias "markdown") or "html"
+    /// * `subdir_pattern` - Pattern for the per-run output directory
+    pub fn new(output_folder: PathBuf, format: String, subdir_pattern: String) -> Self {
+        Self {
+            output_folder,
+            format: format.to_lowercase(),
+            subdir_pattern,
+            provider: None,
+            config_hash: None,
+            weights_hash: None,
+        }
+    }
+
+    /// Set the AI provider substituted for `{provider}` in the subdir
+    /// pattern. Without one, path segments using `{provider}` are omitted.
+    #[must_use]
+    pub fn with_provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
+    /// Record hashes of `config` and its scoring weights file in every
+    /// manifest, so [`OutputManifest::is_stale`] can detect outputs that
+    /// predate a config or weights change.
+    #[must_use]
+    pub fn with_fingerprint(mut self, config: &Config) -> Self {
+        self.config_hash = Some(OutputManifest::hash_config(config));
+        self.weights_hash = OutputManifest::hash_weights_file(&config.scoring_weights_file);
+        self
+    }
+
+    /// Root output folder.
+    pub fn output_folder(&self) -> &Path {
+        &self.output_folder
+    }
+
+    /// Generate all outputs for the given data.
+    ///
+    /// Returns the directory the outputs were written to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output directory cannot be created, the format
+    /// is unsupported, or any file cannot be serialized or written.
+    pub fn generate(&self, data: &OutputData) -> Result<PathBuf> {
+        let (write_json, write_toml, write_pdf, write_docx, write_markdown, write_html) =
+            match self.format.as_str() {
+                "json" => (true, false, false, false, false, false),
+                "toml" => (false, true, false, false, false, false),
+                "both" => (true, true, false, false, false, false),
+                "pdf" => (false, false, true, false, false, false),
+                "docx" => (false, false, false, true, false, false),
+                "md" | "markdown" => (false, false, false, false, true, false),
+                "html" => (false, false, false, false, false, true),
+                other => {
+                    return Err(AtsError::UnsupportedFormat {
+                        format: other.to_string(),
+                    })
+                }
+            };
+
+        let output_dir = self.create_output_dir(data)?;
+
+        let base_name = Self::base_filename(data);
+        let mut files = Vec::new();
+
+        if write_json {
+            let file_name = format!("{base_name}_enhanced.json");
+            let content = serde_json::to_string_pretty(&data.enhanced_resume)?;
+            atomic_write(output_dir.join(&file_name), &content)?;
+            files.push(file_name);
+        }
+
+        if write_toml {
+            let file_name = format!("{base_name}_enhanced.toml");
+            let content = crate::toml_io::dumps(&strip_nulls(&data.enhanced_resume))?;
+            atomic_write(output_dir.join(&file_name), &content)?;
+            files.push(file_name);
+        }
+
+        if write_pdf {
+            files.push(Self::write_pdf(data, &output_dir)?);
+        }
+
+        if write_docx {
+            files.push(Self::write_docx(data, &output_dir)?);
+        }
+
+        if write_markdown {
+            files.push(Self::write_markdown(data, &output_dir)?);
+        }
+
+        if write_html {
+            files.push(Self::write_html(data, &output_dir)?);
+        }
+
+        let txt_name = format!("{base_name}_enhanced.txt");
+        atomic_write(
+            output_dir.join(&txt_name),
+            &render_resume_text(&data.enhanced_resume),
+        )?;
+        files.push(txt_name);
+
+        if let Some(letter) = &data.cover_letter {
+            let file_name = format!("{base_name}_cover_letter.txt");
+            atomic_write(output_dir.join(&file_name), letter)?;
+            files.push(file_name);
+        }
+
+        if let Some(scores) = &data.scores {
+            write_scores(&output_dir, scores)?;
+            files.push(SCORES_FILE.to_string());
+        }
+
+        let manifest = OutputManifest {
+            resume_name: data.resume_name.clone(),
+            job_title: data.job_title.clone(),
+            generated_at: chrono::Local::now().to_rfc3339(),
+            version: crate::VERSION.to_string(),
+            config_hash: self.config_hash.clone(),
+            weights_hash: self.weights_hash.clone(),
+            format: self.format.clone(),
+            files,
+            total_score: data.scores.as_ref().map(|s| s.total),
+            recommendations_count: data.recommendations.len(),
+            metadata: data.metadata.clone(),
+        };
+        manifest.save(&output_dir)?;
+
+        let entry = OutputIndexEntry {
+            resume_name: manifest.resume_name,
+            job_title: manifest.job_title,
+            score: manifest.total_score,
+            path: output_dir
+                .strip_prefix(&self.output_folder)
+                .unwrap_or(&output_dir)
+                .to_path_buf(),
+            timestamp: manifest.generated_at,
+        };
+        // The run's outputs are complete; a broken index shouldn't fail it
+        if let Err(e) = OutputIndex::append(&self.output_folder, entry) {
+            log::warn!(
+                "Failed to update {} in {}: {e}",
+                INDEX_FILE,
+                self.output_folder.display()
+            );
+        }
+
+        Ok(output_dir)
+    }
+
+    /// Render the enhanced resume as a PDF.
+    ///
+    /// The file is written to the same per-run directory `generate` would use
+    /// and its path is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output directory cannot be created or the PDF
+    /// cannot be rendered or written.
+    pub fn generate_pdf(&self, data: &OutputData) -> Result<PathBuf> {
+        let output_dir = self.create_output_dir(data)?;
+        let file_name = Self::write_pdf(data, &output_dir)?;
+        Ok(output_dir.join(file_name))
+    }
+
+    /// Write `<base>_enhanced.pdf` into `output_dir`, returning the file name.
+    fn write_pdf(data: &OutputData, output_dir: &Path) -> Result<String> {
+        let file_name = format!("{}_enhanced.pdf", Self::base_filename(data));
+        let bytes = pdf::render_resume_pdf(&data.enhanced_resume)?;
+        atomic_write_bytes(output_dir.join(&file_name), &bytes)?;
+        Ok(file_name)
+    }
+
+    /// Render the enhanced resume as a Word (DOCX) document.
+    ///
+    /// Section titles use Word heading styles, organisations are bold and
+    /// entry details are bulleted lists. The file is written to the same
+    /// per-run directory `generate` would use and its path is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output directory cannot be created or the
+    /// document cannot be packaged or written.
+    pub fn generate_docx(&self, data: &OutputData) -> Result<PathBuf> {
+        let output_dir = self.create_output_dir(data)?;
+        let file_name = Self::write_docx(data, &output_dir)?;
+        Ok(output_dir.join(file_name))
+    }
+
+    /// Write `<base>_enhanced.docx` into `output_dir`, returning the file name.
+    fn write_docx(data: &OutputData, output_dir: &Path) -> Result<String> {
+        let file_name = format!("{}_enhanced.docx", Self::base_filename(data));
+        let bytes = docx::render_resume_docx(&data.enhanced_resume)?;
+        atomic_write_bytes(output_dir.join(&file_name), &bytes)?;
+        Ok(file_name)
+    }
+
+    /// Render the enhanced resume as Markdown.
+    ///
+    /// Includes a score summary table when `scores` is present. The file is
+    /// written to the same per-run directory `generate` would use and its path
+    /// is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output directory cannot be created or the file
+    /// cannot be written.
+    pub fn generate_markdown(&self, data: &OutputData) -> Result<PathBuf> {
+        let output_dir = self.create_output_dir(data)?;
+        let file_name = Self::write_markdown(data, &output_dir)?;
+        Ok(output_dir.join(file_name))
+    }
+
+    /// Write `<base>_enhanced.md` into `output_dir`, returning the file name.
+    fn write_markdown(data: &OutputData, output_dir: &Path) -> Result<String> {
+        let file_name = format!("{}_enhanced.md", Self::base_filename(data));
+        let content = markdown::render_resume_markdown(&data.enhanced_resume, data.scores.as_ref());
+        atomic_write(output_dir.join(&file_name), &content)?;
+        Ok(file_name)
+    }
+
+    /// Render the enhanced resume as a self-contained HTML page.
+    ///
+    /// The page embeds the score breakdown and recommendations next to the
+    /// resume. The file is written to the same per-run directory `generate`
+    /// would use and its path is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output directory cannot be created or the file
+    /// cannot be written.
+    pub fn generate_html(&self, data: &OutputData) -> Result<PathBuf> {
+        let output_dir = self.create_output_dir(data)?;
+        let file_name = Self::write_html(data, &output_dir)?;
+        Ok(output_dir.join(file_name))
+    }
+
+    /// Write `<base>_enhanced.html` into `output_dir`, returning the file name.
+    fn write_html(data: &OutputData, output_dir: &Path) -> Result<String> {
+        let file_name = format!("{}_enhanced.html", Self::base_filename(data));
+        atomic_write(output_dir.join(&file_name), &html::render_resume_html(data))?;
+        Ok(file_name)
+    }
+
+    /// Resolve and create the output directory for the given data.
+    fn create_output_dir(&self, data: &OutputData) -> Result<PathBuf> {
+        let output_dir = self.output_dir_for(data);
+        std::fs::create_dir_all(&output_dir).map_err(|e| AtsError::DirectoryCreation {
+            path: output_dir.clone(),
+            source: e,
+        })?;
+        Ok(output_dir)
+    }
+
+    /// Resolve the output directory for the given data from the subdir pattern.
+    fn output_dir_for(&self, data: &OutputData) -> PathBuf {
+        let now = chrono::Local::now();
+        let tokens = SubdirTokens {
+            resume_name: data.resume_name.clone(),
+            job_title: data.job_title.clone().unwrap_or_default(),
+            timestamp: now.format("%Y%m%d_%H%M%S").to_string(),
+            date: now.format("%Y%m%d").to_string(),
+            score: data
+                .scores
+                .as_ref()
+                .map(|s| format!("{:.0}", s.total))
+                .unwrap_or_default(),
+            provider: self.provider.clone().unwrap_or_default(),
+        };
+
+        self.output_folder
+            .join(render_subdir(&self.subdir_pattern, &tokens))
+    }
+
+    /// Base file name shared by all resume artifacts: `<resume>_<job>`.
+    fn base_filename(data: &OutputData) -> String {
+        let job = data.job_title.as_deref().unwrap_or(NO_JOB_PLACEHOLDER);
+        sanitize_filename(&format!("{}_{}", data.resume_name, job))
+    }
+}
+
+// -------------------------
+// Subdirectory Patterns
+// -------------------------
+
+/// Values substituted into an output subdirectory pattern.
+///
+/// An empty value means the token is unavailable (e.g. no job title).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubdirTokens {
+    /// `{resume_name}`: the input file stem.
+    pub resume_name: String,
+    /// `{job_title}`: the job description's file stem.
+    pub job_title: String,
+    /// `{timestamp}`: generation time as `YYYYMMDD_HHMMSS`.
+    pub timestamp: String,
+    /// `{date}`: generation date as `YYYYMMDD`.
+    pub date: String,
+    /// `{score}`: the resume's total score, rounded.
+    pub score: String,
+    /// `{provider}`: the AI provider that enhanced the resume.
+    pub provider: String,
+}
+
+impl SubdirTokens {
+    fn get(&self, name: &str) -> Option<&str> {
+        Some(match name {
+            "resume_name" => &self.resume_name,
+            "job_title" => &self.job_title,
+            "timestamp" => &self.timestamp,
+            "date" => &self.date,
+            "score" => &self.score,
+            "provider" => &self.provider,
+            _ => return None,
+        })
+    }
+}
+
+/// Expand a subdirectory pattern such as `{resume_name}/{job_title}` into a
+/// relative path.
+///
+/// Each `/`-separated segment becomes one directory level. Token values are
+/// passed through [`sanitize_filename`], so they can't add levels of their
+/// own, and unknown `{tokens}` are kept literally. A segment whose tokens are
+/// all empty is omitted rather than leaving an empty or dangling level.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::output::{render_subdir, SubdirTokens};
+/// use std::path::PathBuf;
+///
+/// let tokens = SubdirTokens {
+///     resume_name: "jane_doe".to_string(),
+///     score: "82".to_string(),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     render_subdir("{resume_name}/{job_title}/score_{score}", &tokens),
+///     PathBuf::from("jane_doe").join("score_82")
+/// );
+/// ```
+#[must_use]
+pub fn render_subdir(pattern: &str, tokens: &SubdirTokens) -> PathBuf {
+    pattern
+        .split('/')
+        .filter_map(|segment| render_segment(segment, tokens))
+        .collect()
+}
+
+/// Render one path segment, or `None` if it should be omitted.
+fn render_segment(segment: &str, tokens: &SubdirTokens) -> Option<String> {
+    let mut rendered = String::new();
+    let mut has_token = false;
+    let mut has_value = false;
+    let mut rest = segment;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start..];
+        let token = after
+            .find('}')
+            .and_then(|end| tokens.get(&after[1..end]).map(|value| (end, value)));
+        if let Some((end, value)) = token {
+            let value = sanitize_filename(value);
+            has_token = true;
+            has_value |= !value.is_empty();
+            rendered.push_str(&value);
+            rest = &after[end + 1..];
+        } else {
+            rendered.push('{');
+            rest = &after[1..];
+        }
+    }
+    rendered.push_str(rest);
+
+    let rendered = rendered.trim();
+    if rendered.is_empty() || (has_token && !has_value) {
+        return None;
+    }
+    // A bare "." or ".." would escape or collapse the level
+    if rendered.chars().all(|c| c == '.') {
+        return Some(rendered.replace('.', "_"));
+    }
+    Some(rendered.to_string())
+}
+
+/// Write a score report to [`SCORES_FILE`] in `output_dir`.
+///
+/// # Errors
+///
+/// Returns an error if the report cannot be serialized or written.
+pub fn write_scores(output_dir: impl AsRef<Path>, scores: &ScoreReport) -> Result<()> {
+    let content = crate::toml_io::dumps(&strip_nulls(&scores.as_dict()))?;
+    atomic_write(output_dir.as_ref().join(SCORES_FILE), &content)
+}
+
+// -------------------------
+// Document Sections
+// -------------------------
+
+/// A section of the formatted (PDF, DOCX and Markdown) resume renderings.
+enum DocumentSection {
+    /// `summary`, as one or more paragraphs.
+    Summary,
+    /// `skills`, one line per category when grouped.
+    Skills,
+    /// A list of entries such as jobs or degrees.
+    Entries(EntrySection),
+}
+
+/// Where a list-of-entries section lives and how its entries are labelled.
+struct EntrySection {
+    title: &'static str,
+    /// Resume keys holding the section; the first present one is used.
+    keys: &'static [&'static str],
+    /// Keys for the entry heading; the first present one is used.
+    title_keys: &'static [&'static str],
+    /// Keys joined into the entry's details line.
+    detail_keys: &'static [&'static str],
+}
+
+/// Sections of the formatted renderings, in document order.
+const DOCUMENT_SECTIONS: [DocumentSection; 6] = [
+    DocumentSection::Summary,
+    DocumentSection::Entries(EntrySection {
+        title: "Experience",
+        keys: &["experience", "work_experience"],
+        title_keys: &["title", "position", "role"],
+        detail_keys: &["company", "organization", "location", "duration", "dates"],
+    }),
+    DocumentSection::Skills,
+    DocumentSection::Entries(EntrySection {
+        title: "Education",
+        keys: &["education"],
+        title_keys: &["degree", "field", "field_of_study"],
+        detail_keys: &[
+            "institution",
+            "school",
+            "location",
+            "year",
+            "graduation_date",
+        ],
+    }),
+    DocumentSection::Entries(EntrySection {
+        title: "Projects",
+        keys: &["projects"],
+        title_keys: &["name", "title"],
+        detail_keys: &["role", "technologies", "duration", "dates"],
+    }),
+    DocumentSection::Entries(EntrySection {
+        title: "Certifications",
+        keys: &["certifications"],
+        title_keys: &["name", "title"],
+        detail_keys: &["issuer", "organization", "date", "year"],
+    }),
+];
+
+/// Name, headline and contact details for the top of a document.
+struct DocumentHeader {
+    name: Option<String>,
+    headline: Option<String>,
+    contact: Vec<String>,
+}
+
+impl DocumentHeader {
+    /// Read the header from `personal_info`, falling back to top-level keys.
+    fn from_resume(resume: &serde_json::Value) -> Self {
+        let personal = resume.get("personal_info").unwrap_or(resume);
+        let field = |key: &str| {
+            personal
+                .get(key)
+                .or_else(|| resume.get(key))
+                .and_then(scalar_text)
+        };
+
+        Self {
+            name: field("name"),
+            headline: field("title").or_else(|| field("headline")),
+            contact: [
+                "email", "phone", "location", "linkedin", "github", "website",
+            ]
+            .iter()
+            .filter_map(|key| field(key))
+            .collect(),
+        }
+    }
+}
+
+/// A single entry of an [`EntrySection`], ready for layout.
+struct DocumentEntry {
+    heading: Option<String>,
+    details: Vec<String>,
+    bullets: Vec<String>,
+}
+
+impl EntrySection {
+    /// Entries of this section. Plain string items become bullet-only entries.
+    fn entries(&self, resume: &serde_json::Value) -> Vec<DocumentEntry> {
+        let Some(items) = self
+            .keys
+            .iter()
+            .find_map(|key| resume.get(*key))
+            .and_then(serde_json::Value::as_array)
+        else {
+            return Vec::new();
+        };
+
+        items
+            .iter()
+            .filter_map(|item| {
+                if let Some(text) = scalar_text(item) {
+                    return Some(DocumentEntry {
+                        heading: None,
+                        details: Vec::new(),
+                        bullets: vec![text],
+                    });
+                }
+                if !item.is_object() {
+                    return None;
+                }
+
+                let pick = |keys: &[&str]| -> Vec<String> {
+                    keys.iter()
+                        .filter_map(|key| item.get(*key).and_then(scalar_text))
+                        .collect()
+                };
+                Some(DocumentEntry {
+                    heading: pick(self.title_keys).into_iter().next(),
+                    details: pick(self.detail_keys),
+                    bullets: entry_bullets(item),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Summary paragraphs from a string or array `summary`.
+fn summary_paragraphs(resume: &serde_json::Value) -> Vec<String> {
+    match resume.get("summary") {
+        Some(serde_json::Value::Array(items)) => items.iter().filter_map(scalar_text).collect(),
+        Some(other) => scalar_text(other).into_iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Skill lines as `(category, comma-separated skills)`.
+///
+/// Grouped skills yield one line per category; a flat list or string yields a
+/// single line without a category.
+fn skill_lines(resume: &serde_json::Value) -> Vec<(Option<String>, String)> {
+    match resume.get("skills") {
+        Some(serde_json::Value::Object(groups)) => groups
+            .iter()
+            .filter_map(|(category, items)| {
+                let list = join_list(items)?;
+                Some((Some(capitalize(&category.replace('_', " "))), list))
+            })
+            .collect(),
+        Some(other) => join_list(other)
+            .map(|list| (None, list))
+            .into_iter()
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Comma-join a list (array or plain string).
+fn join_list(value: &serde_json::Value) -> Option<String> {
+    let joined = match value {
+        serde_json::Value::Array(items) => items
+            .iter()
+            .filter_map(scalar_text)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => scalar_text(other)?,
+    };
+    (!joined.is_empty()).then_some(joined)
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// -------------------------
+// Rendering Helpers
+// -------------------------
+
+/// Remove null values recursively (TOML has no null).
+fn strip_nulls(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k.clone(), strip_nulls(v)))
+                .collect(),
+        ),
+        serde_json::Value::Array(arr) => serde_json::Value::Array(
+            arr.iter()
+                .filter(|v| !v.is_null())
+                .map(strip_nulls)
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Text of a scalar JSON value, or `None` for empty strings and non-scalars.
+fn scalar_text(value: &serde_json::Value) -> Option<String> {
+    let text = match value {
+        serde_json::Value::String(s) => s.trim().to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        _ => return None,
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+/// Bullet points of an experience/project entry.
+///
+/// Reads the first of `bullets`, `description`, `achievements`,
+/// `responsibilities` or `highlights` that yields any lines. String values are
+/// split on newlines; arrays contribute one bullet per string element.
+pub(crate) fn entry_bullets(entry: &serde_json::Value) -> Vec<String> {
+    [
+        "bullets",
+        "description",
+        "achievements",
+        "responsibilities",
+        "highlights",
+    ]
+    .iter()
+    .map(|key| extract_bullets(entry.get(*key)))
+    .find(|bullets| !bullets.is_empty())
+    .unwrap_or_default()
+}
+
+fn extract_bullets(value: Option<&serde_json::Value>) -> Vec<String> {
+    match value {
+        Some(serde_json::Value::String(s)) => s
+            .lines()
+            .map(|l| l.trim().trim_start_matches(['-', '*', '\u{2022}']).trim())
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Some(serde_json::Value::Array(arr)) => arr.iter().filter_map(scalar_text).collect(),
+        _ => vec![],
+    }
+}
+
+/// Render a resume JSON value as plain text.
+///
+/// Known sections are rendered first in a conventional order; any remaining
+/// top-level keys are appended afterwards so no content is lost.
+pub fn render_resume_text(resume: &serde_json::Value) -> String {
+    let Some(obj) = resume.as_object() else {
+        return value_to_text(resume, 0);
+    };
+
+    let mut out = String::new();
+
+    if let Some(personal) = obj.get("personal_info") {
+        out.push_str(&value_to_text(personal, 0));
+        out.push('\n');
+    }
+
+    for key in ["name", "email", "phone", "location"] {
+        if let Some(v) = obj.get(key) {
+            out.push_str(&value_to_text(v, 0));
+        }
+    }
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+
+    let ordered = [
+        "summary",
+        "experience",
+        "work_experience",
+        "education",
+        "skills",
+        "projects",
+        "certifications",
+    ];
+    let skip = ["personal_info", "name", "email", "phone", "location"];
+
+    for key in ordered {
+        if let Some(v) = obj.get(key) {
+            push_section(&mut out, key, v);
+        }
+    }
+
+    for (key, v) in obj {
+        if ordered.contains(&key.as_str()) || skip.contains(&key.as_str()) {
+            continue;
+        }
+        push_section(&mut out, key, v);
+    }
+
+    out.trim_end().to_string() + "\n"
+}
+
+fn push_section(out: &mut String, key: &str, value: &serde_json::Value) {
+    let body = value_to_text(value, 0);
+    if body.trim().is_empty() {
+        return;
+    }
+    out.push_str(&key.replace('_', " ").to_uppercase());
+    out.push('\n');
+    out.push_str(&body);
+    out.push('\n');
+}
+
+fn value_to_text(value: &serde_json::Value, indent: usize) -> String {
+    let pad = "  ".repeat(indent);
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => format!("{pad}{s}\n"),
+        serde_json::Value::Bool(_) | serde_json::Value::Number(_) => format!("{pad}{value}\n"),
+        serde_json::Value::Array(arr) => arr
+            .iter()
+            .map(|v| match v {
+                serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
+                    value_to_text(v, indent) + "\n"
+                }
+                _ => format!("{pad}- {}", value_to_text(v, 0)),
+            })
+            .collect(),
+        serde_json::Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| match v {
+                serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
+                    format!("{pad}{k}:\n{}", value_to_text(v, indent + 1))
+                }
+                _ => format!("{pad}{k}: {}", value_to_text(v, 0)),
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,414 @@
+//! PDF rendering for enhanced resumes.
+//!
+//! Lays the resume JSON out as a single-column US Letter document using the
+//! standard Helvetica fonts, so no font files need to be embedded. Sections
+//! that are missing or empty in the resume are skipped.
+
+use super::{
+    skill_lines, summary_paragraphs, DocumentEntry, DocumentHeader, DocumentSection,
+    DOCUMENT_SECTIONS,
+};
+use crate::error::{AtsError, Result};
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Document, Object, Stream};
+use serde_json::Value;
+
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 54.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+
+const NAME_SIZE: f32 = 20.0;
+const HEADING_SIZE: f32 = 12.5;
+const BODY_SIZE: f32 = 10.5;
+const LINE_SPACING: f32 = 1.35;
+const BULLET_INDENT: f32 = 14.0;
+
+/// Standard fonts registered in the page resources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource_name(self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+}
+
+/// Render a resume JSON value as a PDF document.
+///
+/// # Errors
+///
+/// Returns an error if the PDF content cannot be encoded or serialized.
+pub(crate) fn render_resume_pdf(resume: &Value) -> Result<Vec<u8>> {
+    let mut layout = PdfLayout::new();
+
+    layout.header(&DocumentHeader::from_resume(resume));
+
+    for section in &DOCUMENT_SECTIONS {
+        match section {
+            DocumentSection::Summary => {
+                let paragraphs = summary_paragraphs(resume);
+                if !paragraphs.is_empty() {
+                    layout.heading("Summary");
+                    for paragraph in paragraphs {
+                        layout.paragraph(&paragraph, Font::Regular, 0.0);
+                    }
+                }
+            }
+            DocumentSection::Skills => layout.skills(&skill_lines(resume)),
+            DocumentSection::Entries(section) => {
+                layout.entries(section.title, &section.entries(resume));
+            }
+        }
+    }
+
+    layout.finish()
+}
+
+// -------------------------
+// Layout
+// -------------------------
+
+/// Lays text out top-to-bottom, starting a new page when the current one is full.
+struct PdfLayout {
+    pages: Vec<Vec<Operation>>,
+    y: f32,
+}
+
+impl PdfLayout {
+    fn new() -> Self {
+        Self {
+            pages: vec![Vec::new()],
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    fn ops(&mut self) -> &mut Vec<Operation> {
+        self.pages.last_mut().expect("layout always has a page")
+    }
+
+    /// Start a new page if fewer than `height` points remain on this one.
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.pages.push(Vec::new());
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+    }
+
+    fn gap(&mut self, amount: f32) {
+        self.y -= amount;
+    }
+
+    /// Place a single line of text at the given indent and advance.
+    fn line(&mut self, text: &str, font: Font, size: f32, indent: f32) {
+        let height = size * LINE_SPACING;
+        self.ensure_space(height);
+        let baseline = self.y - size;
+        self.text_at(text, font, size, MARGIN + indent, baseline);
+        self.y -= height;
+    }
+
+    fn text_at(&mut self, text: &str, font: Font, size: f32, x: f32, baseline: f32) {
+        self.ops().extend([
+            Operation::new("BT", vec![]),
+            Operation::new("Tf", vec![font.resource_name().into(), size.into()]),
+            Operation::new("Td", vec![x.into(), baseline.into()]),
+            Operation::new("Tj", vec![Object::string_literal(encode_win_ansi(text))]),
+            Operation::new("ET", vec![]),
+        ]);
+    }
+
+    /// Place word-wrapped text at the given indent.
+    fn paragraph(&mut self, text: &str, font: Font, indent: f32) {
+        for line in wrap(text, font, BODY_SIZE, CONTENT_WIDTH - indent) {
+            self.line(&line, font, BODY_SIZE, indent);
+        }
+    }
+
+    /// Place a bulleted, word-wrapped item with a hanging indent.
+    fn bullet(&mut self, text: &str) {
+        let lines = wrap(
+            text,
+            Font::Regular,
+            BODY_SIZE,
+            CONTENT_WIDTH - BULLET_INDENT,
+        );
+        for (i, line) in lines.iter().enumerate() {
+            self.line(line, Font::Regular, BODY_SIZE, BULLET_INDENT);
+            if i == 0 {
+                let baseline = self.y + BODY_SIZE * (LINE_SPACING - 1.0);
+                self.text_at("\u{2022}", Font::Regular, BODY_SIZE, MARGIN + 4.0, baseline);
+            }
+        }
+    }
+
+    /// Section heading followed by a horizontal rule.
+    fn heading(&mut self, title: &str) {
+        self.gap(BODY_SIZE * 0.8);
+        // Keep the heading together with at least one line of its section
+        self.ensure_space(HEADING_SIZE * LINE_SPACING + BODY_SIZE * LINE_SPACING * 2.0);
+        self.line(&title.to_uppercase(), Font::Bold, HEADING_SIZE, 0.0);
+
+        let rule_y = self.y + HEADING_SIZE * (LINE_SPACING - 1.0) / 2.0;
+        self.ops().extend([
+            Operation::new("w", vec![0.75.into()]),
+            Operation::new("m", vec![MARGIN.into(), rule_y.into()]),
+            Operation::new("l", vec![(PAGE_WIDTH - MARGIN).into(), rule_y.into()]),
+            Operation::new("S", vec![]),
+        ]);
+        self.gap(BODY_SIZE * 0.3);
+    }
+
+    /// Name, headline and contact details.
+    fn header(&mut self, header: &DocumentHeader) {
+        if let Some(name) = &header.name {
+            self.line(name, Font::Bold, NAME_SIZE, 0.0);
+        }
+        if let Some(headline) = &header.headline {
+            self.paragraph(headline, Font::Regular, 0.0);
+        }
+        if !header.contact.is_empty() {
+            self.paragraph(&header.contact.join("  |  "), Font::Regular, 0.0);
+        }
+    }
+
+    /// A section of entries (experience, education, ...) with a bold title
+    /// line, a details line and any bullets.
+    fn entries(&mut self, title: &str, entries: &[DocumentEntry]) {
+        if entries.is_empty() {
+            return;
+        }
+
+        self.heading(title);
+        for (i, entry) in entries.iter().enumerate() {
+            if i > 0 && entry.heading.is_some() {
+                self.gap(BODY_SIZE * 0.5);
+            }
+            if let Some(heading) = &entry.heading {
+                self.paragraph(heading, Font::Bold, 0.0);
+            }
+            if !entry.details.is_empty() {
+                self.paragraph(&entry.details.join("  |  "), Font::Regular, 0.0);
+            }
+            for bullet in &entry.bullets {
+                self.bullet(bullet);
+            }
+        }
+    }
+
+    /// Skills as `Category: a, b, c` lines, or a single comma-separated list.
+    fn skills(&mut self, lines: &[(Option<String>, String)]) {
+        if lines.is_empty() {
+            return;
+        }
+
+        self.heading("Skills");
+        for (category, list) in lines {
+            let text = match category {
+                Some(category) => format!("{category}: {list}"),
+                None => list.clone(),
+            };
+            self.paragraph(&text, Font::Regular, 0.0);
+        }
+    }
+
+    /// Assemble the laid-out pages into a PDF file.
+    fn finish(self) -> Result<Vec<u8>> {
+        let mut doc = Document::with_version("1.5");
+        let page_tree_id = doc.new_object_id();
+
+        let font = |base: &str| {
+            dictionary! {
+                "Type" => "Font",
+                "Subtype" => "Type1",
+                "BaseFont" => base,
+                "Encoding" => "WinAnsiEncoding",
+            }
+        };
+        let regular_id = doc.add_object(font("Helvetica"));
+        let bold_id = doc.add_object(font("Helvetica-Bold"));
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! {
+                Font::Regular.resource_name() => regular_id,
+                Font::Bold.resource_name() => bold_id,
+            },
+        });
+
+        let mut kids = Vec::with_capacity(self.pages.len());
+        for operations in self.pages {
+            let content =
+                Content { operations }
+                    .encode()
+                    .map_err(|e| AtsError::OutputGeneration {
+                        message: format!("Failed to encode PDF content: {e}"),
+                    })?;
+            let content_id = doc.add_object(Stream::new(dictionary! {}, content));
+            let page_id = doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => page_tree_id,
+                "Contents" => content_id,
+            });
+            kids.push(page_id.into());
+        }
+
+        let page_count = i64::try_from(kids.len()).unwrap_or(i64::MAX);
+        doc.objects.insert(
+            page_tree_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => page_count,
+                "Resources" => resources_id,
+                "MediaBox" => vec![0.into(), 0.into(), PAGE_WIDTH.into(), PAGE_HEIGHT.into()],
+            }),
+        );
+
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => page_tree_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.compress();
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes)
+            .map_err(|e| AtsError::OutputGeneration {
+                message: format!("Failed to write PDF: {e}"),
+            })?;
+        Ok(bytes)
+    }
+}
+
+// -------------------------
+// Text Helpers
+// -------------------------
+
+/// Greedy word wrap using approximate Helvetica metrics.
+fn wrap(text: &str, font: Font, size: f32, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() {
+            word.to_string()
+        } else {
+            format!("{current} {word}")
+        };
+        if !current.is_empty() && text_width(&candidate, font, size) > max_width {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+        } else {
+            current = candidate;
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+}
+
+/// Approximate rendered width of `text` in points.
+///
+/// Uses coarse character classes rather than full AFM metrics; this is only
+/// used for line breaking, where slight overestimates are harmless.
+fn text_width(text: &str, font: Font, size: f32) -> f32 {
+    let units: f32 = text
+        .chars()
+        .map(|c| match c {
+            'i' | 'j' | 'l' | '.' | ',' | ':' | ';' | '\'' | '|' | '!' => 278.0,
+            ' ' | 'f' | 't' | 'r' | 'I' | '(' | ')' | '[' | ']' | '/' | '-' => 333.0,
+            'm' | 'w' | 'M' | 'W' | '@' | '%' => 889.0,
+            'A'..='Z' => 722.0,
+            _ => 556.0,
+        })
+        .sum();
+    let weight = if font == Font::Bold { 1.06 } else { 1.0 };
+    units * weight * size / 1000.0
+}
+
+/// Encode text for a `WinAnsiEncoding` standard font.
+///
+/// Characters outside the encoding are replaced with `?`.
+fn encode_win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            '\u{20}'..='\u{7e}' | '\u{a0}'..='\u{ff}' => u8::try_from(u32::from(c)).unwrap_or(b'?'),
+            '\u{20ac}' => 0x80,
+            '\u{201a}' => 0x82,
+            '\u{201e}' => 0x84,
+            '\u{2026}' => 0x85,
+            '\u{2018}' => 0x91,
+            '\u{2019}' => 0x92,
+            '\u{201c}' => 0x93,
+            '\u{201d}' => 0x94,
+            '\u{2022}' => 0x95,
+            '\u{2013}' => 0x96,
+            '\u{2014}' => 0x97,
+            '\u{2122}' => 0x99,
+            '\t' => b' ',
+            _ => b'?',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_wrap_respects_width() {
+        let text =
+            "Developed microservices architecture handling over one million requests per day";
+        let lines = wrap(text, Font::Regular, BODY_SIZE, 150.0);
+        assert!(lines.len() > 1);
+        assert!(lines
+            .iter()
+            .all(|line| text_width(line, Font::Regular, BODY_SIZE) <= 150.0));
+        assert_eq!(lines.join(" "), text);
+    }
+
+    #[test]
+    fn test_encode_win_ansi() {
+        assert_eq!(
+            encode_win_ansi("Caf\u{e9} \u{2022} \u{2014}"),
+            b"Caf\xe9 \x95 \x97"
+        );
+        assert_eq!(encode_win_ansi("\u{4e2d}"), b"?");
+    }
+
+    #[test]
+    fn test_long_resume_spans_multiple_pages() {
+        let bullets: Vec<String> = (0..120).map(|i| format!("Accomplishment {i}")).collect();
+        let resume = json!({
+            "personal_info": {"name": "Jane Doe"},
+            "experience": [{"title": "Engineer", "bullets": bullets}]
+        });
+
+        let mut layout = PdfLayout::new();
+        layout.header(&DocumentHeader::from_resume(&resume));
+        let DocumentSection::Entries(experience) = &DOCUMENT_SECTIONS[1] else {
+            panic!("experience should be an entry section");
+        };
+        layout.entries(experience.title, &experience.entries(&resume));
+        assert!(layout.pages.len() > 1);
+    }
+
+    #[test]
+    fn test_missing_sections_are_skipped() {
+        let resume = json!({"education": []});
+        let bytes = render_resume_pdf(&resume).unwrap();
+        assert!(bytes.starts_with(b"%PDF-"));
+
+        let mut layout = PdfLayout::new();
+        layout.header(&DocumentHeader::from_resume(&resume));
+        layout.skills(&skill_lines(&resume));
+        assert!(layout.pages[0].is_empty());
+    }
+}
@@ -0,0 +1,166 @@
+//! Ranking of several resume variants against each other.
+//!
+//! Scores each resume (and its match against a job, when one is given) with
+//! the deterministic scoring engine, so users can pick the variant that fares
+//! best without running the AI pipeline.
+
+use crate::error::Result;
+use crate::scoring::{
+    compute_iteration_score, score_match_with_options, score_resume_with_options, ScoreReport,
+    ScoringOptions,
+};
+use serde::{Deserialize, Serialize};
+
+/// Scores for one resume in a comparison.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResumeComparison {
+    /// Name the resume was given in the comparison (e.g. its file stem).
+    pub name: String,
+    /// Standalone resume quality score, with its category breakdown.
+    pub resume_score: ScoreReport,
+    /// Resume-job match score, if a job was given.
+    pub match_score: Option<ScoreReport>,
+    /// Resume and match totals blended with the `[overall.weights]` of the
+    /// weights file, or the resume total when no job was given.
+    pub combined_score: f64,
+}
+
+/// Score and rank named resumes, best combined score first.
+///
+/// Resumes with equal combined scores keep their input order. Pass
+/// [`Config::scoring_options`] so the ranking uses the same profile as the
+/// rest of the pipeline.
+///
+/// [`Config::scoring_options`]: crate::config::Config::scoring_options
+///
+/// # Errors
+///
+/// Returns an error if the weights file cannot be loaded or parsed.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::processor::compare_resumes;
+/// use ats_checker::scoring::ScoringOptions;
+/// use serde_json::json;
+///
+/// let resumes = vec![
+///     ("short".to_string(), json!({"skills": ["Rust"]})),
+///     ("full".to_string(), json!({
+///         "personal_info": {"name": "Jane Doe", "email": "jane@example.com"},
+///         "summary": "Backend engineer building Rust services",
+///         "experience": [{"title": "Engineer", "description": ["Built a Rust API"]}],
+///         "skills": ["Rust", "PostgreSQL"]
+///     })),
+/// ];
+///
+/// let ranking = compare_resumes(&resumes, None, None, &ScoringOptions::default())?;
+/// assert_eq!(ranking[0].name, "full");
+/// # Ok::<(), ats_checker::AtsError>(())
+/// ```
+pub fn compare_resumes(
+    resumes: &[(String, serde_json::Value)],
+    job: Option<&serde_json::Value>,
+    weights_path: Option<&str>,
+    options: &ScoringOptions,
+) -> Result<Vec<ResumeComparison>> {
+    let mut comparisons = resumes
+        .iter()
+        .map(|(name, resume)| {
+            let resume_score = score_resume_with_options(resume, weights_path, options)?;
+            let match_score = job
+                .map(|job| score_match_with_options(resume, job, weights_path, options))
+                .transpose()?;
+            let combined_score = match &match_score {
+                Some(ms) => compute_iteration_score(&resume_score, ms, weights_path).0,
+                None => resume_score.total,
+            };
+
+            Ok(ResumeComparison {
+                name: name.clone(),
+                resume_score,
+                match_score,
+                combined_score,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    comparisons.sort_by(|a, b| b.combined_score.total_cmp(&a.combined_score));
+    Ok(comparisons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_stronger_resume_ranks_first() {
+        let weak = json!({
+            "personal_info": {"name": "Sam Weak"},
+            "skills": ["Excel"]
+        });
+        let strong = json!({
+            "personal_info": {"name": "Jane Strong", "email": "jane@example.com", "phone": "555-123-4567"},
+            "summary": "Backend engineer building Rust services on Kubernetes",
+            "experience": [{
+                "title": "Senior Backend Engineer",
+                "company": "Acme",
+                "description": [
+                    "Built Rust microservices handling 2M requests per day",
+                    "Reduced Kubernetes deployment time by 40%"
+                ]
+            }],
+            "education": [{"degree": "BS Computer Science"}],
+            "skills": ["Rust", "Kubernetes", "PostgreSQL", "AWS"]
+        });
+        let job = json!({
+            "title": "Backend Engineer",
+            "description": "Rust services on Kubernetes with PostgreSQL"
+        });
+
+        let resumes = vec![("weak".to_string(), weak), ("strong".to_string(), strong)];
+        let ranking =
+            compare_resumes(&resumes, Some(&job), None, &ScoringOptions::default()).unwrap();
+
+        assert_eq!(ranking.len(), 2);
+        assert_eq!(ranking[0].name, "strong");
+        assert!(ranking[0].combined_score > ranking[1].combined_score);
+
+        for comparison in &ranking {
+            assert!(!comparison.resume_score.categories.is_empty());
+            let match_score = comparison.match_score.as_ref().unwrap();
+            assert!(!match_score.categories.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_scoring_options_apply_to_matches() {
+        let resumes = vec![(
+            "rust".to_string(),
+            json!({"summary": "Rust engineer", "skills": ["Rust", "Kubernetes"]}),
+        )];
+        let job = json!({"description": "Rust and Kubernetes services on Terraform"});
+        let options = ScoringOptions {
+            required_keywords: vec!["terraform".to_string()],
+            ..ScoringOptions::default()
+        };
+
+        let plain =
+            compare_resumes(&resumes, Some(&job), None, &ScoringOptions::default()).unwrap();
+        let required = compare_resumes(&resumes, Some(&job), None, &options).unwrap();
+
+        let match_total =
+            |ranking: &[ResumeComparison]| ranking[0].match_score.as_ref().unwrap().total;
+        assert!(match_total(&required) < match_total(&plain));
+    }
+
+    #[test]
+    fn test_without_job_uses_resume_score() {
+        let resumes = vec![("only".to_string(), json!({"skills": ["Rust"]}))];
+        let ranking = compare_resumes(&resumes, None, None, &ScoringOptions::default()).unwrap();
+
+        assert!(ranking[0].match_score.is_none());
+        assert_eq!(ranking[0].combined_score, ranking[0].resume_score.total);
+    }
+}
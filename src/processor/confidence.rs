@@ -0,0 +1,193 @@
+//! Sanity checks on AI-enhanced resumes.
+//!
+//! Enhancement can silently drop contact details or invent employers. These
+//! checks compare the structured output with the source text and blend the
+//! results into a single 0-1 confidence score.
+
+use crate::scoring::text::EMAIL_IN_TEXT_RE;
+use crate::validation::validate_json;
+use serde::{Deserialize, Serialize};
+
+/// Confidence below which the processor warns that enhancement may have
+/// mangled the resume.
+pub const LOW_ENHANCEMENT_CONFIDENCE: f64 = 0.6;
+
+/// Weights of the schema, contact and employer checks in the score.
+const SCHEMA_WEIGHT: f64 = 0.3;
+const CONTACT_WEIGHT: f64 = 0.4;
+const EMPLOYER_WEIGHT: f64 = 0.3;
+
+/// How much an enhanced resume can be trusted to reflect its source text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnhancementConfidence {
+    /// Weighted blend of the checks below, from 0 (untrustworthy) to 1.
+    pub score: f64,
+    /// Whether the output passed schema validation (`None` if no schema was
+    /// available).
+    pub schema_valid: Option<bool>,
+    /// Whether the output's candidate name appears in the source text.
+    pub name_found: bool,
+    /// Email addresses in the source text that the output dropped.
+    pub missing_emails: Vec<String>,
+    /// Employers in the output that don't appear in the source text.
+    pub unknown_employers: Vec<String>,
+}
+
+/// Check an enhanced resume against the text it was generated from.
+///
+/// The score blends three checks: schema validity, whether the candidate's
+/// name and every email address in the source survived, and whether each
+/// employer listed under `experience` appears in the source. Name and
+/// employer comparisons ignore case and whitespace differences. When
+/// `schema` is `None` the schema check is left out and the others are
+/// reweighted.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::processor::enhancement_confidence;
+/// use serde_json::json;
+///
+/// let source = "Jane Doe\njane@example.com\nEngineer at Acme Corp";
+/// let faithful = json!({
+///     "personal_info": {"name": "Jane Doe", "email": "jane@example.com"},
+///     "experience": [{"company": "Acme Corp"}]
+/// });
+/// let invented = json!({
+///     "personal_info": {"name": "Jane Doe"},
+///     "experience": [{"company": "Globex"}]
+/// });
+///
+/// assert_eq!(enhancement_confidence(source, &faithful, None).score, 1.0);
+/// assert!(enhancement_confidence(source, &invented, None).score < 0.5);
+/// ```
+#[must_use]
+pub fn enhancement_confidence(
+    source_text: &str,
+    enhanced: &serde_json::Value,
+    schema: Option<&serde_json::Value>,
+) -> EnhancementConfidence {
+    let source = normalize(source_text);
+    let output = enhanced.to_string().to_lowercase();
+
+    let schema_valid = schema
+        .and_then(|schema| validate_json(enhanced, schema).ok())
+        .map(|validation| validation.ok);
+
+    let name_found = candidate_name(enhanced).is_some_and(|name| source.contains(&normalize(name)));
+
+    let mut emails: Vec<String> = EMAIL_IN_TEXT_RE
+        .find_iter(source_text)
+        .map(|m| m.as_str().to_lowercase())
+        .collect();
+    emails.sort();
+    emails.dedup();
+    let missing_emails: Vec<String> = emails
+        .iter()
+        .filter(|email| !output.contains(email.as_str()))
+        .cloned()
+        .collect();
+
+    let employers = employers(enhanced);
+    let unknown_employers: Vec<String> = employers
+        .iter()
+        .filter(|employer| !source.contains(&normalize(employer)))
+        .map(|employer| (*employer).to_string())
+        .collect();
+
+    // Contact checks: the name plus one per source email
+    let contact_checks = 1 + emails.len();
+    let contact_passed = usize::from(name_found) + emails.len() - missing_emails.len();
+    let contact = contact_passed as f64 / contact_checks as f64;
+
+    let grounded = if employers.is_empty() {
+        1.0
+    } else {
+        (employers.len() - unknown_employers.len()) as f64 / employers.len() as f64
+    };
+
+    let mut weighted = CONTACT_WEIGHT * contact + EMPLOYER_WEIGHT * grounded;
+    let mut total_weight = CONTACT_WEIGHT + EMPLOYER_WEIGHT;
+    if let Some(valid) = schema_valid {
+        weighted += if valid { SCHEMA_WEIGHT } else { 0.0 };
+        total_weight += SCHEMA_WEIGHT;
+    }
+
+    EnhancementConfidence {
+        score: (weighted / total_weight).clamp(0.0, 1.0),
+        schema_valid,
+        name_found,
+        missing_emails,
+        unknown_employers,
+    }
+}
+
+/// The candidate's name from `personal_info` or the top level.
+fn candidate_name(resume: &serde_json::Value) -> Option<&str> {
+    resume
+        .get("personal_info")
+        .and_then(|p| p.get("name"))
+        .or_else(|| resume.get("name"))
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// Non-empty `company` (or `employer`) values from the experience entries.
+fn employers(resume: &serde_json::Value) -> Vec<&str> {
+    resume
+        .get("experience")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.get("company").or_else(|| entry.get("employer")))
+        .filter_map(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Lowercase with runs of whitespace collapsed to single spaces.
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_enhancement_confidence_flags_dropped_email_and_invented_employer() {
+        let source = "Jane  Doe\nJane.Doe@Example.com | jd@work.io\nEngineer, Acme Corp";
+        let enhanced = json!({
+            "personal_info": {"name": "Jane Doe", "email": "jane.doe@example.com"},
+            "experience": [{"company": "ACME Corp"}, {"company": "Initech"}]
+        });
+
+        let confidence = enhancement_confidence(source, &enhanced, None);
+
+        assert!(confidence.name_found);
+        assert_eq!(confidence.missing_emails, vec!["jd@work.io".to_string()]);
+        assert_eq!(confidence.unknown_employers, vec!["Initech".to_string()]);
+        assert_eq!(confidence.schema_valid, None);
+        // Contact 2/3 and employers 1/2, weighted 0.4 and 0.3
+        let expected = (0.4 * 2.0 / 3.0 + 0.3 * 0.5) / 0.7;
+        assert!((confidence.score - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_enhancement_confidence_includes_schema_check() {
+        let schema = crate::validation::resume_schema();
+        let source = "Jane Doe";
+        let invalid = json!({"personal_info": {"name": "Jane Doe"}, "experience": "Acme"});
+
+        let confidence = enhancement_confidence(source, &invalid, Some(&schema));
+
+        assert_eq!(confidence.schema_valid, Some(false));
+        assert!((confidence.score - 0.7).abs() < 1e-9);
+    }
+}
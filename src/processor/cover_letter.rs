@@ -0,0 +1,108 @@
+//! Prompt construction for tailored cover letters.
+//!
+//! The prompt points the model at the experience entries that best overlap
+//! the job description and the job keywords the resume already supports, so
+//! the letter argues from facts in the resume rather than invented ones.
+
+use crate::output::render_resume_text;
+use crate::scoring::text::extract_keywords;
+use std::collections::HashSet;
+
+/// Experience entries quoted in the prompt as the strongest matches.
+const TOP_EXPERIENCE_ENTRIES: usize = 2;
+
+/// Job keywords listed in the prompt as requirements the resume supports.
+const MAX_MATCHED_REQUIREMENTS: usize = 12;
+
+/// Build the cover-letter prompt for `resume` against `job_text`.
+pub(crate) fn cover_letter_prompt(resume: &serde_json::Value, job_text: &str) -> String {
+    let job_keywords = extract_keywords(job_text);
+    let resume_text = render_resume_text(resume);
+
+    let top_experience = top_matching_experience(resume, &job_keywords);
+    let experience_section = if top_experience.is_empty() {
+        "(no experience entries overlap the job description)".to_string()
+    } else {
+        top_experience.join("\n\n")
+    };
+
+    let resume_keywords = extract_keywords(&resume_text);
+    let mut requirements: Vec<&str> = job_keywords
+        .intersection(&resume_keywords)
+        .map(String::as_str)
+        .collect();
+    requirements.sort_unstable();
+    requirements.truncate(MAX_MATCHED_REQUIREMENTS);
+    let requirements_section = if requirements.is_empty() {
+        "(none found; rely on the job description)".to_string()
+    } else {
+        requirements.join(", ")
+    };
+
+    format!(
+        "Write a tailored cover letter for the candidate below applying to the given job.\n\n\
+         Requirements:\n\
+         - Plain text only: no Markdown, headings or placeholders such as [Company].\n\
+         - 3 to 4 paragraphs: an opening naming the role, one or two paragraphs \
+         connecting the candidate's most relevant experience to the job's key \
+         requirements, and a short closing.\n\
+         - Use only facts stated in the resume. Do not invent employers, titles, \
+         dates, metrics or skills.\n\n\
+         TOP MATCHING EXPERIENCE:\n{experience_section}\n\n\
+         JOB REQUIREMENTS THE RESUME SUPPORTS:\n{requirements_section}\n\n\
+         RESUME:\n{resume_text}\n\n\
+         JOB DESCRIPTION:\n{job_text}"
+    )
+}
+
+/// Render the experience entries sharing the most keywords with the job.
+///
+/// Entries with no overlap are left out; ties keep resume order.
+fn top_matching_experience(
+    resume: &serde_json::Value,
+    job_keywords: &HashSet<String>,
+) -> Vec<String> {
+    let Some(entries) = resume.get("experience").and_then(|v| v.as_array()) else {
+        return Vec::new();
+    };
+
+    let mut scored: Vec<(usize, String)> = entries
+        .iter()
+        .map(|entry| {
+            let text = render_resume_text(entry).trim().to_string();
+            let overlap = extract_keywords(&text).intersection(job_keywords).count();
+            (overlap, text)
+        })
+        .filter(|(overlap, text)| *overlap > 0 && !text.is_empty())
+        .collect();
+
+    scored.sort_by_key(|(overlap, _)| std::cmp::Reverse(*overlap));
+    scored
+        .into_iter()
+        .take(TOP_EXPERIENCE_ENTRIES)
+        .map(|(_, text)| text)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_top_matching_experience_prefers_overlap() {
+        let resume = json!({
+            "experience": [
+                {"title": "Barista", "company": "Cafe", "description": ["Brewed coffee"]},
+                {"title": "Backend Engineer", "company": "Acme", "description": ["Built Rust services on Kubernetes"]},
+                {"title": "Intern", "company": "Initech", "description": ["Wrote Python scripts"]}
+            ]
+        });
+        let job = extract_keywords("Rust engineer for Kubernetes services; Python a plus");
+
+        let top = top_matching_experience(&resume, &job);
+        assert_eq!(top.len(), 2);
+        assert!(top[0].contains("Acme"));
+        assert!(top[1].contains("Initech"));
+    }
+}
@@ -0,0 +1,332 @@
+//! Structured comparison of an original resume against its enhanced version.
+//!
+//! Surfaces what the AI changed (skills, summary and experience bullets) so
+//! users can check the enhancement for fabricated content.
+
+use crate::output::entry_bullets;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Share of a phrase's tokens that must appear in raw original text for the
+/// phrase to count as already present.
+const TEXT_COVERAGE_THRESHOLD: f64 = 0.6;
+
+/// Changes between an original and an enhanced resume.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResumeDiff {
+    /// Skills in the enhanced resume that were not in the original.
+    pub added_skills: Vec<String>,
+    /// Skills in the original resume that the enhanced one dropped.
+    pub removed_skills: Vec<String>,
+    /// Summary change, if the summary was added, removed or reworded.
+    pub summary: Option<SummaryChange>,
+    /// Experience bullets that are new in the enhanced resume.
+    pub added_bullets: Vec<String>,
+    /// Experience bullets from the original that no longer appear.
+    pub removed_bullets: Vec<String>,
+}
+
+impl ResumeDiff {
+    /// Whether the enhanced resume made no reportable changes.
+    pub fn is_empty(&self) -> bool {
+        self.added_skills.is_empty()
+            && self.removed_skills.is_empty()
+            && self.summary.is_none()
+            && self.added_bullets.is_empty()
+            && self.removed_bullets.is_empty()
+    }
+}
+
+/// A changed professional summary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SummaryChange {
+    /// Original summary (`None` if absent or the original was raw text).
+    pub original: Option<String>,
+    /// Enhanced summary (`None` if it was removed).
+    pub enhanced: Option<String>,
+    /// Token overlap between the two versions (0.0 - 1.0).
+    ///
+    /// For raw-text originals this is the share of the enhanced summary's
+    /// tokens found anywhere in the original text.
+    pub similarity: f64,
+}
+
+/// Compare an original resume with its enhanced version.
+///
+/// Both values are normally structured resume JSON. When `original` is a
+/// JSON string (raw resume text), a best-effort token comparison is used
+/// instead: enhanced skills, summary and bullets are reported as added when
+/// their words do not appear in the text, and nothing is reported as removed.
+pub fn resume_diff(original: &serde_json::Value, enhanced: &serde_json::Value) -> ResumeDiff {
+    match original {
+        serde_json::Value::String(text) => text_diff(text, enhanced),
+        _ => structured_diff(original, enhanced),
+    }
+}
+
+fn structured_diff(original: &serde_json::Value, enhanced: &serde_json::Value) -> ResumeDiff {
+    let original_skills = collect_skills(original.get("skills"));
+    let enhanced_skills = collect_skills(enhanced.get("skills"));
+    let original_bullets = collect_bullets(original);
+    let enhanced_bullets = collect_bullets(enhanced);
+
+    let summary = match (summary_text(original), summary_text(enhanced)) {
+        (None, None) => None,
+        (Some(before), Some(after)) if normalize(&before) == normalize(&after) => None,
+        (before, after) => {
+            let similarity = match (&before, &after) {
+                (Some(b), Some(a)) => jaccard(&tokens(b), &tokens(a)),
+                _ => 0.0,
+            };
+            Some(SummaryChange {
+                original: before,
+                enhanced: after,
+                similarity,
+            })
+        }
+    };
+
+    ResumeDiff {
+        added_skills: missing_from(&enhanced_skills, &original_skills),
+        removed_skills: missing_from(&original_skills, &enhanced_skills),
+        summary,
+        added_bullets: missing_from(&enhanced_bullets, &original_bullets),
+        removed_bullets: missing_from(&original_bullets, &enhanced_bullets),
+    }
+}
+
+fn text_diff(original: &str, enhanced: &serde_json::Value) -> ResumeDiff {
+    let original_tokens = tokens(original);
+    let is_new =
+        |phrase: &str| coverage(&tokens(phrase), &original_tokens) < TEXT_COVERAGE_THRESHOLD;
+
+    let summary = summary_text(enhanced).and_then(|after| {
+        let similarity = coverage(&tokens(&after), &original_tokens);
+        (similarity < 1.0).then_some(SummaryChange {
+            original: None,
+            enhanced: Some(after),
+            similarity,
+        })
+    });
+
+    ResumeDiff {
+        added_skills: collect_skills(enhanced.get("skills"))
+            .into_iter()
+            .filter(|skill| is_new(skill))
+            .collect(),
+        removed_skills: Vec::new(),
+        summary,
+        added_bullets: collect_bullets(enhanced)
+            .into_iter()
+            .filter(|bullet| is_new(bullet))
+            .collect(),
+        removed_bullets: Vec::new(),
+    }
+}
+
+// -------------------------
+// Extraction Helpers
+// -------------------------
+
+/// Flatten skills given as a list, a category map or a comma-separated string.
+fn collect_skills(skills: Option<&serde_json::Value>) -> Vec<String> {
+    let mut out = Vec::new();
+    match skills {
+        Some(serde_json::Value::String(s)) => out.extend(
+            s.split([',', ';', '\n'])
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string),
+        ),
+        Some(serde_json::Value::Array(items)) => {
+            for item in items {
+                match item {
+                    serde_json::Value::Object(_) => {
+                        // e.g. {"name": "Rust", "level": "expert"}
+                        if let Some(name) = item.get("name").and_then(|v| v.as_str()) {
+                            out.push(name.trim().to_string());
+                        }
+                    }
+                    other => out.extend(collect_skills(Some(other))),
+                }
+            }
+        }
+        Some(serde_json::Value::Object(groups)) => {
+            for items in groups.values() {
+                out.extend(collect_skills(Some(items)));
+            }
+        }
+        _ => {}
+    }
+    dedup_normalized(out)
+}
+
+fn collect_bullets(resume: &serde_json::Value) -> Vec<String> {
+    let entries = resume
+        .get("experience")
+        .or_else(|| resume.get("work_experience"))
+        .and_then(|v| v.as_array());
+    let bullets = entries
+        .into_iter()
+        .flatten()
+        .flat_map(entry_bullets)
+        .collect();
+    dedup_normalized(bullets)
+}
+
+fn summary_text(resume: &serde_json::Value) -> Option<String> {
+    let text = match resume.get("summary")? {
+        serde_json::Value::String(s) => s.trim().to_string(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => return None,
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+// -------------------------
+// Comparison Helpers
+// -------------------------
+
+/// Lowercase and collapse whitespace/trailing punctuation for comparisons.
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(['.', ';', ','])
+        .to_lowercase()
+}
+
+fn dedup_normalized(items: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    items
+        .into_iter()
+        .filter(|item| seen.insert(normalize(item)))
+        .collect()
+}
+
+/// Items of `items` (in order) whose normalized form is not in `other`.
+fn missing_from(items: &[String], other: &[String]) -> Vec<String> {
+    let other: HashSet<String> = other.iter().map(|s| normalize(s)).collect();
+    items
+        .iter()
+        .filter(|item| !other.contains(&normalize(item)))
+        .cloned()
+        .collect()
+}
+
+/// Lowercase word tokens; `+` and `#` are kept so "C++" and "C#" survive.
+fn tokens(text: &str) -> HashSet<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '+' || c == '#'))
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Share of `phrase` tokens present in `text`.
+#[allow(clippy::cast_precision_loss)]
+fn coverage(phrase: &HashSet<String>, text: &HashSet<String>) -> f64 {
+    if phrase.is_empty() {
+        return 1.0;
+    }
+    phrase.intersection(text).count() as f64 / phrase.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_added_and_removed_skills() {
+        let original = json!({"skills": ["Python", "SQL", "jQuery"]});
+        let enhanced = json!({
+            "skills": {"languages": ["python", "SQL", "Rust"], "cloud": ["AWS"]}
+        });
+
+        let diff = resume_diff(&original, &enhanced);
+        // Categories are visited in key order: "cloud" before "languages"
+        assert_eq!(diff.added_skills, ["AWS", "Rust"]);
+        assert_eq!(diff.removed_skills, ["jQuery"]);
+        assert!(diff.summary.is_none());
+    }
+
+    #[test]
+    fn test_reworded_summary() {
+        let original = json!({"summary": "Software engineer with 5 years of backend experience."});
+        let enhanced = json!({
+            "summary": "Backend software engineer with 5 years of experience building APIs."
+        });
+
+        let change = resume_diff(&original, &enhanced)
+            .summary
+            .expect("summary should be reported as changed");
+        assert_eq!(
+            change.original.as_deref(),
+            Some("Software engineer with 5 years of backend experience.")
+        );
+        assert!(change
+            .enhanced
+            .unwrap()
+            .starts_with("Backend software engineer"));
+        assert!(change.similarity > 0.5 && change.similarity < 1.0);
+
+        // Whitespace, case and trailing punctuation are not changes
+        let same = json!({"summary": "software  engineer with 5 years of backend experience"});
+        assert!(resume_diff(&original, &same).summary.is_none());
+    }
+
+    #[test]
+    fn test_bullet_changes() {
+        let original = json!({"experience": [{
+            "title": "Engineer",
+            "description": "Built APIs\nFixed bugs"
+        }]});
+        let enhanced = json!({"experience": [{
+            "title": "Engineer",
+            "bullets": ["Built APIs", "Cut latency by 40%"]
+        }]});
+
+        let diff = resume_diff(&original, &enhanced);
+        assert_eq!(diff.added_bullets, ["Cut latency by 40%"]);
+        assert_eq!(diff.removed_bullets, ["Fixed bugs"]);
+    }
+
+    #[test]
+    fn test_raw_text_original() {
+        let original =
+            json!("Jane Doe\nSkills: Python, SQL\nBuilt internal APIs for the billing team");
+        let enhanced = json!({
+            "summary": "Backend engineer",
+            "skills": ["Python", "SQL", "Kubernetes"],
+            "experience": [{"bullets": [
+                "Built internal APIs for billing",
+                "Managed a team of 12 engineers"
+            ]}]
+        });
+
+        let diff = resume_diff(&original, &enhanced);
+        assert_eq!(diff.added_skills, ["Kubernetes"]);
+        assert_eq!(diff.added_bullets, ["Managed a team of 12 engineers"]);
+        assert!(diff.removed_skills.is_empty() && diff.removed_bullets.is_empty());
+        assert!(diff.summary.is_some());
+    }
+
+    #[test]
+    fn test_identical_resumes_have_empty_diff() {
+        let resume = json!({"summary": "Engineer", "skills": ["Rust"]});
+        assert!(resume_diff(&resume, &resume).is_empty());
+    }
+}
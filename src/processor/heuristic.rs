@@ -0,0 +1,551 @@
+//! Heuristic plain-text resume parsing.
+//!
+//! Converts a plain-text resume into the structured JSON shape described by
+//! `config/resume_schema.json` without calling an LLM. Sections are found by
+//! their headings ("Experience", "Skills", ...); entries within a section are
+//! split on header lines, with date ranges and bullet markers recognised so
+//! the result can be scored directly with [`crate::scoring::score_resume`].
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::sync::LazyLock;
+
+use crate::scoring::text::EMAIL_IN_TEXT_RE;
+
+static PHONE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\+?[\d(][\d\s().-]{6,}\d").unwrap());
+
+static URL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:https?://)?(?:www\.)?[a-z0-9-]+\.[a-z]{2,}/\S*").unwrap()
+});
+
+const MONTH: &str = r"(?:jan|feb|mar|apr|may|jun|jul|aug|sep|sept|oct|nov|dec)[a-z]*\.?";
+
+static DATE_RANGE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?P<start>(?:{MONTH}\s+)?(?:\d{{1,2}}/)?(?:19|20)\d{{2}})\s*(?:-|–|—|to)\s*(?P<end>(?:{MONTH}\s+)?(?:\d{{1,2}}/)?(?:19|20)\d{{2}}|present|current|now)\b"
+    ))
+    .unwrap()
+});
+
+static YEAR_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?i)\b(?:{MONTH}\s+)?(?:19|20)\d{{2}}\b")).unwrap());
+
+/// Resume sections recognised by their heading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Header,
+    Summary,
+    Experience,
+    Education,
+    Skills,
+    Projects,
+    Other,
+}
+
+impl Section {
+    /// Classify a line as a section heading, if it is one.
+    fn from_heading(line: &str) -> Option<Self> {
+        let heading = line.trim().trim_end_matches(':').trim().to_lowercase();
+        if heading.is_empty() || heading.split_whitespace().count() > 4 {
+            return None;
+        }
+
+        let section = match heading.as_str() {
+            "summary"
+            | "professional summary"
+            | "profile"
+            | "professional profile"
+            | "objective"
+            | "career objective"
+            | "about"
+            | "about me" => Self::Summary,
+            "experience"
+            | "work experience"
+            | "professional experience"
+            | "employment"
+            | "employment history"
+            | "work history"
+            | "career history" => Self::Experience,
+            "education" | "education and training" | "academic background" => Self::Education,
+            "skills" | "technical skills" | "core skills" | "key skills" | "core competencies"
+            | "competencies" | "technologies" => Self::Skills,
+            "projects" | "personal projects" | "selected projects" | "key projects" => {
+                Self::Projects
+            }
+            "certifications"
+            | "certificates"
+            | "awards"
+            | "publications"
+            | "languages"
+            | "interests"
+            | "volunteering"
+            | "volunteer experience"
+            | "references" => Self::Other,
+            _ => return None,
+        };
+        Some(section)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Header => "header",
+            Self::Summary => "summary",
+            Self::Experience => "experience",
+            Self::Education => "education",
+            Self::Skills => "skills",
+            Self::Projects => "projects",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// A section heading found in a plain-text resume.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionHeading {
+    /// The heading as written, without a trailing colon ("Work History").
+    pub heading: String,
+    /// The section it introduces: `summary`, `experience`, `education`,
+    /// `skills`, `projects` or `other`.
+    pub section: String,
+}
+
+/// Find the section headings of a plain-text resume, in document order.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::processor::detect_section_order;
+///
+/// let order = detect_section_order("Jane Smith\n\nEducation\nBSc\n\nWork History:\nEngineer\n");
+/// assert_eq!(order[0].section, "education");
+/// assert_eq!(order[1].heading, "Work History");
+/// assert_eq!(order[1].section, "experience");
+/// ```
+pub fn detect_section_order(text: &str) -> Vec<SectionHeading> {
+    text.lines()
+        .filter_map(|line| {
+            Section::from_heading(line).map(|section| SectionHeading {
+                heading: line.trim().trim_end_matches(':').trim().to_string(),
+                section: section.as_str().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Parse a plain-text resume into structured JSON without using an LLM.
+///
+/// The output always contains `personal_info`, `summary`, `experience`,
+/// `education`, `skills` and `projects`, so it can be passed straight to
+/// [`crate::scoring::score_resume`]. Fields that cannot be found are left
+/// empty rather than guessed.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::processor::parse_resume_heuristic;
+///
+/// let resume = parse_resume_heuristic(
+///     "Jane Smith\njane@example.com\n\nSKILLS\nRust, Python\n",
+/// );
+/// assert_eq!(resume["personal_info"]["email"], "jane@example.com");
+/// assert_eq!(resume["skills"][1], "Python");
+/// ```
+pub fn parse_resume_heuristic(text: &str) -> Value {
+    let mut sections: Vec<(Section, Vec<&str>)> = vec![(Section::Header, Vec::new())];
+    for line in text.lines().map(str::trim) {
+        if let Some(section) = Section::from_heading(line) {
+            sections.push((section, Vec::new()));
+        } else if !line.is_empty() {
+            if let Some((_, lines)) = sections.last_mut() {
+                lines.push(line);
+            }
+        }
+    }
+
+    let lines_for = |wanted: Section| -> Vec<&str> {
+        sections
+            .iter()
+            .filter(|(section, _)| *section == wanted)
+            .flat_map(|(_, lines)| lines.iter().copied())
+            .collect()
+    };
+
+    let header = lines_for(Section::Header);
+    let summary = lines_for(Section::Summary)
+        .iter()
+        .map(|line| strip_bullet(line).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    json!({
+        "personal_info": parse_personal_info(&header, text),
+        "summary": summary,
+        "experience": parse_experience(&lines_for(Section::Experience)),
+        "education": parse_education(&lines_for(Section::Education)),
+        "skills": parse_skills(&lines_for(Section::Skills)),
+        "projects": parse_projects(&lines_for(Section::Projects)),
+    })
+}
+
+// -------------------------
+// Sections
+// -------------------------
+
+fn parse_personal_info(header: &[&str], text: &str) -> Value {
+    let mut info = Map::new();
+
+    // The email may sit anywhere (e.g. in a footer); everything else is only
+    // taken from the lines before the first section heading.
+    if let Some(email) = EMAIL_IN_TEXT_RE.find(text) {
+        info.insert("email".to_string(), json!(email.as_str()));
+    }
+
+    let mut plain_lines = Vec::new();
+    for line in header {
+        let mut is_contact = false;
+        if let Some(phone) = find_phone(line) {
+            info.entry("phone").or_insert_with(|| json!(phone));
+            is_contact = true;
+        }
+        if EMAIL_IN_TEXT_RE.is_match(line) {
+            is_contact = true;
+        }
+        for url in URL_RE.find_iter(line) {
+            let url = url.as_str();
+            let key = if url.contains("linkedin.com") {
+                "linkedin"
+            } else if url.contains("github.com") {
+                "github"
+            } else {
+                "portfolio"
+            };
+            info.entry(key).or_insert_with(|| json!(url));
+            is_contact = true;
+        }
+        if !is_contact {
+            plain_lines.push(*line);
+        }
+    }
+
+    let mut plain_lines = plain_lines.into_iter();
+    if let Some(name) = plain_lines.next() {
+        info.insert("name".to_string(), json!(name));
+    }
+    if let Some(headline) = plain_lines.next() {
+        info.insert("headline".to_string(), json!(headline));
+    }
+
+    Value::Object(info)
+}
+
+fn parse_experience(lines: &[&str]) -> Vec<Value> {
+    group_entries(lines)
+        .into_iter()
+        .map(|entry| {
+            let mut fields = Map::new();
+            let mut parts = entry.header_parts.into_iter();
+            insert_some(&mut fields, "title", parts.next());
+            insert_some(&mut fields, "company", parts.next());
+            let location = parts.collect::<Vec<_>>().join(", ");
+            if !location.is_empty() {
+                fields.insert("location".to_string(), json!(location));
+            }
+            if let Some(dates) = entry.dates {
+                fields.insert("dates".to_string(), json!(dates.text));
+                fields.insert("start_date".to_string(), json!(dates.start));
+                fields.insert("end_date".to_string(), json!(dates.end));
+            }
+            fields.insert("description".to_string(), json!(entry.bullets));
+            Value::Object(fields)
+        })
+        .collect()
+}
+
+fn parse_education(lines: &[&str]) -> Vec<Value> {
+    group_entries(lines)
+        .into_iter()
+        .map(|entry| {
+            let mut fields = Map::new();
+            let mut gpa = None;
+            let mut parts = Vec::new();
+            for part in entry.header_parts {
+                match part
+                    .strip_prefix("GPA")
+                    .or_else(|| part.strip_prefix("gpa"))
+                {
+                    Some(value) => gpa = Some(value.trim_start_matches([':', ' ']).to_string()),
+                    None => parts.push(part),
+                }
+            }
+
+            let mut parts = parts.into_iter();
+            insert_some(&mut fields, "degree", parts.next());
+            insert_some(&mut fields, "institution", parts.next());
+            let location = parts.collect::<Vec<_>>().join(", ");
+            if !location.is_empty() {
+                fields.insert("location".to_string(), json!(location));
+            }
+            if let Some(dates) = entry.dates {
+                fields.insert("graduation_date".to_string(), json!(dates.end));
+            }
+            insert_some(&mut fields, "gpa", gpa);
+            if !entry.bullets.is_empty() {
+                fields.insert("description".to_string(), json!(entry.bullets));
+            }
+            Value::Object(fields)
+        })
+        .collect()
+}
+
+fn parse_skills(lines: &[&str]) -> Vec<String> {
+    let mut skills: Vec<String> = Vec::new();
+    for line in lines {
+        let line = strip_bullet(line).unwrap_or(line);
+        // Drop "Languages:"-style category labels
+        let list = match line.split_once(':') {
+            Some((label, rest)) if label.split_whitespace().count() <= 3 => rest,
+            _ => line,
+        };
+        for skill in list.split([',', ';', '|', '•', '·']) {
+            let skill = skill.trim().trim_end_matches('.');
+            if !skill.is_empty()
+                && !skills
+                    .iter()
+                    .any(|existing| existing.eq_ignore_ascii_case(skill))
+            {
+                skills.push(skill.to_string());
+            }
+        }
+    }
+    skills
+}
+
+fn parse_projects(lines: &[&str]) -> Vec<Value> {
+    group_entries(lines)
+        .into_iter()
+        .map(|entry| {
+            let mut fields = Map::new();
+            let mut parts = entry.header_parts.into_iter();
+            insert_some(&mut fields, "name", parts.next());
+            let link = parts.find(|part| URL_RE.is_match(part));
+            insert_some(&mut fields, "link", link);
+            if !entry.bullets.is_empty() {
+                fields.insert("description".to_string(), json!(entry.bullets.join(" ")));
+            }
+            Value::Object(fields)
+        })
+        .collect()
+}
+
+// -------------------------
+// Entries
+// -------------------------
+
+/// A date range found in an entry header.
+#[derive(Debug)]
+struct DateRange {
+    text: String,
+    start: String,
+    end: String,
+}
+
+/// Lines belonging to a single experience, education or project entry.
+#[derive(Debug, Default)]
+struct RawEntry {
+    header_parts: Vec<String>,
+    dates: Option<DateRange>,
+    bullets: Vec<String>,
+}
+
+/// Split section lines into entries.
+///
+/// A non-bullet line starts a new entry unless it continues the header of an
+/// entry that has no bullets yet: a company name or date range on its own
+/// line below the job title, or an undated detail such as `GPA: 3.8`.
+fn group_entries(lines: &[&str]) -> Vec<RawEntry> {
+    let mut entries: Vec<RawEntry> = Vec::new();
+
+    for line in lines {
+        if let Some(bullet) = strip_bullet(line) {
+            match entries.last_mut() {
+                Some(entry) => entry.bullets.push(bullet.to_string()),
+                None => entries.push(RawEntry {
+                    bullets: vec![bullet.to_string()],
+                    ..RawEntry::default()
+                }),
+            }
+            continue;
+        }
+
+        let (dates, rest) = extract_dates(line);
+        let continues_header = entries.last().is_some_and(|entry| {
+            entry.bullets.is_empty() && (entry.dates.is_none() || dates.is_none())
+        });
+
+        if !continues_header {
+            entries.push(RawEntry::default());
+        }
+        if let Some(entry) = entries.last_mut() {
+            entry.header_parts.extend(split_header(&rest));
+            if dates.is_some() {
+                entry.dates = dates;
+            }
+        }
+    }
+
+    entries
+}
+
+/// Pull a date range (or a single year) out of a header line.
+fn extract_dates(line: &str) -> (Option<DateRange>, String) {
+    if let Some(caps) = DATE_RANGE_RE.captures(line) {
+        let whole = caps.get(0).map_or("", |m| m.as_str());
+        let dates = DateRange {
+            text: whole.to_string(),
+            start: caps["start"].to_string(),
+            end: capitalize_word(&caps["end"]),
+        };
+        return (Some(dates), line.replacen(whole, "", 1));
+    }
+
+    if let Some(year) = YEAR_RE.find(line) {
+        let year = year.as_str();
+        let dates = DateRange {
+            text: year.to_string(),
+            start: year.to_string(),
+            end: year.to_string(),
+        };
+        return (Some(dates), line.replacen(year, "", 1));
+    }
+
+    (None, line.to_string())
+}
+
+/// Split an entry header such as `Engineer | Acme, Inc. | Remote` into parts.
+fn split_header(line: &str) -> Vec<String> {
+    let mut parts = vec![line.to_string()];
+    for separator in ["|", " – ", " — ", " - ", " at ", " @ "] {
+        parts = parts
+            .iter()
+            .flat_map(|part| part.split(separator).map(str::to_string))
+            .collect();
+    }
+    parts
+        .into_iter()
+        .map(|part| {
+            part.trim()
+                .trim_matches(|c: char| c == ',' || c == '(' || c == ')' || c.is_whitespace())
+                .to_string()
+        })
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+// -------------------------
+// Helpers
+// -------------------------
+
+/// Return the bullet text if the line starts with a bullet marker.
+fn strip_bullet(line: &str) -> Option<&str> {
+    ["- ", "* ", "• ", "· ", "– ", "▪ ", "● "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+        .map(str::trim)
+        .or_else(|| line.strip_prefix(['•', '·', '▪', '●']).map(str::trim))
+}
+
+/// Find a phone number: a digit run with 7 to 15 digits.
+fn find_phone(line: &str) -> Option<String> {
+    PHONE_RE
+        .find_iter(line)
+        .map(|m| m.as_str().trim())
+        .find(|candidate| {
+            let digits = candidate.chars().filter(char::is_ascii_digit).count();
+            (7..=15).contains(&digits) && !DATE_RANGE_RE.is_match(candidate)
+        })
+        .map(str::to_string)
+}
+
+fn insert_some(fields: &mut Map<String, Value>, key: &str, value: Option<String>) {
+    if let Some(value) = value.filter(|v| !v.is_empty()) {
+        fields.insert(key.to_string(), json!(value));
+    }
+}
+
+fn capitalize_word(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_section_headings() {
+        assert_eq!(
+            Section::from_heading("EXPERIENCE"),
+            Some(Section::Experience)
+        );
+        assert_eq!(
+            Section::from_heading("Technical Skills:"),
+            Some(Section::Skills)
+        );
+        assert_eq!(Section::from_heading("Experienced engineer"), None);
+    }
+
+    #[test]
+    fn test_detect_section_order() {
+        let order = detect_section_order(
+            "Jane Smith\nPROFILE\nEngineer\nSkills:\nRust\nEmployment History\nAcme\n",
+        );
+        let sections: Vec<&str> = order.iter().map(|h| h.section.as_str()).collect();
+        assert_eq!(sections, ["summary", "skills", "experience"]);
+        assert_eq!(order[1].heading, "Skills");
+        assert_eq!(order[2].heading, "Employment History");
+    }
+
+    #[test]
+    fn test_extract_dates() {
+        let (dates, rest) = extract_dates("Engineer | Acme | Jan 2019 - Present");
+        let dates = dates.unwrap();
+        assert_eq!(dates.start, "Jan 2019");
+        assert_eq!(dates.end, "Present");
+        assert_eq!(split_header(&rest), vec!["Engineer", "Acme"]);
+
+        let (dates, _) = extract_dates("B.S. Computer Science, 2018");
+        assert_eq!(dates.unwrap().end, "2018");
+    }
+
+    #[test]
+    fn test_entries_spanning_several_lines() {
+        let experience = parse_experience(&[
+            "Data Analyst",
+            "Globex",
+            "2015 to 2017",
+            "• Built dashboards",
+            "Intern at Initech (2014)",
+        ]);
+
+        assert_eq!(experience.len(), 2);
+        assert_eq!(experience[0]["title"], "Data Analyst");
+        assert_eq!(experience[0]["company"], "Globex");
+        assert_eq!(experience[0]["end_date"], "2017");
+        assert_eq!(experience[0]["description"], json!(["Built dashboards"]));
+        assert_eq!(experience[1]["company"], "Initech");
+    }
+
+    #[test]
+    fn test_find_phone_ignores_years() {
+        assert_eq!(
+            find_phone("+1 (555) 123-4567 | Austin"),
+            Some("+1 (555) 123-4567".to_string())
+        );
+        assert_eq!(find_phone("2018 - 2020"), None);
+    }
+}
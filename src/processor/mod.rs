@@ -7,8 +7,9 @@
 //! 4. Score enhanced resume (and optionally match against job)
 //! 5. Generate recommendations (optional)
 //! 6. Iterate to improve scores (optional)
-//! 7. Write outputs (TOML/JSON/TXT)
-//! 8. Update state
+//! 7. Write a tailored cover letter (optional, requires a job description)
+//! 8. Write outputs (TOML/JSON/TXT) and a run report with per-step timings
+//! 9. Update state
 //!
 //! # Example
 //!
@@ -27,18 +28,52 @@
 //! }
 //! ```
 
-use crate::agents::AgentRegistry;
+mod compare;
+mod confidence;
+mod cover_letter;
+mod diff;
+mod heuristic;
+mod prompts;
+mod report;
+mod rewrite;
+
+pub use compare::{compare_resumes, ResumeComparison};
+pub use confidence::{enhancement_confidence, EnhancementConfidence, LOW_ENHANCEMENT_CONFIDENCE};
+pub use diff::{resume_diff, ResumeDiff, SummaryChange};
+pub use heuristic::{detect_section_order, parse_resume_heuristic, SectionHeading};
+pub use prompts::{render_prompt, PromptTemplates};
+pub use report::{RunReport, StepRecord, RUN_REPORT_FILE};
+pub use rewrite::BulletRewrite;
+
+use crate::agents::{Agent, AgentRegistry, Generation};
 use crate::config::Config;
 use crate::error::{AtsError, Result};
 use crate::input::InputHandler;
-use crate::output::{OutputData, OutputGenerator};
-use crate::recommendations::{generate_recommendations, Recommendation};
-use crate::scoring::{score_match, score_resume, ScoreReport};
-use crate::state::StateManager;
-use crate::utils::hash::calculate_file_hash;
-use crate::validation::validate_json;
+use crate::output::{
+    write_scores, OutputData, OutputGenerator, OutputManifest, MANIFEST_FILE, SCORES_FILE,
+};
+use crate::recommendations::{
+    generate_recommendations, keyword_gap_recommendation, language_recommendation,
+    section_recommendations, Recommendation,
+};
+use crate::scoring::{
+    compute_iteration_score, keyword_gap, score_match_with_options, score_resume_with_options,
+    KeywordGap, ScoreReport,
+};
+use crate::state::SyncStateManager;
+use crate::utils::csv::CsvWriter;
+use crate::utils::file::atomic_write;
+use crate::utils::hash::{calculate_file_hash, calculate_string_hash};
+use crate::utils::redact::{anonymize_resume, redact_strings, redact_text, resume_alias};
+use crate::utils::text::{detect_language, strip_markdown_fences, truncate_to_budget, Lang};
+use crate::validation::{resume_schema, validate_json, ValidationResult};
+use futures::stream::{self, StreamExt};
+use report::{RunRecorder, StepTimer};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use tokio::sync::Semaphore;
+use walkdir::WalkDir;
 
 // -------------------------
 // Data Structures
@@ -47,6 +82,13 @@ use std::path::{Path, PathBuf};
 /// Result of resume processing.
 #[derive(Debug, Clone)]
 pub struct ProcessingResult {
+    /// Position of the resume in its batch (0 for single-resume processing).
+    ///
+    /// Batch results may complete out of order; sort on this to restore the
+    /// input order.
+    pub index: usize,
+    /// Base name of the resume (the input file stem).
+    pub resume_name: String,
     /// Whether processing succeeded.
     pub success: bool,
     /// Path to output directory (if successful).
@@ -57,10 +99,37 @@ pub struct ProcessingResult {
     pub enhanced_resume: Option<serde_json::Value>,
     /// Recommendations.
     pub recommendations: Vec<Recommendation>,
+    /// Number of improvement iterations run (0 when iteration was skipped).
+    pub iterations: u32,
+    /// One record per improvement iteration, in order.
+    pub iteration_history: Vec<IterationRecord>,
+    /// Step timings and agent usage (`None` if the resume was skipped or
+    /// processing failed).
+    pub run_report: Option<RunReport>,
+    /// How faithfully the enhanced resume reflects the source text, from 0
+    /// to 1 (0 if the resume was skipped or processing failed). See
+    /// [`enhancement_confidence`].
+    pub enhancement_confidence: f64,
+    /// ISO 639-1 code of the resume's language, if it could be detected
+    /// (see [`detect_language`]).
+    pub detected_language: Option<String>,
     /// Error message if failed.
     pub error: Option<String>,
 }
 
+/// Outcome of a single improvement iteration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IterationRecord {
+    /// Iteration number (1-based).
+    pub iteration: u32,
+    /// Combined score of the candidate produced in this iteration.
+    pub combined_score: f64,
+    /// Whether the candidate replaced the previous best.
+    pub accepted: bool,
+    /// Resume category scores of the candidate, keyed by category name.
+    pub category_scores: HashMap<String, f64>,
+}
+
 /// Iteration strategy for improving scores.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IterationStrategy {
@@ -91,13 +160,18 @@ impl std::str::FromStr for IterationStrategy {
 // ResumeProcessor
 // -------------------------
 
+/// Missing job keywords named in the keyword gap recommendation.
+const KEYWORD_GAP_TERMS: usize = 8;
+
 /// Main resume processor.
 pub struct ResumeProcessor {
     config: Config,
-    state_manager: StateManager,
+    state_manager: SyncStateManager,
     input_handler: InputHandler,
     output_generator: OutputGenerator,
     agent_registry: AgentRegistry,
+    prompts: PromptTemplates,
+    force: bool,
 }
 
 impl ResumeProcessor {
@@ -105,23 +179,34 @@ impl ResumeProcessor {
     ///
     /// # Errors
     ///
-    /// Returns an error if the state file cannot be loaded or the agent registry cannot be initialized.
+    /// Returns an error if the state file or the configured
+    /// `prompt_templates_file` cannot be loaded. Agents that fail to
+    /// initialize are logged and left out of the registry.
     pub fn new(config: Config) -> Result<Self> {
         // Initialize state manager
-        let state_manager = StateManager::new(config.state_file.clone())?;
+        let state_manager = SyncStateManager::new(config.state_file.clone())?;
 
         // Initialize input handler
         let input_handler = InputHandler::new(
             config.input_resumes_folder.clone(),
             config.job_descriptions_folder.clone(),
-        );
+        )
+        .with_recursive(config.input_recursive);
 
         // Initialize output generator
         let output_generator = OutputGenerator::new(
             config.output_folder.clone(),
             config.structured_output_format.clone(),
             config.output_subdir_pattern.clone(),
-        );
+        )
+        .with_fingerprint(&config);
+        let output_generator = if config.enhancement_mode == "heuristic" {
+            output_generator.with_provider("heuristic")
+        } else if let Some(enhancer) = config.ai_agents.get("enhancer") {
+            output_generator.with_provider(enhancer.provider.clone())
+        } else {
+            output_generator
+        };
 
         // Initialize agent registry from config
         // Convert config::AgentConfig to agents::AgentConfig
@@ -140,14 +225,27 @@ impl ResumeProcessor {
                     max_output_tokens: cfg.max_output_tokens,
                     max_retries: cfg.max_retries,
                     retry_on_empty: cfg.retry_on_empty,
+                    retry_policy: cfg.retry_policy.clone(),
                     require_json: cfg.require_json,
+                    system_prompt: cfg.system_prompt.clone(),
+                    request_timeout_secs: cfg.request_timeout_secs,
                     extras: cfg.extras.clone(),
                 };
                 (name.clone(), agent_cfg)
             })
             .collect();
 
-        let agent_registry = AgentRegistry::from_config(&agents_config)?;
+        // An agent that can't start (e.g. a missing API key) only matters if
+        // a step actually needs it, and that step reports it as not found
+        let (agent_registry, failures) = AgentRegistry::from_config_lenient(&agents_config);
+        for (name, e) in failures {
+            log::warn!("Agent '{name}' unavailable: {e}");
+        }
+
+        let prompts = match &config.prompt_templates_file {
+            Some(path) => PromptTemplates::load(path)?,
+            None => PromptTemplates::default(),
+        };
 
         Ok(Self {
             config,
@@ -155,43 +253,109 @@ impl ResumeProcessor {
             input_handler,
             output_generator,
             agent_registry,
+            prompts,
+            force: false,
         })
     }
 
+    /// Reprocess resumes even if the state file records them as processed.
+    ///
+    /// State is still updated after each run. Off by default.
+    #[must_use]
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// The agent registry built from the configuration.
+    pub fn agent_registry(&self) -> &AgentRegistry {
+        &self.agent_registry
+    }
+
+    /// Mutable access to the agent registry, e.g. to register custom agents.
+    pub fn agent_registry_mut(&mut self) -> &mut AgentRegistry {
+        &mut self.agent_registry
+    }
+
     /// Process a single resume.
     ///
     /// # Arguments
     ///
-    /// * `resume_path` - Path to the resume file
+    /// * `resume_path` - Path to the resume file, or an `http://`/`https://` URL to download it from
     /// * `job_path` - Optional path to job description file
     ///
     /// # Errors
     ///
-    /// Returns an error if the resume file cannot be read, AI enhancement fails, scoring fails, or output generation fails.
+    /// Returns an error if the resume file cannot be read or downloaded, AI enhancement fails, scoring fails, or output generation fails.
     pub async fn process_resume(
-        &mut self,
+        &self,
         resume_path: &str,
         job_path: Option<&str>,
     ) -> Result<ProcessingResult> {
         let resume_file = Path::new(resume_path);
+        let resume_name = if InputHandler::is_url(resume_path) {
+            // Drop any query string or fragment so they don't end up in file names
+            let url_path = resume_path.split(['?', '#']).next().unwrap_or(resume_path);
+            resume_stem(Path::new(url_path))
+        } else {
+            resume_stem(resume_file)
+        };
+        let resume_name = self.output_name(resume_name);
+
+        let mut run = RunRecorder::new(&resume_name);
 
-        // Step 1: Calculate hash and check if already processed
-        let resume_hash = calculate_file_hash(resume_file)?;
-        if self.state_manager.is_processed(&resume_hash) {
+        // Step 1: Calculate hash and check if already processed. Remote
+        // resumes have to be downloaded first, so they are keyed on their text.
+        let timer = StepTimer::start("hash");
+        let downloaded_text = if InputHandler::is_url(resume_path) {
+            Some(self.input_handler.load_resume_from_url(resume_path).await?)
+        } else {
+            None
+        };
+        let resume_hash = match &downloaded_text {
+            Some(text) => calculate_string_hash(text),
+            None => calculate_file_hash(resume_file)?,
+        };
+        if !self.force && self.state_manager.is_processed(&resume_hash) {
             log::info!("Resume already processed (hash: {resume_hash}), skipping");
             return Ok(ProcessingResult {
+                index: 0,
+                resume_name,
                 success: true,
                 output_dir: None,
                 scores: None,
                 enhanced_resume: None,
                 recommendations: vec![],
+                iterations: 0,
+                iteration_history: vec![],
+                run_report: None,
+                enhancement_confidence: 0.0,
+                detected_language: None,
                 error: None,
             });
         }
+        run.record(timer);
 
         // Step 2: Load resume text
-        log::info!("Loading resume from: {resume_path}");
-        let resume_text = self.input_handler.load_resume(resume_file)?;
+        let timer = StepTimer::start("load");
+        let resume_text = if let Some(text) = downloaded_text {
+            text
+        } else {
+            log::info!("Loading resume from: {resume_path}");
+            self.input_handler.load_resume(resume_file)?
+        };
+
+        // Scoring lexicons cover one language; flag resumes in another
+        let detected_language = detect_language(&resume_text);
+        let lexicon_language = self.config.lexicon_language.as_deref().unwrap_or("en");
+        let unsupported_language =
+            detected_language.filter(|lang| !lang.code().eq_ignore_ascii_case(lexicon_language));
+        if let Some(lang) = unsupported_language {
+            log::warn!(
+                "Resume appears to be in {} but the scoring lexicons are '{lexicon_language}'; scores may be inaccurate",
+                lang.name()
+            );
+        }
 
         // Step 3: Load job description (optional)
         let job_text = if let Some(jp) = job_path {
@@ -200,41 +364,77 @@ impl ResumeProcessor {
         } else {
             None
         };
+        run.record(timer);
 
-        // Step 4: Enhance resume using AI
-        log::info!("Enhancing resume with AI...");
-        let enhanced_resume = self
-            .enhance_resume(&resume_text, job_text.as_deref())
+        // Step 4: Enhance resume using AI (or parse it heuristically)
+        log::info!(
+            "Enhancing resume ({} mode)...",
+            self.config.enhancement_mode
+        );
+        let mut timer = StepTimer::start("enhance");
+        let enhancer = if self.config.enhancement_mode == "heuristic" {
+            None
+        } else {
+            self.agent_registry.get("enhancer").ok()
+        };
+        if let Some(agent) = enhancer {
+            timer.agent(agent);
+        }
+        let mut enhanced_resume = self
+            .enhance_resume(&resume_text, job_text.as_deref(), None, &mut timer)
             .await?;
 
-        // Step 5: Validate schema (if enabled)
+        // Step 5: Validate schema (if enabled), re-prompting with the
+        // validation errors until the output is valid or retries run out
         if self.config.schema_validation_enabled {
             log::info!("Validating enhanced resume against schema...");
-            // Load schema file
-            let schema_content =
-                std::fs::read_to_string(&self.config.resume_schema_path).map_err(|e| {
-                    AtsError::io(
-                        format!(
-                            "Failed to read schema file: {}",
-                            self.config.resume_schema_path.display()
-                        ),
-                        e,
-                    )
-                })?;
-            let schema: serde_json::Value = serde_json::from_str(&schema_content)
-                .map_err(|e| AtsError::internal(format!("Failed to parse schema JSON: {e}")))?;
+            let schema = self.load_resume_schema()?;
+            // The heuristic parser is deterministic, so retrying can't help
+            let max_retries = if self.config.enhancement_mode == "heuristic" {
+                0
+            } else {
+                self.config.schema_validation_max_retries.max(0)
+            };
 
-            let validation = validate_json(&enhanced_resume, &schema)?;
-            if !validation.ok {
+            let mut retries = 0;
+            loop {
+                let validation = validate_json(&enhanced_resume, &schema)?;
+                if validation.ok {
+                    break;
+                }
                 log::warn!("Schema validation failed: {:?}", validation.errors);
-                // Optionally retry or fail here
+
+                if retries >= max_retries {
+                    return Err(AtsError::SchemaValidation {
+                        message: format!(
+                            "Enhanced resume is still invalid after {retries} retries"
+                        ),
+                        errors: validation.errors,
+                    });
+                }
+                retries += 1;
+
+                log::info!("Re-enhancing resume (retry {retries}/{max_retries})...");
+                let feedback = validation_feedback(&validation);
+                timer.retry();
+                enhanced_resume = self
+                    .enhance_resume(
+                        &resume_text,
+                        job_text.as_deref(),
+                        Some(&feedback),
+                        &mut timer,
+                    )
+                    .await?;
             }
         }
+        run.record(timer);
 
         // Step 6: Score the enhanced resume
         log::info!("Scoring enhanced resume...");
+        let timer = StepTimer::start("score");
         let weights_path = self.config.scoring_weights_file.to_str();
-        let resume_score = score_resume(&enhanced_resume, weights_path)?;
+        let options = self.config.scoring_options();
+        let resume_score = score_resume_with_options(&enhanced_resume, weights_path, &options)?;
 
         // Step 7: Score match if job description provided
         let match_score = if let Some(job_txt) = &job_text {
@@ -244,59 +444,162 @@ impl ResumeProcessor {
                 "description": job_txt,
                 "raw_text": job_txt
             });
-            Some(score_match(&enhanced_resume, &job_json, weights_path)?)
+            Some(score_match_with_options(
+                &enhanced_resume,
+                &job_json,
+                weights_path,
+                &options,
+            )?)
         } else {
             None
         };
 
         // Step 8: Combine scores for overall evaluation
-        let combined_score = if let Some(ms) = &match_score {
-            // Weighted average: 50% resume quality + 50% match quality
-            f64::midpoint(resume_score.total, ms.total)
-        } else {
-            resume_score.total
-        };
+        let combined_score = self.calculate_combined_score(&resume_score, match_score.as_ref());
+        run.record(timer);
 
         // Step 9: Iterate to improve scores (if enabled)
-        let (final_resume, final_resume_score, _final_match_score) =
-            if self.config.iterate_until_score_reached && combined_score < self.config.target_score
+        let (mut final_resume, final_resume_score, _final_match_score, iteration_history) =
+            if self.config.iterate_until_score_reached
+                && self.config.enhancement_mode != "heuristic"
+                && combined_score < self.config.target_score
             {
                 log::info!(
                     "Iterating to improve scores (current: {:.2}, target: {:.2})...",
                     combined_score,
                     self.config.target_score
                 );
-                self.iterate_improvement(
-                    &resume_text,
-                    job_text.as_deref(),
-                    enhanced_resume,
-                    resume_score,
-                    match_score,
-                )
-                .await?
+                let mut timer = StepTimer::start("iterate");
+                let iterated = self
+                    .iterate_improvement(
+                        &resume_text,
+                        job_text.as_deref(),
+                        enhanced_resume,
+                        resume_score,
+                        match_score,
+                        &mut timer,
+                    )
+                    .await?;
+                run.record(timer);
+                iterated
             } else {
-                (enhanced_resume, resume_score, match_score)
+                (enhanced_resume, resume_score, match_score, vec![])
             };
 
-        // Step 10: Generate recommendations (if enabled)
+        // Check the final output still reflects the source: schema, contact
+        // details and employers
+        let schema = self.load_resume_schema().ok();
+        let confidence = enhancement_confidence(&resume_text, &final_resume, schema.as_ref());
+        if confidence.score < LOW_ENHANCEMENT_CONFIDENCE {
+            log::warn!(
+                "Low enhancement confidence ({:.2}): missing emails {:?}, unknown employers {:?}, name found: {}",
+                confidence.score,
+                confidence.missing_emails,
+                confidence.unknown_employers,
+                confidence.name_found
+            );
+        }
+        if confidence.score < self.config.min_enhancement_confidence {
+            let message = format!(
+                "Enhancement confidence {:.2} is below min_enhancement_confidence {:.2}; outputs not written",
+                confidence.score, self.config.min_enhancement_confidence
+            );
+            log::warn!("{resume_name}: {message}");
+            return Ok(ProcessingResult {
+                index: 0,
+                resume_name,
+                success: false,
+                output_dir: None,
+                scores: Some(final_resume_score),
+                enhanced_resume: Some(final_resume),
+                recommendations: vec![],
+                iterations: u32::try_from(iteration_history.len()).unwrap_or(u32::MAX),
+                iteration_history,
+                run_report: Some(run.finish()),
+                enhancement_confidence: confidence.score,
+                detected_language: detected_language.map(|lang| lang.code().to_string()),
+                error: Some(message),
+            });
+        }
+
+        // Record the source resume's section headings; structured output
+        // doesn't preserve their order, which layout recommendations need
+        let section_order = detect_section_order(&resume_text);
+        if !section_order.is_empty() {
+            if let Some(resume) = final_resume.as_object_mut() {
+                let meta = resume
+                    .entry("_meta")
+                    .or_insert_with(|| serde_json::json!({}));
+                if let Some(meta) = meta.as_object_mut() {
+                    meta.insert(
+                        "section_order".to_string(),
+                        serde_json::to_value(&section_order)?,
+                    );
+                }
+            }
+        }
+
+        // Step 10: Compare keywords against the job (if provided)
+        let gap: Option<KeywordGap> = job_text.as_ref().map(|job_txt| {
+            keyword_gap(
+                &final_resume,
+                &serde_json::json!({ "description": job_txt }),
+            )
+        });
+
+        // Step 11: Generate recommendations (if enabled), leading with the
+        // missing job keywords
         let recommendations = if self.config.recommendations_enabled {
             log::info!("Generating recommendations...");
+            let timer = StepTimer::start("recommend");
             // Convert score report to JSON for recommendation generation
             let score_json = serde_json::to_value(&final_resume_score).map_err(|e| {
                 AtsError::internal(format!("Failed to serialize score report: {e}"))
             })?;
-            generate_recommendations(&score_json, self.config.recommendations_max_items as usize)
+            let max_items = self.config.recommendations_max_items as usize;
+            let mut recommendations: Vec<Recommendation> = unsupported_language
+                .map(language_recommendation)
+                .into_iter()
+                .collect();
+            recommendations.extend(
+                gap.as_ref()
+                    .and_then(|gap| keyword_gap_recommendation(gap, KEYWORD_GAP_TERMS)),
+            );
+            recommendations.extend(section_recommendations(&final_resume));
+            recommendations.extend(generate_recommendations(&score_json, max_items));
+            recommendations.truncate(max_items);
+            run.record(timer);
+            recommendations
         } else {
             vec![]
         };
 
-        // Step 11: Prepare output data
-        let resume_name = resume_file
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("resume")
-            .to_string();
+        // Step 12: Write a cover letter (if enabled and matched against a job).
+        // A failure here shouldn't discard the enhanced resume.
+        let cover_letter = match &job_text {
+            Some(job) if self.config.cover_letter_enabled => {
+                log::info!("Generating cover letter...");
+                let mut timer = StepTimer::start("cover_letter");
+                if let Ok(agent) = self.cover_letter_agent() {
+                    timer.agent(agent);
+                }
+                let letter = match self.cover_letter_generation(&final_resume, job).await {
+                    Ok(generation) => {
+                        timer.generation(&generation);
+                        Some(generation.value)
+                    }
+                    Err(e) => {
+                        log::warn!("Cover letter generation failed: {e}");
+                        None
+                    }
+                };
+                run.record(timer);
+                letter
+            }
+            _ => None,
+        };
 
+        // Step 13: Prepare output data
         let job_title = job_path.and_then(|jp| {
             Path::new(jp)
                 .file_stem()
@@ -304,40 +607,126 @@ impl ResumeProcessor {
                 .map(std::string::ToString::to_string)
         });
 
+        // Scores were computed on the real resume; redaction only changes
+        // what is written to disk
+        let mut scores = final_resume_score.clone();
+        let (enhanced_resume, cover_letter) = if self.config.redact_pii {
+            for category in &mut scores.categories {
+                category
+                    .details
+                    .values_mut()
+                    .for_each(|detail| redact_strings(detail, &final_resume));
+            }
+            (
+                anonymize_resume(&final_resume),
+                cover_letter.map(|letter| redact_text(&letter, &final_resume)),
+            )
+        } else {
+            (final_resume.clone(), cover_letter)
+        };
+
         let output_data = OutputData {
             resume_name: resume_name.clone(),
             job_title: job_title.clone(),
-            enhanced_resume: final_resume.clone(),
-            scores: Some(final_resume_score.clone()),
+            enhanced_resume,
+            scores: Some(scores),
             recommendations: recommendations.clone(),
-            metadata: HashMap::new(),
+            keyword_gap: gap,
+            cover_letter,
+            metadata: HashMap::from([
+                (
+                    "enhancement_confidence".to_string(),
+                    serde_json::to_value(&confidence)?,
+                ),
+                (
+                    "detected_language".to_string(),
+                    serde_json::json!(detected_language.map(Lang::code)),
+                ),
+            ]),
         };
 
-        // Step 12: Generate outputs
+        // Step 14: Generate outputs
         log::info!("Writing outputs...");
+        let timer = StepTimer::start("write");
         let output_dir = self.output_generator.generate(&output_data)?;
 
-        // Step 13: Update state
+        // Step 15: Update state
         self.state_manager
             .update_resume_state(&resume_hash, &output_dir.display().to_string())?;
+        run.record(timer);
+
+        // Step 16: Write the run report next to the outputs. It is a
+        // diagnostic, so failing to write it doesn't fail the run.
+        let run_report = run.finish();
+        if let Err(e) = write_run_report(&run_report, &output_dir) {
+            log::warn!("Failed to write run report: {e}");
+        }
 
         log::info!("Resume processing completed successfully!");
         Ok(ProcessingResult {
+            index: 0,
+            resume_name,
             success: true,
             output_dir: Some(output_dir),
             scores: Some(final_resume_score),
             enhanced_resume: Some(final_resume),
             recommendations,
+            iterations: u32::try_from(iteration_history.len()).unwrap_or(u32::MAX),
+            iteration_history,
+            run_report: Some(run_report),
+            enhancement_confidence: confidence.score,
+            detected_language: detected_language.map(|lang| lang.code().to_string()),
             error: None,
         })
     }
 
-    /// Enhance resume using AI agent.
+    /// The name a resume is reported and written under: its file stem, or
+    /// an alias of it when `redact_pii` is set.
+    fn output_name(&self, resume_name: String) -> String {
+        if self.config.redact_pii {
+            resume_alias(&resume_name)
+        } else {
+            resume_name
+        }
+    }
+
+    /// Load the resume schema from `resume_schema_path`, falling back to the
+    /// built-in [`resume_schema`] when that file does not exist.
+    fn load_resume_schema(&self) -> Result<serde_json::Value> {
+        let path = &self.config.resume_schema_path;
+        if !path.exists() {
+            log::debug!(
+                "Schema file {} not found, using built-in resume schema",
+                path.display()
+            );
+            return Ok(resume_schema());
+        }
+
+        let schema_content = std::fs::read_to_string(path).map_err(|e| {
+            AtsError::io(format!("Failed to read schema file: {}", path.display()), e)
+        })?;
+        serde_json::from_str(&schema_content)
+            .map_err(|e| AtsError::internal(format!("Failed to parse schema JSON: {e}")))
+    }
+
+    /// Enhance resume using AI agent, or the heuristic parser when
+    /// `enhancement_mode` is `"heuristic"`.
+    ///
+    /// `feedback` is appended to the prompt when retrying after the previous
+    /// output failed schema validation.
+    ///
+    /// The answering agent and its token usage are recorded on `timer`.
     async fn enhance_resume(
         &self,
         resume_text: &str,
         job_text: Option<&str>,
+        feedback: Option<&str>,
+        timer: &mut StepTimer,
     ) -> Result<serde_json::Value> {
+        if self.config.enhancement_mode == "heuristic" {
+            return Ok(parse_resume_heuristic(resume_text));
+        }
+
         // Get the enhancer agent
         let agent = self
             .agent_registry
@@ -345,32 +734,103 @@ impl ResumeProcessor {
             .map_err(|_| AtsError::internal("Enhancer agent not found in registry"))?;
 
         // Build prompt
-        let prompt = if let Some(job) = job_text {
-            format!(
-                "Enhance the following resume for the given job description. \
-                 Return a structured JSON object with fields: name, email, phone, \
-                 location, summary, experience (array), skills (array), education (array), \
-                 certifications (array).\n\n\
-                 RESUME:\n{resume_text}\n\n\
-                 JOB DESCRIPTION:\n{job}"
-            )
-        } else {
-            format!(
-                "Enhance the following resume. Return a structured JSON object with \
-                 fields: name, email, phone, location, summary, experience (array), \
-                 skills (array), education (array), certifications (array).\n\n\
-                 RESUME:\n{resume_text}"
-            )
-        };
+        let prompt = self.enhance_prompt(resume_text, job_text, feedback);
 
         // Call agent
-        let response = agent.generate_json(&prompt).await?;
+        let response = agent.generate_json_traced(&prompt).await?;
+        timer.generation(&response);
+
+        resume_object(response.value)
+    }
+
+    /// Build the enhancer prompt, trimming the resume and job description to
+    /// fit `max_prompt_chars`.
+    fn enhance_prompt(
+        &self,
+        resume_text: &str,
+        job_text: Option<&str>,
+        feedback: Option<&str>,
+    ) -> String {
+        let render = |resume_text: &str, job_text: Option<&str>| {
+            let mut prompt = if let Some(job) = job_text {
+                render_prompt(
+                    &self.prompts.enhance_with_job,
+                    &[("resume", resume_text), ("job", job)],
+                )
+            } else {
+                render_prompt(&self.prompts.enhance, &[("resume", resume_text)])
+            };
+
+            if let Some(feedback) = feedback {
+                prompt.push_str("\n\n");
+                prompt.push_str(feedback);
+            }
+            prompt
+        };
+
+        let overhead = render("", job_text.map(|_| "")).chars().count();
+        let (resume_text, job_text) = self.fit_prompt_inputs(overhead, resume_text, job_text);
+        render(&resume_text, job_text.as_deref())
+    }
 
-        // Response is already a JSON value
-        Ok(response)
+    /// Trim the resume and job description so a prompt with `overhead`
+    /// characters of instructions stays within `max_prompt_chars`.
+    ///
+    /// The job description is trimmed first, down to a third of the budget;
+    /// only then is the resume trimmed. Both keep their beginning (see
+    /// [`truncate_to_budget`]).
+    fn fit_prompt_inputs(
+        &self,
+        overhead: usize,
+        resume_text: &str,
+        job_text: Option<&str>,
+    ) -> (String, Option<String>) {
+        let resume_len = resume_text.chars().count();
+        let job_len = job_text.map_or(0, |job| job.chars().count());
+        let Some((resume_budget, job_budget)) = self.prompt_budgets(overhead, resume_len, job_len)
+        else {
+            return (resume_text.to_string(), job_text.map(str::to_string));
+        };
+
+        let resume = truncate_to_budget(resume_text, resume_budget);
+        let job = job_text.map(|job| truncate_to_budget(job, job_budget));
+        log::warn!(
+            "Prompt exceeds max_prompt_chars ({}); trimmed resume from {resume_len} \
+             to {} chars and job description from {job_len} to {} chars",
+            self.config.max_prompt_chars,
+            resume.chars().count(),
+            job.as_ref().map_or(0, |job| job.chars().count())
+        );
+        (resume, job)
+    }
+
+    /// Character budgets for the resume and job description of a prompt
+    /// with `overhead` characters of instructions, or `None` when both fit
+    /// `max_prompt_chars` as they are.
+    ///
+    /// The job description is trimmed first, down to a third of the budget;
+    /// only then is the resume trimmed.
+    fn prompt_budgets(
+        &self,
+        overhead: usize,
+        resume_len: usize,
+        job_len: usize,
+    ) -> Option<(usize, usize)> {
+        let max_chars = self.config.max_prompt_chars;
+        if max_chars == 0 || overhead + resume_len + job_len <= max_chars {
+            return None;
+        }
+
+        let available = max_chars.saturating_sub(overhead);
+        let job_floor = job_len.min(available / 3);
+        let resume_budget = resume_len.min(available - job_floor);
+        Some((resume_budget, available - resume_budget))
     }
 
     /// Iterate to improve scores.
+    ///
+    /// Returns the best candidate, its scores and a record of every iteration.
+    /// The reviser agent and its token usage are recorded on `timer`.
     #[allow(clippy::type_complexity)]
     async fn iterate_improvement(
         &self,
@@ -379,7 +839,13 @@ impl ResumeProcessor {
         initial_resume: serde_json::Value,
         initial_resume_score: ScoreReport,
         initial_match_score: Option<ScoreReport>,
-    ) -> Result<(serde_json::Value, ScoreReport, Option<ScoreReport>)> {
+        timer: &mut StepTimer,
+    ) -> Result<(
+        serde_json::Value,
+        ScoreReport,
+        Option<ScoreReport>,
+        Vec<IterationRecord>,
+    )> {
         let strategy = self
             .config
             .iteration_strategy
@@ -392,19 +858,25 @@ impl ResumeProcessor {
             self.calculate_combined_score(&best_resume_score, best_match_score.as_ref());
 
         let mut no_improvement_count = 0;
+        let mut history = Vec::new();
 
         let weights_path = self.config.scoring_weights_file.to_str();
+        let options = self.config.scoring_options();
 
         for iteration in 1..=self.config.max_iterations {
             log::info!("Iteration {}/{}...", iteration, self.config.max_iterations);
 
             // Generate new candidate
-            let candidate = self
-                .revise_resume(&best_resume, &best_resume_score, job_text)
-                .await?;
+            let Some(candidate) = self
+                .revise_resume(&best_resume, &best_resume_score, job_text, timer)
+                .await?
+            else {
+                break;
+            };
 
             // Score new candidate
-            let candidate_resume_score = score_resume(&candidate, weights_path)?;
+            let candidate_resume_score =
+                score_resume_with_options(&candidate, weights_path, &options)?;
 
             let candidate_match_score = if let Some(job_txt) = job_text {
                 // Convert job text to JSON structure
@@ -412,7 +884,12 @@ impl ResumeProcessor {
                     "description": job_txt,
                     "raw_text": job_txt
                 });
-                Some(score_match(&candidate, &job_json, weights_path)?)
+                Some(score_match_with_options(
+                    &candidate,
+                    &job_json,
+                    weights_path,
+                    &options,
+                )?)
             } else {
                 None
             };
@@ -423,14 +900,32 @@ impl ResumeProcessor {
             log::info!(
                 "Candidate score: {candidate_combined:.2} (previous best: {best_combined:.2})"
             );
+            for change in best_resume_score.delta(&candidate_resume_score).categories {
+                if change.change != 0.0 {
+                    log::debug!("  {}: {:+.2}", change.name, change.change);
+                }
+            }
+
+            let gain = candidate_combined - best_combined;
+            let accepted = gain > 0.0;
+            let improved = accepted && gain >= self.config.min_improvement_delta;
+            history.push(IterationRecord {
+                iteration: iteration.unsigned_abs(),
+                combined_score: candidate_combined,
+                accepted,
+                category_scores: candidate_resume_score
+                    .categories
+                    .iter()
+                    .map(|c| (c.name.clone(), c.score))
+                    .collect(),
+            });
 
             // Check for improvement
-            if candidate_combined > best_combined {
+            if accepted {
                 best_resume = candidate;
                 best_resume_score = candidate_resume_score;
                 best_match_score = candidate_match_score;
                 best_combined = candidate_combined;
-                no_improvement_count = 0;
 
                 log::info!("New best score: {best_combined:.2}");
 
@@ -441,7 +936,17 @@ impl ResumeProcessor {
                     log::info!("Target score reached, stopping iteration (FirstHit)");
                     break;
                 }
+            }
+
+            if improved {
+                no_improvement_count = 0;
             } else {
+                if accepted {
+                    log::info!(
+                        "Gain of {gain:.2} is below min_improvement_delta {:.2}, counting as no progress",
+                        self.config.min_improvement_delta
+                    );
+                }
                 no_improvement_count += 1;
 
                 // Patience: stop if no improvement for too long
@@ -462,16 +967,25 @@ impl ResumeProcessor {
             }
         }
 
-        Ok((best_resume, best_resume_score, best_match_score))
+        Ok((best_resume, best_resume_score, best_match_score, history))
     }
 
     /// Revise resume to improve scores.
+    ///
+    /// When the resume doesn't fit `max_prompt_chars`, its oldest project and
+    /// experience entries are left out of the prompt and appended to the
+    /// candidate afterwards, so the reviser never sees a cut-off resume and
+    /// can't drop entries by omission. Returns `None`, skipping the revision,
+    /// if the resume doesn't fit even without them.
+    ///
+    /// The answering agent and its token usage are recorded on `timer`.
     async fn revise_resume(
         &self,
         current_resume: &serde_json::Value,
         current_scores: &ScoreReport,
         job_text: Option<&str>,
-    ) -> Result<serde_json::Value> {
+        timer: &mut StepTimer,
+    ) -> Result<Option<serde_json::Value>> {
         // Get the reviser agent
         let agent = self
             .agent_registry
@@ -490,83 +1004,224 @@ impl ResumeProcessor {
                 .join("\n")
         );
 
-        let prompt = if let Some(job) = job_text {
-            format!(
-                "Revise the following resume to improve its scores. Focus on the lower-scoring categories.\n\n\
-                 CURRENT SCORES:\n{}\n\n\
-                 CURRENT RESUME:\n{}\n\n\
-                 JOB DESCRIPTION:\n{}\n\n\
-                 Return an improved, structured JSON resume.",
-                score_feedback,
-                serde_json::to_string_pretty(current_resume).unwrap_or_default(),
-                job
-            )
-        } else {
-            format!(
-                "Revise the following resume to improve its scores. Focus on the lower-scoring categories.\n\n\
-                 CURRENT SCORES:\n{}\n\n\
-                 CURRENT RESUME:\n{}\n\n\
-                 Return an improved, structured JSON resume.",
-                score_feedback,
-                serde_json::to_string_pretty(current_resume).unwrap_or_default()
-            )
+        let render = |resume: &str, job_text: Option<&str>| {
+            if let Some(job) = job_text {
+                render_prompt(
+                    &self.prompts.revise_with_job,
+                    &[
+                        ("scores", &score_feedback),
+                        ("resume", resume),
+                        ("job", job),
+                    ],
+                )
+            } else {
+                render_prompt(
+                    &self.prompts.revise,
+                    &[("scores", &score_feedback), ("resume", resume)],
+                )
+            }
+        };
+
+        let resume_json = serde_json::to_string_pretty(current_resume).unwrap_or_default();
+        let overhead = render("", job_text.map(|_| "")).chars().count();
+        let job_len = job_text.map_or(0, |job| job.chars().count());
+        let budgets = self.prompt_budgets(overhead, resume_json.chars().count(), job_len);
+        let (resume_json, job_text, dropped) = match budgets {
+            None => (resume_json, job_text.map(str::to_string), Vec::new()),
+            Some((resume_budget, job_budget)) => {
+                let Some((trimmed, dropped)) = trim_resume_entries(current_resume, resume_budget)
+                else {
+                    log::warn!(
+                        "Resume doesn't fit max_prompt_chars ({}) even without its project \
+                         and experience entries; skipping revision",
+                        self.config.max_prompt_chars
+                    );
+                    return Ok(None);
+                };
+                log::warn!(
+                    "Prompt exceeds max_prompt_chars ({}); left {} older project and \
+                     experience entries out of the revision prompt",
+                    self.config.max_prompt_chars,
+                    dropped.len()
+                );
+                (
+                    serde_json::to_string_pretty(&trimmed).unwrap_or_default(),
+                    job_text.map(|job| truncate_to_budget(job, job_budget)),
+                    dropped,
+                )
+            }
         };
+        let prompt = render(&resume_json, job_text.as_deref());
 
         // Call agent
-        let response = agent.generate_json(&prompt).await?;
+        let response = agent.generate_json_traced(&prompt).await?;
+        timer.generation(&response);
 
-        // Response is already a JSON value
-        Ok(response)
+        let mut candidate = resume_object(response.value)?;
+        restore_entries(&mut candidate, dropped);
+        Ok(Some(candidate))
     }
 
     /// Calculate combined score from resume and match scores.
+    ///
+    /// With a match score, the two are blended using the `[overall.weights]`
+    /// of the configured scoring weights file (see
+    /// [`compute_iteration_score`]); without one, the resume score is used
+    /// as is.
     fn calculate_combined_score(
         &self,
         resume_score: &ScoreReport,
         match_score: Option<&ScoreReport>,
     ) -> f64 {
-        if let Some(ms) = match_score {
-            // Weighted average: 50% resume quality + 50% match quality
-            f64::midpoint(resume_score.total, ms.total)
-        } else {
-            resume_score.total
+        match match_score {
+            Some(ms) => {
+                let weights_path = self.config.scoring_weights_file.to_str();
+                compute_iteration_score(resume_score, ms, weights_path).0
+            }
+            None => resume_score.total,
         }
     }
 
-    /// Process all new resumes in the input folder.
+    /// Process all new resumes in the input folder, or every resume when
+    /// forced (see [`with_force`](Self::with_force)).
+    ///
+    /// Up to `max_concurrent_requests` resumes are processed at once, so
+    /// results are returned in completion order; sort on
+    /// [`ProcessingResult::index`] to restore the input order. A failure for
+    /// one resume is reported in its result rather than aborting the batch.
     ///
     /// # Errors
     ///
     /// Returns an error if the input folder cannot be read or listing resumes fails.
-    pub async fn process_all_resumes(&mut self) -> Result<Vec<ProcessingResult>> {
-        let resume_paths = self.input_handler.list_new_resumes(&self.state_manager)?;
-        let mut results = Vec::new();
+    pub async fn process_all_resumes(&self) -> Result<Vec<ProcessingResult>> {
+        let resume_paths = if self.force {
+            self.input_handler.list_resumes()?
+        } else {
+            self.state_manager
+                .read(|state| self.input_handler.list_new_resumes(state))?
+        };
+        let limit = usize::try_from(self.config.max_concurrent_requests)
+            .unwrap_or(1)
+            .max(1);
+        let permits = Semaphore::new(limit);
+
+        log::info!(
+            "Found {} resumes to process (up to {limit} concurrently)",
+            resume_paths.len()
+        );
 
-        log::info!("Found {} resumes to process", resume_paths.len());
+        let results = stream::iter(resume_paths.into_iter().enumerate())
+            .map(|(index, resume_path)| {
+                let permits = &permits;
+                async move {
+                    let _permit = permits.acquire().await.ok();
+                    log::info!("Processing: {}", resume_path.display());
+
+                    let mut result = match self
+                        .process_resume(&resume_path.display().to_string(), None)
+                        .await
+                    {
+                        Ok(result) => result,
+                        Err(e) => {
+                            log::error!("Failed to process {}: {}", resume_path.display(), e);
+                            ProcessingResult {
+                                index,
+                                resume_name: self.output_name(resume_stem(&resume_path)),
+                                success: false,
+                                output_dir: None,
+                                scores: None,
+                                enhanced_resume: None,
+                                recommendations: vec![],
+                                iterations: 0,
+                                iteration_history: vec![],
+                                run_report: None,
+                                enhancement_confidence: 0.0,
+                                detected_language: None,
+                                error: Some(e.to_string()),
+                            }
+                        }
+                    };
+                    result.index = index;
+                    result
+                }
+            })
+            .buffer_unordered(limit)
+            .collect()
+            .await;
 
-        for resume_path in resume_paths {
-            log::info!("Processing: {}", resume_path.display());
+        Ok(results)
+    }
 
-            match self
-                .process_resume(&resume_path.display().to_string(), None)
-                .await
-            {
-                Ok(result) => results.push(result),
-                Err(e) => {
-                    log::error!("Failed to process {}: {}", resume_path.display(), e);
-                    results.push(ProcessingResult {
-                        success: false,
-                        output_dir: None,
-                        scores: None,
-                        enhanced_resume: None,
-                        recommendations: vec![],
-                        error: Some(e.to_string()),
-                    });
-                }
+    /// Re-score every enhanced resume in the output folder with the current
+    /// scoring weights, without calling any AI agent.
+    ///
+    /// Each output directory with a manifest and a JSON or TOML enhanced
+    /// resume gets a fresh `scores.toml`, and its manifest's total score and
+    /// weights hash are updated. Directories without a structured resume
+    /// (e.g. PDF-only outputs) are skipped.
+    ///
+    /// Returns the rescored output directories with their new score reports,
+    /// in path order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output folder cannot be walked, or an output
+    /// cannot be loaded, scored or rewritten.
+    pub fn rescore_outputs(&self) -> Result<Vec<(PathBuf, ScoreReport)>> {
+        let output_folder = self.output_generator.output_folder();
+        if !output_folder.exists() {
+            return Ok(vec![]);
+        }
+
+        let weights_path = &self.config.scoring_weights_file;
+        let weights_hash = OutputManifest::hash_weights_file(weights_path);
+        let mut rescored = Vec::new();
+
+        for entry in WalkDir::new(output_folder).sort_by_file_name() {
+            let entry = entry.map_err(|e| {
+                AtsError::internal(format!("Failed to walk {}: {e}", output_folder.display()))
+            })?;
+            if entry.file_name() != MANIFEST_FILE {
+                continue;
+            }
+            let Some(output_dir) = entry.path().parent() else {
+                continue;
+            };
+
+            let mut manifest = OutputManifest::load(output_dir)?;
+            let Some(resume_file) = manifest.enhanced_resume_file() else {
+                log::warn!(
+                    "No JSON or TOML resume in {}, skipping",
+                    output_dir.display()
+                );
+                continue;
+            };
+            let resume_path = output_dir.join(resume_file);
+            let resume = if resume_path.extension().is_some_and(|ext| ext == "json") {
+                serde_json::from_str(&std::fs::read_to_string(&resume_path)?)?
+            } else {
+                crate::toml_io::load(&resume_path)?
+            };
+
+            let scores = score_resume_with_options(
+                &resume,
+                weights_path.to_str(),
+                &self.config.scoring_options(),
+            )?;
+            log::info!("Rescored {}: {:.2}", output_dir.display(), scores.total);
+
+            write_scores(output_dir, &scores)?;
+            if !manifest.files.iter().any(|f| f == SCORES_FILE) {
+                manifest.files.push(SCORES_FILE.to_string());
             }
+            manifest.total_score = Some(scores.total);
+            manifest.weights_hash.clone_from(&weights_hash);
+            manifest.save(output_dir)?;
+
+            rescored.push((output_dir.to_path_buf(), scores));
         }
 
-        Ok(results)
+        Ok(rescored)
     }
 
     /// Summarize a job description using the AI agent.
@@ -627,51 +1282,1515 @@ impl ResumeProcessor {
 
         let agent = self.agent_registry.get(agent_name)?;
 
-        let prompt = format!(
-            "Summarize the following job description and extract key information. \
-             Return a JSON object with these fields:\n\
-             - title: Job title\n\
-             - company: Company name (if mentioned)\n\
-             - key_responsibilities: Array of main responsibilities\n\
-             - required_skills: Array of required technical skills\n\
-             - preferred_skills: Array of preferred/nice-to-have skills\n\
-             - required_experience: Years of experience required (if specified)\n\
-             - education_requirements: Education requirements\n\
-             - summary: A brief 2-3 sentence summary of the position\n\n\
-             Job Description:\n{job_description}\n\n\
-             Output as raw JSON only, no markdown fences."
-        );
+        let prompt = render_prompt(&self.prompts.summarize_job, &[("job", job_description)]);
 
         agent.generate_json(&prompt).await
     }
+
+    /// Write a cover letter for `enhanced_resume` tailored to `job_text`.
+    ///
+    /// Uses the `cover_letter` agent, falling back to the `enhancer`. The
+    /// prompt highlights the candidate's best-matching experience and the job
+    /// requirements the resume supports, and asks for 3–4 plain-text
+    /// paragraphs that only use facts from the resume.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the job description is empty, neither agent is
+    /// configured, or the agent fails or returns an empty letter.
+    pub async fn generate_cover_letter(
+        &self,
+        enhanced_resume: &serde_json::Value,
+        job_text: &str,
+    ) -> Result<String> {
+        self.cover_letter_generation(enhanced_resume, job_text)
+            .await
+            .map(|generation| generation.value)
+    }
+
+    /// [`generate_cover_letter`](Self::generate_cover_letter), also reporting
+    /// the agent that wrote the letter and its token usage.
+    async fn cover_letter_generation(
+        &self,
+        enhanced_resume: &serde_json::Value,
+        job_text: &str,
+    ) -> Result<Generation<String>> {
+        if job_text.trim().is_empty() {
+            return Err(AtsError::InputValidation {
+                message: "Job description cannot be empty".to_string(),
+            });
+        }
+
+        let agent = self.cover_letter_agent()?;
+
+        let prompt = cover_letter::cover_letter_prompt(enhanced_resume, job_text);
+        let mut generation = agent.generate_text_traced(&prompt).await?;
+        generation.value = strip_markdown_fences(&generation.value);
+        if generation.value.is_empty() {
+            return Err(AtsError::AgentResponse {
+                message: "Agent returned an empty cover letter".to_string(),
+            });
+        }
+
+        Ok(generation)
+    }
+
+    /// Suggest stronger versions of the resume's weakest experience bullets.
+    ///
+    /// Up to `max` bullets failing the scorer's action-verb or quantification
+    /// check are sent to the `bullet_rewriter` agent (falling back to the
+    /// `reviser`, then the `enhancer`), one request per bullet. Bullets
+    /// passing both checks are left alone. Each suggestion carries the
+    /// original bullet and the checks it failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if none of those agents is configured, or an agent
+    /// request fails or returns no `suggested` text.
+    pub async fn suggest_bullet_rewrites(
+        &self,
+        resume: &serde_json::Value,
+        max: usize,
+    ) -> Result<Vec<BulletRewrite>> {
+        let weak = rewrite::weak_bullets(resume, max);
+        if weak.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let agent = ["bullet_rewriter", "reviser", "enhancer"]
+            .iter()
+            .find_map(|name| self.agent_registry.get(name).ok())
+            .ok_or_else(|| AtsError::AgentConfig {
+                message: "No bullet_rewriter, reviser or enhancer agent configured".to_string(),
+            })?;
+
+        let prompts: Vec<String> = weak.iter().map(rewrite::rewrite_prompt).collect();
+        let responses = agent.generate_json_batch(&prompts).await;
+
+        weak.into_iter()
+            .zip(responses)
+            .map(|(bullet, response)| {
+                let suggested = response?
+                    .get("suggested")
+                    .and_then(|v| v.as_str())
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(String::from)
+                    .ok_or_else(|| AtsError::AgentResponse {
+                        message: format!("No rewrite returned for bullet: {}", bullet.text),
+                    })?;
+                Ok(BulletRewrite {
+                    original: bullet.text,
+                    suggested,
+                    reason: bullet.reason,
+                })
+            })
+            .collect()
+    }
+
+    /// The agent that writes cover letters: `cover_letter`, else `enhancer`.
+    fn cover_letter_agent(&self) -> Result<&dyn Agent> {
+        self.agent_registry
+            .get("cover_letter")
+            .or_else(|_| self.agent_registry.get("enhancer"))
+            .map_err(|_| AtsError::AgentConfig {
+                message: "No cover_letter or enhancer agent configured".to_string(),
+            })
+    }
 }
 
 // -------------------------
-// Tests
+// Batch Summary
 // -------------------------
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Write a CSV summary of batch processing results.
+///
+/// One row is written per result with the columns `resume_name`,
+/// `total_score`, one column per score category (in first-seen order across
+/// all results), `iterations`, `output_dir` and `success`. Missing scores and
+/// output directories are written as empty cells.
+///
+/// # Errors
+///
+/// Returns an error if the CSV cannot be written to `path`.
+pub fn export_summary_csv(results: &[ProcessingResult], path: impl AsRef<Path>) -> Result<()> {
+    let mut categories: Vec<&str> = Vec::new();
+    for category in results
+        .iter()
+        .filter_map(|r| r.scores.as_ref())
+        .flat_map(|s| &s.categories)
+    {
+        if !categories.contains(&category.name.as_str()) {
+            categories.push(&category.name);
+        }
+    }
 
-    #[test]
-    fn test_iteration_strategy_parsing() {
-        assert_eq!(
-            "best_of".parse::<IterationStrategy>().unwrap(),
-            IterationStrategy::BestOf
-        );
-        assert_eq!(
-            "first_hit".parse::<IterationStrategy>().unwrap(),
-            IterationStrategy::FirstHit
-        );
-        assert_eq!(
-            "patience".parse::<IterationStrategy>().unwrap(),
-            IterationStrategy::Patience
+    let mut writer = CsvWriter::new(Vec::new());
+
+    let mut header = vec!["resume_name", "total_score"];
+    header.extend(&categories);
+    header.extend(["iterations", "output_dir", "success"]);
+    writer.write_record(&header)?;
+
+    for result in results {
+        let score_cell = |score: Option<f64>| score.map(|s| format!("{s:.2}")).unwrap_or_default();
+
+        let mut row = vec![
+            result.resume_name.clone(),
+            score_cell(result.scores.as_ref().map(|s| s.total)),
+        ];
+        row.extend(categories.iter().map(|name| {
+            score_cell(result.scores.as_ref().and_then(|s| {
+                s.categories
+                    .iter()
+                    .find(|c| c.name == *name)
+                    .map(|c| c.score)
+            }))
+        }));
+        row.push(result.iterations.to_string());
+        row.push(
+            result
+                .output_dir
+                .as_ref()
+                .map(|dir| dir.display().to_string())
+                .unwrap_or_default(),
         );
+        row.push(result.success.to_string());
+
+        writer.write_record(&row)?;
     }
 
-    #[test]
-    fn test_combined_score_calculation() {
-        // This test would require a full processor setup, skipping for now
+    let content = String::from_utf8(writer.into_inner())
+        .map_err(|e| AtsError::internal(format!("CSV output was not UTF-8: {e}")))?;
+    atomic_write(path, &content)
+}
+
+/// Write `report` as pretty JSON to [`RUN_REPORT_FILE`] in `output_dir`.
+fn write_run_report(report: &RunReport, output_dir: &Path) -> Result<()> {
+    let content = serde_json::to_string_pretty(report)?;
+    atomic_write(output_dir.join(RUN_REPORT_FILE), &content)
+}
+
+/// Describe schema validation failures for the enhancer to fix on retry.
+fn validation_feedback(validation: &ValidationResult) -> String {
+    let problems: Vec<String> = if validation.detailed_errors.is_empty() {
+        validation.errors.clone()
+    } else {
+        validation
+            .detailed_errors
+            .iter()
+            .map(|e| {
+                let field = e.instance_path.trim_start_matches('/').replace('/', ".");
+                let field = if field.is_empty() { "(root)" } else { &field };
+                if e.keyword == "required" {
+                    format!("field {field} missing")
+                } else {
+                    format!("field {field}: {}", e.message)
+                }
+            })
+            .collect()
+    };
+
+    format!(
+        "The previous output was invalid: {}. Return the complete corrected JSON object.",
+        problems.join("; ")
+    )
+}
+
+/// Resume sections whose trailing (oldest) entries may be left out of a
+/// revision prompt, in the order they are trimmed.
+const TRIMMABLE_SECTIONS: &[&str] = &["projects", "experience"];
+
+/// Drop the oldest entries of [`TRIMMABLE_SECTIONS`] until `resume`
+/// pretty-prints within `budget` characters.
+///
+/// Returns the trimmed resume and the dropped entries with their section, in
+/// the order they were dropped, or `None` if it can't be made to fit.
+fn trim_resume_entries(
+    resume: &serde_json::Value,
+    budget: usize,
+) -> Option<(serde_json::Value, Vec<(&'static str, serde_json::Value)>)> {
+    let mut trimmed = resume.clone();
+    let mut dropped = Vec::new();
+    loop {
+        let len =
+            serde_json::to_string_pretty(&trimmed).map_or(usize::MAX, |json| json.chars().count());
+        if len <= budget {
+            return Some((trimmed, dropped));
+        }
+        let entry = TRIMMABLE_SECTIONS.iter().find_map(|&section| {
+            trimmed
+                .get_mut(section)
+                .and_then(serde_json::Value::as_array_mut)
+                .and_then(Vec::pop)
+                .map(|entry| (section, entry))
+        })?;
+        dropped.push(entry);
+    }
+}
+
+/// Append entries dropped by [`trim_resume_entries`] back onto `candidate`,
+/// in their original order.
+fn restore_entries(candidate: &mut serde_json::Value, dropped: Vec<(&str, serde_json::Value)>) {
+    let Some(fields) = candidate.as_object_mut() else {
+        return;
+    };
+    for (section, entry) in dropped.into_iter().rev() {
+        let entries = fields
+            .entry(section)
+            .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+        if let Some(entries) = entries.as_array_mut() {
+            entries.push(entry);
+        }
+    }
+}
+
+/// Reduce an agent's resume response to the resume object itself.
+///
+/// Models sometimes wrap the object in a single-element array, under a lone
+/// `resume` key, or as a JSON string; those wrappers are removed. Anything
+/// else that isn't an object is rejected, since scoring it would silently
+/// yield zeros.
+fn resume_object(response: serde_json::Value) -> Result<serde_json::Value> {
+    match response {
+        serde_json::Value::Object(mut fields) => {
+            if fields.len() == 1
+                && fields
+                    .get("resume")
+                    .is_some_and(serde_json::Value::is_object)
+            {
+                resume_object(fields.remove("resume").unwrap_or_default())
+            } else {
+                Ok(serde_json::Value::Object(fields))
+            }
+        }
+        serde_json::Value::Array(mut items) if items.len() == 1 => resume_object(items.remove(0)),
+        serde_json::Value::String(text)
+            if serde_json::from_str::<serde_json::Value>(&text).is_ok_and(|v| !v.is_string()) =>
+        {
+            resume_object(serde_json::from_str(&text)?)
+        }
+        other => {
+            let kind = match other {
+                serde_json::Value::Null => "null",
+                serde_json::Value::Bool(_) => "a boolean",
+                serde_json::Value::Number(_) => "a number",
+                serde_json::Value::String(_) => "a string",
+                serde_json::Value::Array(_) => "an array",
+                serde_json::Value::Object(_) => "an object",
+            };
+            Err(AtsError::ApiResponse {
+                message: format!("Expected a JSON object for the resume, got {kind}"),
+                status_code: None,
+            })
+        }
+    }
+}
+
+/// Resume base name from its file path.
+fn resume_stem(path: &Path) -> String {
+    path.file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("resume")
+        .to_string()
+}
+
+// -------------------------
+// Tests
+// -------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_iteration_strategy_parsing() {
+        assert_eq!(
+            "best_of".parse::<IterationStrategy>().unwrap(),
+            IterationStrategy::BestOf
+        );
+        assert_eq!(
+            "first_hit".parse::<IterationStrategy>().unwrap(),
+            IterationStrategy::FirstHit
+        );
+        assert_eq!(
+            "patience".parse::<IterationStrategy>().unwrap(),
+            IterationStrategy::Patience
+        );
+    }
+
+    #[test]
+    fn test_combined_score_calculation() {
+        let dir = tempfile::tempdir().unwrap();
+        let weights_path = dir.path().join("weights.toml");
+        std::fs::write(
+            &weights_path,
+            "[overall.weights]\nresume = 0.2\nmatch = 0.8\n",
+        )
+        .unwrap();
+        let config = Config {
+            scoring_weights_file: weights_path.clone(),
+            ..schema_retry_config(dir.path())
+        };
+        let processor = ResumeProcessor::new(config).unwrap();
+
+        let report = |total: f64| ScoreReport {
+            kind: "test".to_string(),
+            total,
+            categories: vec![],
+            meta: HashMap::new(),
+        };
+        let (resume, matched) = (report(40.0), report(90.0));
+
+        let combined = processor.calculate_combined_score(&resume, Some(&matched));
+        assert!((combined - 80.0).abs() < 1e-9);
+        assert!((combined - matched.total).abs() < (combined - resume.total).abs());
+
+        // Without a match score the resume score is used as is
+        assert_eq!(processor.calculate_combined_score(&resume, None), 40.0);
+
+        // Zero weights fall back to the mean
+        std::fs::write(
+            &weights_path,
+            "[overall.weights]\nresume = 0.0\nmatch = 0.0\n",
+        )
+        .unwrap();
+        let combined = processor.calculate_combined_score(&resume, Some(&matched));
+        assert!((combined - 65.0).abs() < 1e-9);
+    }
+
+    /// Agent returning a fixed resume, tracking how many calls overlap.
+    struct ConcurrencyProbeAgent {
+        config: crate::agents::AgentConfig,
+        in_flight: Arc<AtomicUsize>,
+        max_in_flight: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::agents::Agent for ConcurrencyProbeAgent {
+        fn config(&self) -> &crate::agents::AgentConfig {
+            &self.config
+        }
+
+        async fn generate_text(&self, _prompt: &str) -> Result<String> {
+            Ok(String::new())
+        }
+
+        async fn generate_json(&self, _prompt: &str) -> Result<serde_json::Value> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(40)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            Ok(serde_json::json!({
+                "name": "Jane Doe",
+                "summary": "Engineer",
+                "experience": [],
+                "skills": ["Rust"]
+            }))
+        }
+    }
+
+    /// Agent replaying a fixed sequence of JSON responses (the last repeats).
+    struct ScriptedAgent {
+        config: crate::agents::AgentConfig,
+        responses: Vec<serde_json::Value>,
+        calls: AtomicUsize,
+    }
+
+    impl ScriptedAgent {
+        fn boxed(name: &str, responses: Vec<serde_json::Value>) -> Box<dyn crate::agents::Agent> {
+            Box::new(Self {
+                config: crate::agents::AgentConfig::builder().name(name).build(),
+                responses,
+                calls: AtomicUsize::new(0),
+            })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl crate::agents::Agent for ScriptedAgent {
+        fn config(&self) -> &crate::agents::AgentConfig {
+            &self.config
+        }
+
+        async fn generate_text(&self, _prompt: &str) -> Result<String> {
+            Ok(String::new())
+        }
+
+        async fn generate_json(&self, _prompt: &str) -> Result<serde_json::Value> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self.responses[call.min(self.responses.len() - 1)].clone())
+        }
+    }
+
+    fn schema_retry_config(dir: &Path) -> Config {
+        Config {
+            output_folder: dir.join("output"),
+            state_file: dir.join("state.toml"),
+            // Missing file: falls back to the built-in resume schema
+            resume_schema_path: dir.join("missing_schema.json"),
+            schema_validation_enabled: true,
+            schema_validation_max_retries: 1,
+            recommendations_enabled: false,
+            ..Config::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dropped_email_lowers_enhancement_confidence() {
+        let source = "Jane Doe\njane.doe@example.com\nEngineer at Acme, 2019-2023";
+        let faithful = serde_json::json!({
+            "personal_info": {"name": "Jane Doe", "email": "jane.doe@example.com"},
+            "experience": [{"title": "Engineer", "company": "Acme"}],
+            "education": [],
+            "skills": ["Rust"]
+        });
+        let mut dropped = faithful.clone();
+        dropped["personal_info"]
+            .as_object_mut()
+            .unwrap()
+            .remove("email");
+
+        let mut confidences = Vec::new();
+        for response in [faithful, dropped] {
+            let dir = tempfile::tempdir().unwrap();
+            let resume_path = dir.path().join("resume.txt");
+            std::fs::write(&resume_path, source).unwrap();
+
+            let mut processor = ResumeProcessor::new(schema_retry_config(dir.path())).unwrap();
+            processor
+                .agent_registry_mut()
+                .register("enhancer", ScriptedAgent::boxed("enhancer", vec![response]));
+
+            let result = processor
+                .process_resume(&resume_path.display().to_string(), None)
+                .await
+                .unwrap();
+            confidences.push(result.enhancement_confidence);
+        }
+
+        assert!((confidences[0] - 1.0).abs() < 1e-9);
+        assert!(confidences[1] < confidences[0]);
+    }
+
+    #[tokio::test]
+    async fn test_low_confidence_skips_outputs() {
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        std::fs::write(
+            &resume_path,
+            "Jane Doe\njane.doe@example.com\nEngineer at Acme, 2019-2023",
+        )
+        .unwrap();
+        // Drops the email and invents an employer
+        let invented = serde_json::json!({
+            "personal_info": {"name": "Jane Doe"},
+            "experience": [{"title": "Engineer", "company": "Globex"}],
+            "education": [],
+            "skills": ["Rust"]
+        });
+
+        let config = Config {
+            min_enhancement_confidence: 0.8,
+            ..schema_retry_config(dir.path())
+        };
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        processor
+            .agent_registry_mut()
+            .register("enhancer", ScriptedAgent::boxed("enhancer", vec![invented]));
+
+        let result = processor
+            .process_resume(&resume_path.display().to_string(), None)
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.enhancement_confidence < 0.8);
+        assert!(result.output_dir.is_none());
+        assert!(result
+            .error
+            .unwrap()
+            .contains("below min_enhancement_confidence"));
+        assert!(!dir.path().join("output").exists());
+        assert!(!processor
+            .state_manager
+            .is_processed(&calculate_file_hash(&resume_path).unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_enhance_resume_unwraps_wrapped_objects() {
+        let resume = serde_json::json!({
+            "personal_info": {"name": "Jane Doe"},
+            "skills": ["Rust"]
+        });
+        let wrapped = [
+            serde_json::json!([resume.clone()]),
+            serde_json::json!({"resume": resume.clone()}),
+            serde_json::json!([{"resume": resume.clone()}]),
+        ];
+
+        for response in wrapped {
+            let dir = tempfile::tempdir().unwrap();
+            let mut processor = ResumeProcessor::new(schema_retry_config(dir.path())).unwrap();
+            processor.agent_registry_mut().register(
+                "enhancer",
+                ScriptedAgent::boxed("enhancer", vec![response.clone()]),
+            );
+
+            let enhanced = processor
+                .enhance_resume("Jane Doe", None, None, &mut StepTimer::start("enhance"))
+                .await
+                .unwrap();
+            assert_eq!(enhanced, resume, "response {response}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_non_english_resume_gets_language_warning() {
+        let source = "Jane Doe\nIngeniera de software con seis años de experiencia en el \
+                      desarrollo de servicios para los equipos de pagos y datos del banco.";
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        std::fs::write(&resume_path, source).unwrap();
+
+        let config = Config {
+            recommendations_enabled: true,
+            ..schema_retry_config(dir.path())
+        };
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        processor.agent_registry_mut().register(
+            "enhancer",
+            ScriptedAgent::boxed(
+                "enhancer",
+                vec![serde_json::json!({
+                    "personal_info": {"name": "Jane Doe"},
+                    "experience": [],
+                    "education": [],
+                    "skills": []
+                })],
+            ),
+        );
+
+        let result = processor
+            .process_resume(&resume_path.display().to_string(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.detected_language.as_deref(), Some("es"));
+        assert!(result.recommendations[0].message.contains("Spanish"));
+    }
+
+    #[test]
+    fn test_resume_object_rejects_non_objects() {
+        for response in [
+            serde_json::json!("Jane Doe, engineer"),
+            serde_json::json!([{"name": "A"}, {"name": "B"}]),
+            serde_json::json!(null),
+        ] {
+            let err = resume_object(response).unwrap_err();
+            assert!(matches!(err, AtsError::ApiResponse { .. }));
+        }
+
+        // Objects that merely contain a resume field are kept as is
+        let resume = serde_json::json!({"resume": {"name": "A"}, "summary": "B"});
+        assert_eq!(resume_object(resume.clone()).unwrap(), resume);
+        assert_eq!(
+            resume_object(serde_json::json!("{\"name\": \"A\"}")).unwrap(),
+            serde_json::json!({"name": "A"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_schema_validation_failure_triggers_retry() {
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        std::fs::write(&resume_path, "Jane Doe, engineer").unwrap();
+
+        let invalid =
+            serde_json::json!({"personal_info": {"name": "Jane Doe"}, "experience": "Acme"});
+        let valid = serde_json::json!({
+            "personal_info": {"name": "Jane Doe"},
+            "experience": [{"title": "Engineer", "company": "Acme"}],
+            "education": [],
+            "skills": ["Rust"]
+        });
+
+        let mut processor = ResumeProcessor::new(schema_retry_config(dir.path())).unwrap();
+        processor.agent_registry_mut().register(
+            "enhancer",
+            ScriptedAgent::boxed("enhancer", vec![invalid.clone(), valid.clone()]),
+        );
+
+        let result = processor
+            .process_resume(&resume_path.display().to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(result.enhanced_resume, Some(valid));
+
+        // Output that never becomes valid is an error rather than being scored
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        std::fs::write(&resume_path, "Jane Doe, engineer").unwrap();
+
+        let mut processor = ResumeProcessor::new(schema_retry_config(dir.path())).unwrap();
+        processor
+            .agent_registry_mut()
+            .register("enhancer", ScriptedAgent::boxed("enhancer", vec![invalid]));
+
+        let err = processor
+            .process_resume(&resume_path.display().to_string(), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AtsError::SchemaValidation { .. }));
+    }
+
+    #[test]
+    fn test_validation_feedback_names_failed_fields() {
+        let validation = ValidationResult::from_schema_errors(vec![
+            crate::validation::SchemaError {
+                instance_path: "/personal_info/email".to_string(),
+                schema_path: "/properties/personal_info/required".to_string(),
+                message: "\"email\" is a required property".to_string(),
+                keyword: "required".to_string(),
+            },
+            crate::validation::SchemaError {
+                instance_path: "/experience".to_string(),
+                schema_path: "/properties/experience/type".to_string(),
+                message: "\"Acme\" is not of type \"array\"".to_string(),
+                keyword: "type".to_string(),
+            },
+        ]);
+
+        let feedback = validation_feedback(&validation);
+        assert!(feedback
+            .starts_with("The previous output was invalid: field personal_info.email missing; "));
+        assert!(feedback.contains("field experience: \"Acme\" is not of type \"array\""));
+    }
+
+    #[test]
+    fn test_enhance_prompt_fits_budget() {
+        use std::fmt::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config {
+            max_prompt_chars: 2_000,
+            ..schema_retry_config(dir.path())
+        };
+        let processor = ResumeProcessor::new(config).unwrap();
+
+        let mut resume = String::from(
+            "Jane Doe\nSUMMARY\nBackend engineer.\nEXPERIENCE\n\
+             Staff Engineer, Acme Corp (2021-present)\n",
+        );
+        for year in (1990..2021).rev() {
+            let _ = writeln!(resume, "Engineer, Company {year} ({year}-{})", year + 1);
+            resume.push_str(&"- Built and maintained internal services.\n".repeat(5));
+        }
+        let job = "We are hiring a backend engineer to build services. ".repeat(200);
+        assert!(resume.len() + job.len() > 2_000);
+
+        let prompt = processor.enhance_prompt(&resume, Some(&job), Some("Fix the dates."));
+        assert!(prompt.chars().count() <= 2_000);
+        assert!(prompt.contains("Jane Doe"));
+        assert!(prompt.contains("Staff Engineer, Acme Corp"));
+        assert!(prompt.contains("JOB DESCRIPTION:\nWe are hiring"));
+        assert!(prompt.ends_with("Fix the dates."));
+
+        // Prompts within budget are left alone
+        let prompt = processor.enhance_prompt("Jane Doe", Some("Rust role"), None);
+        assert!(prompt.contains("RESUME:\nJane Doe\n\nJOB DESCRIPTION:\nRust role"));
+    }
+
+    #[tokio::test]
+    async fn test_revise_prompt_drops_whole_entries_to_fit_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config {
+            max_prompt_chars: 2_000,
+            ..schema_retry_config(dir.path())
+        };
+        let experience: Vec<serde_json::Value> = (1990..2021)
+            .rev()
+            .map(|year| {
+                serde_json::json!({
+                    "title": "Engineer",
+                    "company": format!("Company {year}"),
+                    "bullets": ["Built and maintained internal services."]
+                })
+            })
+            .collect();
+        let resume = serde_json::json!({
+            "personal_info": {"name": "Jane Doe"},
+            "experience": experience,
+            "projects": [{"name": "Side project"}]
+        });
+        let scores = crate::scoring::score_resume(&resume, None).unwrap();
+
+        // The reviser rewrites the entries it was shown: the newest one
+        let revised = serde_json::json!({
+            "personal_info": {"name": "Jane Doe"},
+            "experience": [{
+                "title": "Senior Engineer",
+                "company": "Company 2020",
+                "bullets": ["Cut deploy time by 40%."]
+            }]
+        });
+        let prompts = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        processor.agent_registry_mut().register(
+            "reviser",
+            RecordingAgent::boxed("reviser", &prompts, revised),
+        );
+
+        let candidate = processor
+            .revise_resume(&resume, &scores, None, &mut StepTimer::start("iterate"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        // The prompt holds complete JSON with the newest entries only
+        let prompt = prompts.lock().unwrap().pop().unwrap();
+        assert!(prompt.chars().count() <= 2_000);
+        let sent = prompt
+            .split("CURRENT RESUME:\n")
+            .nth(1)
+            .and_then(|rest| rest.split("\n\nReturn").next())
+            .unwrap();
+        let sent: serde_json::Value = serde_json::from_str(sent).unwrap();
+        let sent_experience = sent["experience"].as_array().unwrap();
+        assert!(!sent_experience.is_empty() && sent_experience.len() < 31);
+        assert_eq!(sent_experience[0]["company"], "Company 2020");
+        assert_eq!(sent["projects"], serde_json::json!([]));
+
+        // Entries the reviser never saw are carried over in order
+        let companies: Vec<&str> = candidate["experience"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["company"].as_str().unwrap())
+            .collect();
+        assert_eq!(companies.len(), 31 - sent_experience.len() + 1);
+        assert_eq!(companies[0], "Company 2020");
+        assert_eq!(companies.last(), Some(&"Company 1990"));
+        assert_eq!(
+            candidate["projects"],
+            serde_json::json!([{"name": "Side project"}])
+        );
+
+        // A resume that can't fit without trimming other sections is not revised
+        let huge = serde_json::json!({"summary": "Backend engineer. ".repeat(200)});
+        assert!(processor
+            .revise_resume(&huge, &scores, None, &mut StepTimer::start("iterate"))
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_iteration_history_records_every_iteration() {
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        std::fs::write(&resume_path, "Jane Doe, engineer").unwrap();
+
+        let config = Config {
+            output_folder: dir.path().join("output"),
+            state_file: dir.path().join("state.toml"),
+            iterate_until_score_reached: true,
+            target_score: 100.0,
+            max_iterations: 3,
+            recommendations_enabled: false,
+            ..Config::default()
+        };
+
+        let weak = serde_json::json!({"personal_info": {"name": "Jane Doe"}});
+        let better = serde_json::json!({
+            "personal_info": {"name": "Jane Doe", "email": "jane@example.com"},
+            "summary": "Backend engineer focused on reliable distributed systems.",
+            "skills": ["Rust", "Go"]
+        });
+        let best = serde_json::json!({
+            "personal_info": {"name": "Jane Doe", "email": "jane@example.com", "phone": "555-0100"},
+            "summary": "Backend engineer focused on reliable distributed systems.",
+            "skills": ["Rust", "Go", "PostgreSQL", "Kubernetes", "AWS"],
+            "experience": [{
+                "title": "Senior Engineer",
+                "company": "Acme",
+                "bullets": [
+                    "Reduced p99 latency by 45% across 12 services",
+                    "Led migration of 3M records to PostgreSQL with zero downtime"
+                ]
+            }],
+            "education": [{"degree": "B.S. Computer Science", "institution": "State University"}]
+        });
+
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        let registry = processor.agent_registry_mut();
+        registry.register("enhancer", ScriptedAgent::boxed("enhancer", vec![weak]));
+        registry.register(
+            "reviser",
+            ScriptedAgent::boxed("reviser", vec![better.clone(), best, better]),
+        );
+
+        let result = processor
+            .process_resume(&resume_path.display().to_string(), None)
+            .await
+            .unwrap();
+
+        let history = &result.iteration_history;
+        assert_eq!(history.len(), 3);
+        assert_eq!(result.iterations, 3);
+        assert_eq!(
+            history.iter().map(|r| r.iteration).collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert!(history.iter().all(|r| !r.category_scores.is_empty()));
+
+        // Accepted candidates strictly improve; the best-so-far never drops
+        assert_eq!(
+            history.iter().map(|r| r.accepted).collect::<Vec<_>>(),
+            [true, true, false]
+        );
+        let mut best_so_far = f64::MIN;
+        for record in history {
+            if record.accepted {
+                assert!(record.combined_score > best_so_far);
+                best_so_far = record.combined_score;
+            } else {
+                assert!(record.combined_score <= best_so_far);
+            }
+        }
+        assert_eq!(result.scores.unwrap().total, best_so_far);
+    }
+
+    #[tokio::test]
+    async fn test_small_gain_counts_as_no_progress_for_patience() {
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        std::fs::write(&resume_path, "Jane Doe, engineer").unwrap();
+        // The small gain below comes from the opt-in length category
+        let weights_path = dir.path().join("weights.toml");
+        std::fs::write(&weights_path, "[resume.weights]\nlength = 0.05\n").unwrap();
+
+        let config = Config {
+            output_folder: dir.path().join("output"),
+            state_file: dir.path().join("state.toml"),
+            scoring_weights_file: weights_path,
+            iterate_until_score_reached: true,
+            target_score: 100.0,
+            max_iterations: 4,
+            iteration_strategy: "patience".to_string(),
+            max_regressions: 2,
+            min_improvement_delta: 0.5,
+            recommendations_enabled: false,
+            ..Config::default()
+        };
+
+        let base = serde_json::json!({
+            "personal_info": {"name": "Jane Doe", "email": "jane@example.com"},
+            "summary": "Backend engineer focused on reliable distributed systems.",
+            "skills": ["Rust", "Go"]
+        });
+        // A slightly longer summary gains about 0.2 points
+        let mut slightly_better = base.clone();
+        slightly_better["summary"] = serde_json::json!(
+            "Backend engineer focused on reliable distributed systems and public APIs for \
+             payments and billing teams at a mid-size fintech company in Berlin and Lisbon."
+        );
+
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        let registry = processor.agent_registry_mut();
+        registry.register(
+            "enhancer",
+            ScriptedAgent::boxed("enhancer", vec![base.clone()]),
+        );
+        registry.register(
+            "reviser",
+            ScriptedAgent::boxed("reviser", vec![slightly_better]),
+        );
+
+        let result = processor
+            .process_resume(&resume_path.display().to_string(), None)
+            .await
+            .unwrap();
+
+        // The better candidate is kept, but neither iteration counts as
+        // progress, so patience stops after 2 of the 4 iterations
+        let history = &result.iteration_history;
+        assert_eq!(
+            history.iter().map(|r| r.accepted).collect::<Vec<_>>(),
+            [true, false]
+        );
+        let base_score = score_resume_with_options(
+            &base,
+            processor.config.scoring_weights_file.to_str(),
+            &processor.config.scoring_options(),
+        )
+        .unwrap();
+        let gain = history[0].combined_score - base_score.total;
+        assert!(gain > 0.0 && gain < 0.5, "gain {gain}");
+    }
+
+    #[tokio::test]
+    async fn test_run_report_lists_executed_steps() {
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        std::fs::write(&resume_path, "Jane Doe, engineer").unwrap();
+
+        let config = Config {
+            iterate_until_score_reached: true,
+            target_score: 100.0,
+            max_iterations: 1,
+            ..schema_retry_config(dir.path())
+        };
+
+        let invalid =
+            serde_json::json!({"personal_info": {"name": "Jane Doe"}, "experience": "Acme"});
+        let valid = serde_json::json!({
+            "personal_info": {"name": "Jane Doe"},
+            "experience": [{"title": "Engineer", "company": "Acme"}],
+            "education": [],
+            "skills": ["Rust"]
+        });
+
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        let registry = processor.agent_registry_mut();
+        registry.register(
+            "enhancer",
+            ScriptedAgent::boxed("enhancer", vec![invalid, valid.clone()]),
+        );
+        registry.register("reviser", ScriptedAgent::boxed("reviser", vec![valid]));
+
+        let result = processor
+            .process_resume(&resume_path.display().to_string(), None)
+            .await
+            .unwrap();
+
+        let report = result.run_report.unwrap();
+        assert_eq!(report.resume_name, "resume");
+        assert_eq!(
+            report
+                .steps
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>(),
+            ["hash", "load", "enhance", "score", "iterate", "write"]
+        );
+        assert!(report.steps.iter().all(|s| s.duration_ms >= 0.0));
+        assert!(report.total_ms >= 0.0);
+
+        let enhance = report.step("enhance").unwrap();
+        assert_eq!(enhance.agent.as_deref(), Some("enhancer"));
+        assert_eq!(enhance.retries, 1);
+        assert_eq!(
+            report.step("iterate").unwrap().agent.as_deref(),
+            Some("reviser")
+        );
+        assert!(report.step("hash").unwrap().agent.is_none());
+
+        // The same report is written next to the outputs
+        let path = result.output_dir.unwrap().join(RUN_REPORT_FILE);
+        let written: RunReport =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(written.steps.len(), report.steps.len());
+        assert_eq!(written.step("enhance").unwrap().retries, 1);
+    }
+
+    #[tokio::test]
+    async fn test_rescore_outputs_uses_current_weights() {
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        std::fs::write(&resume_path, "Jane Doe, engineer").unwrap();
+        let weights_path = dir.path().join("weights.toml");
+        std::fs::write(&weights_path, "[resume.weights]\ncompleteness = 1.0\n").unwrap();
+
+        let config = Config {
+            scoring_weights_file: weights_path.clone(),
+            ..schema_retry_config(dir.path())
+        };
+        let enhanced = serde_json::json!({
+            "personal_info": {"name": "Jane Doe"},
+            "experience": [{"title": "Engineer", "company": "Acme"}],
+            "education": [],
+            "skills": ["Rust"]
+        });
+
+        let mut processor = ResumeProcessor::new(config.clone()).unwrap();
+        processor
+            .agent_registry_mut()
+            .register("enhancer", ScriptedAgent::boxed("enhancer", vec![enhanced]));
+        let result = processor
+            .process_resume(&resume_path.display().to_string(), None)
+            .await
+            .unwrap();
+        let output_dir = result.output_dir.unwrap();
+        let original_total = result.scores.unwrap().total;
+
+        // Reweight towards a category the resume scores differently on
+        std::fs::write(&weights_path, "[resume.weights]\nimpact = 1.0\n").unwrap();
+        let rescored = ResumeProcessor::new(config)
+            .unwrap()
+            .rescore_outputs()
+            .unwrap();
+
+        assert_eq!(rescored.len(), 1);
+        let (rescored_dir, scores) = &rescored[0];
+        assert_eq!(rescored_dir, &output_dir);
+        assert!((scores.total - original_total).abs() > f64::EPSILON);
+
+        let manifest = OutputManifest::load(&output_dir).unwrap();
+        assert_eq!(manifest.total_score, Some(scores.total));
+        assert_eq!(
+            manifest.weights_hash,
+            OutputManifest::hash_weights_file(&weights_path)
+        );
+        let written = crate::toml_io::load(output_dir.join(SCORES_FILE)).unwrap();
+        assert_eq!(written["total"].as_f64(), Some(scores.total));
+    }
+
+    #[tokio::test]
+    async fn test_force_reprocesses_processed_resume() {
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        std::fs::write(&resume_path, "Jane Doe\nEngineer at Acme").unwrap();
+        let resume_path = resume_path.display().to_string();
+
+        let config = Config {
+            enhancement_mode: "heuristic".to_string(),
+            ..schema_retry_config(dir.path())
+        };
+
+        let processor = ResumeProcessor::new(config.clone()).unwrap();
+        let first = processor.process_resume(&resume_path, None).await.unwrap();
+        assert!(first.output_dir.is_some());
+
+        // Already in state: skipped by default
+        let skipped = processor.process_resume(&resume_path, None).await.unwrap();
+        assert!(skipped.output_dir.is_none());
+        assert!(skipped.run_report.is_none());
+
+        let forced = ResumeProcessor::new(config).unwrap().with_force(true);
+        let second = forced.process_resume(&resume_path, None).await.unwrap();
+        let output_dir = second.output_dir.expect("forced run writes outputs");
+        assert!(output_dir.join(MANIFEST_FILE).exists());
+        assert!(second.run_report.is_some());
+        assert!(second.scores.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_process_all_resumes_bounded_concurrency() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("input");
+        std::fs::create_dir_all(&input).unwrap();
+        for i in 0..6 {
+            std::fs::write(input.join(format!("resume_{i}.txt")), format!("Resume {i}")).unwrap();
+        }
+
+        let config = Config {
+            input_resumes_folder: input,
+            output_folder: dir.path().join("output"),
+            state_file: dir.path().join("state.toml"),
+            max_concurrent_requests: 3,
+            recommendations_enabled: false,
+            ..Config::default()
+        };
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        processor.agent_registry_mut().register(
+            "enhancer",
+            Box::new(ConcurrencyProbeAgent {
+                config: crate::agents::AgentConfig::builder()
+                    .name("enhancer")
+                    .build(),
+                in_flight: Arc::clone(&in_flight),
+                max_in_flight: Arc::clone(&max_in_flight),
+            }),
+        );
+
+        let mut results = processor.process_all_resumes().await.unwrap();
+        results.sort_by_key(|r| r.index);
+
+        assert_eq!(results.len(), 6);
+        assert!(results.iter().all(|r| r.success && r.output_dir.is_some()));
+        assert_eq!(
+            results.iter().map(|r| r.index).collect::<Vec<_>>(),
+            (0..6).collect::<Vec<_>>()
+        );
+        assert_eq!(processor.state_manager.count(), 6);
+
+        let peak = max_in_flight.load(Ordering::SeqCst);
+        assert!(peak > 1, "resumes were not processed concurrently");
+        assert!(peak <= 3, "{peak} requests in flight exceeds the limit");
+    }
+
+    /// Agent recording every text prompt and answering with a fixed letter.
+    struct CoverLetterAgent {
+        config: crate::agents::AgentConfig,
+        prompts: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::agents::Agent for CoverLetterAgent {
+        fn config(&self) -> &crate::agents::AgentConfig {
+            &self.config
+        }
+
+        async fn generate_text(&self, prompt: &str) -> Result<String> {
+            self.prompts.lock().unwrap().push(prompt.to_string());
+            Ok("```\nDear Hiring Manager,\n\nI build Rust services.\n```".to_string())
+        }
+
+        async fn generate_json(&self, _prompt: &str) -> Result<serde_json::Value> {
+            Ok(serde_json::json!({
+                "personal_info": {"name": "Jane Doe"},
+                "experience": [{"title": "Engineer", "company": "Acme"}]
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_cover_letter_prompt_and_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        let job_path = dir.path().join("platform_engineer.txt");
+        std::fs::write(&resume_path, "Jane Doe, engineer").unwrap();
+        std::fs::write(
+            &job_path,
+            "Platform engineer at Globex building Rust services",
+        )
+        .unwrap();
+
+        let config = Config {
+            output_folder: dir.path().join("output"),
+            state_file: dir.path().join("state.toml"),
+            recommendations_enabled: false,
+            cover_letter_enabled: true,
+            ..Config::default()
+        };
+        let prompts = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        processor.agent_registry_mut().register(
+            "enhancer",
+            Box::new(CoverLetterAgent {
+                config: crate::agents::AgentConfig::builder()
+                    .name("enhancer")
+                    .build(),
+                prompts: Arc::clone(&prompts),
+            }),
+        );
+
+        // Without a cover_letter agent the enhancer writes the letter
+        let resume = serde_json::json!({
+            "personal_info": {"name": "Jane Doe"},
+            "experience": [{
+                "title": "Backend Engineer",
+                "company": "Acme",
+                "description": ["Built Rust services handling 2M requests a day"]
+            }]
+        });
+        let letter = processor
+            .generate_cover_letter(&resume, "Globex needs a Rust engineer for services")
+            .await
+            .unwrap();
+        assert_eq!(letter, "Dear Hiring Manager,\n\nI build Rust services.");
+
+        let prompt = prompts.lock().unwrap().pop().unwrap();
+        assert!(prompt.contains("Jane Doe"));
+        assert!(prompt.contains("Built Rust services handling 2M requests a day"));
+        assert!(prompt.contains("Globex needs a Rust engineer for services"));
+        assert!(prompt.contains("3 to 4 paragraphs"));
+
+        assert!(processor
+            .generate_cover_letter(&resume, "  ")
+            .await
+            .is_err());
+
+        // The pipeline writes the letter next to the resume outputs
+        let result = processor
+            .process_resume(
+                &resume_path.display().to_string(),
+                Some(&job_path.display().to_string()),
+            )
+            .await
+            .unwrap();
+        let letter_path = result
+            .output_dir
+            .unwrap()
+            .join("resume_platform_engineer_cover_letter.txt");
+        assert_eq!(
+            std::fs::read_to_string(letter_path).unwrap(),
+            "Dear Hiring Manager,\n\nI build Rust services."
+        );
+        assert!(prompts.lock().unwrap()[0].contains("Platform engineer at Globex"));
+    }
+
+    /// Agent answering with a resume and a cover letter signed with its name.
+    struct SignedLetterAgent {
+        config: crate::agents::AgentConfig,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::agents::Agent for SignedLetterAgent {
+        fn config(&self) -> &crate::agents::AgentConfig {
+            &self.config
+        }
+
+        async fn generate_text(&self, _prompt: &str) -> Result<String> {
+            Ok("Dear Hiring Manager,\n\nSincerely,\nJane Doe".to_string())
+        }
+
+        async fn generate_json(&self, _prompt: &str) -> Result<serde_json::Value> {
+            Ok(serde_json::json!({
+                "personal_info": {
+                    "name": "Jane Doe",
+                    "location": "Springfield, IL",
+                    "website": "https://janedoe.dev"
+                },
+                "experience": [{
+                    "title": "Engineer",
+                    "company": "Acme",
+                    "description": ["Worked on services for Jane Doe Consulting"]
+                }]
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_redact_pii_covers_every_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("jane_doe.txt");
+        let job_path = dir.path().join("platform_engineer.txt");
+        std::fs::write(&resume_path, "Jane Doe, engineer").unwrap();
+        std::fs::write(&job_path, "Platform engineer building Rust services").unwrap();
+
+        let config = Config {
+            output_folder: dir.path().join("output"),
+            state_file: dir.path().join("state.toml"),
+            recommendations_enabled: false,
+            cover_letter_enabled: true,
+            redact_pii: true,
+            ..Config::default()
+        };
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        processor.agent_registry_mut().register(
+            "enhancer",
+            Box::new(SignedLetterAgent {
+                config: crate::agents::AgentConfig::builder()
+                    .name("enhancer")
+                    .build(),
+            }),
+        );
+
+        let result = processor
+            .process_resume(
+                &resume_path.display().to_string(),
+                Some(&job_path.display().to_string()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.resume_name, resume_alias("jane_doe"));
+        assert_eq!(
+            result.run_report.unwrap().resume_name,
+            resume_alias("jane_doe")
+        );
+
+        // Nothing written under the output folder names the candidate
+        let mut pending = vec![dir.path().join("output")];
+        let mut files = 0;
+        while let Some(path) = pending.pop() {
+            assert!(!path.display().to_string().contains("jane_doe"));
+            if path.is_dir() {
+                pending.extend(std::fs::read_dir(&path).unwrap().map(|e| e.unwrap().path()));
+            } else {
+                files += 1;
+                let content = std::fs::read_to_string(&path).unwrap_or_default();
+                for secret in ["Jane Doe", "jane_doe", "Springfield", "janedoe.dev"] {
+                    assert!(!content.contains(secret), "{secret} in {}", path.display());
+                }
+            }
+        }
+        assert!(files > 3);
+    }
+
+    /// Agent recording every JSON prompt and answering with a fixed value.
+    struct RecordingAgent {
+        config: crate::agents::AgentConfig,
+        prompts: Arc<std::sync::Mutex<Vec<String>>>,
+        response: serde_json::Value,
+    }
+
+    impl RecordingAgent {
+        fn boxed(
+            name: &str,
+            prompts: &Arc<std::sync::Mutex<Vec<String>>>,
+            response: serde_json::Value,
+        ) -> Box<dyn crate::agents::Agent> {
+            Box::new(Self {
+                config: crate::agents::AgentConfig::builder().name(name).build(),
+                prompts: Arc::clone(prompts),
+                response,
+            })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl crate::agents::Agent for RecordingAgent {
+        fn config(&self) -> &crate::agents::AgentConfig {
+            &self.config
+        }
+
+        async fn generate_text(&self, _prompt: &str) -> Result<String> {
+            Ok(String::new())
+        }
+
+        async fn generate_json(&self, prompt: &str) -> Result<serde_json::Value> {
+            self.prompts.lock().unwrap().push(prompt.to_string());
+            Ok(self.response.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_suggest_bullet_rewrites_only_sends_weak_bullets() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config {
+            output_folder: dir.path().join("output"),
+            state_file: dir.path().join("state.toml"),
+            recommendations_enabled: false,
+            ..Config::default()
+        };
+        let prompts = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        processor.agent_registry_mut().register(
+            "reviser",
+            RecordingAgent::boxed(
+                "reviser",
+                &prompts,
+                serde_json::json!({
+                    "suggested": "Owned the billing system, cutting invoice errors by 30%"
+                }),
+            ),
+        );
+
+        let resume = serde_json::json!({
+            "experience": [{
+                "title": "Engineer",
+                "description": [
+                    "Led migration of 12 services to Kubernetes",
+                    "Responsible for the billing system",
+                    "Reduced deploy time by 40%"
+                ]
+            }]
+        });
+        let rewrites = processor.suggest_bullet_rewrites(&resume, 5).await.unwrap();
+
+        assert_eq!(
+            rewrites,
+            vec![BulletRewrite {
+                original: "Responsible for the billing system".to_string(),
+                suggested: "Owned the billing system, cutting invoice errors by 30%".to_string(),
+                reason: "Bullet doesn't start with an action verb and has no measurable result."
+                    .to_string(),
+            }]
+        );
+        let prompts = prompts.lock().unwrap();
+        assert_eq!(prompts.len(), 1);
+        assert!(prompts[0].contains("Responsible for the billing system"));
+        assert!(!prompts[0].contains("Kubernetes"));
+        assert!(!prompts[0].contains("deploy time"));
+    }
+
+    /// Enhancer reporting usage for JSON requests whose text requests fail,
+    /// while `last_usage` still holds the previous request's tokens.
+    struct UsageAgent {
+        config: crate::agents::AgentConfig,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::agents::Agent for UsageAgent {
+        fn config(&self) -> &crate::agents::AgentConfig {
+            &self.config
+        }
+
+        fn last_usage(&self) -> Option<crate::agents::TokenUsage> {
+            Some(crate::agents::TokenUsage::new(100, 50))
+        }
+
+        async fn generate_text(&self, _prompt: &str) -> Result<String> {
+            Err(AtsError::ApiResponse {
+                message: "API error (500): overloaded".to_string(),
+                status_code: Some(500),
+            })
+        }
+
+        async fn generate_json(&self, _prompt: &str) -> Result<serde_json::Value> {
+            Ok(serde_json::json!({
+                "personal_info": {"name": "Jane Doe"},
+                "experience": [{"title": "Engineer", "company": "Acme"}]
+            }))
+        }
+
+        async fn generate_json_traced(
+            &self,
+            prompt: &str,
+        ) -> Result<Generation<serde_json::Value>> {
+            let value = self.generate_json(prompt).await?;
+            Ok(Generation::new(
+                value,
+                &self.config,
+                Some(crate::agents::TokenUsage::new(100, 50)),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_request_records_no_usage() {
+        let dir = tempfile::tempdir().unwrap();
+        let resume_path = dir.path().join("resume.txt");
+        let job_path = dir.path().join("job.txt");
+        std::fs::write(&resume_path, "Jane Doe, engineer").unwrap();
+        std::fs::write(&job_path, "Rust engineer at Globex").unwrap();
+
+        let config = Config {
+            output_folder: dir.path().join("output"),
+            state_file: dir.path().join("state.toml"),
+            recommendations_enabled: false,
+            cover_letter_enabled: true,
+            ..Config::default()
+        };
+        let mut processor = ResumeProcessor::new(config).unwrap();
+        processor.agent_registry_mut().register(
+            "enhancer",
+            Box::new(UsageAgent {
+                config: crate::agents::AgentConfig::builder()
+                    .name("enhancer")
+                    .build(),
+            }),
+        );
+
+        let result = processor
+            .process_resume(
+                &resume_path.display().to_string(),
+                Some(&job_path.display().to_string()),
+            )
+            .await
+            .unwrap();
+
+        let report = result.run_report.unwrap();
+        assert_eq!(
+            report.step("enhance").unwrap().token_usage,
+            Some(crate::agents::TokenUsage::new(100, 50))
+        );
+        // The cover letter request failed, so it isn't billed the enhancer's tokens
+        let cover_letter = report.step("cover_letter").unwrap();
+        assert_eq!(cover_letter.agent.as_deref(), Some("enhancer"));
+        assert_eq!(cover_letter.token_usage, None);
+        assert_eq!(
+            report.token_usage,
+            Some(crate::agents::TokenUsage::new(100, 50))
+        );
     }
 }
@@ -0,0 +1,256 @@
+//! Prompt templates for the enhancer, reviser and job summarizer.
+//!
+//! Templates are plain text with `{resume}`, `{job}` and `{scores}`
+//! placeholders. The built-in prompts are used unless `prompt_templates_file`
+//! points at a TOML file overriding some of them:
+//!
+//! ```toml
+//! enhance = """
+//! Rewrite this resume as JSON with name, email, experience and skills.
+//!
+//! RESUME:
+//! {resume}
+//! """
+//! ```
+//!
+//! Templates missing from the file keep their built-in text; unknown keys are
+//! rejected. `config/prompts.toml` holds the built-in prompts as a starting
+//! point.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AtsError, Result};
+
+const DEFAULT_ENHANCE: &str = "Enhance the following resume. Return a structured JSON object with \
+fields: name, email, phone, location, summary, experience (array), skills (array), education \
+(array), certifications (array).\n\n\
+RESUME:\n{resume}";
+
+const DEFAULT_ENHANCE_WITH_JOB: &str = "Enhance the following resume for the given job \
+description. Return a structured JSON object with fields: name, email, phone, location, summary, \
+experience (array), skills (array), education (array), certifications (array).\n\n\
+RESUME:\n{resume}\n\n\
+JOB DESCRIPTION:\n{job}";
+
+const DEFAULT_REVISE: &str = "Revise the following resume to improve its scores. Focus on the \
+lower-scoring categories.\n\n\
+CURRENT SCORES:\n{scores}\n\n\
+CURRENT RESUME:\n{resume}\n\n\
+Return an improved, structured JSON resume.";
+
+const DEFAULT_REVISE_WITH_JOB: &str = "Revise the following resume to improve its scores. Focus \
+on the lower-scoring categories.\n\n\
+CURRENT SCORES:\n{scores}\n\n\
+CURRENT RESUME:\n{resume}\n\n\
+JOB DESCRIPTION:\n{job}\n\n\
+Return an improved, structured JSON resume.";
+
+const DEFAULT_SUMMARIZE_JOB: &str = "Summarize the following job description and extract key \
+information. Return a JSON object with these fields:\n\
+- title: Job title\n\
+- company: Company name (if mentioned)\n\
+- key_responsibilities: Array of main responsibilities\n\
+- required_skills: Array of required technical skills\n\
+- preferred_skills: Array of preferred/nice-to-have skills\n\
+- required_experience: Years of experience required (if specified)\n\
+- education_requirements: Education requirements\n\
+- summary: A brief 2-3 sentence summary of the position\n\n\
+Job Description:\n{job}\n\n\
+Output as raw JSON only, no markdown fences.";
+
+/// Named prompt templates used by the processor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PromptTemplates {
+    /// Enhancer prompt without a job description (`{resume}`).
+    pub enhance: String,
+    /// Enhancer prompt tailored to a job (`{resume}`, `{job}`).
+    pub enhance_with_job: String,
+    /// Reviser prompt without a job description (`{scores}`, `{resume}`).
+    pub revise: String,
+    /// Reviser prompt tailored to a job (`{scores}`, `{resume}`, `{job}`).
+    pub revise_with_job: String,
+    /// Job summarizer prompt (`{job}`).
+    pub summarize_job: String,
+}
+
+impl Default for PromptTemplates {
+    fn default() -> Self {
+        Self {
+            enhance: DEFAULT_ENHANCE.to_string(),
+            enhance_with_job: DEFAULT_ENHANCE_WITH_JOB.to_string(),
+            revise: DEFAULT_REVISE.to_string(),
+            revise_with_job: DEFAULT_REVISE_WITH_JOB.to_string(),
+            summarize_job: DEFAULT_SUMMARIZE_JOB.to_string(),
+        }
+    }
+}
+
+impl PromptTemplates {
+    /// Load templates from a TOML file, keeping the built-in text for any
+    /// template the file leaves out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed. A template
+    /// missing one of its placeholders is loaded with a warning.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            AtsError::io(
+                format!("Failed to read prompt templates {}", path.display()),
+                e,
+            )
+        })?;
+        let templates: Self = toml::from_str(&content).map_err(|e| {
+            AtsError::config_parse(format!("Invalid prompt templates {}: {e}", path.display()))
+        })?;
+        for (template, placeholder) in templates.missing_placeholders() {
+            log::warn!(
+                "Prompt template '{template}' in {} has no {{{placeholder}}} placeholder",
+                path.display()
+            );
+        }
+        Ok(templates)
+    }
+
+    /// `(template, placeholder)` pairs for placeholders a template leaves out,
+    /// meaning that input never reaches the agent.
+    #[must_use]
+    pub fn missing_placeholders(&self) -> Vec<(&'static str, &'static str)> {
+        [
+            ("enhance", &self.enhance, &["resume"][..]),
+            (
+                "enhance_with_job",
+                &self.enhance_with_job,
+                &["resume", "job"],
+            ),
+            ("revise", &self.revise, &["scores", "resume"]),
+            (
+                "revise_with_job",
+                &self.revise_with_job,
+                &["scores", "resume", "job"],
+            ),
+            ("summarize_job", &self.summarize_job, &["job"]),
+        ]
+        .into_iter()
+        .flat_map(|(name, template, placeholders)| {
+            placeholders
+                .iter()
+                .filter(|placeholder| !template.contains(&format!("{{{placeholder}}}")))
+                .map(move |placeholder| (name, *placeholder))
+        })
+        .collect()
+    }
+}
+
+/// Substitute `{name}` placeholders in `template` with their values.
+///
+/// Substitution is a single pass, so placeholders appearing inside a value
+/// are left alone. Braces that don't form a known placeholder (such as JSON
+/// examples in the template) are kept as written.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::processor::render_prompt;
+///
+/// let prompt = render_prompt("RESUME:\n{resume}\nJSON: {\"name\": ...}", &[("resume", "Jane Doe")]);
+/// assert_eq!(prompt, "RESUME:\nJane Doe\nJSON: {\"name\": ...}");
+/// ```
+#[must_use]
+pub fn render_prompt(template: &str, values: &[(&str, &str)]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let tail = &rest[start + 1..];
+        let placeholder = values.iter().find_map(|(name, value)| {
+            tail.strip_prefix(name)
+                .and_then(|after| after.strip_prefix('}'))
+                .map(|after| (value, after))
+        });
+        if let Some((value, after)) = placeholder {
+            rendered.push_str(value);
+            rest = after;
+        } else {
+            rendered.push('{');
+            rest = tail;
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_custom_template_replaces_placeholders() {
+        let template = "Scores:\n{scores}\nResume:\n{resume}\nJob:\n{job}\nKeep {other}";
+
+        let prompt = render_prompt(
+            template,
+            &[
+                ("resume", "Jane Doe, wrote {job} parsers"),
+                ("job", "Rust engineer"),
+                ("scores", "total 72.5"),
+            ],
+        );
+
+        assert_eq!(
+            prompt,
+            "Scores:\ntotal 72.5\nResume:\nJane Doe, wrote {job} parsers\nJob:\nRust engineer\nKeep {other}"
+        );
+    }
+
+    #[test]
+    fn test_load_keeps_defaults_for_missing_templates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("prompts.toml");
+        std::fs::write(&path, "enhance = \"Tidy up:\\n{resume}\"\n").unwrap();
+
+        let templates = PromptTemplates::load(&path).unwrap();
+
+        assert_eq!(templates.enhance, "Tidy up:\n{resume}");
+        assert_eq!(templates.revise, PromptTemplates::default().revise);
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_templates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("prompts.toml");
+        std::fs::write(&path, "enhanse = \"Tidy up:\\n{resume}\"\n").unwrap();
+
+        assert!(PromptTemplates::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_missing_placeholders() {
+        let templates = PromptTemplates {
+            enhance: "Tidy up this resume".to_string(),
+            summarize_job: "Summarize {resume}".to_string(),
+            ..PromptTemplates::default()
+        };
+
+        assert_eq!(
+            templates.missing_placeholders(),
+            [("enhance", "resume"), ("summarize_job", "job")]
+        );
+        assert!(PromptTemplates::default().missing_placeholders().is_empty());
+    }
+
+    #[test]
+    fn test_shipped_sample_matches_built_in_prompts() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("config/prompts.toml");
+
+        assert_eq!(
+            PromptTemplates::load(path).unwrap(),
+            PromptTemplates::default()
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Machine-readable report of a single resume processing run.
+//!
+//! Records how long each pipeline step took and, for AI-backed steps, which
+//! agent and model served it, how many times the step re-prompted and the
+//! token usage the provider reported. The report is written to the output
+//! directory as [`RUN_REPORT_FILE`] for debugging slow runs and attributing
+//! cost.
+
+use crate::agents::{Agent, Generation, TokenUsage};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+/// File name of the run report in the output directory.
+pub const RUN_REPORT_FILE: &str = "run_report.json";
+
+/// Timings and agent usage for one `process_resume` run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunReport {
+    /// Base name of the processed resume.
+    pub resume_name: String,
+    /// When processing started.
+    pub started_at: DateTime<Utc>,
+    /// Wall-clock time of the whole run in milliseconds.
+    pub total_ms: f64,
+    /// Executed steps, in order.
+    pub steps: Vec<StepRecord>,
+    /// Token usage summed over all steps, if any provider reported it.
+    pub token_usage: Option<TokenUsage>,
+}
+
+impl RunReport {
+    /// Find a step by name.
+    pub fn step(&self, name: &str) -> Option<&StepRecord> {
+        self.steps.iter().find(|s| s.name == name)
+    }
+}
+
+/// One executed pipeline step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepRecord {
+    /// Step name: `hash`, `load`, `enhance`, `score`, `iterate`,
+    /// `recommend`, `cover_letter` or `write`.
+    pub name: String,
+    /// Time spent in the step in milliseconds.
+    pub duration_ms: f64,
+    /// Name of the agent that served the step, for AI-backed steps.
+    pub agent: Option<String>,
+    /// Provider of that agent.
+    pub provider: Option<String>,
+    /// Model of that agent.
+    pub model: Option<String>,
+    /// Extra requests the step made after its first, e.g. re-prompts after
+    /// failed schema validation. Retries inside an agent are not counted.
+    pub retries: u32,
+    /// Token usage reported for the step's requests.
+    pub token_usage: Option<TokenUsage>,
+}
+
+/// Times a step while it runs.
+pub(crate) struct StepTimer {
+    record: StepRecord,
+    started: Instant,
+}
+
+impl StepTimer {
+    pub(crate) fn start(name: &str) -> Self {
+        Self {
+            record: StepRecord {
+                name: name.to_string(),
+                duration_ms: 0.0,
+                agent: None,
+                provider: None,
+                model: None,
+                retries: 0,
+                token_usage: None,
+            },
+            started: Instant::now(),
+        }
+    }
+
+    /// Attribute the step to `agent`.
+    pub(crate) fn agent(&mut self, agent: &dyn Agent) {
+        let config = agent.config();
+        self.record.agent = Some(config.name.clone());
+        self.record.provider = Some(config.provider.clone());
+        self.record.model = Some(config.model_name.clone());
+    }
+
+    /// Attribute the step to the agent that answered `generation` and add
+    /// the token usage of that request.
+    pub(crate) fn generation<T>(&mut self, generation: &Generation<T>) {
+        self.record.agent = Some(generation.agent.clone());
+        self.record.provider = Some(generation.provider.clone());
+        self.record.model = Some(generation.model.clone());
+        if let Some(usage) = generation.usage {
+            *self
+                .record
+                .token_usage
+                .get_or_insert_with(TokenUsage::default) += usage;
+        }
+    }
+
+    pub(crate) fn retry(&mut self) {
+        self.record.retries += 1;
+    }
+}
+
+/// Collects step records over a run.
+pub(crate) struct RunRecorder {
+    resume_name: String,
+    started_at: DateTime<Utc>,
+    started: Instant,
+    steps: Vec<StepRecord>,
+}
+
+impl RunRecorder {
+    pub(crate) fn new(resume_name: &str) -> Self {
+        Self {
+            resume_name: resume_name.to_string(),
+            started_at: Utc::now(),
+            started: Instant::now(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Stop `timer` and add its step to the report.
+    pub(crate) fn record(&mut self, timer: StepTimer) {
+        let mut record = timer.record;
+        record.duration_ms = elapsed_ms(timer.started);
+        self.steps.push(record);
+    }
+
+    pub(crate) fn finish(self) -> RunReport {
+        let token_usage =
+            self.steps
+                .iter()
+                .filter_map(|s| s.token_usage)
+                .reduce(|mut total, usage| {
+                    total += usage;
+                    total
+                });
+
+        RunReport {
+            resume_name: self.resume_name,
+            started_at: self.started_at,
+            total_ms: elapsed_ms(self.started),
+            steps: self.steps,
+            token_usage,
+        }
+    }
+}
+
+fn elapsed_ms(started: Instant) -> f64 {
+    started.elapsed().as_secs_f64() * 1000.0
+}
@@ -0,0 +1,121 @@
+//! Bullet-level rewrite suggestions.
+//!
+//! Experience bullets are checked the same way the `experience_quality`
+//! scorer checks them: a bullet should open with an action verb and contain
+//! a number. Only bullets failing a check are sent to the agent, weakest
+//! first, so bullets that already read well are never rewritten.
+
+use serde::{Deserialize, Serialize};
+
+use crate::scoring::extract_bullets;
+use crate::scoring::text::{contains_number, looks_like_action_bullet};
+
+/// A suggested rewrite of one weak experience bullet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BulletRewrite {
+    /// The bullet as written in the resume.
+    pub original: String,
+    /// The agent's stronger, quantified version.
+    pub suggested: String,
+    /// Which scorer checks the original fails.
+    pub reason: String,
+}
+
+/// A bullet failing at least one quality check.
+pub(crate) struct WeakBullet {
+    pub(crate) text: String,
+    pub(crate) reason: String,
+}
+
+/// Up to `max` experience bullets failing the action-verb or quantification
+/// check.
+///
+/// Bullets failing both checks come first; otherwise resume order is kept.
+pub(crate) fn weak_bullets(resume: &serde_json::Value, max: usize) -> Vec<WeakBullet> {
+    let mut weak: Vec<(usize, WeakBullet)> = resume
+        .get("experience")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .flat_map(extract_bullets)
+        .filter_map(|bullet| {
+            let mut failures = Vec::new();
+            if !looks_like_action_bullet(&bullet) {
+                failures.push("doesn't start with an action verb");
+            }
+            if !contains_number(&bullet) {
+                failures.push("has no measurable result");
+            }
+            if failures.is_empty() {
+                return None;
+            }
+            let reason = format!("Bullet {}.", failures.join(" and "));
+            Some((
+                failures.len(),
+                WeakBullet {
+                    text: bullet,
+                    reason,
+                },
+            ))
+        })
+        .collect();
+
+    weak.sort_by_key(|(failed, _)| std::cmp::Reverse(*failed));
+    weak.into_iter()
+        .take(max)
+        .map(|(_, bullet)| bullet)
+        .collect()
+}
+
+/// Build the prompt asking for a rewrite of `bullet`.
+pub(crate) fn rewrite_prompt(bullet: &WeakBullet) -> String {
+    format!(
+        "Rewrite the following resume bullet into one strong, quantified bullet.\n\n\
+         Requirements:\n\
+         - Start with a strong action verb.\n\
+         - Include a concrete metric (number, percentage, time or money) where the \
+         bullet supports one; keep any numbers already given.\n\
+         - Keep it to one line and do not invent employers, tools or responsibilities.\n\n\
+         PROBLEM: {}\n\n\
+         BULLET:\n{}\n\n\
+         Return a JSON object with a single field \"suggested\" holding the rewritten \
+         bullet. Output raw JSON only, no markdown fences.",
+        bullet.reason, bullet.text
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weak_bullets_orders_by_failed_checks() {
+        let resume = serde_json::json!({
+            "experience": [{
+                "description": [
+                    "Led migration of 12 services to Kubernetes",
+                    "Improved test coverage",
+                    "Responsible for the billing system"
+                ]
+            }, {
+                "bullets": ["Team of 4 engineers under me"]
+            }]
+        });
+
+        let weak = weak_bullets(&resume, 2);
+        let texts: Vec<&str> = weak.iter().map(|b| b.text.as_str()).collect();
+
+        assert_eq!(
+            texts,
+            [
+                "Responsible for the billing system",
+                "Improved test coverage"
+            ]
+        );
+        assert_eq!(
+            weak[0].reason,
+            "Bullet doesn't start with an action verb and has no measurable result."
+        );
+        assert_eq!(weak[1].reason, "Bullet has no measurable result.");
+    }
+}
@@ -1,6 +1,14 @@
 //! Recommendation generation module.
 
+use crate::processor::SectionHeading;
+use crate::scoring::text::years_of_experience;
+use crate::scoring::{
+    KeywordGap, ACTION_VERB_POINTS, BULLET_VOLUME_POINTS, MAX_SKILL_CHARS, QUANTIFIED_POINTS,
+    TARGET_BULLET_COUNT, TARGET_SKILL_COUNT,
+};
+use crate::utils::text::Lang;
 use serde::{Deserialize, Serialize};
+use std::fmt::Write;
 
 /// A recommendation for resume improvement.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,6 +77,18 @@ pub fn generate_recommendations(
                 .unwrap_or(0.0);
             let details = cat.get("details");
 
+            // Employment gaps and typos are worth addressing whatever the score
+            if name == "tenure" {
+                if let Some(recommendation) = details.and_then(tenure_gap_recommendation) {
+                    recommendations.push(recommendation);
+                }
+            }
+            if name == "readability" {
+                if let Some(recommendation) = details.and_then(spelling_recommendation) {
+                    recommendations.push(recommendation);
+                }
+            }
+
             // Generate recommendations for low-scoring categories
             if score < 50.0 {
                 match name {
@@ -79,16 +99,10 @@ pub fn generate_recommendations(
                         );
                     }
                     "skills_quality" => {
-                        recommendations.push(
-                            Recommendation::new("Improve skills section")
-                                .with_reason("Add more relevant technical skills and tools. Include proficiency levels where applicable")
-                        );
+                        recommendations.push(skills_recommendation(details));
                     }
                     "experience_quality" => {
-                        recommendations.push(
-                            Recommendation::new("Enhance work experience descriptions")
-                                .with_reason("Use action verbs, quantify achievements, and highlight impact in your experience bullet points")
-                        );
+                        recommendations.push(experience_recommendation(details));
                     }
                     "impact" => {
                         recommendations.push(
@@ -124,6 +138,24 @@ pub fn generate_recommendations(
                                 .with_reason("Your resume is missing several skills listed in the job requirements")
                         );
                     }
+                    "length" => {
+                        let band = details.and_then(|d| d.get("band")).and_then(|v| v.as_str());
+                        let words = details
+                            .and_then(|d| d.get("word_count"))
+                            .and_then(serde_json::Value::as_u64)
+                            .unwrap_or(0);
+                        if band == Some("too_long") {
+                            recommendations.push(
+                                Recommendation::new("Tighten your resume")
+                                    .with_reason(format!("Your summary and experience run to {words} words; aim for 400-800 by cutting older or less relevant bullets")),
+                            );
+                        } else {
+                            recommendations.push(
+                                Recommendation::new("Expand your summary and experience")
+                                    .with_reason(format!("Your summary and experience total only {words} words; aim for 400-800 by describing your work and its results")),
+                            );
+                        }
+                    }
                     "role_alignment" => {
                         recommendations.push(
                             Recommendation::new("Better align your job titles with the target role")
@@ -156,3 +188,309 @@ pub fn generate_recommendations(
 
     recommendations
 }
+
+/// Recommend a better skills section, citing overlong entries from the
+/// skills quality details when there are any.
+fn skills_recommendation(details: Option<&serde_json::Value>) -> Recommendation {
+    let count = |key: &str| {
+        details
+            .and_then(|d| d.get(key))
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0)
+    };
+    let too_long = count("too_long_skills");
+    let unique = count("unique_skill_count");
+
+    if too_long > 0 {
+        return Recommendation::new("Shorten your skills list entries").with_reason(format!(
+            "{too_long} of your skills read like sentences (over {MAX_SKILL_CHARS} characters). \
+             List each tool or technology on its own; you have {unique} distinct skills and \
+             {TARGET_SKILL_COUNT} or more is ideal"
+        ));
+    }
+    Recommendation::new("Improve skills section").with_reason(format!(
+        "You list {unique} distinct skills; add more relevant technical skills and tools to \
+         reach {TARGET_SKILL_COUNT} or more. Include proficiency levels where applicable"
+    ))
+}
+
+/// Recommend stronger experience bullets, addressing whichever part of the
+/// experience quality score lost the most points: bullet count, action-verb
+/// ratio (quoting the bullets that lack one) or quantification.
+fn experience_recommendation(details: Option<&serde_json::Value>) -> Recommendation {
+    let generic = Recommendation::new("Enhance work experience descriptions").with_reason(
+        "Use action verbs, quantify achievements, and highlight impact in your experience bullet points",
+    );
+    let Some(details) = details else {
+        return generic;
+    };
+    let count = |key: &str| details.get(key).and_then(serde_json::Value::as_u64);
+    let ratio = |key: &str| details.get(key).and_then(serde_json::Value::as_f64);
+    let (Some(total), Some(action), Some(quantified), Some(ratio), Some(quant_ratio)) = (
+        count("total_bullets"),
+        count("action_bullets"),
+        count("quantified_bullets"),
+        ratio("action_ratio"),
+        ratio("quantified_ratio"),
+    ) else {
+        return generic;
+    };
+
+    let volume_lost =
+        BULLET_VOLUME_POINTS * (1.0 - (total as f64 / f64::from(TARGET_BULLET_COUNT)).min(1.0));
+    let action_lost = ACTION_VERB_POINTS * (1.0 - ratio);
+    let quant_lost = QUANTIFIED_POINTS * (1.0 - quant_ratio);
+
+    if quant_lost > action_lost && quant_lost >= volume_lost {
+        return Recommendation::new("Quantify your experience bullets").with_reason(format!(
+            "Only {:.0}% of your bullets ({quantified} of {total}) include a number. Add the \
+             scale or result of each achievement: users, requests, time or money saved",
+            quant_ratio * 100.0
+        ));
+    }
+    if volume_lost > action_lost {
+        return Recommendation::new("Add more experience bullets").with_reason(format!(
+            "Your experience has {total} bullet{}; aim for {TARGET_BULLET_COUNT} or more, each \
+             describing one achievement",
+            if total == 1 { "" } else { "s" }
+        ));
+    }
+
+    let mut reason = format!(
+        "Only {:.0}% of your bullets ({action} of {total}) start with an action verb",
+        ratio * 100.0
+    );
+    let weak: Vec<String> = details
+        .get("weak_bullets")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str())
+                .map(|b| format!("\"{b}\""))
+                .collect()
+        })
+        .unwrap_or_default();
+    if weak.is_empty() {
+        reason.push_str(". Lead each bullet with what you did and quantify the result");
+    } else {
+        let _ = write!(
+            reason,
+            ". Rewrite these to lead with what you did: {}",
+            weak.join("; ")
+        );
+    }
+
+    Recommendation::new("Start your experience bullets with action verbs").with_reason(reason)
+}
+
+/// Recommend explaining the employment gaps listed in a tenure category's
+/// details, if there are any.
+fn tenure_gap_recommendation(details: &serde_json::Value) -> Option<Recommendation> {
+    let gaps: Vec<String> = details
+        .get("gaps")?
+        .as_array()?
+        .iter()
+        .filter_map(|gap| {
+            Some(format!(
+                "{} months between {} and {}",
+                gap.get("months")?.as_u64()?,
+                gap.get("after")?.as_str()?,
+                gap.get("before")?.as_str()?
+            ))
+        })
+        .collect();
+    if gaps.is_empty() {
+        return None;
+    }
+    Some(
+        Recommendation::new("Account for gaps in your employment history").with_reason(format!(
+            "Found {}: {}. Briefly note what you did (study, caregiving, freelance work) so \
+             reviewers don't have to guess",
+            if gaps.len() == 1 { "a gap" } else { "gaps" },
+            gaps.join("; ")
+        )),
+    )
+}
+
+/// Recommend fixing the misspellings listed in readability details.
+///
+/// Known typos are listed with their correction; other unrecognized words
+/// are listed on their own.
+fn spelling_recommendation(details: &serde_json::Value) -> Option<Recommendation> {
+    let fixes: Vec<String> = details
+        .get("misspellings")?
+        .as_array()?
+        .iter()
+        .filter_map(|m| {
+            let word = m.get("word")?.as_str()?;
+            Some(match m.get("suggestion").and_then(|s| s.as_str()) {
+                Some(suggestion) => format!("\"{word}\" → \"{suggestion}\""),
+                None => format!("\"{word}\""),
+            })
+        })
+        .collect();
+    if fixes.is_empty() {
+        return None;
+    }
+
+    Some(
+        Recommendation::new("Fix spelling mistakes")
+            .with_reason(format!("Likely typos: {}", fixes.join(", "))),
+    )
+}
+
+/// Turn a keyword gap into an "add these terms" recommendation.
+///
+/// Lists up to `max_terms` missing keywords, most frequent in the job
+/// description first. Returns `None` when nothing is missing.
+pub fn keyword_gap_recommendation(gap: &KeywordGap, max_terms: usize) -> Option<Recommendation> {
+    let terms: Vec<&str> = gap
+        .missing
+        .iter()
+        .take(max_terms)
+        .map(String::as_str)
+        .collect();
+    if terms.is_empty() {
+        return None;
+    }
+
+    Some(
+        Recommendation::new(format!(
+            "Add these {} job keywords where they reflect your experience",
+            terms.len()
+        ))
+        .with_reason(format!(
+            "Missing from your resume, most frequent in the job description first: {}",
+            terms.join(", ")
+        )),
+    )
+}
+
+/// Warn that a resume isn't in the language the scoring lexicons cover.
+pub fn language_recommendation(lang: Lang) -> Recommendation {
+    Recommendation::new(format!(
+        "Scores may be inaccurate: this resume appears to be in {}",
+        lang.name()
+    ))
+    .with_reason(format!(
+        "Keyword, action-verb and stopword lists used for scoring are English. Load {} \
+         stopwords and synonyms files and set lexicon_language = \"{}\" for reliable scores",
+        lang.name(),
+        lang.code()
+    ))
+}
+
+/// Headings ATS parsers reliably recognise for each section, canonical first.
+fn standard_headings(section: &str) -> &'static [&'static str] {
+    match section {
+        "summary" => &["Summary", "Professional Summary", "Profile"],
+        "experience" => &["Experience", "Work Experience", "Professional Experience"],
+        "education" => &["Education"],
+        "skills" => &["Skills", "Technical Skills"],
+        "projects" => &["Projects"],
+        _ => &[],
+    }
+}
+
+/// Conventional position of a section: experienced candidates lead with their
+/// work history, others with their education.
+fn section_rank(section: &str, experienced: bool) -> usize {
+    let order: &[&str] = if experienced {
+        &["summary", "experience", "skills", "projects", "education"]
+    } else {
+        &["summary", "education", "experience", "skills", "projects"]
+    };
+    order
+        .iter()
+        .position(|s| *s == section)
+        .unwrap_or(order.len())
+}
+
+/// Suggest canonical section headings and order for ATS parsing.
+///
+/// Reads the headings recorded under `_meta.section_order` (see
+/// [`detect_section_order`](crate::processor::detect_section_order)) and
+/// flags a summary that isn't first, education listed before experience
+/// for an experienced candidate (two or more roles, or two or more years in
+/// the summary), and non-standard headings such as "Work History". Returns
+/// nothing if no section order was recorded.
+pub fn section_recommendations(resume: &serde_json::Value) -> Vec<Recommendation> {
+    let Some(order) = resume
+        .get("_meta")
+        .and_then(|m| m.get("section_order"))
+        .and_then(|v| serde_json::from_value::<Vec<SectionHeading>>(v.clone()).ok())
+    else {
+        return Vec::new();
+    };
+
+    let role_count = resume
+        .get("experience")
+        .and_then(|v| v.as_array())
+        .map_or(0, Vec::len);
+    let years = resume
+        .get("summary")
+        .and_then(|v| v.as_str())
+        .and_then(years_of_experience)
+        .unwrap_or(0);
+    let experienced = role_count >= 2 || years >= 2;
+
+    // First occurrence of each standard section, in document order
+    let mut sections: Vec<&str> = Vec::new();
+    for heading in &order {
+        let section = heading.section.as_str();
+        if !standard_headings(section).is_empty() && !sections.contains(&section) {
+            sections.push(section);
+        }
+    }
+
+    let mut recommendations = Vec::new();
+
+    let position = |name: &str| sections.iter().position(|s| *s == name);
+    let mut problems = Vec::new();
+    if position("summary").is_some_and(|i| i > 0) {
+        problems.push("your summary should open the resume".to_string());
+    }
+    if experienced {
+        if let (Some(education), Some(experience)) = (position("education"), position("experience"))
+        {
+            if education < experience {
+                problems.push(
+                    "with your work history, experience should come before education".to_string(),
+                );
+            }
+        }
+    }
+
+    if !problems.is_empty() {
+        let mut suggested = sections.clone();
+        suggested.sort_by_key(|s| section_rank(s, experienced));
+        let suggested: Vec<&str> = suggested.iter().map(|s| standard_headings(s)[0]).collect();
+        recommendations.push(
+            Recommendation::new(format!("Reorder your sections: {}", suggested.join(", ")))
+                .with_reason(format!(
+                    "ATS parsers expect a conventional section order; {}",
+                    problems.join(" and ")
+                )),
+        );
+    }
+
+    for heading in &order {
+        let standard = standard_headings(&heading.section);
+        if standard.is_empty()
+            || standard
+                .iter()
+                .any(|h| h.eq_ignore_ascii_case(&heading.heading))
+        {
+            continue;
+        }
+        recommendations.push(
+            Recommendation::new(format!(
+                "Rename the \"{}\" heading to \"{}\"",
+                heading.heading, standard[0]
+            ))
+            .with_reason("ATS parsers look for standard section headings"),
+        );
+    }
+
+    recommendations
+}
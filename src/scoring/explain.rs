@@ -0,0 +1,425 @@
+//! Plain-language explanations of score reports.
+//!
+//! Each category's `details` map is turned into one sentence, such as
+//! "Experience quality is 62/100 because 3 of 8 bullets use action verbs and
+//! 2 are quantified." Explanations only read the details keys the scorers
+//! write, so the same report always yields the same text. Categories without
+//! recognized details get a bare "<Category> is N/100." sentence.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use super::{ScoreCategoryResult, ScoreReport};
+
+/// Missing job keywords named in the keyword overlap explanation.
+const MISSING_KEYWORDS_SHOWN: usize = 5;
+
+/// Misspellings named in the readability explanation.
+const MISSPELLINGS_SHOWN: usize = 3;
+
+type Details = HashMap<String, Value>;
+
+/// Explain every category of `report`, one sentence each, in report order.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::{explain_report, score_resume};
+/// use serde_json::json;
+///
+/// let resume = json!({
+///     "experience": [{
+///         "title": "Engineer",
+///         "description": ["Led migration of 12 services", "Responsible for on-call"]
+///     }]
+/// });
+/// let report = score_resume(&resume, None)?;
+///
+/// let explanation = explain_report(&report);
+/// assert!(explanation
+///     .iter()
+///     .any(|s| s.contains("1 of 2 bullets uses action verbs and 1 is quantified")));
+/// # Ok::<(), ats_checker::error::AtsError>(())
+/// ```
+#[must_use]
+pub fn explain_report(report: &ScoreReport) -> Vec<String> {
+    report.categories.iter().map(explain_category).collect()
+}
+
+/// One sentence explaining a category's score.
+#[must_use]
+pub fn explain_category(category: &ScoreCategoryResult) -> String {
+    let details = &category.details;
+    let because = text(details, "reason")
+        .map(reason)
+        .or_else(|| match category.name.as_str() {
+            "completeness" => completeness(details),
+            "skills_quality" => skills_quality(details),
+            "experience_quality" => experience_quality(details),
+            "impact" => impact(details),
+            "length" => length(details),
+            "tenure" => tenure(details),
+            "readability" => readability(details),
+            "keyword_overlap" => keyword_overlap(details),
+            "skills_overlap" => skills_overlap(details),
+            "role_alignment" => role_alignment(details),
+            "seniority_alignment" => seniority_alignment(details),
+            _ => None,
+        });
+
+    let label = label(&category.name);
+    match because {
+        Some(because) => format!("{label} is {:.0}/100 because {because}.", category.score),
+        None => format!("{label} is {:.0}/100.", category.score),
+    }
+}
+
+// -------------------------
+// Categories
+// -------------------------
+
+fn completeness(details: &Details) -> Option<String> {
+    let mut missing = Vec::new();
+    let checks = [
+        ("has_name", "a name"),
+        ("has_email", "an email address"),
+        ("has_phone", "a phone number"),
+        ("has_summary", "a summary"),
+        ("has_experience", "experience"),
+        ("has_education", "education"),
+        ("has_skills", "skills"),
+    ];
+    for (key, what) in checks {
+        if !flag(details, key)? {
+            missing.push(what.to_string());
+        }
+    }
+    if flag(details, "has_email")? && !flag(details, "valid_email")? {
+        missing.push("a valid email address".to_string());
+    }
+    if flag(details, "has_phone")? && !flag(details, "valid_phone")? {
+        missing.push("a valid phone number".to_string());
+    }
+
+    Some(if missing.is_empty() {
+        "all key sections and contact details are present".to_string()
+    } else {
+        format!("it is missing {}", join(&missing))
+    })
+}
+
+fn skills_quality(details: &Details) -> Option<String> {
+    let unique = count(details, "unique_skill_count")?;
+    let mut because = format!(
+        "{} listed (12 earn full marks)",
+        plural(unique, "unique skill")
+    );
+    let too_long = count(details, "too_long_skills").unwrap_or(0);
+    if too_long > 0 {
+        let _ = write!(
+            because,
+            " and {too_long} {} too long to read as a skill",
+            if too_long == 1 {
+                "entry is"
+            } else {
+                "entries are"
+            }
+        );
+    }
+    Some(because)
+}
+
+fn experience_quality(details: &Details) -> Option<String> {
+    let total = count(details, "total_bullets")?;
+    let action = count(details, "action_bullets")?;
+    let quantified = count(details, "quantified_bullets")?;
+    Some(format!(
+        "{action} of {} {} action verbs and {quantified} {} quantified",
+        plural(total, "bullet"),
+        verb(action, "uses", "use"),
+        verb(quantified, "is", "are"),
+    ))
+}
+
+fn impact(details: &Details) -> Option<String> {
+    let bullets = count(details, "bullets")?;
+    let quantified = count(details, "quantified")?;
+    let outcome = count(details, "outcome")?;
+    let strong = count(details, "strong")?;
+    let mut because = format!(
+        "{quantified} of {} {} quantified, {outcome} {} outcomes and {strong} {} strong",
+        plural(bullets, "bullet"),
+        verb(quantified, "is", "are"),
+        verb(outcome, "describes", "describe"),
+        verb(strong, "is", "are"),
+    );
+
+    let penalty = details
+        .get("filler_penalty")
+        .and_then(Value::as_f64)
+        .unwrap_or(0.0);
+    if penalty > 0.0 {
+        let phrases = strings(details, "filler_phrases");
+        let _ = write!(
+            because,
+            ", less {penalty:.0} points for filler ({})",
+            quoted(&phrases)
+        );
+    }
+    Some(because)
+}
+
+fn length(details: &Details) -> Option<String> {
+    let words = count(details, "word_count")?;
+    let range = details.get("ideal_range").and_then(Value::as_array)?;
+    let (min, max) = (range.first()?.as_u64()?, range.get(1)?.as_u64()?);
+    let assessment = match text(details, "band")? {
+        "too_short" => "short of",
+        "too_long" => "over",
+        _ => "within",
+    };
+    Some(format!(
+        "the summary and experience run to {}, {assessment} the ideal {min}-{max}",
+        plural(words, "word"),
+    ))
+}
+
+fn tenure(details: &Details) -> Option<String> {
+    let roles = count(details, "dated_roles")?;
+    let months = count(details, "total_months")?;
+    let average = details.get("average_tenure_months")?.as_f64()?;
+    let gaps = details
+        .get("gaps")
+        .and_then(Value::as_array)
+        .map_or(0, Vec::len);
+    let mut because = format!(
+        "{} cover {} (about {average:.0} months per role)",
+        plural(roles, "dated role"),
+        plural(months, "month"),
+    );
+    if gaps > 0 {
+        let _ = write!(because, " with {}", plural(gaps as u64, "employment gap"));
+    }
+    Some(because)
+}
+
+fn readability(details: &Details) -> Option<String> {
+    let words = count(details, "word_count")?;
+    let misspelled = count(details, "misspelled_count")?;
+    let mut because = format!(
+        "{misspelled} of {} {} misspelled",
+        plural(words, "word"),
+        verb(misspelled, "is", "are")
+    );
+
+    let examples: Vec<String> = details
+        .get("misspellings")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|m| {
+            let word = m.get("word")?.as_str()?;
+            Some(match m.get("suggestion").and_then(Value::as_str) {
+                Some(suggestion) => format!("{word} → {suggestion}"),
+                None => word.to_string(),
+            })
+        })
+        .take(MISSPELLINGS_SHOWN)
+        .collect();
+    if !examples.is_empty() {
+        let _ = write!(because, " ({})", examples.join(", "));
+    }
+    Some(because)
+}
+
+fn keyword_overlap(details: &Details) -> Option<String> {
+    let overlap = count(details, "overlap_count")?;
+    let job_terms = count(details, "job_token_count")?;
+    let mut because = format!(
+        "{overlap} of {} {} in the resume",
+        plural(job_terms, "job keyword"),
+        verb(overlap, "appears", "appear"),
+    );
+
+    let missing: Vec<String> = strings(details, "top_weighted_missing")
+        .into_iter()
+        .take(MISSING_KEYWORDS_SHOWN)
+        .collect();
+    if !missing.is_empty() {
+        let _ = write!(because, "; most important missing: {}", missing.join(", "));
+    }
+    Some(because)
+}
+
+fn skills_overlap(details: &Details) -> Option<String> {
+    let matched = count(details, "matched_skill_count")?;
+    let skills = count(details, "resume_skill_count")?;
+    Some(format!(
+        "{matched} of {} {} the job",
+        plural(skills, "listed skill"),
+        verb(matched, "matches", "match"),
+    ))
+}
+
+fn role_alignment(details: &Details) -> Option<String> {
+    let job_title = text(details, "job_title")?;
+    let resume_title = text(details, "best_resume_title")?;
+    let ratio = details.get("best_overlap_ratio")?.as_f64()?;
+    Some(format!(
+        "the closest resume title \"{resume_title}\" shares {:.0}% of the words in \"{job_title}\"",
+        ratio * 100.0
+    ))
+}
+
+fn seniority_alignment(details: &Details) -> Option<String> {
+    let job_level = text(details, "job_level")?;
+    let resume_level = text(details, "resume_level")?;
+    Some(if resume_level == job_level {
+        format!("the resume and the job are both {job_level} level")
+    } else {
+        format!("the resume reads as {resume_level} level but the job is {job_level} level")
+    })
+}
+
+/// A scorer's `reason` code as a clause.
+fn reason(code: &str) -> String {
+    match code {
+        "no_experience_entries" => "there are no experience entries".to_string(),
+        "experience_without_bullets" | "no_bullets" => {
+            "the experience entries have no bullet points".to_string()
+        }
+        "job_has_no_tokens" => "the job description has no keywords".to_string(),
+        "resume_has_no_skills" => "the resume lists no skills".to_string(),
+        "missing_job_title" => "the job has no title".to_string(),
+        "missing_resume_titles" => "the experience entries have no titles".to_string(),
+        "job_title_no_tokens" => "the job title has no keywords".to_string(),
+        "resume_level_unknown" => "the resume's seniority could not be determined".to_string(),
+        other => other.replace('_', " "),
+    }
+}
+
+// -------------------------
+// Helpers
+// -------------------------
+
+/// `experience_quality` -> `Experience quality`.
+fn label(name: &str) -> String {
+    let name = name.replace('_', " ");
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn count(details: &Details, key: &str) -> Option<u64> {
+    details.get(key)?.as_u64()
+}
+
+fn flag(details: &Details, key: &str) -> Option<bool> {
+    details.get(key)?.as_bool()
+}
+
+fn text<'a>(details: &'a Details, key: &str) -> Option<&'a str> {
+    details.get(key)?.as_str()
+}
+
+fn strings(details: &Details, key: &str) -> Vec<String> {
+    details
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_str().map(str::to_string))
+        .collect()
+}
+
+/// `3 bullets`, `1 bullet`.
+fn plural(n: u64, noun: &str) -> String {
+    if n == 1 {
+        format!("{n} {noun}")
+    } else {
+        format!("{n} {noun}s")
+    }
+}
+
+/// The singular or plural form of a verb for `n` subjects.
+fn verb(n: u64, singular: &'static str, plural: &'static str) -> &'static str {
+    if n == 1 {
+        singular
+    } else {
+        plural
+    }
+}
+
+/// `a`, `a and b`, `a, b and c`.
+fn join(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
+fn quoted(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|item| format!("\"{item}\""))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_explain_report_uses_bullet_counts() {
+        let resume = json!({
+            "personal_info": {"name": "Jane Doe", "email": "jane@example.com"},
+            "experience": [{
+                "title": "Engineer",
+                "company": "Acme",
+                "description": [
+                    "Led migration of 12 services to Kubernetes",
+                    "Built a billing pipeline",
+                    "Responsible for on-call"
+                ]
+            }],
+            "skills": ["Rust", "Kubernetes"]
+        });
+        let report = crate::scoring::score_resume(&resume, None).unwrap();
+
+        let explanation = explain_report(&report);
+
+        assert_eq!(explanation.len(), report.categories.len());
+        let experience = explanation
+            .iter()
+            .find(|s| s.starts_with("Experience quality is "))
+            .unwrap();
+        assert!(
+            experience.ends_with("because 2 of 3 bullets use action verbs and 1 is quantified."),
+            "{experience}"
+        );
+        let completeness = explanation
+            .iter()
+            .find(|s| s.starts_with("Completeness is "))
+            .unwrap();
+        assert!(completeness.contains("missing a phone number, a summary and education"));
+    }
+
+    #[test]
+    fn test_explain_category_falls_back_without_details() {
+        let category = ScoreCategoryResult {
+            name: "job_clarity".to_string(),
+            score: 61.6,
+            weight: 0.5,
+            details: HashMap::new(),
+        };
+
+        assert_eq!(explain_category(&category), "Job clarity is 62/100.");
+    }
+}
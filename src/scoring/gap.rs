@@ -0,0 +1,165 @@
+//! Keyword gap analysis between a resume and a job posting.
+//!
+//! Reports which job keywords the resume already covers and which it is
+//! missing, with missing terms ranked by how often the job description uses
+//! them, so the most-repeated requirements come first.
+
+use super::{match_job_text, resume_as_text, KeywordContext, ScoringOptions};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Job keywords present in and missing from a resume.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KeywordGap {
+    /// Job keywords found in the resume, most important first.
+    pub present: Vec<String>,
+    /// Job keywords not found in the resume, most important first.
+    pub missing: Vec<String>,
+    /// Missing keywords with their importance: the keyword's share of all
+    /// keyword occurrences in the job description (0.0–1.0).
+    pub missing_by_importance: Vec<(String, f64)>,
+}
+
+impl KeywordGap {
+    /// Whether the resume covers every job keyword.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// Compare a resume's keywords against a job posting's.
+///
+/// Job keywords come from the posting's `title`, `description`, `company` and
+/// `location` fields, as in [`score_match`](super::score_match). Ties in
+/// importance are broken alphabetically.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::keyword_gap;
+/// use serde_json::json;
+///
+/// let resume = json!({"skills": ["Rust"]});
+/// let job = json!({"description": "Rust and Kubernetes. Kubernetes on AWS."});
+///
+/// let gap = keyword_gap(&resume, &job);
+/// assert_eq!(gap.present, ["rust"]);
+/// assert_eq!(gap.missing, ["kubernetes", "aws"]);
+/// ```
+pub fn keyword_gap(resume: &serde_json::Value, job: &serde_json::Value) -> KeywordGap {
+    let keywords = KeywordContext::from_options(&ScoringOptions::default());
+    compute_keyword_gap(resume, job, &keywords)
+}
+
+/// [`keyword_gap`] with the stopwords, abbreviations and synonyms of a
+/// scoring run.
+pub(super) fn compute_keyword_gap(
+    resume: &serde_json::Value,
+    job: &serde_json::Value,
+    keywords: &KeywordContext,
+) -> KeywordGap {
+    let job_text = match_job_text(job);
+    let job_tokens = keywords.extract(&job_text);
+    let resume_tokens = keywords.extract(&resume_as_text(resume));
+
+    let counts = keywords.token_counts(&job_text);
+    let total: usize = job_tokens
+        .iter()
+        .map(|t| counts.get(t).copied().unwrap_or(0))
+        .sum();
+
+    let mut ranked: Vec<(String, f64)> = job_tokens
+        .into_iter()
+        .map(|token| {
+            let count = counts.get(&token).copied().unwrap_or(0);
+            let importance = if total == 0 {
+                0.0
+            } else {
+                count as f64 / total as f64
+            };
+            (token, importance)
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.0.cmp(&b.0))
+    });
+
+    let mut gap = KeywordGap::default();
+    for (token, importance) in ranked {
+        if resume_tokens.contains(&token) {
+            gap.present.push(token);
+        } else {
+            gap.missing.push(token.clone());
+            gap.missing_by_importance.push((token, importance));
+        }
+    }
+    gap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_missing_terms_ranked_by_job_frequency() {
+        let resume = json!({
+            "summary": "Backend engineer building Rust services",
+            "skills": ["Rust", "PostgreSQL"]
+        });
+        let job = json!({
+            "title": "Backend Engineer",
+            "description": "Rust services on Kubernetes. Kubernetes and \
+                            Kubernetes, with Terraform for PostgreSQL."
+        });
+
+        let gap = keyword_gap(&resume, &job);
+
+        assert_eq!(gap.missing, ["kubernetes", "terraform"]);
+        assert!(gap.present.contains(&"rust".to_string()));
+        assert!(gap.present.contains(&"postgresql".to_string()));
+
+        let (term, importance) = &gap.missing_by_importance[0];
+        assert_eq!(term, "kubernetes");
+        assert!(*importance > gap.missing_by_importance[1].1);
+        assert!(gap.missing_by_importance.iter().all(|(_, w)| *w > 0.0));
+        assert!(!gap.is_complete());
+    }
+
+    #[test]
+    fn test_abbreviation_counts_as_one_term() {
+        let job = json!({"description": "Rust on AWS"});
+
+        let gap = keyword_gap(&json!({"skills": ["Rust"]}), &job);
+        assert_eq!(gap.missing, ["aws"]);
+
+        // The expansion on either side matches the abbreviation
+        let gap = keyword_gap(&json!({"skills": ["Rust", "Amazon Web Services"]}), &job);
+        assert!(gap.is_complete());
+        let gap = keyword_gap(
+            &json!({"skills": ["Rust", "AWS"]}),
+            &json!({"description": "Rust on Amazon Web Services with CI"}),
+        );
+        assert_eq!(gap.missing, ["ci"]);
+    }
+
+    #[test]
+    fn test_synonyms_close_gap() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("synonyms.toml");
+        std::fs::write(&path, "[synonyms]\nkubernetes = [\"k8s\"]\n").unwrap();
+        let options = ScoringOptions {
+            synonyms_path: Some(path),
+            ..ScoringOptions::default()
+        };
+
+        let resume = json!({"skills": ["K8s"]});
+        let job = json!({"description": "Kubernetes"});
+        let gap = compute_keyword_gap(&resume, &job, &KeywordContext::from_options(&options));
+
+        assert_eq!(gap.present, ["kubernetes"]);
+        assert!(gap.is_complete());
+    }
+}
@@ -7,21 +7,44 @@
 //! # Scoring Categories
 //!
 //! ## Resume Score (0-100)
-//! - **completeness**: Has required fields (name, email, experience, etc.)
+//! - **completeness**: Has required fields (name, experience, etc.) and a
+//!   well-formed email and phone, with a bonus for a linkedin.com or github.com profile
 //! - **`skills_quality`**: Quality and quantity of skills listed
 //! - **`experience_quality`**: Quality of experience bullets (action verbs, quantification)
 //! - **impact**: Quantification and outcome-focused language
+//! - **length**: Word count of the summary and experience (400-800 words is ideal)
+//! - **tenure**: Total experience and average time per role from the
+//!   experience dates, less a penalty per gap of 6+ months. Omitted when no
+//!   entry has parseable dates
+//! - **readability**: Share of misspelled words: words missing from the
+//!   English word list with the `english-dictionary` feature, otherwise known
+//!   typos only (see [`spelling`]).
+//!   Opt-in: only scored when the weights file gives it a positive weight
 //!
 //! ## Job Score (0-100)
 //! - **completeness**: Has required fields (title, company, description, etc.)
 //! - **clarity**: Description length and structure
-//! - **`compensation_transparency`**: Salary information present
+//! - **`compensation_transparency`**: Salary information present, with full
+//!   marks for a concrete range
 //! - **`link_quality`**: Valid URL present
+//! - **recency**: How recently the job was posted
 //!
 //! ## Match Score (0-100)
-//! - **`keyword_overlap`**: Keywords from job found in resume
+//! - **`keyword_overlap`**: Keywords from job found in resume, rarer job terms weighted higher
 //! - **`skills_overlap`**: Skills from resume found in job description
 //! - **`role_alignment`**: Job title matches resume titles
+//! - **`seniority_alignment`**: Resume seniority (from titles or years of
+//!   experience) is close to the level the job title implies. Omitted when the
+//!   job's level cannot be inferred
+//!
+//! Must-have keywords from `[match.requirements]` (or
+//! [`ScoringOptions::required_keywords`]) act as knockout rules: if the resume
+//! lacks any of them, the match total is capped and the missing keywords are
+//! listed under `failed_requirements` in the `keyword_overlap` category's
+//! details.
+//!
+//! The tokenization and bullet heuristics behind these categories are exposed
+//! in the [`text`] submodule, and employment date parsing in [`tenure`].
 //!
 //! # Example
 //!
@@ -42,10 +65,28 @@
 //! # }
 //! ```
 
+mod explain;
+mod gap;
+pub mod spelling;
+pub mod tenure;
+pub mod text;
+
+pub use explain::{explain_category, explain_report};
+pub use gap::{keyword_gap, KeywordGap};
+
 use crate::error::Result;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use std::time::SystemTime;
+use text::{
+    contains_number, contains_outcome_language, contains_profile_url, default_filler_phrases,
+    default_stopwords, extract_keywords_with, find_filler_phrases, is_valid_email, is_valid_phone,
+    looks_like_action_bullet, seniority_from_title, tokenize, years_of_experience,
+    DefaultTokenizer, SeniorityLevel, Tokenizer,
+};
+use unicode_segmentation::UnicodeSegmentation;
 
 // -------------------------
 // Data Structures
@@ -82,6 +123,426 @@ impl ScoreReport {
     pub fn as_dict(&self) -> serde_json::Value {
         serde_json::to_value(self).unwrap_or(serde_json::Value::Null)
     }
+
+    /// Plain-language explanation of each category's score, one sentence
+    /// per category (see [`explain_report`]).
+    #[must_use]
+    pub fn explain(&self) -> Vec<String> {
+        explain_report(self)
+    }
+
+    /// Compare this report (before) with `other` (after).
+    ///
+    /// Categories are matched by name. A category present in only one report
+    /// counts as 0 in the other, so its change is its full score (or minus
+    /// it). Categories are listed in this report's order, followed by those
+    /// only `other` has.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ats_checker::scoring::score_resume;
+    /// use serde_json::json;
+    ///
+    /// let before = score_resume(&json!({"summary": "Engineer"}), None).unwrap();
+    /// let after = score_resume(
+    ///     &json!({"summary": "Engineer", "skills": ["Rust", "Go", "SQL"]}),
+    ///     None,
+    /// )
+    /// .unwrap();
+    ///
+    /// let delta = before.delta(&after);
+    /// assert!(delta.total > 0.0);
+    /// assert!(delta.improved().iter().any(|c| c.name == "skills_quality"));
+    /// ```
+    #[must_use]
+    pub fn delta(&self, other: &ScoreReport) -> ScoreDelta {
+        let score_in = |report: &ScoreReport, name: &str| {
+            report
+                .categories
+                .iter()
+                .find(|c| c.name == name)
+                .map(|c| c.score)
+        };
+
+        let mut categories: Vec<CategoryDelta> = self
+            .categories
+            .iter()
+            .map(|c| CategoryDelta::new(&c.name, Some(c.score), score_in(other, &c.name)))
+            .collect();
+        categories.extend(
+            other
+                .categories
+                .iter()
+                .filter(|c| score_in(self, &c.name).is_none())
+                .map(|c| CategoryDelta::new(&c.name, None, Some(c.score))),
+        );
+
+        ScoreDelta {
+            total: other.total - self.total,
+            categories,
+        }
+    }
+}
+
+/// Change in one category between two reports.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CategoryDelta {
+    /// Category name.
+    pub name: String,
+    /// Score in the earlier report (`None` if the category was absent).
+    pub before: Option<f64>,
+    /// Score in the later report (`None` if the category was absent).
+    pub after: Option<f64>,
+    /// `after - before`, treating an absent score as 0.
+    pub change: f64,
+}
+
+impl CategoryDelta {
+    fn new(name: &str, before: Option<f64>, after: Option<f64>) -> Self {
+        Self {
+            name: name.to_string(),
+            before,
+            after,
+            change: after.unwrap_or(0.0) - before.unwrap_or(0.0),
+        }
+    }
+}
+
+/// Difference between two score reports (see [`ScoreReport::delta`]).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScoreDelta {
+    /// Change in the weighted total.
+    pub total: f64,
+    /// Per-category changes.
+    pub categories: Vec<CategoryDelta>,
+}
+
+impl ScoreDelta {
+    /// Categories whose score went up.
+    #[must_use]
+    pub fn improved(&self) -> Vec<&CategoryDelta> {
+        self.categories.iter().filter(|c| c.change > 0.0).collect()
+    }
+
+    /// Categories whose score went down.
+    #[must_use]
+    pub fn declined(&self) -> Vec<&CategoryDelta> {
+        self.categories.iter().filter(|c| c.change < 0.0).collect()
+    }
+}
+
+/// Optional tuning knobs for scoring beyond category weights.
+///
+/// The default value reproduces the behavior of the plain scoring functions.
+#[derive(Debug, Clone, Default)]
+pub struct ScoringOptions {
+    /// Path to a stopwords TOML file used by keyword extraction.
+    ///
+    /// Expected format:
+    /// ```toml
+    /// # "extend" (default) adds to the built-in list, "replace" discards it
+    /// mode = "extend"
+    /// words = ["etc", "various"]
+    /// ```
+    pub stopwords_path: Option<PathBuf>,
+
+    /// Path to a synonyms TOML file used by match scoring.
+    ///
+    /// Each key is a canonical term and its value lists aliases that count as
+    /// a match for it. Multi-word terms and aliases are supported.
+    /// ```toml
+    /// [synonyms]
+    /// javascript = ["js", "node"]
+    /// "machine learning" = ["ml"]
+    /// ```
+    pub synonyms_path: Option<PathBuf>,
+
+    /// Must-have keywords for match scoring, in addition to those in the
+    /// weights file's `[match.requirements]` table.
+    ///
+    /// If any is missing from the resume, the match total is capped (see
+    /// [`MatchRequirements`]).
+    pub required_keywords: Vec<String>,
+
+    /// Tokenizer used by match keyword extraction. Defaults to
+    /// [`DefaultTokenizer`]; [`UnicodeTokenizer`](text::UnicodeTokenizer) handles accented and CJK
+    /// text better.
+    pub tokenizer: Option<Arc<dyn Tokenizer>>,
+
+    /// Abbreviations folded together with their expansions before match
+    /// keyword extraction, so "K8s" and "Kubernetes" count as the same term
+    /// and "Amazon Web Services" counts once, as `aws`.
+    ///
+    /// `None` uses [`default_abbreviations`]; pass an empty
+    /// [`SynonymMap`] to disable expansion, or build one from
+    /// [`DEFAULT_ABBREVIATIONS`] plus your own entries to extend it.
+    pub abbreviations: Option<SynonymMap>,
+
+    /// Named weight profile from the weights file's `[profiles]` table (see
+    /// [`load_scoring_weights_profile`]). `None` uses the top-level weights.
+    pub profile: Option<String>,
+
+    /// Month that open-ended ("Present") roles run until in the resume
+    /// `tenure` category. `None` uses the current month; set it to make
+    /// tenure scores reproducible.
+    pub today: Option<tenure::YearMonth>,
+}
+
+/// Default match total ceiling when a must-have keyword is missing.
+pub const DEFAULT_REQUIREMENT_CAP: f64 = 40.0;
+
+/// Knockout rules for match scoring, read from the weights file:
+/// ```toml
+/// [match.requirements]
+/// keywords = ["aws", "kubernetes"]
+/// cap = 40.0
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchRequirements {
+    /// Keywords (single words or phrases) the resume must mention.
+    pub keywords: Vec<String>,
+    /// Highest match total a resume missing any keyword can get.
+    pub cap: f64,
+}
+
+impl Default for MatchRequirements {
+    fn default() -> Self {
+        Self {
+            keywords: Vec::new(),
+            cap: DEFAULT_REQUIREMENT_CAP,
+        }
+    }
+}
+
+/// Default minimum description length, in words, for a job posting to count
+/// as having a real description. Roughly the old 200-byte cutoff for English
+/// text.
+pub const DEFAULT_MIN_DESCRIPTION_WORDS: usize = 30;
+
+/// How long a job description must be to count in job completeness, read
+/// from the weights file:
+/// ```toml
+/// [job.completeness]
+/// min_description_words = 30
+/// # or, counting characters instead (takes precedence):
+/// min_description_chars = 200
+/// ```
+///
+/// Words are Unicode words, so each CJK ideograph counts as one; characters
+/// are Unicode scalar values rather than bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DescriptionThreshold {
+    /// Minimum number of words.
+    Words(usize),
+    /// Minimum number of characters.
+    Chars(usize),
+}
+
+impl Default for DescriptionThreshold {
+    fn default() -> Self {
+        Self::Words(DEFAULT_MIN_DESCRIPTION_WORDS)
+    }
+}
+
+impl DescriptionThreshold {
+    /// Length of `description` in this threshold's unit.
+    #[must_use]
+    pub fn measure(self, description: &str) -> usize {
+        match self {
+            Self::Words(_) => description.unicode_words().count(),
+            Self::Chars(_) => description.chars().count(),
+        }
+    }
+
+    /// Whether `description` is long enough.
+    #[must_use]
+    pub fn is_met(self, description: &str) -> bool {
+        let (Self::Words(min) | Self::Chars(min)) = self;
+        self.measure(description) >= min
+    }
+}
+
+/// Alias to canonical term mapping used to expand keyword matches.
+///
+/// Matching is case-insensitive and operates on whole tokens, so an alias
+/// `js` matches `JS` in "JS/React" but not the `js` inside `json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SynonymMap {
+    /// (variant tokens, canonical tokens), longest variants first.
+    variants: Vec<(Vec<String>, Vec<String>)>,
+}
+
+impl SynonymMap {
+    /// Build a map from canonical terms and their aliases.
+    pub fn new<I, A>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (String, A)>,
+        A: IntoIterator<Item = String>,
+    {
+        let mut variants = Vec::new();
+        for (canonical, aliases) in entries {
+            let canonical_tokens = tokenize(&canonical);
+            if canonical_tokens.is_empty() {
+                continue;
+            }
+            for alias in aliases {
+                let alias_tokens = tokenize(&alias);
+                if !alias_tokens.is_empty() && alias_tokens != canonical_tokens {
+                    variants.push((alias_tokens, canonical_tokens.clone()));
+                }
+            }
+        }
+
+        // Longest variants first so multi-token aliases win over their parts;
+        // ties are broken lexically to keep rewriting deterministic.
+        variants.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+        variants.dedup_by(|a, b| a.0 == b.0);
+
+        Self { variants }
+    }
+
+    /// Returns `true` if no aliases are configured.
+    pub fn is_empty(&self) -> bool {
+        self.variants.is_empty()
+    }
+
+    /// Rewrite `text` so every alias is replaced by its canonical term.
+    ///
+    /// The result is lowercased and whitespace-joined, ready for keyword extraction.
+    pub fn canonicalize(&self, text: &str) -> String {
+        self.canonicalize_with(text, &DefaultTokenizer)
+    }
+
+    /// [`canonicalize`](Self::canonicalize) with a custom tokenizer.
+    pub fn canonicalize_with(&self, text: &str, tokenizer: &dyn Tokenizer) -> String {
+        let tokens = tokenizer.tokenize(text);
+        self.rewrite(&tokens, |_, _| {}).join(" ")
+    }
+
+    /// The aliases found in `text`, each paired with its canonical term.
+    ///
+    /// Both sides are lowercased and whitespace-joined. Pairs are listed in
+    /// order of first occurrence, without repeats.
+    pub fn expansions_with(&self, text: &str, tokenizer: &dyn Tokenizer) -> Vec<(String, String)> {
+        let tokens = tokenizer.tokenize(text);
+        let mut expansions = Vec::new();
+        self.rewrite(&tokens, |variant, canonical| {
+            let pair = (variant.join(" "), canonical.join(" "));
+            if !expansions.contains(&pair) {
+                expansions.push(pair);
+            }
+        });
+        expansions
+    }
+
+    /// This map with every multi-token canonical term replaced by one of its
+    /// single-token aliases, which the multi-token term then folds into too.
+    ///
+    /// `amazon web services = ["aws"]` becomes `aws` for both spellings, so
+    /// the term counts once in keyword overlap rather than as three words.
+    /// Terms without a single-token alias are left as they are.
+    fn folded(&self) -> Self {
+        let mut representatives: HashMap<&[String], &[String]> = HashMap::new();
+        for (variant, canonical) in &self.variants {
+            if canonical.len() > 1 && variant.len() == 1 {
+                let current = representatives
+                    .entry(canonical.as_slice())
+                    .or_insert(variant.as_slice());
+                if variant.as_slice() < *current {
+                    *current = variant.as_slice();
+                }
+            }
+        }
+
+        let mut variants: Vec<(Vec<String>, Vec<String>)> = Vec::new();
+        for (variant, canonical) in &self.variants {
+            match representatives.get(canonical.as_slice()) {
+                Some(rep) if variant.as_slice() == *rep => {}
+                Some(rep) => variants.push((variant.clone(), rep.to_vec())),
+                None => variants.push((variant.clone(), canonical.clone())),
+            }
+        }
+        for (canonical, rep) in representatives {
+            variants.push((canonical.to_vec(), rep.to_vec()));
+        }
+
+        variants.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+        variants.dedup_by(|a, b| a.0 == b.0);
+        Self { variants }
+    }
+
+    /// Every token a variant can be rewritten to.
+    fn canonical_tokens(&self) -> HashSet<String> {
+        self.variants
+            .iter()
+            .flat_map(|(_, canonical)| canonical.iter().cloned())
+            .collect()
+    }
+
+    /// Replace aliases in `tokens`, calling `on_match` for each replacement.
+    fn rewrite<'a>(
+        &'a self,
+        tokens: &'a [String],
+        mut on_match: impl FnMut(&'a [String], &'a [String]),
+    ) -> Vec<&'a str> {
+        let mut out: Vec<&str> = Vec::with_capacity(tokens.len());
+        let mut i = 0;
+
+        'outer: while i < tokens.len() {
+            for (variant, canonical) in &self.variants {
+                let end = i + variant.len();
+                if end <= tokens.len() && tokens[i..end] == variant[..] {
+                    on_match(variant, canonical);
+                    out.extend(canonical.iter().map(String::as_str));
+                    i = end;
+                    continue 'outer;
+                }
+            }
+            out.push(&tokens[i]);
+            i += 1;
+        }
+
+        out
+    }
+}
+
+/// Built-in abbreviations for common tech terms, as (expansion, abbreviations).
+///
+/// Used by match scoring unless [`ScoringOptions::abbreviations`] overrides it.
+pub const DEFAULT_ABBREVIATIONS: &[(&str, &[&str])] = &[
+    ("amazon web services", &["aws"]),
+    ("artificial intelligence", &["ai"]),
+    ("continuous delivery", &["cd"]),
+    ("continuous integration", &["ci"]),
+    ("google cloud platform", &["gcp"]),
+    ("javascript", &["js"]),
+    ("kubernetes", &["k8s"]),
+    ("large language model", &["llm", "llms"]),
+    ("machine learning", &["ml"]),
+    ("natural language processing", &["nlp"]),
+    ("object oriented programming", &["oop"]),
+    ("postgresql", &["postgres"]),
+    ("quality assurance", &["qa"]),
+    ("site reliability engineering", &["sre"]),
+    ("typescript", &["ts"]),
+    ("user experience", &["ux"]),
+    ("user interface", &["ui"]),
+];
+
+/// [`DEFAULT_ABBREVIATIONS`] as a [`SynonymMap`].
+#[must_use]
+pub fn default_abbreviations() -> SynonymMap {
+    SynonymMap::new(
+        DEFAULT_ABBREVIATIONS
+            .iter()
+            .map(|(expansion, abbreviations)| {
+                (
+                    (*expansion).to_string(),
+                    abbreviations.iter().map(|a| (*a).to_string()),
+                )
+            }),
+    )
 }
 
 // -------------------------
@@ -95,6 +556,8 @@ fn default_resume_weights() -> HashMap<String, f64> {
     weights.insert("skills_quality".to_string(), 0.20);
     weights.insert("experience_quality".to_string(), 0.30);
     weights.insert("impact".to_string(), 0.20);
+    weights.insert("length".to_string(), 0.0);
+    weights.insert("tenure".to_string(), 0.0);
     weights
 }
 
@@ -105,6 +568,7 @@ fn default_job_weights() -> HashMap<String, f64> {
     weights.insert("clarity".to_string(), 0.35);
     weights.insert("compensation_transparency".to_string(), 0.15);
     weights.insert("link_quality".to_string(), 0.15);
+    weights.insert("recency".to_string(), 0.0);
     weights
 }
 
@@ -114,6 +578,7 @@ fn default_match_weights() -> HashMap<String, f64> {
     weights.insert("keyword_overlap".to_string(), 0.45);
     weights.insert("skills_overlap".to_string(), 0.35);
     weights.insert("role_alignment".to_string(), 0.20);
+    weights.insert("seniority_alignment".to_string(), 0.0);
     weights
 }
 
@@ -129,6 +594,44 @@ fn default_overall_iteration_weights() -> HashMap<String, f64> {
 // Weight Loading & Normalization
 // -------------------------
 
+/// Modification time and size of a weights file when it was parsed.
+type FileStamp = (Option<SystemTime>, u64);
+
+/// A weights file's stamp and its parsed contents (`None` if it isn't valid
+/// TOML).
+type CachedDocument = (FileStamp, Option<Arc<toml::Value>>);
+
+/// Parsed weights files, keyed by path. An entry is reparsed once the file's
+/// modification time or size changes.
+static WEIGHTS_DOCUMENTS: LazyLock<Mutex<HashMap<PathBuf, CachedDocument>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The parsed weights file at `weights_path`, read at most once per change.
+///
+/// Returns `None` if no path is given or the file is missing or not valid
+/// TOML.
+fn weights_document(weights_path: Option<&str>) -> Option<Arc<toml::Value>> {
+    let path = Path::new(weights_path?);
+    let metadata = std::fs::metadata(path).ok()?;
+    let stamp = (metadata.modified().ok(), metadata.len());
+
+    let mut documents = WEIGHTS_DOCUMENTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some((cached, doc)) = documents.get(path) {
+        if *cached == stamp {
+            return doc.clone();
+        }
+    }
+
+    let doc = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| toml::from_str::<toml::Value>(&content).ok())
+        .map(Arc::new);
+    documents.insert(path.to_path_buf(), (stamp, doc.clone()));
+    doc
+}
+
 /// Load scoring weights from a TOML file.
 ///
 /// Expected format:
@@ -149,24 +652,52 @@ pub fn load_scoring_weights(weights_path: Option<&str>) -> HashMap<String, HashM
     all_weights.insert("job".to_string(), default_job_weights());
     all_weights.insert("match".to_string(), default_match_weights());
 
-    let Some(path) = weights_path else {
+    let Some(doc) = weights_document(weights_path) else {
         return all_weights;
     };
 
-    if !Path::new(path).exists() {
-        return all_weights;
-    }
+    apply_group_weights(&mut all_weights, doc.as_ref());
+    all_weights
+}
 
-    // Try to load and parse TOML
-    let Ok(content) = std::fs::read_to_string(path) else {
-        return all_weights;
-    };
+/// Load scoring weights from a named profile in a TOML file.
+///
+/// Profiles let one file hold weight sets for different kinds of roles:
+/// ```toml
+/// [profiles.tech.resume.weights]
+/// skills_quality = 0.40
+///
+/// [profiles.management.resume.weights]
+/// experience_quality = 0.45
+/// ```
+///
+/// Each profile group has the same shape as a top-level group. Weights a
+/// profile doesn't set fall back to the top-level weights, then to the
+/// defaults. An unknown profile logs a warning and yields the top-level
+/// weights.
+pub fn load_scoring_weights_profile(
+    weights_path: Option<&str>,
+    profile_name: &str,
+) -> HashMap<String, HashMap<String, f64>> {
+    let mut all_weights = load_scoring_weights(weights_path);
 
-    let Ok(doc) = toml::from_str::<toml::Value>(&content) else {
+    let Some(doc) = weights_document(weights_path) else {
         return all_weights;
     };
 
-    // Parse weights for each group
+    match doc.get("profiles").and_then(|v| v.get(profile_name)) {
+        Some(profile) => apply_group_weights(&mut all_weights, profile),
+        None => log::warn!(
+            "Scoring profile '{profile_name}' not found in {}; using top-level weights",
+            weights_path.unwrap_or_default()
+        ),
+    }
+
+    all_weights
+}
+
+/// Overlay the `[resume]`, `[job]` and `[match]` weights found in `doc`.
+fn apply_group_weights(all_weights: &mut HashMap<String, HashMap<String, f64>>, doc: &toml::Value) {
     for group in ["resume", "job", "match"] {
         if let Some(group_table) = doc.get(group).and_then(|v| v.as_table()) {
             // Try [group.weights] first, fallback to [group]
@@ -193,27 +724,71 @@ pub fn load_scoring_weights(weights_path: Option<&str>) -> HashMap<String, HashM
             }
         }
     }
-
-    all_weights
 }
 
-/// Load overall iteration weights from TOML file.
-pub fn load_overall_iteration_weights(weights_path: Option<&str>) -> HashMap<String, f64> {
-    let mut weights = default_overall_iteration_weights();
+/// Load the categories excluded from each scoring group.
+///
+/// Categories listed in a group's `exclude` array are dropped from that
+/// group's reports entirely, and the remaining weights are renormalized:
+/// ```toml
+/// [job]
+/// exclude = ["compensation_transparency"]
+/// ```
+pub fn load_excluded_categories(weights_path: Option<&str>) -> HashMap<String, HashSet<String>> {
+    let mut excluded = HashMap::new();
 
-    let Some(path) = weights_path else {
-        return weights;
+    let Some(doc) = weights_document(weights_path) else {
+        return excluded;
     };
 
-    if !Path::new(path).exists() {
-        return weights;
+    for group in ["resume", "job", "match"] {
+        let names: HashSet<String> = doc
+            .get(group)
+            .and_then(|v| v.get("exclude"))
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(|s| s.trim().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if !names.is_empty() {
+            excluded.insert(group.to_string(), names);
+        }
     }
 
-    let Ok(content) = std::fs::read_to_string(path) else {
-        return weights;
+    excluded
+}
+
+/// Normalized weights for a scoring group plus its excluded categories.
+///
+/// Excluded categories are removed before normalizing, so the remaining
+/// weights still sum to 1.
+fn group_weights(
+    group: &str,
+    weights_path: Option<&str>,
+    profile: Option<&str>,
+) -> (HashMap<String, f64>, HashSet<String>) {
+    let excluded = load_excluded_categories(weights_path)
+        .remove(group)
+        .unwrap_or_default();
+    let all_weights = match profile {
+        Some(name) => load_scoring_weights_profile(weights_path, name),
+        None => load_scoring_weights(weights_path),
     };
+    let mut weights = all_weights.get(group).cloned().unwrap_or_default();
+    weights.retain(|name, _| !excluded.contains(name));
 
-    let Ok(doc) = toml::from_str::<toml::Value>(&content) else {
+    (normalize_weights(&weights), excluded)
+}
+
+/// Load overall iteration weights from TOML file.
+pub fn load_overall_iteration_weights(weights_path: Option<&str>) -> HashMap<String, f64> {
+    let mut weights = default_overall_iteration_weights();
+
+    let Some(doc) = weights_document(weights_path) else {
         return weights;
     };
 
@@ -239,6 +814,210 @@ pub fn load_overall_iteration_weights(weights_path: Option<&str>) -> HashMap<Str
     weights
 }
 
+/// Load the stopword set used for keyword extraction.
+///
+/// Words from the file are trimmed and lowercased. With `mode = "replace"` the
+/// built-in list is discarded; otherwise the file's words extend it. Falls back
+/// to the built-in list if the path is missing or the file cannot be parsed.
+pub fn load_stopwords(stopwords_path: Option<&Path>) -> HashSet<String> {
+    let mut words = default_stopwords();
+
+    let Some(path) = stopwords_path else {
+        return words;
+    };
+
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return words;
+    };
+
+    let Ok(doc) = toml::from_str::<toml::Value>(&content) else {
+        return words;
+    };
+
+    let custom: HashSet<String> = doc
+        .get("words")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str())
+                .map(|s| s.trim().to_lowercase())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let replace = doc
+        .get("mode")
+        .and_then(|v| v.as_str())
+        .is_some_and(|m| m.trim().eq_ignore_ascii_case("replace"));
+
+    if replace {
+        words = custom;
+    } else {
+        words.extend(custom);
+    }
+
+    words
+}
+
+/// Load the filler phrases penalized by resume impact scoring.
+///
+/// Reads the `[resume.filler]` table of the weights file. With
+/// `mode = "replace"` the built-in list is discarded; otherwise the file's
+/// phrases extend it:
+/// ```toml
+/// [resume.filler]
+/// mode = "extend"
+/// phrases = ["rockstar", "ninja"]
+/// ```
+/// Falls back to the built-in list if the path is missing or the file cannot
+/// be parsed.
+pub fn load_filler_phrases(weights_path: Option<&str>) -> Vec<String> {
+    let mut phrases = default_filler_phrases();
+
+    let Some(doc) = weights_document(weights_path) else {
+        return phrases;
+    };
+
+    let Some(filler) = doc.get("resume").and_then(|v| v.get("filler")) else {
+        return phrases;
+    };
+
+    let custom: Vec<String> = filler
+        .get("phrases")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str())
+                .map(|s| s.trim().to_lowercase())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let replace = filler
+        .get("mode")
+        .and_then(|v| v.as_str())
+        .is_some_and(|m| m.trim().eq_ignore_ascii_case("replace"));
+
+    if replace {
+        phrases = custom;
+    } else {
+        phrases.extend(
+            custom
+                .into_iter()
+                .filter(|p| !phrases.contains(p))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    phrases
+}
+
+/// Load the must-have keywords and cap from the `[match.requirements]` table
+/// of the weights file.
+///
+/// Keywords are trimmed and lowercased. Returns no keywords and the default
+/// cap if the path is missing or the file cannot be parsed.
+pub fn load_match_requirements(weights_path: Option<&str>) -> MatchRequirements {
+    let mut requirements = MatchRequirements::default();
+
+    let Some(doc) = weights_document(weights_path) else {
+        return requirements;
+    };
+
+    let Some(table) = doc.get("match").and_then(|v| v.get("requirements")) else {
+        return requirements;
+    };
+
+    if let Some(keywords) = table.get("keywords").and_then(|v| v.as_array()) {
+        requirements.keywords = keywords
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(|s| s.trim().to_lowercase())
+            .filter(|s| !s.is_empty())
+            .collect();
+    }
+    if let Some(cap) = table
+        .get("cap")
+        .and_then(|v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+    {
+        requirements.cap = clamp(cap, 0.0, 100.0);
+    }
+
+    requirements
+}
+
+/// Load the job description length threshold from the `[job.completeness]`
+/// table of the weights file.
+///
+/// `min_description_chars` takes precedence over `min_description_words`.
+/// Returns [`DescriptionThreshold::default`] if neither is set or the file
+/// cannot be parsed.
+pub fn load_description_threshold(weights_path: Option<&str>) -> DescriptionThreshold {
+    let Some(doc) = weights_document(weights_path) else {
+        return DescriptionThreshold::default();
+    };
+
+    let Some(table) = doc.get("job").and_then(|v| v.get("completeness")) else {
+        return DescriptionThreshold::default();
+    };
+
+    let setting = |key: &str| {
+        table
+            .get(key)
+            .and_then(toml::Value::as_integer)
+            .and_then(|n| usize::try_from(n).ok())
+    };
+
+    if let Some(chars) = setting("min_description_chars") {
+        DescriptionThreshold::Chars(chars)
+    } else if let Some(words) = setting("min_description_words") {
+        DescriptionThreshold::Words(words)
+    } else {
+        DescriptionThreshold::default()
+    }
+}
+
+/// Load a synonym map from a TOML file.
+///
+/// Reads the `[synonyms]` table (or the top-level table if absent). Returns an
+/// empty map if the path is missing or the file cannot be parsed.
+pub fn load_synonyms(synonyms_path: Option<&Path>) -> SynonymMap {
+    let Some(path) = synonyms_path else {
+        return SynonymMap::default();
+    };
+
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return SynonymMap::default();
+    };
+
+    let Ok(doc) = toml::from_str::<toml::Value>(&content) else {
+        return SynonymMap::default();
+    };
+
+    let Some(table) = doc
+        .get("synonyms")
+        .and_then(|v| v.as_table())
+        .or_else(|| doc.as_table())
+    else {
+        return SynonymMap::default();
+    };
+
+    SynonymMap::new(table.iter().map(|(canonical, aliases)| {
+        let aliases: Vec<String> = aliases
+            .as_array()
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(ToString::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        (canonical.clone(), aliases)
+    }))
+}
+
 /// Normalize weights to sum to 1.0.
 ///
 /// Ignores non-positive weights. If all weights are <= 0, returns 0 for all.
@@ -251,7 +1030,11 @@ pub fn normalize_weights<S: std::hash::BuildHasher>(
         .map(|(k, &v)| (k.clone(), v))
         .collect();
 
-    let sum: f64 = positive.values().sum();
+    // Sum in a fixed order so the same weights always normalize to the
+    // same values, whatever the map's iteration order
+    let mut values: Vec<f64> = positive.values().copied().collect();
+    values.sort_by(f64::total_cmp);
+    let sum: f64 = values.iter().sum();
 
     if sum <= 0.0 {
         return weights.keys().map(|k| (k.clone(), 0.0)).collect();
@@ -312,19 +1095,32 @@ pub fn compute_iteration_score(
 ///
 /// Returns an error if the weights file cannot be loaded or parsed.
 pub fn score_resume(resume: &serde_json::Value, weights_path: Option<&str>) -> Result<ScoreReport> {
-    let all_weights = load_scoring_weights(weights_path);
-    let resume_weights = all_weights
-        .get("resume")
-        .cloned()
-        .unwrap_or_else(default_resume_weights);
-    let normalized = normalize_weights(&resume_weights);
+    score_resume_with_options(resume, weights_path, &ScoringOptions::default())
+}
+
+/// Score a resume with custom options.
+///
+/// Only [`ScoringOptions::profile`] and [`ScoringOptions::today`] affect
+/// resume scoring.
+///
+/// # Errors
+///
+/// Returns an error if the weights file cannot be loaded or parsed.
+pub fn score_resume_with_options(
+    resume: &serde_json::Value,
+    weights_path: Option<&str>,
+    options: &ScoringOptions,
+) -> Result<ScoreReport> {
+    let (normalized, excluded) = group_weights("resume", weights_path, options.profile.as_deref());
 
     let (completeness_score, completeness_details) = score_resume_completeness(resume);
     let (skills_score, skills_details) = score_resume_skills_quality(resume);
     let (exp_score, exp_details) = score_resume_experience_quality(resume);
-    let (impact_score, impact_details) = score_resume_impact(resume);
+    let filler_phrases = load_filler_phrases(weights_path);
+    let (impact_score, impact_details) = score_resume_impact(resume, &filler_phrases);
+    let (length_score, length_details) = score_resume_length(resume);
 
-    let categories = vec![
+    let mut categories = vec![
         ScoreCategoryResult {
             name: "completeness".to_string(),
             score: completeness_score,
@@ -349,7 +1145,33 @@ pub fn score_resume(resume: &serde_json::Value, weights_path: Option<&str>) -> R
             weight: *normalized.get("impact").unwrap_or(&0.0),
             details: impact_details,
         },
+        ScoreCategoryResult {
+            name: "length".to_string(),
+            score: length_score,
+            weight: *normalized.get("length").unwrap_or(&0.0),
+            details: length_details,
+        },
     ];
+    let today = options.today.unwrap_or_else(tenure::YearMonth::current);
+    if let Some((tenure_score, tenure_details)) = score_resume_tenure(resume, today) {
+        categories.push(ScoreCategoryResult {
+            name: "tenure".to_string(),
+            score: tenure_score,
+            weight: *normalized.get("tenure").unwrap_or(&0.0),
+            details: tenure_details,
+        });
+    }
+    // Spelling is heuristic, so it only runs when the weights file asks for it
+    if let Some(&weight) = normalized.get("readability").filter(|w| **w > 0.0) {
+        let (readability_score, readability_details) = score_resume_readability(resume);
+        categories.push(ScoreCategoryResult {
+            name: "readability".to_string(),
+            score: readability_score,
+            weight,
+            details: readability_details,
+        });
+    }
+    categories.retain(|c| !excluded.contains(&c.name));
 
     let total = weighted_total(&categories);
 
@@ -369,6 +1191,100 @@ pub fn score_resume(resume: &serde_json::Value, weights_path: Option<&str>) -> R
     })
 }
 
+/// Readability points lost per percentage point of unrecognized words.
+const MISSPELLING_PENALTY_PER_PERCENT: f64 = 10.0;
+
+/// Misspellings listed in readability details.
+const MAX_REPORTED_MISSPELLINGS: usize = 10;
+
+/// Readability score from the share of unrecognized words in the resume's
+/// prose.
+///
+/// Skills (and each word in them) are allowlisted so tool names are never
+/// flagged.
+fn score_resume_readability(
+    resume: &serde_json::Value,
+) -> (f64, HashMap<String, serde_json::Value>) {
+    let allowlist: HashSet<String> = resume_skills(resume)
+        .iter()
+        .flat_map(|skill| {
+            let skill = skill.to_lowercase();
+            let words: Vec<String> = skill.split_whitespace().map(str::to_string).collect();
+            std::iter::once(skill).chain(words)
+        })
+        .collect();
+
+    let text = resume_prose(resume);
+    let word_count = spelling::word_count(&text);
+    let found = spelling::find_misspellings(&text, &allowlist);
+
+    let ratio = if word_count == 0 {
+        0.0
+    } else {
+        found.len() as f64 / word_count as f64
+    };
+    let score = 100.0 - ratio * 100.0 * MISSPELLING_PENALTY_PER_PERCENT;
+
+    let mut reported: Vec<&spelling::Misspelling> = Vec::new();
+    for misspelling in &found {
+        if reported.len() == MAX_REPORTED_MISSPELLINGS {
+            break;
+        }
+        if !reported
+            .iter()
+            .any(|m| m.word.eq_ignore_ascii_case(&misspelling.word))
+        {
+            reported.push(misspelling);
+        }
+    }
+
+    let mut details = HashMap::new();
+    details.insert("word_count".to_string(), serde_json::json!(word_count));
+    details.insert(
+        "misspelled_count".to_string(),
+        serde_json::json!(found.len()),
+    );
+    details.insert("misspelling_ratio".to_string(), serde_json::json!(ratio));
+    details.insert("misspellings".to_string(), serde_json::json!(reported));
+
+    (clamp(score, 0.0, 100.0), details)
+}
+
+/// Sentences written by the candidate, one field per line: summary, headline,
+/// job titles, bullets, degrees and project descriptions.
+///
+/// Names, employers, schools, locations and links are left out; they are
+/// proper nouns a word list can't check.
+fn resume_prose(resume: &serde_json::Value) -> String {
+    let entries = |section: &str| {
+        resume
+            .get(section)
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+    };
+
+    let mut parts = vec![
+        safe_str(resume.get("personal_info").and_then(|p| p.get("headline"))),
+        safe_str(resume.get("summary")),
+    ];
+    for entry in entries("experience") {
+        parts.push(safe_str(entry.get("title")));
+        parts.extend(extract_bullets(entry));
+    }
+    parts.extend(entries("education").map(|entry| safe_str(entry.get("degree"))));
+    parts.extend(entries("projects").map(|entry| safe_str(entry.get("description"))));
+
+    parts
+        .into_iter()
+        .filter(|p| !p.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Points added to resume completeness for a linkedin.com or github.com profile URL.
+const PROFILE_LINK_BONUS: f64 = 5.0;
+
 fn score_resume_completeness(
     resume: &serde_json::Value,
 ) -> (f64, HashMap<String, serde_json::Value>) {
@@ -382,24 +1298,40 @@ fn score_resume_completeness(
         .get("education")
         .and_then(|v| v.as_array())
         .map_or(0, std::vec::Vec::len);
-    let skills = resume
-        .get("skills")
-        .and_then(|v| v.as_array())
-        .map_or(0, std::vec::Vec::len);
+    let skills = resume_skills(resume).len();
     let projects = resume
         .get("projects")
         .and_then(|v| v.as_array())
         .map_or(0, std::vec::Vec::len);
 
-    let has_name = personal
-        .and_then(|p| p.get("name"))
-        .and_then(|v| v.as_str())
-        .is_some_and(|s| !s.trim().is_empty());
+    let personal_field = |key: &str| {
+        personal
+            .and_then(|p| p.get(key))
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    };
 
-    let has_email = personal
-        .and_then(|p| p.get("email"))
-        .and_then(|v| v.as_str())
-        .is_some_and(|s| !s.trim().is_empty());
+    let has_name = personal_field("name").is_some();
+
+    // Contact details only count when they are usable, not merely present
+    let email = personal_field("email");
+    let has_email = email.is_some();
+    let valid_email = email.is_some_and(is_valid_email);
+
+    let phone = personal_field("phone");
+    let has_phone = phone.is_some();
+    let valid_phone = phone.is_some_and(is_valid_phone);
+
+    let has_profile_link = personal.is_some_and(|p| {
+        p.values()
+            .flat_map(|v| match v {
+                serde_json::Value::Array(items) => items.iter().collect(),
+                other => vec![other],
+            })
+            .filter_map(|v| v.as_str())
+            .any(contains_profile_url)
+    });
 
     let has_summary = !summary.trim().is_empty();
     let has_experience = exp > 0;
@@ -409,8 +1341,9 @@ fn score_resume_completeness(
 
     // Weighted checklist
     let weights = [
-        (has_name, 0.10),
-        (has_email, 0.10),
+        (has_name, 0.05),
+        (valid_email, 0.10),
+        (valid_phone, 0.05),
         (has_summary, 0.15),
         (has_experience, 0.25),
         (has_education, 0.15),
@@ -418,15 +1351,25 @@ fn score_resume_completeness(
         (has_projects, 0.05),
     ];
 
-    let score = weights
+    let mut score = weights
         .iter()
         .map(|(check, weight)| if *check { weight } else { &0.0 })
         .sum::<f64>()
         * 100.0;
+    if has_profile_link {
+        score += PROFILE_LINK_BONUS;
+    }
 
     let mut details = HashMap::new();
     details.insert("has_name".to_string(), serde_json::json!(has_name));
     details.insert("has_email".to_string(), serde_json::json!(has_email));
+    details.insert("valid_email".to_string(), serde_json::json!(valid_email));
+    details.insert("has_phone".to_string(), serde_json::json!(has_phone));
+    details.insert("valid_phone".to_string(), serde_json::json!(valid_phone));
+    details.insert(
+        "has_profile_link".to_string(),
+        serde_json::json!(has_profile_link),
+    );
     details.insert("has_summary".to_string(), serde_json::json!(has_summary));
     details.insert(
         "has_experience".to_string(),
@@ -446,29 +1389,26 @@ fn score_resume_completeness(
     (clamp(score, 0.0, 100.0), details)
 }
 
+/// Distinct skills at which the skills count score saturates.
+pub const TARGET_SKILL_COUNT: usize = 12;
+
+/// Skill entries longer than this many characters read like sentences and
+/// are penalized.
+pub const MAX_SKILL_CHARS: usize = 32;
+
 fn score_resume_skills_quality(
     resume: &serde_json::Value,
 ) -> (f64, HashMap<String, serde_json::Value>) {
-    let skills = resume
-        .get("skills")
-        .and_then(|v| v.as_array())
-        .map(|arr| {
-            arr.iter()
-                .filter_map(|v| v.as_str())
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect::<Vec<_>>()
-        })
-        .unwrap_or_default();
+    let skills = resume_skills(resume);
 
     let unique: HashSet<String> = skills.iter().map(|s| s.to_lowercase()).collect();
     let count = unique.len();
 
-    // Heuristics: saturates at 12 skills
-    let count_score = 100.0 * (count as f64 / 12.0).min(1.0);
+    // Heuristics: saturates at TARGET_SKILL_COUNT skills
+    let count_score = 100.0 * (count as f64 / TARGET_SKILL_COUNT as f64).min(1.0);
 
     // Penalize overly long skill strings (likely sentences, not skills)
-    let too_long = skills.iter().filter(|s| s.len() > 32).count();
+    let too_long = skills.iter().filter(|s| s.len() > MAX_SKILL_CHARS).count();
     let long_penalty = (too_long as f64 * 7.5).min(30.0);
 
     let score = count_score - long_penalty;
@@ -480,6 +1420,21 @@ fn score_resume_skills_quality(
     (clamp(score, 0.0, 100.0), details)
 }
 
+/// Bullets without an action verb listed in experience quality details.
+const MAX_WEAK_BULLETS: usize = 3;
+
+/// Bullet count at which the experience volume points saturate.
+pub const TARGET_BULLET_COUNT: u32 = 10;
+
+/// Experience quality points for bullet volume.
+pub const BULLET_VOLUME_POINTS: f64 = 35.0;
+
+/// Experience quality points for the share of bullets opening with an action verb.
+pub const ACTION_VERB_POINTS: f64 = 35.0;
+
+/// Experience quality points for the share of bullets containing a number.
+pub const QUANTIFIED_POINTS: f64 = 30.0;
+
 fn score_resume_experience_quality(
     resume: &serde_json::Value,
 ) -> (f64, HashMap<String, serde_json::Value>) {
@@ -501,13 +1456,16 @@ fn score_resume_experience_quality(
     let mut total_bullets = 0;
     let mut action_bullets = 0;
     let mut quantified_bullets = 0;
+    let mut weak_bullets = Vec::new();
 
     for entry in exp {
-        let bullets = extract_bullets(entry.get("description"));
+        let bullets = extract_bullets(entry);
         for bullet in bullets {
             total_bullets += 1;
             if looks_like_action_bullet(&bullet) {
                 action_bullets += 1;
+            } else if weak_bullets.len() < MAX_WEAK_BULLETS {
+                weak_bullets.push(bullet.trim().to_string());
             }
             if contains_number(&bullet) {
                 quantified_bullets += 1;
@@ -524,12 +1482,14 @@ fn score_resume_experience_quality(
         return (15.0, details);
     }
 
-    // Scoring: bullet volume (saturates at 10), action verb ratio, quantified ratio
-    let vol = (f64::from(total_bullets) / 10.0).min(1.0) * 35.0;
+    // Scoring: bullet volume (saturates at TARGET_BULLET_COUNT), action verb
+    // ratio, quantified ratio
+    let vol =
+        (f64::from(total_bullets) / f64::from(TARGET_BULLET_COUNT)).min(1.0) * BULLET_VOLUME_POINTS;
     let action_ratio = f64::from(action_bullets) / f64::from(total_bullets);
-    let action = action_ratio * 35.0;
+    let action = action_ratio * ACTION_VERB_POINTS;
     let quant_ratio = f64::from(quantified_bullets) / f64::from(total_bullets);
-    let quant = quant_ratio * 30.0;
+    let quant = quant_ratio * QUANTIFIED_POINTS;
 
     let score = vol + action + quant;
 
@@ -551,11 +1511,52 @@ fn score_resume_experience_quality(
         "quantified_ratio".to_string(),
         serde_json::json!(quant_ratio),
     );
+    details.insert("weak_bullets".to_string(), serde_json::json!(weak_bullets));
 
     (clamp(score, 0.0, 100.0), details)
 }
 
-fn score_resume_impact(resume: &serde_json::Value) -> (f64, HashMap<String, serde_json::Value>) {
+/// Points deducted from the impact score per filler phrase occurrence.
+const FILLER_PENALTY_PER_PHRASE: f64 = 8.0;
+
+/// Maximum total filler penalty.
+const MAX_FILLER_PENALTY: f64 = 40.0;
+
+/// Impact score with a penalty for filler phrases in the summary and bullets.
+fn score_resume_impact(
+    resume: &serde_json::Value,
+    filler_phrases: &[String],
+) -> (f64, HashMap<String, serde_json::Value>) {
+    let (score, mut details) = score_resume_impact_base(resume);
+
+    let mut text = vec![safe_str(resume.get("summary"))];
+    if let Some(exp) = resume.get("experience").and_then(|v| v.as_array()) {
+        for entry in exp {
+            text.extend(extract_bullets(entry));
+        }
+    }
+
+    let found: Vec<String> = text
+        .iter()
+        .flat_map(|t| find_filler_phrases(t, filler_phrases))
+        .collect();
+    let penalty = (found.len() as f64 * FILLER_PENALTY_PER_PHRASE).min(MAX_FILLER_PENALTY);
+
+    let mut detected: Vec<&String> = Vec::new();
+    for phrase in &found {
+        if !detected.contains(&phrase) {
+            detected.push(phrase);
+        }
+    }
+    details.insert("filler_phrases".to_string(), serde_json::json!(detected));
+    details.insert("filler_penalty".to_string(), serde_json::json!(penalty));
+
+    (clamp(score - penalty, 0.0, 100.0), details)
+}
+
+fn score_resume_impact_base(
+    resume: &serde_json::Value,
+) -> (f64, HashMap<String, serde_json::Value>) {
     let empty_vec = vec![];
     let exp = resume
         .get("experience")
@@ -573,7 +1574,7 @@ fn score_resume_impact(resume: &serde_json::Value) -> (f64, HashMap<String, serd
 
     let mut bullets = Vec::new();
     for entry in exp {
-        bullets.extend(extract_bullets(entry.get("description")));
+        bullets.extend(extract_bullets(entry));
     }
 
     if bullets.is_empty() {
@@ -619,6 +1620,105 @@ fn score_resume_impact(resume: &serde_json::Value) -> (f64, HashMap<String, serd
     (clamp(score, 0.0, 100.0), details)
 }
 
+/// Fewest summary and experience words that earn a full length score.
+const IDEAL_MIN_WORDS: usize = 400;
+
+/// Most summary and experience words that earn a full length score.
+const IDEAL_MAX_WORDS: usize = 800;
+
+/// Length score from the word count of the summary and experience bullets.
+///
+/// Counts between [`IDEAL_MIN_WORDS`] and [`IDEAL_MAX_WORDS`] score 100.
+/// Shorter resumes scale down linearly; longer ones decay in proportion to
+/// the excess (twice the maximum scores 50).
+fn score_resume_length(resume: &serde_json::Value) -> (f64, HashMap<String, serde_json::Value>) {
+    let mut text = vec![safe_str(resume.get("summary"))];
+    if let Some(exp) = resume.get("experience").and_then(|v| v.as_array()) {
+        for entry in exp {
+            text.extend(extract_bullets(entry));
+        }
+    }
+    let words: usize = text.iter().map(|t| t.split_whitespace().count()).sum();
+
+    let (score, band) = if words < IDEAL_MIN_WORDS {
+        (100.0 * words as f64 / IDEAL_MIN_WORDS as f64, "too_short")
+    } else if words <= IDEAL_MAX_WORDS {
+        (100.0, "ideal")
+    } else {
+        (100.0 * IDEAL_MAX_WORDS as f64 / words as f64, "too_long")
+    };
+
+    let mut details = HashMap::new();
+    details.insert("word_count".to_string(), serde_json::json!(words));
+    details.insert("band".to_string(), serde_json::json!(band));
+    details.insert(
+        "ideal_range".to_string(),
+        serde_json::json!([IDEAL_MIN_WORDS, IDEAL_MAX_WORDS]),
+    );
+
+    (clamp(score, 0.0, 100.0), details)
+}
+
+/// Months of total experience that earn the full experience share of the
+/// tenure score.
+const TENURE_FULL_MONTHS: f64 = 60.0;
+
+/// Average months per role that earn the full stability share of the tenure
+/// score.
+const TENURE_STABLE_MONTHS: f64 = 24.0;
+
+/// Points deducted from the tenure score per employment gap.
+const TENURE_GAP_PENALTY: f64 = 10.0;
+
+/// Tenure score: up to 60 points for total experience (full at five years)
+/// and 40 for average time per role (full at two years), less a penalty per
+/// gap of [`tenure::DEFAULT_GAP_MONTHS`] or more. `None` when no experience
+/// entry has parseable dates.
+fn score_resume_tenure(
+    resume: &serde_json::Value,
+    today: tenure::YearMonth,
+) -> Option<(f64, HashMap<String, serde_json::Value>)> {
+    let tenure = tenure::resume_tenure(resume, today, tenure::DEFAULT_GAP_MONTHS);
+    if tenure.ranges.is_empty() {
+        return None;
+    }
+
+    let total = f64::from(tenure.total_months);
+    let average = tenure.average_months();
+    let score = 60.0 * (total / TENURE_FULL_MONTHS).min(1.0)
+        + 40.0 * (average / TENURE_STABLE_MONTHS).min(1.0)
+        - TENURE_GAP_PENALTY * tenure.gaps.len() as f64;
+
+    let gaps: Vec<serde_json::Value> = tenure
+        .gaps
+        .iter()
+        .map(|gap| {
+            serde_json::json!({
+                "after": gap.after.to_string(),
+                "before": gap.before.to_string(),
+                "months": gap.months,
+            })
+        })
+        .collect();
+
+    let mut details = HashMap::new();
+    details.insert(
+        "total_months".to_string(),
+        serde_json::json!(tenure.total_months),
+    );
+    details.insert(
+        "dated_roles".to_string(),
+        serde_json::json!(tenure.ranges.len()),
+    );
+    details.insert(
+        "average_tenure_months".to_string(),
+        serde_json::json!((average * 10.0).round() / 10.0),
+    );
+    details.insert("gaps".to_string(), serde_json::json!(gaps));
+
+    Some((clamp(score, 0.0, 100.0), details))
+}
+
 // -------------------------
 // Job Scoring
 // -------------------------
@@ -629,19 +1729,31 @@ fn score_resume_impact(resume: &serde_json::Value) -> (f64, HashMap<String, serd
 ///
 /// Returns an error if the weights file cannot be loaded or parsed.
 pub fn score_job(job: &serde_json::Value, weights_path: Option<&str>) -> Result<ScoreReport> {
-    let all_weights = load_scoring_weights(weights_path);
-    let job_weights = all_weights
-        .get("job")
-        .cloned()
-        .unwrap_or_else(default_job_weights);
-    let normalized = normalize_weights(&job_weights);
+    score_job_with_options(job, weights_path, &ScoringOptions::default())
+}
+
+/// Score a job posting with custom options.
+///
+/// Only [`ScoringOptions::profile`] affects job scoring.
+///
+/// # Errors
+///
+/// Returns an error if the weights file cannot be loaded or parsed.
+pub fn score_job_with_options(
+    job: &serde_json::Value,
+    weights_path: Option<&str>,
+    options: &ScoringOptions,
+) -> Result<ScoreReport> {
+    let (normalized, excluded) = group_weights("job", weights_path, options.profile.as_deref());
 
-    let (completeness_score, completeness_details) = score_job_completeness(job);
+    let (completeness_score, completeness_details) =
+        score_job_completeness(job, load_description_threshold(weights_path));
     let (clarity_score, clarity_details) = score_job_clarity(job);
     let (comp_score, comp_details) = score_job_compensation(job);
     let (link_score, link_details) = score_job_link_quality(job);
+    let (recency_score, recency_details) = score_job_recency(job, chrono::Utc::now());
 
-    let categories = vec![
+    let mut categories = vec![
         ScoreCategoryResult {
             name: "completeness".to_string(),
             score: completeness_score,
@@ -666,7 +1778,14 @@ pub fn score_job(job: &serde_json::Value, weights_path: Option<&str>) -> Result<
             weight: *normalized.get("link_quality").unwrap_or(&0.0),
             details: link_details,
         },
+        ScoreCategoryResult {
+            name: "recency".to_string(),
+            score: recency_score,
+            weight: *normalized.get("recency").unwrap_or(&0.0),
+            details: recency_details,
+        },
     ];
+    categories.retain(|c| !excluded.contains(&c.name));
 
     let total = weighted_total(&categories);
 
@@ -686,7 +1805,10 @@ pub fn score_job(job: &serde_json::Value, weights_path: Option<&str>) -> Result<
     })
 }
 
-fn score_job_completeness(job: &serde_json::Value) -> (f64, HashMap<String, serde_json::Value>) {
+fn score_job_completeness(
+    job: &serde_json::Value,
+    threshold: DescriptionThreshold,
+) -> (f64, HashMap<String, serde_json::Value>) {
     let title = safe_str(job.get("title")).trim().to_string();
     let company = safe_str(job.get("company")).trim().to_string();
     let location = safe_str(job.get("location")).trim().to_string();
@@ -696,7 +1818,7 @@ fn score_job_completeness(job: &serde_json::Value) -> (f64, HashMap<String, serd
     let has_title = !title.is_empty() && title.to_lowercase() != "unknown";
     let has_company = !company.is_empty() && company.to_lowercase() != "unknown";
     let has_location = !location.is_empty() && location.to_lowercase() != "unknown";
-    let has_description = description.len() >= 200; // Signal of real posting
+    let has_description = threshold.is_met(&description); // Signal of real posting
     let has_url = !url.is_empty();
 
     let weights = [
@@ -726,6 +1848,10 @@ fn score_job_completeness(job: &serde_json::Value) -> (f64, HashMap<String, serd
         "description_length".to_string(),
         serde_json::json!(description.len()),
     );
+    details.insert(
+        "description_words".to_string(),
+        serde_json::json!(description.unicode_words().count()),
+    );
 
     (clamp(score, 0.0, 100.0), details)
 }
@@ -777,6 +1903,15 @@ fn score_job_clarity(job: &serde_json::Value) -> (f64, HashMap<String, serde_jso
     (clamp(score, 0.0, 100.0), details)
 }
 
+/// Compensation score for a posting with a concrete salary range.
+const SALARY_RANGE_SCORE: f64 = 100.0;
+
+/// Compensation score for a posting quoting a single salary figure.
+const SALARY_FIGURE_SCORE: f64 = 70.0;
+
+/// Compensation score for salary text without figures ("Competitive").
+const SALARY_VAGUE_SCORE: f64 = 20.0;
+
 fn score_job_compensation(job: &serde_json::Value) -> (f64, HashMap<String, serde_json::Value>) {
     let salary = job
         .get("salary")
@@ -789,11 +1924,26 @@ fn score_job_compensation(job: &serde_json::Value) -> (f64, HashMap<String, serd
     let mut details = HashMap::new();
     details.insert("has_salary".to_string(), serde_json::json!(has_salary));
 
-    if has_salary {
-        (100.0, details)
-    } else {
-        (0.0, details)
+    if !has_salary {
+        return (0.0, details);
     }
+
+    let Some(range) = crate::scraper::parse_salary(salary) else {
+        details.insert("reason".to_string(), serde_json::json!("no_salary_figures"));
+        return (SALARY_VAGUE_SCORE, details);
+    };
+
+    let score = if range.is_range() {
+        SALARY_RANGE_SCORE
+    } else {
+        SALARY_FIGURE_SCORE
+    };
+    details.insert(
+        "salary_range".to_string(),
+        serde_json::to_value(&range).unwrap_or_default(),
+    );
+
+    (score, details)
 }
 
 fn score_job_link_quality(job: &serde_json::Value) -> (f64, HashMap<String, serde_json::Value>) {
@@ -818,6 +1968,100 @@ fn score_job_link_quality(job: &serde_json::Value) -> (f64, HashMap<String, serd
     }
 }
 
+/// Postings at most this many days old get full recency score.
+const RECENCY_FULL_SCORE_DAYS: f64 = 7.0;
+
+/// Postings at least this many days old get zero recency score.
+const RECENCY_ZERO_SCORE_DAYS: f64 = 60.0;
+
+fn score_job_recency(
+    job: &serde_json::Value,
+    now: chrono::DateTime<chrono::Utc>,
+) -> (f64, HashMap<String, serde_json::Value>) {
+    let raw = ["posted_at", "date_posted", "posted_date"]
+        .iter()
+        .map(|key| safe_str(job.get(*key)).trim().to_string())
+        .find(|s| !s.is_empty());
+
+    let mut details = HashMap::new();
+
+    let Some(raw) = raw else {
+        details.insert(
+            "reason".to_string(),
+            serde_json::json!("missing_posting_date"),
+        );
+        return (50.0, details);
+    };
+
+    details.insert("posted_at".to_string(), serde_json::json!(raw));
+
+    let Some(age_days) = parse_posting_age_days(&raw, now) else {
+        details.insert(
+            "reason".to_string(),
+            serde_json::json!("unparseable_posting_date"),
+        );
+        return (50.0, details);
+    };
+
+    // Full score for fresh postings, then linear decay to zero
+    let score = if age_days <= RECENCY_FULL_SCORE_DAYS {
+        100.0
+    } else {
+        100.0 * (RECENCY_ZERO_SCORE_DAYS - age_days)
+            / (RECENCY_ZERO_SCORE_DAYS - RECENCY_FULL_SCORE_DAYS)
+    };
+
+    details.insert("age_days".to_string(), serde_json::json!(age_days));
+
+    (clamp(score, 0.0, 100.0), details)
+}
+
+/// Parse a posting date into its age in days relative to `now`.
+///
+/// Accepts RFC 3339 timestamps, `YYYY-MM-DD` dates, and relative phrases such
+/// as "today", "yesterday", "3 days ago" or "30+ days ago". Future dates are
+/// treated as posted now.
+pub(crate) fn parse_posting_age_days(raw: &str, now: chrono::DateTime<chrono::Utc>) -> Option<f64> {
+    let text = raw.trim().to_lowercase();
+
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(&text) {
+        let age = now.signed_duration_since(dt.with_timezone(&chrono::Utc));
+        return Some((age.num_seconds() as f64 / 86_400.0).max(0.0));
+    }
+
+    // Date-only, or a datetime without timezone (take the date part)
+    let date_part = text.get(..10).unwrap_or(&text);
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(date_part, "%Y-%m-%d") {
+        let age = now.date_naive().signed_duration_since(date);
+        return Some((age.num_days() as f64).max(0.0));
+    }
+
+    match text.as_str() {
+        "just now" | "today" | "just posted" | "new" => return Some(0.0),
+        "yesterday" => return Some(1.0),
+        _ => {}
+    }
+
+    // "<n>[+] <unit>[s] ago"
+    let rest = text.strip_suffix("ago")?.trim();
+    let mut parts = rest.split_whitespace();
+    let amount: f64 = parts.next()?.trim_end_matches('+').parse().ok()?;
+    let unit = parts.next()?.trim_end_matches('s');
+
+    let days_per_unit = match unit {
+        "second" | "sec" => 1.0 / 86_400.0,
+        "minute" | "min" => 1.0 / 1_440.0,
+        "hour" | "hr" => 1.0 / 24.0,
+        "day" => 1.0,
+        "week" => 7.0,
+        "month" => 30.0,
+        "year" => 365.0,
+        _ => return None,
+    };
+
+    Some(amount * days_per_unit)
+}
+
 // -------------------------
 // Match Scoring
 // -------------------------
@@ -832,18 +2076,28 @@ pub fn score_match(
     job: &serde_json::Value,
     weights_path: Option<&str>,
 ) -> Result<ScoreReport> {
-    let all_weights = load_scoring_weights(weights_path);
-    let match_weights = all_weights
-        .get("match")
-        .cloned()
-        .unwrap_or_else(default_match_weights);
-    let normalized = normalize_weights(&match_weights);
+    score_match_with_options(resume, job, weights_path, &ScoringOptions::default())
+}
 
-    let (keyword_score, keyword_details) = score_match_keyword_overlap(resume, job);
-    let (skills_score, skills_details) = score_match_skills_overlap(resume, job);
-    let (role_score, role_details) = score_match_role_alignment(resume, job);
+/// Score the match between a resume and a job posting with custom options.
+///
+/// # Errors
+///
+/// Returns an error if the weights file cannot be loaded or parsed.
+pub fn score_match_with_options(
+    resume: &serde_json::Value,
+    job: &serde_json::Value,
+    weights_path: Option<&str>,
+    options: &ScoringOptions,
+) -> Result<ScoreReport> {
+    let keywords = KeywordContext::from_options(options);
+    let (normalized, excluded) = group_weights("match", weights_path, options.profile.as_deref());
 
-    let categories = vec![
+    let (keyword_score, keyword_details) = score_match_keyword_overlap(resume, job, &keywords);
+    let (skills_score, skills_details) = score_match_skills_overlap(resume, job, &keywords);
+    let (role_score, role_details) = score_match_role_alignment(resume, job, &keywords);
+
+    let mut categories = vec![
         ScoreCategoryResult {
             name: "keyword_overlap".to_string(),
             score: keyword_score,
@@ -863,10 +2117,173 @@ pub fn score_match(
             details: role_details,
         },
     ];
-
-    let total = weighted_total(&categories);
+    if let Some((seniority_score, seniority_details)) = score_match_seniority_alignment(resume, job)
+    {
+        categories.push(ScoreCategoryResult {
+            name: "seniority_alignment".to_string(),
+            score: seniority_score,
+            weight: *normalized.get("seniority_alignment").unwrap_or(&0.0),
+            details: seniority_details,
+        });
+    }
+    categories.retain(|c| !excluded.contains(&c.name));
 
     let mut meta = HashMap::new();
+    let total = capped_match_total(
+        resume,
+        weights_path,
+        options,
+        &keywords,
+        &mut categories,
+        &mut meta,
+    );
+
+    Ok(ScoreReport {
+        kind: "match".to_string(),
+        total,
+        categories,
+        meta,
+    })
+}
+
+/// Score one resume against several job descriptions at once.
+///
+/// Keyword overlap is computed over the union of the jobs' keywords, each
+/// weighted by how many of the jobs mention it, so terms shared by every
+/// target role matter most. Skills overlap, role alignment and seniority
+/// alignment are averaged over the jobs. Must-have requirements cap the
+/// total as in [`score_match`].
+///
+/// # Errors
+///
+/// Returns an error if `jobs` is empty or the weights file cannot be loaded.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::score_match_multi;
+/// use serde_json::json;
+///
+/// let resume = json!({"summary": "Rust engineer", "skills": ["Rust", "Kubernetes"]});
+/// let jobs = [
+///     json!({"title": "Backend Engineer", "description": "Rust and Kubernetes services"}),
+///     json!({"title": "Platform Engineer", "description": "Rust tooling and Terraform"}),
+/// ];
+///
+/// let report = score_match_multi(&resume, &jobs, None)?;
+/// assert_eq!(report.meta["job_count"], 2);
+/// # Ok::<(), ats_checker::error::AtsError>(())
+/// ```
+pub fn score_match_multi(
+    resume: &serde_json::Value,
+    jobs: &[serde_json::Value],
+    weights_path: Option<&str>,
+) -> Result<ScoreReport> {
+    score_match_multi_with_options(resume, jobs, weights_path, &ScoringOptions::default())
+}
+
+/// Score one resume against several job descriptions with custom options.
+/// See [`score_match_multi`].
+///
+/// # Errors
+///
+/// Returns an error if `jobs` is empty or the weights file cannot be loaded.
+pub fn score_match_multi_with_options(
+    resume: &serde_json::Value,
+    jobs: &[serde_json::Value],
+    weights_path: Option<&str>,
+    options: &ScoringOptions,
+) -> Result<ScoreReport> {
+    if jobs.is_empty() {
+        return Err(crate::error::AtsError::InputValidation {
+            message: "At least one job description is required".to_string(),
+        });
+    }
+
+    let keywords = KeywordContext::from_options(options);
+    let (normalized, excluded) = group_weights("match", weights_path, options.profile.as_deref());
+    let mean = |scores: &[f64]| scores.iter().sum::<f64>() / scores.len() as f64;
+
+    let (keyword_score, keyword_details) =
+        score_match_multi_keyword_overlap(resume, jobs, &keywords);
+
+    let skills_scores: Vec<f64> = jobs
+        .iter()
+        .map(|job| score_match_skills_overlap(resume, job, &keywords).0)
+        .collect();
+    let role_scores: Vec<f64> = jobs
+        .iter()
+        .map(|job| score_match_role_alignment(resume, job, &keywords).0)
+        .collect();
+    let seniority_scores: Vec<f64> = jobs
+        .iter()
+        .filter_map(|job| score_match_seniority_alignment(resume, job).map(|(score, _)| score))
+        .collect();
+    let per_job_details =
+        |scores: &[f64]| HashMap::from([("job_scores".to_string(), serde_json::json!(scores))]);
+
+    let mut categories = vec![
+        ScoreCategoryResult {
+            name: "keyword_overlap".to_string(),
+            score: keyword_score,
+            weight: *normalized.get("keyword_overlap").unwrap_or(&0.0),
+            details: keyword_details,
+        },
+        ScoreCategoryResult {
+            name: "skills_overlap".to_string(),
+            score: mean(&skills_scores),
+            weight: *normalized.get("skills_overlap").unwrap_or(&0.0),
+            details: per_job_details(&skills_scores),
+        },
+        ScoreCategoryResult {
+            name: "role_alignment".to_string(),
+            score: mean(&role_scores),
+            weight: *normalized.get("role_alignment").unwrap_or(&0.0),
+            details: per_job_details(&role_scores),
+        },
+    ];
+    if !seniority_scores.is_empty() {
+        categories.push(ScoreCategoryResult {
+            name: "seniority_alignment".to_string(),
+            score: mean(&seniority_scores),
+            weight: *normalized.get("seniority_alignment").unwrap_or(&0.0),
+            details: per_job_details(&seniority_scores),
+        });
+    }
+    categories.retain(|c| !excluded.contains(&c.name));
+
+    let mut meta = HashMap::from([("job_count".to_string(), serde_json::json!(jobs.len()))]);
+    let total = capped_match_total(
+        resume,
+        weights_path,
+        options,
+        &keywords,
+        &mut categories,
+        &mut meta,
+    );
+
+    Ok(ScoreReport {
+        kind: "match".to_string(),
+        total,
+        categories,
+        meta,
+    })
+}
+
+/// Weighted total of `categories` after the knockout rules.
+///
+/// A missing must-have caps the total regardless of overlap. Requirement
+/// results go in the `keyword_overlap` details (in `meta` if that category
+/// is excluded); the weights source and any uncapped total go in `meta`.
+fn capped_match_total(
+    resume: &serde_json::Value,
+    weights_path: Option<&str>,
+    options: &ScoringOptions,
+    keywords: &KeywordContext,
+    categories: &mut [ScoreCategoryResult],
+    meta: &mut HashMap<String, serde_json::Value>,
+) -> f64 {
+    let mut total = weighted_total(categories);
     if let Some(path) = weights_path {
         meta.insert(
             "weights_source".to_string(),
@@ -874,30 +2291,117 @@ pub fn score_match(
         );
     }
 
-    Ok(ScoreReport {
-        kind: "match".to_string(),
-        total,
-        categories,
-        meta,
-    })
+    let mut requirements = load_match_requirements(weights_path);
+    for keyword in &options.required_keywords {
+        let keyword = keyword.trim().to_lowercase();
+        if !keyword.is_empty() && !requirements.keywords.contains(&keyword) {
+            requirements.keywords.push(keyword);
+        }
+    }
+    if !requirements.keywords.is_empty() {
+        let failed = failed_requirements(resume, &requirements.keywords, keywords);
+        if !failed.is_empty() && total > requirements.cap {
+            meta.insert("uncapped_total".to_string(), serde_json::json!(total));
+            total = requirements.cap;
+        }
+        let details = match categories.iter_mut().find(|c| c.name == "keyword_overlap") {
+            Some(category) => &mut category.details,
+            None => meta,
+        };
+        details.insert(
+            "required_keywords".to_string(),
+            serde_json::json!(requirements.keywords),
+        );
+        details.insert("failed_requirements".to_string(), serde_json::json!(failed));
+        details.insert(
+            "requirement_cap".to_string(),
+            serde_json::json!(requirements.cap),
+        );
+    }
+
+    total
+}
+
+/// Keyword overlap against the union of several jobs' keywords, each term
+/// weighted by the number of jobs that mention it.
+fn score_match_multi_keyword_overlap(
+    resume: &serde_json::Value,
+    jobs: &[serde_json::Value],
+    keywords: &KeywordContext,
+) -> (f64, HashMap<String, serde_json::Value>) {
+    let resume_tokens = keywords.extract(&resume_as_text(resume));
+
+    let mut term_weights: BTreeMap<String, usize> = BTreeMap::new();
+    for job in jobs {
+        for token in keywords.extract(&match_job_text(job)) {
+            *term_weights.entry(token).or_insert(0) += 1;
+        }
+    }
+
+    if term_weights.is_empty() {
+        let mut details = HashMap::new();
+        details.insert("reason".to_string(), serde_json::json!("job_has_no_tokens"));
+        return (0.0, details);
+    }
+
+    let total_weight: usize = term_weights.values().sum();
+    let overlap_weight: usize = term_weights
+        .iter()
+        .filter(|(term, _)| resume_tokens.contains(*term))
+        .map(|(_, weight)| weight)
+        .sum();
+    let overlap_count = term_weights
+        .keys()
+        .filter(|term| resume_tokens.contains(*term))
+        .count();
+    let weighted_ratio = overlap_weight as f64 / total_weight as f64;
+
+    // sqrt as in single-job keyword overlap
+    let score = 100.0 * weighted_ratio.sqrt();
+
+    let mut top_missing: Vec<(&String, &usize)> = term_weights
+        .iter()
+        .filter(|(term, _)| !resume_tokens.contains(*term))
+        .collect();
+    top_missing.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    let top_missing: Vec<&String> = top_missing
+        .into_iter()
+        .take(TOP_WEIGHTED_MISSING)
+        .map(|(term, _)| term)
+        .collect();
+
+    let mut details = HashMap::new();
+    details.insert(
+        "job_token_count".to_string(),
+        serde_json::json!(term_weights.len()),
+    );
+    details.insert(
+        "overlap_count".to_string(),
+        serde_json::json!(overlap_count),
+    );
+    details.insert(
+        "weighted_overlap_ratio".to_string(),
+        serde_json::json!(weighted_ratio),
+    );
+    details.insert(
+        "top_weighted_missing".to_string(),
+        serde_json::json!(top_missing),
+    );
+    details.insert("term_weights".to_string(), serde_json::json!(term_weights));
+
+    (clamp(score, 0.0, 100.0), details)
 }
 
 fn score_match_keyword_overlap(
     resume: &serde_json::Value,
     job: &serde_json::Value,
+    keywords: &KeywordContext,
 ) -> (f64, HashMap<String, serde_json::Value>) {
-    let job_text = [
-        safe_str(job.get("title")),
-        safe_str(job.get("description")),
-        safe_str(job.get("company")),
-        safe_str(job.get("location")),
-    ]
-    .join(" ");
-
+    let job_text = match_job_text(job);
     let resume_text = resume_as_text(resume);
 
-    let job_tokens = extract_keywords(&job_text);
-    let resume_tokens = extract_keywords(&resume_text);
+    let job_tokens = keywords.extract(&job_text);
+    let resume_tokens = keywords.extract(&resume_text);
 
     if job_tokens.is_empty() {
         let mut details = HashMap::new();
@@ -908,10 +2412,38 @@ fn score_match_keyword_overlap(
     let overlap: HashSet<_> = job_tokens.intersection(&resume_tokens).collect();
     let missing: HashSet<_> = job_tokens.difference(&resume_tokens).collect();
 
+    // Overlap gained only through abbreviation or alias expansion
+    let mut alias_matches: Vec<String> = if keywords.expands() {
+        let literal_job = keywords.extract_literal(&job_text);
+        let literal_resume = keywords.extract_literal(&resume_text);
+        overlap
+            .iter()
+            .filter(|t| !(literal_job.contains(**t) && literal_resume.contains(**t)))
+            .map(|t| (*t).clone())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    alias_matches.sort();
+
     let ratio = overlap.len() as f64 / job_tokens.len() as f64;
 
+    // Rarer job terms carry more weight, so "kubernetes" outweighs "team"
+    let weights = job_token_weights(&job_tokens, &keywords.token_counts(&job_text));
+    let total_weight: f64 = weights.values().sum();
+    let overlap_weight: f64 = overlap.iter().map(|t| weights[*t]).sum();
+    let weighted_ratio = if total_weight > 0.0 {
+        overlap_weight / total_weight
+    } else {
+        ratio
+    };
+
     // sqrt makes it easier to get decent scores on large job token sets
-    let score = 100.0 * ratio.sqrt();
+    let score = 100.0 * weighted_ratio.sqrt();
+
+    let mut top_missing: Vec<&String> = missing.iter().copied().collect();
+    top_missing.sort_by(|a, b| weights[*b].total_cmp(&weights[*a]).then_with(|| a.cmp(b)));
+    top_missing.truncate(TOP_WEIGHTED_MISSING);
 
     let mut details = HashMap::new();
     details.insert(
@@ -931,6 +2463,22 @@ fn score_match_keyword_overlap(
         serde_json::json!(missing.len()),
     );
     details.insert("overlap_ratio".to_string(), serde_json::json!(ratio));
+    details.insert(
+        "weighted_overlap_ratio".to_string(),
+        serde_json::json!(weighted_ratio),
+    );
+    details.insert(
+        "top_weighted_missing".to_string(),
+        serde_json::json!(top_missing),
+    );
+    details.insert(
+        "alias_match_count".to_string(),
+        serde_json::json!(alias_matches.len()),
+    );
+    details.insert(
+        "alias_matches".to_string(),
+        serde_json::json!(alias_matches),
+    );
 
     let sample_overlap: Vec<String> = overlap.iter().take(20).map(|s| (*s).clone()).collect();
     let sample_missing: Vec<String> = missing.iter().take(20).map(|s| (*s).clone()).collect();
@@ -942,25 +2490,43 @@ fn score_match_keyword_overlap(
         "sample_missing".to_string(),
         serde_json::json!(sample_missing),
     );
+    details.insert(
+        "missing_keywords".to_string(),
+        serde_json::json!(gap::compute_keyword_gap(resume, job, keywords).missing),
+    );
 
     (clamp(score, 0.0, 100.0), details)
 }
 
+/// Missing job terms listed in `top_weighted_missing`, highest weight first.
+const TOP_WEIGHTED_MISSING: usize = 10;
+
+/// Inverse-frequency weight of each job keyword within the job text.
+///
+/// A term's weight is `ln(1 + total / count)`, where `count` is how often it
+/// occurs and `total` is the occurrence count of all keywords, so terms
+/// repeated throughout the posting count for less.
+fn job_token_weights(
+    job_tokens: &HashSet<String>,
+    counts: &HashMap<String, usize>,
+) -> HashMap<String, f64> {
+    let count = |token: &String| counts.get(token).copied().unwrap_or(1).max(1) as f64;
+    let total: f64 = job_tokens.iter().map(count).sum();
+    job_tokens
+        .iter()
+        .map(|token| (token.clone(), (1.0 + total / count(token)).ln()))
+        .collect()
+}
+
 fn score_match_skills_overlap(
     resume: &serde_json::Value,
     job: &serde_json::Value,
+    keywords: &KeywordContext,
 ) -> (f64, HashMap<String, serde_json::Value>) {
-    let skills = resume
-        .get("skills")
-        .and_then(|v| v.as_array())
-        .map(|arr| {
-            arr.iter()
-                .filter_map(|v| v.as_str())
-                .map(|s| s.trim().to_lowercase())
-                .filter(|s| !s.is_empty())
-                .collect::<HashSet<String>>()
-        })
-        .unwrap_or_default();
+    let skills: HashSet<String> = resume_skills(resume)
+        .iter()
+        .map(|s| s.to_lowercase())
+        .collect();
 
     if skills.is_empty() {
         let mut details = HashMap::new();
@@ -972,31 +2538,48 @@ fn score_match_skills_overlap(
     }
 
     let job_text = [safe_str(job.get("title")), safe_str(job.get("description"))].join(" ");
-    let job_tokens = extract_keywords(&job_text);
+    let job_tokens = keywords.extract(&job_text);
+    let literal_job_tokens = if keywords.expands() {
+        keywords.extract_literal(&job_text)
+    } else {
+        HashSet::new()
+    };
 
     let mut matched = HashSet::new();
+    let mut alias_matched = Vec::new();
     for skill in &skills {
-        let skill_tokens = extract_keywords(skill);
-        if skill_tokens.is_empty() {
+        if !skill_matches(&keywords.extract(skill), &job_tokens) {
             continue;
         }
+        matched.insert(skill.clone());
 
-        // Single-token skill: exact match
-        if skill_tokens.len() == 1 {
-            if job_tokens.contains(skill_tokens.iter().next().unwrap()) {
-                matched.insert(skill.clone());
-            }
-        } else {
-            // Multi-token skill: require 60%+ tokens present
-            let hits = skill_tokens
-                .iter()
-                .filter(|t| job_tokens.contains(*t))
-                .count();
-            if hits as f64 / skill_tokens.len() as f64 >= 0.6 {
-                matched.insert(skill.clone());
-            }
+        if keywords.expands()
+            && !skill_matches(&keywords.extract_literal(skill), &literal_job_tokens)
+        {
+            alias_matched.push(skill.clone());
         }
     }
+    alias_matched.sort();
+
+    // Abbreviations expanded in matched skills, and in the job text where
+    // the expansion is part of a matched skill
+    let mut expansions: BTreeMap<String, String> = matched
+        .iter()
+        .flat_map(|skill| keywords.abbreviation_expansions(skill))
+        .collect();
+    let matched_tokens: HashSet<String> = matched
+        .iter()
+        .flat_map(|skill| keywords.extract(skill))
+        .collect();
+    expansions.extend(
+        keywords
+            .abbreviation_expansions(&job_text)
+            .into_iter()
+            .filter(|(_, expansion)| {
+                let tokens = keywords.extract(expansion);
+                !tokens.is_empty() && tokens.is_subset(&matched_tokens)
+            }),
+    );
 
     let ratio = matched.len() as f64 / skills.len() as f64;
     let score = 100.0 * ratio;
@@ -1011,6 +2594,18 @@ fn score_match_skills_overlap(
         serde_json::json!(matched.len()),
     );
     details.insert("match_ratio".to_string(), serde_json::json!(ratio));
+    details.insert(
+        "alias_match_count".to_string(),
+        serde_json::json!(alias_matched.len()),
+    );
+    details.insert(
+        "alias_matched_skills".to_string(),
+        serde_json::json!(alias_matched),
+    );
+    details.insert(
+        "abbreviation_expansions".to_string(),
+        serde_json::json!(expansions),
+    );
 
     let sample_matched: Vec<String> = matched.iter().take(20).cloned().collect();
     details.insert(
@@ -1024,6 +2619,7 @@ fn score_match_skills_overlap(
 fn score_match_role_alignment(
     resume: &serde_json::Value,
     job: &serde_json::Value,
+    keywords: &KeywordContext,
 ) -> (f64, HashMap<String, serde_json::Value>) {
     let job_title = safe_str(job.get("title")).trim().to_string();
 
@@ -1059,7 +2655,7 @@ fn score_match_role_alignment(
         return (25.0, details); // Don't hard-zero
     }
 
-    let job_toks = extract_keywords(&job_title);
+    let job_toks = keywords.extract_literal(&job_title);
 
     if job_toks.is_empty() {
         let mut details = HashMap::new();
@@ -1074,7 +2670,7 @@ fn score_match_role_alignment(
     let mut best_title = String::new();
 
     for title in &titles {
-        let rt = extract_keywords(title);
+        let rt = keywords.extract_literal(title);
         if rt.is_empty() {
             continue;
         }
@@ -1099,10 +2695,243 @@ fn score_match_role_alignment(
     (clamp(score, 0.0, 100.0), details)
 }
 
+/// Required keywords the resume does not mention.
+///
+/// A keyword is present when all of its tokens appear in the resume, with
+/// synonyms folded as in keyword overlap.
+fn failed_requirements(
+    resume: &serde_json::Value,
+    required: &[String],
+    keywords: &KeywordContext,
+) -> Vec<String> {
+    let resume_tokens = keywords.token_counts(&resume_as_text(resume));
+
+    required
+        .iter()
+        .filter(|keyword| {
+            let tokens = keywords.token_counts(keyword);
+            !tokens.is_empty() && !tokens.keys().all(|t| resume_tokens.contains_key(t))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Points lost per seniority level between the resume and the job.
+const SENIORITY_GAP_PENALTY: f64 = 30.0;
+
+/// Score how close the resume's seniority is to the job's.
+///
+/// Returns `None` when the job has no title, experience level or years
+/// requirement to infer a level from.
+fn score_match_seniority_alignment(
+    resume: &serde_json::Value,
+    job: &serde_json::Value,
+) -> Option<(f64, HashMap<String, serde_json::Value>)> {
+    let (job_level, job_source) = infer_job_seniority(job)?;
+
+    let mut details = HashMap::new();
+    details.insert(
+        "job_level".to_string(),
+        serde_json::json!(job_level.as_str()),
+    );
+    details.insert(
+        "job_level_source".to_string(),
+        serde_json::json!(job_source),
+    );
+
+    let Some((resume_level, resume_source)) = infer_resume_seniority(resume) else {
+        details.insert(
+            "reason".to_string(),
+            serde_json::json!("resume_level_unknown"),
+        );
+        return Some((50.0, details)); // Neutral
+    };
+
+    let gap = resume_level.gap(job_level);
+    let score = 100.0 - SENIORITY_GAP_PENALTY * f64::from(gap);
+
+    details.insert(
+        "resume_level".to_string(),
+        serde_json::json!(resume_level.as_str()),
+    );
+    details.insert(
+        "resume_level_source".to_string(),
+        serde_json::json!(resume_source),
+    );
+    details.insert("level_gap".to_string(), serde_json::json!(gap));
+
+    Some((clamp(score, 0.0, 100.0), details))
+}
+
+/// Resume seniority and where it came from: a level keyword in one of the
+/// three most recent titles, else years of experience in the summary, else
+/// mid-level for plain titles.
+fn infer_resume_seniority(resume: &serde_json::Value) -> Option<(SeniorityLevel, &'static str)> {
+    let titles: Vec<String> = resume
+        .get("experience")
+        .and_then(|v| v.as_array())
+        .map(|exp| {
+            exp.iter()
+                .take(3)
+                .map(|entry| safe_str(entry.get("title")))
+                .filter(|t| !t.trim().is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let Some(level) = titles.iter().find_map(|t| seniority_from_title(t)) {
+        return Some((level, "title"));
+    }
+    if let Some(years) = years_of_experience(&safe_str(resume.get("summary"))) {
+        return Some((SeniorityLevel::from_years(years), "years"));
+    }
+    (!titles.is_empty()).then_some((SeniorityLevel::Mid, "default"))
+}
+
+/// Job seniority and where it came from: a level keyword in the title or
+/// `experience_level`, else a years requirement in the description, else
+/// mid-level for a plain title.
+fn infer_job_seniority(job: &serde_json::Value) -> Option<(SeniorityLevel, &'static str)> {
+    let title = safe_str(job.get("title"));
+
+    if let Some(level) = seniority_from_title(&title) {
+        return Some((level, "title"));
+    }
+    if let Some(level) = seniority_from_title(&safe_str(job.get("experience_level"))) {
+        return Some((level, "experience_level"));
+    }
+    if let Some(years) = years_of_experience(&safe_str(job.get("description"))) {
+        return Some((SeniorityLevel::from_years(years), "years"));
+    }
+    (!title.trim().is_empty()).then_some((SeniorityLevel::Mid, "default"))
+}
+
+/// A resume skill matches when all of its tokens (single-token skills) or at
+/// least 60% of them (multi-token skills) appear in the job tokens.
+fn skill_matches(skill_tokens: &HashSet<String>, job_tokens: &HashSet<String>) -> bool {
+    if skill_tokens.is_empty() {
+        return false;
+    }
+
+    // Single-token skill: exact match
+    if skill_tokens.len() == 1 {
+        return skill_tokens.iter().all(|t| job_tokens.contains(t));
+    }
+
+    // Multi-token skill: require 60%+ tokens present
+    let hits = skill_tokens
+        .iter()
+        .filter(|t| job_tokens.contains(*t))
+        .count();
+    hits as f64 / skill_tokens.len() as f64 >= 0.6
+}
+
 // -------------------------
 // Utility Functions
 // -------------------------
 
+/// Keyword extraction settings shared by the match scoring helpers.
+struct KeywordContext {
+    stopwords: HashSet<String>,
+    /// Abbreviations as configured, for reporting expansions.
+    abbreviations: SynonymMap,
+    /// [`abbreviations`](Self::abbreviations) folded to one token per term,
+    /// for matching.
+    folded_abbreviations: SynonymMap,
+    /// Tokens abbreviations fold into, kept even when shorter than the
+    /// keyword length cutoff ("ci", "cd").
+    abbreviation_terms: HashSet<String>,
+    synonyms: SynonymMap,
+    tokenizer: Arc<dyn Tokenizer>,
+}
+
+impl KeywordContext {
+    fn from_options(options: &ScoringOptions) -> Self {
+        let abbreviations = options
+            .abbreviations
+            .clone()
+            .unwrap_or_else(default_abbreviations);
+        let folded_abbreviations = abbreviations.folded();
+        Self {
+            stopwords: load_stopwords(options.stopwords_path.as_deref()),
+            abbreviation_terms: folded_abbreviations.canonical_tokens(),
+            folded_abbreviations,
+            abbreviations,
+            synonyms: load_synonyms(options.synonyms_path.as_deref()),
+            tokenizer: options
+                .tokenizer
+                .clone()
+                .unwrap_or_else(|| Arc::new(DefaultTokenizer)),
+        }
+    }
+
+    /// Whether abbreviations or synonyms can rewrite text.
+    fn expands(&self) -> bool {
+        !self.abbreviations.is_empty() || !self.synonyms.is_empty()
+    }
+
+    /// Extract keywords with abbreviations and aliases folded into their
+    /// canonical terms.
+    fn extract(&self, text: &str) -> HashSet<String> {
+        if !self.expands() {
+            return self.extract_literal(text);
+        }
+        let canonical = self.canonicalize(text);
+        let mut keywords = self.extract_literal(&canonical);
+        keywords.extend(
+            self.tokenizer
+                .tokenize(&canonical)
+                .into_iter()
+                .filter(|t| self.abbreviation_terms.contains(t) && !self.stopwords.contains(t)),
+        );
+        keywords
+    }
+
+    /// Extract keywords without abbreviation or synonym expansion.
+    fn extract_literal(&self, text: &str) -> HashSet<String> {
+        extract_keywords_with(text, &self.stopwords, self.tokenizer.as_ref())
+    }
+
+    /// Fold abbreviations and their expansions, then replace synonyms.
+    fn canonicalize(&self, text: &str) -> String {
+        let tokenizer = self.tokenizer.as_ref();
+        let expanded = self.folded_abbreviations.canonicalize_with(text, tokenizer);
+        self.synonyms.canonicalize_with(&expanded, tokenizer)
+    }
+
+    /// Abbreviations in `text` and their expansions.
+    fn abbreviation_expansions(&self, text: &str) -> Vec<(String, String)> {
+        self.abbreviations
+            .expansions_with(text, self.tokenizer.as_ref())
+    }
+
+    /// Count token occurrences, with aliases folded as in [`extract`](Self::extract).
+    fn token_counts(&self, text: &str) -> HashMap<String, usize> {
+        let tokens = if self.expands() {
+            self.tokenizer.tokenize(&self.canonicalize(text))
+        } else {
+            self.tokenizer.tokenize(text)
+        };
+
+        let mut counts = HashMap::new();
+        for token in tokens {
+            *counts.entry(token).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+/// Job text compared against the resume in match scoring.
+fn match_job_text(job: &serde_json::Value) -> String {
+    [
+        safe_str(job.get("title")),
+        safe_str(job.get("description")),
+        safe_str(job.get("company")),
+        safe_str(job.get("location")),
+    ]
+    .join(" ")
+}
+
 fn weighted_total(categories: &[ScoreCategoryResult]) -> f64 {
     let total_weight: f64 = categories.iter().map(|c| c.weight).sum();
 
@@ -1133,8 +2962,21 @@ fn safe_str(value: Option<&serde_json::Value>) -> String {
     value.and_then(|v| v.as_str()).unwrap_or("").to_string()
 }
 
-fn extract_bullets(description: Option<&serde_json::Value>) -> Vec<String> {
-    match description {
+/// Keys of an experience entry that hold accomplishment bullets.
+const BULLET_KEYS: &[&str] = &["description", "bullets", "achievements"];
+
+/// Bullets from an experience entry's `description`, `bullets` and
+/// `achievements` fields, in that order.
+pub(crate) fn extract_bullets(entry: &serde_json::Value) -> Vec<String> {
+    BULLET_KEYS
+        .iter()
+        .flat_map(|key| field_bullets(entry.get(*key)))
+        .collect()
+}
+
+/// Lines of a string field, or the non-blank strings of an array field.
+fn field_bullets(field: Option<&serde_json::Value>) -> Vec<String> {
+    match field {
         Some(serde_json::Value::String(s)) => s
             .lines()
             .map(|l| l.trim().to_string())
@@ -1150,6 +2992,28 @@ fn extract_bullets(description: Option<&serde_json::Value>) -> Vec<String> {
     }
 }
 
+/// The resume's skills, trimmed and without blanks.
+///
+/// `skills` may be a flat array or an object grouping arrays by category
+/// (`{"languages": [...], "tools": [...]}`), which is flattened in key order.
+fn resume_skills(resume: &serde_json::Value) -> Vec<String> {
+    let items: Vec<&serde_json::Value> = match resume.get("skills") {
+        Some(serde_json::Value::Array(arr)) => arr.iter().collect(),
+        Some(serde_json::Value::Object(groups)) => groups
+            .values()
+            .filter_map(|v| v.as_array())
+            .flatten()
+            .collect(),
+        _ => Vec::new(),
+    };
+    items
+        .into_iter()
+        .filter_map(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
 fn resume_as_text(resume: &serde_json::Value) -> String {
     let mut parts = Vec::new();
 
@@ -1164,11 +3028,7 @@ fn resume_as_text(resume: &serde_json::Value) -> String {
     parts.push(safe_str(resume.get("summary")));
 
     // Skills
-    if let Some(skills) = resume.get("skills").and_then(|v| v.as_array()) {
-        for skill in skills {
-            parts.push(safe_str(Some(skill)));
-        }
-    }
+    parts.extend(resume_skills(resume));
 
     // Experience
     if let Some(exp) = resume.get("experience").and_then(|v| v.as_array()) {
@@ -1178,7 +3038,7 @@ fn resume_as_text(resume: &serde_json::Value) -> String {
                 parts.push(safe_str(obj.get("company")));
                 parts.push(safe_str(obj.get("location")));
 
-                for bullet in extract_bullets(obj.get("description")) {
+                for bullet in extract_bullets(entry) {
                     parts.push(bullet);
                 }
             }
@@ -1213,132 +3073,6 @@ fn resume_as_text(resume: &serde_json::Value) -> String {
         .join("\n")
 }
 
-fn extract_keywords(text: &str) -> HashSet<String> {
-    // Split on non-alphanumeric (but keep + and #)
-    let tokens: Vec<&str> = text
-        .split(|c: char| !c.is_alphanumeric() && c != '+' && c != '#')
-        .filter(|t| !t.is_empty())
-        .collect();
-
-    let stopwords = stopwords();
-    let mut keywords = HashSet::new();
-
-    for token in tokens {
-        let lower = token.to_lowercase();
-
-        if stopwords.iter().any(|&sw| sw == lower) {
-            continue;
-        }
-
-        // Filter out very short tokens except known technical terms
-        if lower.len() <= 2 {
-            if matches!(
-                lower.as_str(),
-                "c" | "go" | "ai" | "ml" | "ui" | "ux" | "qa" | "c#" | "c++"
-            ) {
-                keywords.insert(lower);
-            }
-            continue;
-        }
-
-        keywords.insert(lower);
-    }
-
-    keywords
-}
-
-fn looks_like_action_bullet(bullet: &str) -> bool {
-    let b = bullet.trim().to_lowercase();
-    if b.is_empty() {
-        return false;
-    }
-
-    let first_word = b.split_whitespace().next().unwrap_or("");
-
-    if action_verbs().contains(&first_word) {
-        return true;
-    }
-
-    for verb in action_verbs() {
-        if b.starts_with(&format!("{verb} ")) {
-            return true;
-        }
-    }
-
-    false
-}
-
-fn contains_number(s: &str) -> bool {
-    // Match digits with optional decimal and % sign
-    s.chars().any(char::is_numeric)
-}
-
-fn contains_outcome_language(s: &str) -> bool {
-    let lower = s.to_lowercase();
-    outcome_markers()
-        .iter()
-        .any(|&marker| lower.contains(marker))
-}
-
-fn stopwords() -> &'static [&'static str] {
-    &[
-        "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "has", "have", "he",
-        "in", "is", "it", "its", "of", "on", "or", "that", "the", "their", "they", "this", "to",
-        "was", "were", "will", "with", "you", "your", "we", "our", "us",
-    ]
-}
-
-fn action_verbs() -> &'static [&'static str] {
-    &[
-        "built",
-        "created",
-        "designed",
-        "developed",
-        "delivered",
-        "implemented",
-        "improved",
-        "increased",
-        "reduced",
-        "optimized",
-        "automated",
-        "led",
-        "managed",
-        "owned",
-        "shipped",
-        "launched",
-        "migrated",
-        "refactored",
-        "collaborated",
-        "analyzed",
-        "architected",
-        "tested",
-        "deployed",
-    ]
-}
-
-fn outcome_markers() -> &'static [&'static str] {
-    &[
-        "improved",
-        "increased",
-        "reduced",
-        "decreased",
-        "accelerated",
-        "saved",
-        "cut",
-        "boosted",
-        "grew",
-        "optimized",
-        "revenue",
-        "cost",
-        "latency",
-        "throughput",
-        "uptime",
-        "performance",
-        "efficiency",
-        "scalability",
-    ]
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1358,6 +3092,67 @@ mod tests {
         assert_eq!(*normalized.get("c").unwrap(), 0.25);
     }
 
+    #[test]
+    fn test_score_report_delta() {
+        let category = |name: &str, score: f64| ScoreCategoryResult {
+            name: name.to_string(),
+            score,
+            weight: 0.5,
+            details: HashMap::new(),
+        };
+        let report = |total: f64, categories: Vec<ScoreCategoryResult>| ScoreReport {
+            kind: "resume".to_string(),
+            total,
+            categories,
+            meta: HashMap::new(),
+        };
+        let before = report(
+            60.0,
+            vec![
+                category("completeness", 50.0),
+                category("skills_quality", 70.0),
+                category("formatting", 40.0),
+            ],
+        );
+        let after = report(
+            65.5,
+            vec![
+                category("skills_quality", 62.5),
+                category("completeness", 80.0),
+                category("readability", 90.0),
+            ],
+        );
+
+        let delta = before.delta(&after);
+
+        assert!((delta.total - 5.5).abs() < 1e-9);
+        let change = |name: &str| {
+            delta
+                .categories
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap()
+                .change
+        };
+        assert!((change("completeness") - 30.0).abs() < 1e-9);
+        assert!((change("skills_quality") + 7.5).abs() < 1e-9);
+        assert!((change("formatting") + 40.0).abs() < 1e-9);
+        assert!((change("readability") - 90.0).abs() < 1e-9);
+        let names: Vec<_> = delta.categories.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "completeness",
+                "skills_quality",
+                "formatting",
+                "readability"
+            ]
+        );
+        assert_eq!(delta.categories[2].after, None);
+        assert_eq!(delta.improved().len(), 2);
+        assert_eq!(delta.declined().len(), 2);
+    }
+
     #[test]
     fn test_score_resume_completeness() {
         let resume = json!({
@@ -1373,29 +3168,171 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_keywords() {
-        let text = "Rust developer with C++ and Python experience";
-        let keywords = extract_keywords(text);
+    fn test_score_resume_completeness_validates_contact_info() {
+        let resume_with = |personal: serde_json::Value| {
+            json!({
+                "personal_info": personal,
+                "experience": [{"title": "Engineer"}],
+                "skills": ["Rust"]
+            })
+        };
+
+        let (malformed, details) = score_resume_completeness(&resume_with(
+            json!({"name": "John Doe", "email": "john at example", "phone": "ext. 12"}),
+        ));
+        assert_eq!(details["has_email"], json!(true));
+        assert_eq!(details["valid_email"], json!(false));
+        assert_eq!(details["has_phone"], json!(true));
+        assert_eq!(details["valid_phone"], json!(false));
+
+        let (valid, details) = score_resume_completeness(&resume_with(
+            json!({"name": "John Doe", "email": "john@example.com", "phone": "+1 (555) 123-4567"}),
+        ));
+        assert_eq!(details["valid_email"], json!(true));
+        assert_eq!(details["valid_phone"], json!(true));
+        assert_eq!(details["has_profile_link"], json!(false));
+        assert!(valid > malformed);
+
+        let (with_profile, details) = score_resume_completeness(&resume_with(json!({
+            "name": "John Doe",
+            "email": "john@example.com",
+            "phone": "+1 (555) 123-4567",
+            "links": ["https://github.com/johndoe"]
+        })));
+        assert_eq!(details["has_profile_link"], json!(true));
+        assert!((with_profile - valid - PROFILE_LINK_BONUS).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_load_stopwords_extend_and_replace() {
+        let temp = tempfile::TempDir::new().unwrap();
+
+        let extend_path = temp.path().join("extend.toml");
+        std::fs::write(&extend_path, "words = [\"  Various \", \"ETC\"]").unwrap();
+        let extended = load_stopwords(Some(&extend_path));
+        assert!(extended.contains("various"));
+        assert!(extended.contains("etc"));
+        assert!(extended.contains("the"));
+
+        let replace_path = temp.path().join("replace.toml");
+        std::fs::write(&replace_path, "mode = \"replace\"\nwords = [\"foo\"]").unwrap();
+        let replaced = load_stopwords(Some(&replace_path));
+        assert_eq!(replaced.len(), 1);
+        assert!(replaced.contains("foo"));
+
+        // Missing file falls back to the built-in list
+        let missing = load_stopwords(Some(&temp.path().join("missing.toml")));
+        assert_eq!(missing, default_stopwords());
+    }
+
+    #[test]
+    fn test_weights_document_rereads_changed_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("weights.toml");
+        let path_str = path.to_str().unwrap();
+
+        std::fs::write(&path, "[job.weights]\nrecency = 0.1\n").unwrap();
+        assert!((load_scoring_weights(Some(path_str))["job"]["recency"] - 0.1).abs() < 1e-9);
+
+        std::fs::write(&path, "[job.weights]\nrecency = 0.25\n").unwrap();
+        assert!((load_scoring_weights(Some(path_str))["job"]["recency"] - 0.25).abs() < 1e-9);
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(load_scoring_weights(Some(path_str))["job"]["recency"].abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_synonym_map_canonicalize() {
+        let synonyms = SynonymMap::new(vec![
+            (
+                "javascript".to_string(),
+                vec!["js".to_string(), "Node".to_string()],
+            ),
+            ("machine learning".to_string(), vec!["ML".to_string()]),
+            (
+                "ci/cd".to_string(),
+                vec!["continuous integration".to_string()],
+            ),
+        ]);
+
+        assert_eq!(
+            synonyms.canonicalize("JS and node"),
+            "javascript and javascript"
+        );
+        assert_eq!(
+            synonyms.canonicalize("ML engineer"),
+            "machine learning engineer"
+        );
+        assert_eq!(
+            synonyms.canonicalize("Continuous Integration pipelines"),
+            "ci cd pipelines"
+        );
+        // Whole tokens only
+        assert_eq!(synonyms.canonicalize("json"), "json");
+    }
 
-        assert!(keywords.contains("rust"));
-        assert!(keywords.contains("developer"));
-        assert!(keywords.contains("c++"));
-        assert!(keywords.contains("python"));
-        assert!(keywords.contains("experience"));
-        assert!(!keywords.contains("with")); // stopword
+    #[test]
+    fn test_parse_posting_age_days() {
+        let now = chrono::DateTime::parse_from_rfc3339("2024-03-31T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        assert_eq!(parse_posting_age_days("2024-03-21", now), Some(10.0));
+        assert_eq!(
+            parse_posting_age_days("2024-03-30T12:00:00Z", now),
+            Some(1.0)
+        );
+        assert_eq!(parse_posting_age_days("3 days ago", now), Some(3.0));
+        assert_eq!(parse_posting_age_days("2 weeks ago", now), Some(14.0));
+        assert_eq!(parse_posting_age_days("30+ days ago", now), Some(30.0));
+        assert_eq!(parse_posting_age_days("Yesterday", now), Some(1.0));
+        assert_eq!(parse_posting_age_days("2099-01-01", now), Some(0.0));
+        assert_eq!(parse_posting_age_days("sometime last spring", now), None);
     }
 
     #[test]
-    fn test_looks_like_action_bullet() {
-        assert!(looks_like_action_bullet("Built a scalable system"));
-        assert!(looks_like_action_bullet("Implemented new features"));
-        assert!(!looks_like_action_bullet("Responsible for the project"));
+    fn test_score_job_recency_decay() {
+        let now = chrono::DateTime::parse_from_rfc3339("2024-03-31T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        let (fresh, _) = score_job_recency(&json!({"date_posted": "2024-03-28"}), now);
+        assert_eq!(fresh, 100.0);
+
+        let (mid, details) = score_job_recency(&json!({"posted_at": "2024-02-17"}), now);
+        assert!(mid > 0.0 && mid < 100.0);
+        assert_eq!(details["age_days"], json!(43.0));
+
+        let (stale, _) = score_job_recency(&json!({"date_posted": "90 days ago"}), now);
+        assert_eq!(stale, 0.0);
+
+        let (missing, details) = score_job_recency(&json!({}), now);
+        assert_eq!(missing, 50.0);
+        assert_eq!(details["reason"], json!("missing_posting_date"));
+
+        let (garbled, details) = score_job_recency(&json!({"date_posted": "soon"}), now);
+        assert_eq!(garbled, 50.0);
+        assert_eq!(details["reason"], json!("unparseable_posting_date"));
     }
 
     #[test]
-    fn test_contains_number() {
-        assert!(contains_number("Improved performance by 50%"));
-        assert!(contains_number("Processed 1000 requests"));
-        assert!(!contains_number("No numbers here"));
+    fn test_score_job_compensation_tiers() {
+        let (range, details) = score_job_compensation(&json!({"salary": "$120,000 - $150,000/yr"}));
+        assert_eq!(range, SALARY_RANGE_SCORE);
+        assert_eq!(details["salary_range"]["min"], json!(120_000.0));
+        assert_eq!(details["salary_range"]["max"], json!(150_000.0));
+        assert_eq!(details["salary_range"]["currency"], json!("USD"));
+
+        let (figure, details) = score_job_compensation(&json!({"salary": "£60k"}));
+        assert_eq!(figure, SALARY_FIGURE_SCORE);
+        assert_eq!(details["salary_range"]["period"], json!("annual"));
+
+        let (vague, details) = score_job_compensation(&json!({"salary": "Competitive"}));
+        assert_eq!(vague, SALARY_VAGUE_SCORE);
+        assert!(!details.contains_key("salary_range"));
+
+        let (missing, _) = score_job_compensation(&json!({}));
+        assert_eq!(missing, 0.0);
+        assert!(missing < vague && vague < figure && figure < range);
     }
 }
@@ -0,0 +1,431 @@
+//! Lightweight spelling check for resume text.
+//!
+//! With the `english-dictionary` cargo feature, words are looked up in a
+//! bundled English word list (`english_words.txt`, see
+//! `english_words.LICENSE`) plus [`TECH_WORDS`], and anything else counts as
+//! unrecognized. Without it, only the known typos in
+//! [`COMMON_MISSPELLINGS`] are flagged. To keep false
+//! positives on names, product names and jargon down, a word is skipped when
+//! it:
+//!
+//! - is capitalized anywhere but the start of a line or sentence (a proper
+//!   noun, like a company or product name),
+//! - is written in all caps or mixed case ("AWS", "GraphQL", "iOS"),
+//! - contains digits or symbols ("K8s", "C#", "node.js"), or
+//! - is in the caller's allowlist, such as the candidate's listed skills.
+//!
+//! Known typos from [`COMMON_MISSPELLINGS`] come with a suggested correction.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// Whether the English word list is compiled in (the `english-dictionary`
+/// feature).
+pub const BUNDLED_DICTIONARY: bool = cfg!(feature = "english-dictionary");
+
+/// Bundled English word list, one lowercase word per line; `#` starts a comment.
+#[cfg(feature = "english-dictionary")]
+const ENGLISH_WORDS: &str = include_str!("english_words.txt");
+#[cfg(not(feature = "english-dictionary"))]
+const ENGLISH_WORDS: &str = "";
+
+/// Technical and resume vocabulary missing from the English word list.
+pub const TECH_WORDS: &[&str] = &[
+    "agile",
+    "ai",
+    "api",
+    "apis",
+    "async",
+    "auth",
+    "autoscaling",
+    "backend",
+    "backends",
+    "backlog",
+    "backlogs",
+    "bootcamp",
+    "changelog",
+    "cli",
+    "codebase",
+    "codebases",
+    "config",
+    "configs",
+    "cybersecurity",
+    "dataset",
+    "datasets",
+    "dev",
+    "devops",
+    "docker",
+    "ecommerce",
+    "endpoint",
+    "endpoints",
+    "failover",
+    "fintech",
+    "frontend",
+    "frontends",
+    "fullstack",
+    "github",
+    "gitlab",
+    "golang",
+    "hackathon",
+    "hackathons",
+    "healthtech",
+    "hotfix",
+    "hotfixes",
+    "html",
+    "http",
+    "https",
+    "javascript",
+    "json",
+    "kubernetes",
+    "linux",
+    "llm",
+    "llms",
+    "microservice",
+    "microservices",
+    "middleware",
+    "ml",
+    "monorepo",
+    "mysql",
+    "nosql",
+    "observability",
+    "offboarding",
+    "onboarded",
+    "onboarding",
+    "onsite",
+    "postgres",
+    "postgresql",
+    "proptech",
+    "realtime",
+    "redis",
+    "refactor",
+    "refactored",
+    "refactoring",
+    "refactors",
+    "repo",
+    "repos",
+    "runbook",
+    "runbooks",
+    "saas",
+    "scalability",
+    "serverless",
+    "sql",
+    "stakeholder",
+    "stakeholders",
+    "subteam",
+    "toolchain",
+    "toolchains",
+    "typescript",
+    "ui",
+    "upskilled",
+    "upskilling",
+    "uptime",
+    "ux",
+    "webhook",
+    "webhooks",
+    "workflow",
+    "workflows",
+];
+
+/// Common misspellings and their corrections, lowercase.
+pub const COMMON_MISSPELLINGS: &[(&str, &str)] = &[
+    ("accomodate", "accommodate"),
+    ("accomodated", "accommodated"),
+    ("accross", "across"),
+    ("acheive", "achieve"),
+    ("acheived", "achieved"),
+    ("acheivement", "achievement"),
+    ("acheivements", "achievements"),
+    ("acquisiton", "acquisition"),
+    ("adminstration", "administration"),
+    ("adminstrative", "administrative"),
+    ("analyis", "analysis"),
+    ("analyzeing", "analyzing"),
+    ("apparant", "apparent"),
+    ("arguement", "argument"),
+    ("assesment", "assessment"),
+    ("assistence", "assistance"),
+    ("begining", "beginning"),
+    ("beleive", "believe"),
+    ("benifit", "benefit"),
+    ("benifits", "benefits"),
+    ("buisness", "business"),
+    ("calender", "calendar"),
+    ("carreer", "career"),
+    ("cirriculum", "curriculum"),
+    ("collaberate", "collaborate"),
+    ("collaberated", "collaborated"),
+    ("colleage", "colleague"),
+    ("comittee", "committee"),
+    ("commited", "committed"),
+    ("communciation", "communication"),
+    ("comunication", "communication"),
+    ("competant", "competent"),
+    ("completly", "completely"),
+    ("concious", "conscious"),
+    ("consistant", "consistent"),
+    ("coordinatted", "coordinated"),
+    ("curiculum", "curriculum"),
+    ("definately", "definitely"),
+    ("desicion", "decision"),
+    ("develope", "develop"),
+    ("developement", "development"),
+    ("developped", "developed"),
+    ("diffrent", "different"),
+    ("efficency", "efficiency"),
+    ("efficent", "efficient"),
+    ("embarass", "embarrass"),
+    ("enviroment", "environment"),
+    ("enviroments", "environments"),
+    ("equiptment", "equipment"),
+    ("excercise", "exercise"),
+    ("existance", "existence"),
+    ("experiance", "experience"),
+    ("experienceing", "experiencing"),
+    ("expierence", "experience"),
+    ("familar", "familiar"),
+    ("finacial", "financial"),
+    ("foward", "forward"),
+    ("goverment", "government"),
+    ("gaurantee", "guarantee"),
+    ("guidence", "guidance"),
+    ("harrass", "harass"),
+    ("immediatly", "immediately"),
+    ("implemention", "implementation"),
+    ("improvment", "improvement"),
+    ("improvments", "improvements"),
+    ("independant", "independent"),
+    ("infomation", "information"),
+    ("initative", "initiative"),
+    ("inteligence", "intelligence"),
+    ("intergrated", "integrated"),
+    ("intergration", "integration"),
+    ("knowlege", "knowledge"),
+    ("liason", "liaison"),
+    ("maintainance", "maintenance"),
+    ("maintenence", "maintenance"),
+    ("managment", "management"),
+    ("mangement", "management"),
+    ("millenium", "millennium"),
+    ("neccessary", "necessary"),
+    ("necesary", "necessary"),
+    ("negociate", "negotiate"),
+    ("noticable", "noticeable"),
+    ("occassion", "occasion"),
+    ("occured", "occurred"),
+    ("occurence", "occurrence"),
+    ("oppurtunity", "opportunity"),
+    ("oportunity", "opportunity"),
+    ("organisaton", "organization"),
+    ("organizaton", "organization"),
+    ("particularily", "particularly"),
+    ("performace", "performance"),
+    ("persistant", "persistent"),
+    ("posession", "possession"),
+    ("preformance", "performance"),
+    ("priviledge", "privilege"),
+    ("proffesional", "professional"),
+    ("profesional", "professional"),
+    ("programing", "programming"),
+    ("publically", "publicly"),
+    ("reccomend", "recommend"),
+    ("recieve", "receive"),
+    ("recieved", "received"),
+    ("recomend", "recommend"),
+    ("relevent", "relevant"),
+    ("repetative", "repetitive"),
+    ("requirment", "requirement"),
+    ("requirments", "requirements"),
+    ("resonsible", "responsible"),
+    ("responsibilites", "responsibilities"),
+    ("responsable", "responsible"),
+    ("seperate", "separate"),
+    ("seperated", "separated"),
+    ("sucessful", "successful"),
+    ("sucessfully", "successfully"),
+    ("succesful", "successful"),
+    ("succesfully", "successfully"),
+    ("supervisior", "supervisor"),
+    ("tecnical", "technical"),
+    ("technicial", "technical"),
+    ("teh", "the"),
+    ("tommorow", "tomorrow"),
+    ("transfered", "transferred"),
+    ("truely", "truly"),
+    ("untill", "until"),
+    ("writting", "writing"),
+];
+
+static MISSPELLINGS: LazyLock<HashMap<&'static str, &'static str>> =
+    LazyLock::new(|| COMMON_MISSPELLINGS.iter().copied().collect());
+
+static DICTIONARY: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    ENGLISH_WORDS
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .chain(TECH_WORDS.iter().copied())
+        .collect()
+});
+
+/// Whether `word` (lowercase) is in the bundled word list or
+/// [`TECH_WORDS`].
+///
+/// Without the `english-dictionary` feature only [`TECH_WORDS`] are known.
+#[must_use]
+pub fn is_known_word(word: &str) -> bool {
+    DICTIONARY.contains(word)
+}
+
+/// A word flagged as misspelled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Misspelling {
+    /// The word as written.
+    pub word: String,
+    /// Suggested correction, for known typos.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+/// Find unrecognized words in `text`, one entry per occurrence.
+///
+/// Words are compared case-insensitively; any word in `allowlist`
+/// (lowercase) is skipped, as are the proper nouns, acronyms and symbols
+/// described in the [module docs](self). Without the bundled word list
+/// ([`BUNDLED_DICTIONARY`]), only known typos are reported.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::spelling::{find_misspellings, BUNDLED_DICTIONARY};
+/// use std::collections::HashSet;
+///
+/// let found = find_misspellings(
+///     "Recieved an award for managment of Globex's custmers",
+///     &HashSet::new(),
+/// );
+/// let words: Vec<_> = found.iter().map(|m| m.word.as_str()).collect();
+/// assert_eq!(found[0].suggestion.as_deref(), Some("received"));
+/// if BUNDLED_DICTIONARY {
+///     assert_eq!(words, ["Recieved", "managment", "custmers"]);
+///     assert_eq!(found[2].suggestion, None);
+/// } else {
+///     assert_eq!(words, ["Recieved", "managment"]);
+/// }
+/// ```
+#[must_use]
+pub fn find_misspellings<S: std::hash::BuildHasher>(
+    text: &str,
+    allowlist: &HashSet<String, S>,
+) -> Vec<Misspelling> {
+    words(text)
+        .filter(|word| word.checkable)
+        .filter_map(|Word { text: word, .. }| {
+            let lower = word.to_lowercase();
+            let stem = lower.strip_suffix("'s").unwrap_or(&lower);
+            if allowlist.contains(&lower) || allowlist.contains(stem) {
+                return None;
+            }
+            let suggestion = MISSPELLINGS.get(stem).map(|s| (*s).to_string());
+            let unrecognized = if BUNDLED_DICTIONARY {
+                !is_known_word(stem)
+            } else {
+                suggestion.is_some()
+            };
+            unrecognized.then(|| Misspelling {
+                word: word.to_string(),
+                suggestion,
+            })
+        })
+        .collect()
+}
+
+/// Number of words in `text`, counted the same way [`find_misspellings`]
+/// splits it.
+#[must_use]
+pub fn word_count(text: &str) -> usize {
+    words(text).count()
+}
+
+/// A word of text, and whether the spelling check applies to it.
+struct Word<'a> {
+    text: &'a str,
+    checkable: bool,
+}
+
+fn words(text: &str) -> impl Iterator<Item = Word<'_>> {
+    text.lines().flat_map(|line| {
+        let mut sentence_start = true;
+        line.split(|c: char| c.is_whitespace() || c == '/')
+            .filter(|token| !token.is_empty())
+            .flat_map(move |token| {
+                let starts_sentence = sentence_start;
+                sentence_start = token.ends_with(['.', '!', '?', ':', ';']);
+
+                let trimmed = token.trim_matches(|c: char| !c.is_alphanumeric());
+                // Digits or symbols inside a token mark code, versions or
+                // links rather than prose
+                let symbolic = trimmed
+                    .chars()
+                    .any(|c| !(c.is_alphabetic() || c == '\'' || c == '-'));
+                trimmed
+                    .split('-')
+                    .filter(|part| !part.is_empty())
+                    .enumerate()
+                    .map(move |(i, part)| {
+                        let part = part.trim_matches('\'');
+                        Word {
+                            text: part,
+                            checkable: !symbolic
+                                && part.chars().count() > 1
+                                && is_plain_word(part, starts_sentence && i == 0),
+                        }
+                    })
+                    .filter(|word| !word.text.is_empty())
+            })
+    })
+}
+
+/// Lowercase, or capitalized at the start of a sentence.
+fn is_plain_word(word: &str, starts_sentence: bool) -> bool {
+    let mut chars = word.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    let rest_lower = chars.all(|c| !c.is_uppercase());
+    rest_lower && (first.is_lowercase() || starts_sentence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_misspellings_respects_allowlist() {
+        let allowlist = HashSet::from(["managment".to_string()]);
+        let found = find_misspellings("Managment of the seperate teams", &allowlist);
+
+        assert_eq!(
+            found,
+            vec![Misspelling {
+                word: "seperate".to_string(),
+                suggestion: Some("separate".to_string()),
+            }]
+        );
+        assert_eq!(word_count("Managment of the seperate teams"), 5);
+    }
+
+    #[test]
+    fn test_proper_nouns_and_jargon_are_skipped() {
+        let text = "Migrated Globex billing to Kubernetes on AWS and GraphQL.\n\
+                    Cut p99 latency by 40% with K8s autoscaling, serverless \
+                    webhooks and cross-functional stakeholders' runbooks";
+
+        assert_eq!(find_misspellings(text, &HashSet::new()), vec![]);
+
+        // Capitalized at the start of a sentence, the word is still checked
+        let found = find_misspellings("Shipped it. Teh team grew", &HashSet::new());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].word, "Teh");
+    }
+}
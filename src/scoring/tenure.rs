@@ -0,0 +1,406 @@
+//! Employment date parsing and tenure analysis.
+//!
+//! Experience entries carry their dates in many shapes: a `duration` such as
+//! "2020-Present" or "Jan 2019 – Mar 2021", or separate `start_date` /
+//! `end_date` fields. This module parses them into inclusive month ranges,
+//! totals the months worked (counting overlapping roles once) and finds gaps
+//! between roles.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::LazyLock;
+
+/// Gaps between roles shorter than this many months are not reported.
+pub const DEFAULT_GAP_MONTHS: u32 = 6;
+
+/// A calendar month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct YearMonth {
+    /// Calendar year.
+    pub year: i32,
+    /// Month of the year, 1-12.
+    pub month: u32,
+}
+
+impl YearMonth {
+    /// Create a year-month. `month` is clamped to 1-12.
+    pub fn new(year: i32, month: u32) -> Self {
+        Self {
+            year,
+            month: month.clamp(1, 12),
+        }
+    }
+
+    /// The current month in UTC.
+    pub fn current() -> Self {
+        use chrono::Datelike;
+        let today = chrono::Utc::now().date_naive();
+        Self::new(today.year(), today.month())
+    }
+
+    /// Months elapsed since year 0, for arithmetic.
+    fn index(self) -> i64 {
+        i64::from(self.year) * 12 + i64::from(self.month) - 1
+    }
+
+    /// Number of months from `self` to `later` (negative if `later` is
+    /// earlier).
+    pub fn months_until(self, later: Self) -> i64 {
+        later.index() - self.index()
+    }
+}
+
+impl fmt::Display for YearMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
+}
+
+/// An inclusive range of months, e.g. Jan 2019 through Mar 2021.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonthRange {
+    /// First month worked.
+    pub start: YearMonth,
+    /// Last month worked.
+    pub end: YearMonth,
+}
+
+impl MonthRange {
+    /// Number of months covered, counting both ends.
+    pub fn months(&self) -> u32 {
+        u32::try_from(self.start.months_until(self.end) + 1).unwrap_or(0)
+    }
+}
+
+/// A stretch of months between two roles with no employment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmploymentGap {
+    /// Last month of the earlier role.
+    pub after: YearMonth,
+    /// First month of the next role.
+    pub before: YearMonth,
+    /// Months without a role.
+    pub months: u32,
+}
+
+/// Tenure figures for a resume's experience section.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Tenure {
+    /// Date ranges of the entries whose dates could be parsed.
+    pub ranges: Vec<MonthRange>,
+    /// Months worked, counting overlapping roles once.
+    pub total_months: u32,
+    /// Gaps of at least the requested length, oldest first.
+    pub gaps: Vec<EmploymentGap>,
+}
+
+impl Tenure {
+    /// Average months per dated role (0 when no role is dated).
+    pub fn average_months(&self) -> f64 {
+        if self.ranges.is_empty() {
+            return 0.0;
+        }
+        let sum: u32 = self.ranges.iter().map(MonthRange::months).sum();
+        f64::from(sum) / self.ranges.len() as f64
+    }
+}
+
+// -------------------------
+// Parsing
+// -------------------------
+
+/// A date token: "Jan 2019", "01/2019", "2019-01", "2019" or "Present".
+static DATE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?ix)
+        \b(?P<mon>jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?
+            |sep(?:t(?:ember)?)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\.?,?\s+(?P<mon_year>\d{4})\b
+        | \b(?P<num_month>\d{1,2})/(?P<num_year>\d{4})\b
+        | \b(?P<iso_year>\d{4})[-/](?P<iso_month>\d{1,2})\b
+        | \b(?P<year>\d{4})\b
+        | \b(?P<now>present|current|now|today|ongoing)\b",
+    )
+    .unwrap()
+});
+
+/// Years outside this range are treated as noise (e.g. "team of 1500").
+const YEAR_RANGE: std::ops::RangeInclusive<i32> = 1950..=2100;
+
+/// A parsed date token. Year-only dates cover the whole year, so they open a
+/// range in January and close it in December.
+#[derive(Debug, Clone, Copy)]
+enum DateToken {
+    Month(YearMonth),
+    Year(i32),
+    Present,
+}
+
+impl DateToken {
+    fn as_start(self, today: YearMonth) -> YearMonth {
+        match self {
+            Self::Month(month) => month,
+            Self::Year(year) => YearMonth::new(year, 1),
+            Self::Present => today,
+        }
+    }
+
+    fn as_end(self, today: YearMonth) -> YearMonth {
+        match self {
+            Self::Month(month) => month,
+            Self::Year(year) => YearMonth::new(year, 12),
+            Self::Present => today,
+        }
+    }
+}
+
+fn month_number(name: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let name = name.to_lowercase();
+    MONTHS
+        .iter()
+        .position(|m| name.starts_with(m))
+        .and_then(|i| u32::try_from(i + 1).ok())
+}
+
+fn date_tokens(text: &str) -> Vec<DateToken> {
+    let year = |s: &str| s.parse::<i32>().ok().filter(|y| YEAR_RANGE.contains(y));
+    let month = |s: &str| s.parse::<u32>().ok().filter(|m| (1..=12).contains(m));
+
+    DATE_RE
+        .captures_iter(text)
+        .filter_map(|caps| {
+            if let (Some(mon), Some(y)) = (caps.name("mon"), caps.name("mon_year")) {
+                return Some(DateToken::Month(YearMonth::new(
+                    year(y.as_str())?,
+                    month_number(mon.as_str())?,
+                )));
+            }
+            if let (Some(m), Some(y)) = (caps.name("num_month"), caps.name("num_year")) {
+                return Some(DateToken::Month(YearMonth::new(
+                    year(y.as_str())?,
+                    month(m.as_str())?,
+                )));
+            }
+            if let (Some(y), Some(m)) = (caps.name("iso_year"), caps.name("iso_month")) {
+                return Some(DateToken::Month(YearMonth::new(
+                    year(y.as_str())?,
+                    month(m.as_str())?,
+                )));
+            }
+            if let Some(y) = caps.name("year") {
+                return Some(DateToken::Year(year(y.as_str())?));
+            }
+            caps.name("now").map(|_| DateToken::Present)
+        })
+        .collect()
+}
+
+/// Parse a single date such as "Mar 2021", "03/2021", "2021-03" or "2021".
+///
+/// Year-only dates resolve to January; "Present" and similar resolve to
+/// `today`.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::tenure::{parse_date, YearMonth};
+///
+/// let today = YearMonth::new(2024, 6);
+/// assert_eq!(parse_date("Sept. 2019", today), Some(YearMonth::new(2019, 9)));
+/// assert_eq!(parse_date("2019-09", today), Some(YearMonth::new(2019, 9)));
+/// assert_eq!(parse_date("Present", today), Some(today));
+/// assert_eq!(parse_date("n/a", today), None);
+/// ```
+pub fn parse_date(text: &str, today: YearMonth) -> Option<YearMonth> {
+    date_tokens(text).first().map(|token| token.as_start(today))
+}
+
+/// Parse a date range such as "2020-Present", "Jan 2019 – Mar 2021" or
+/// "2016 to 2018".
+///
+/// The first date opens the range and the second closes it. A lone date
+/// covers just that month (or that whole year, for a year-only date).
+/// Reversed ranges are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::tenure::{parse_range, YearMonth};
+///
+/// let today = YearMonth::new(2024, 6);
+/// let range = parse_range("Jan 2019 – Mar 2021", today).unwrap();
+/// assert_eq!(range.months(), 27);
+///
+/// let range = parse_range("2020-Present", today).unwrap();
+/// assert_eq!((range.start, range.end), (YearMonth::new(2020, 1), today));
+/// ```
+pub fn parse_range(text: &str, today: YearMonth) -> Option<MonthRange> {
+    let tokens = date_tokens(text);
+    let start = tokens.first()?;
+    let end = tokens.get(1).unwrap_or(start);
+    let range = MonthRange {
+        start: start.as_start(today),
+        end: end.as_end(today),
+    };
+    (range.start <= range.end).then_some(range)
+}
+
+/// Date range of one experience entry.
+///
+/// Reads `duration` (or `dates`/`date`), else `start_date`/`end_date` (also
+/// `start`/`end` and camelCase `startDate`/`endDate`). A start date without
+/// an end date is taken to be the current role.
+pub fn entry_range(entry: &serde_json::Value, today: YearMonth) -> Option<MonthRange> {
+    let field = |keys: &[&str]| {
+        keys.iter()
+            .filter_map(|key| entry.get(*key).and_then(serde_json::Value::as_str))
+            .map(str::trim)
+            .find(|s| !s.is_empty())
+    };
+
+    if let Some(duration) = field(&["duration", "dates", "date"]) {
+        if let Some(range) = parse_range(duration, today) {
+            return Some(range);
+        }
+    }
+
+    let start = date_tokens(field(&["start_date", "start", "startDate"])?)
+        .first()?
+        .as_start(today);
+    let end = match field(&["end_date", "end", "endDate"]) {
+        Some(end) => date_tokens(end).first()?.as_end(today),
+        None => today,
+    };
+    (start <= end).then_some(MonthRange { start, end })
+}
+
+// -------------------------
+// Analysis
+// -------------------------
+
+/// Months covered by `ranges`, counting overlapping months once.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::tenure::{parse_range, total_months, YearMonth};
+///
+/// let today = YearMonth::new(2024, 6);
+/// let ranges = [
+///     parse_range("Jan 2018 - Dec 2019", today).unwrap(),
+///     parse_range("Jun 2019 - Dec 2020", today).unwrap(),
+/// ];
+/// assert_eq!(total_months(&ranges), 36);
+/// ```
+pub fn total_months(ranges: &[MonthRange]) -> u32 {
+    merge_ranges(ranges).iter().map(MonthRange::months).sum()
+}
+
+/// Gaps of at least `min_months` between the roles in `ranges`, oldest
+/// first. Overlapping and back-to-back roles leave no gap.
+pub fn find_gaps(ranges: &[MonthRange], min_months: u32) -> Vec<EmploymentGap> {
+    merge_ranges(ranges)
+        .windows(2)
+        .filter_map(|pair| {
+            let months = u32::try_from(pair[0].end.months_until(pair[1].start) - 1).ok()?;
+            (months > 0 && months >= min_months).then_some(EmploymentGap {
+                after: pair[0].end,
+                before: pair[1].start,
+                months,
+            })
+        })
+        .collect()
+}
+
+/// Parse the dates of every experience entry in `resume` and compute its
+/// tenure, reporting gaps of at least `min_gap_months`.
+pub fn resume_tenure(resume: &serde_json::Value, today: YearMonth, min_gap_months: u32) -> Tenure {
+    let ranges: Vec<MonthRange> = resume
+        .get("experience")
+        .and_then(serde_json::Value::as_array)
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| entry_range(entry, today))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Tenure {
+        total_months: total_months(&ranges),
+        gaps: find_gaps(&ranges, min_gap_months),
+        ranges,
+    }
+}
+
+/// Sort ranges and merge overlapping or adjacent ones.
+fn merge_ranges(ranges: &[MonthRange]) -> Vec<MonthRange> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_by_key(|r| (r.start, r.end));
+
+    let mut merged: Vec<MonthRange> = Vec::with_capacity(sorted.len());
+    for range in sorted {
+        match merged.last_mut() {
+            Some(last) if last.end.months_until(range.start) <= 1 => {
+                last.end = last.end.max(range.end);
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TODAY: YearMonth = YearMonth {
+        year: 2024,
+        month: 6,
+    };
+
+    #[test]
+    fn test_merge_and_gaps() {
+        let ranges = [
+            parse_range("2015 - 2016", TODAY).unwrap(),
+            parse_range("Jan 2017 - Mar 2018", TODAY).unwrap(),
+            parse_range("Jan 2019 - Present", TODAY).unwrap(),
+        ];
+        // 2015-2016 and 2017 are back to back; 2018-04..2018-12 is a gap
+        let gaps = find_gaps(&ranges, DEFAULT_GAP_MONTHS);
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].after, YearMonth::new(2018, 3));
+        assert_eq!(gaps[0].before, YearMonth::new(2019, 1));
+        assert_eq!(gaps[0].months, 9);
+
+        assert!(find_gaps(&ranges, 12).is_empty());
+    }
+
+    #[test]
+    fn test_reversed_and_noise() {
+        assert!(parse_range("2021 - 2019", TODAY).is_none());
+        assert!(parse_range("Led a team of 12", TODAY).is_none());
+        assert_eq!(parse_date("13/2020", TODAY), None);
+    }
+
+    #[test]
+    fn test_month_names_must_be_spelled_out() {
+        assert_eq!(
+            parse_date("June 2019", TODAY),
+            Some(YearMonth::new(2019, 6))
+        );
+        assert_eq!(parse_date("Sep 2019", TODAY), Some(YearMonth::new(2019, 9)));
+        // "Junior" is not June; only the year is read
+        assert_eq!(
+            parse_date("Junior 2019", TODAY),
+            Some(YearMonth::new(2019, 1))
+        );
+        assert_eq!(
+            parse_date("Mayor 2019", TODAY),
+            Some(YearMonth::new(2019, 1))
+        );
+    }
+}
@@ -0,0 +1,655 @@
+//! Text helpers used by the scoring heuristics.
+//!
+//! These functions are deterministic and allocation-light so they can be reused
+//! for custom scoring experiments on top of this crate.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ats_checker::scoring::text::{extract_keywords, looks_like_action_bullet};
+//!
+//! let keywords = extract_keywords("Senior Rust engineer with Go experience");
+//! assert!(keywords.contains("rust"));
+//! assert!(keywords.contains("go"));
+//! assert!(!keywords.contains("with"));
+//!
+//! assert!(looks_like_action_bullet("Built a billing service in Rust"));
+//! ```
+
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+use unicode_segmentation::UnicodeSegmentation;
+
+const EMAIL_PATTERN: &str = r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}";
+
+static EMAIL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^{EMAIL_PATTERN}$")).unwrap());
+
+/// Email addresses anywhere in a block of text.
+pub(crate) static EMAIL_IN_TEXT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(EMAIL_PATTERN).unwrap());
+
+static PROFILE_URL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:linkedin\.com/(?:in|pub)|github\.com)/[A-Za-z0-9_-]+").unwrap()
+});
+
+static YEARS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(\d{1,2})\s*\+?\s*(?:years?|yrs?)\b").unwrap());
+
+/// Fewest digits accepted in a phone number (a local number without area code).
+const MIN_PHONE_DIGITS: usize = 7;
+
+/// Most digits accepted in a phone number (the E.164 maximum).
+const MAX_PHONE_DIGITS: usize = 15;
+
+/// Split text into lowercased tokens on non-alphanumeric characters (keeping + and #).
+pub(crate) fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '+' && c != '#')
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+// -------------------------
+// Tokenizers
+// -------------------------
+
+/// Splits text into lowercased word tokens for keyword extraction.
+///
+/// Implementations must keep `+` and `#` suffixes on words so `C++` and `C#`
+/// survive as tokens.
+pub trait Tokenizer: std::fmt::Debug + Send + Sync {
+    /// Lowercased tokens of `text`, in order.
+    fn tokenize(&self, text: &str) -> Vec<String>;
+}
+
+/// The built-in tokenizer: splits on every character that is not
+/// alphanumeric, `+` or `#`.
+///
+/// Fast and fine for English, but it splits words written with combining
+/// accents (`e` + U+0301) and keeps runs of CJK characters as one token.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::{DefaultTokenizer, Tokenizer};
+///
+/// assert_eq!(DefaultTokenizer.tokenize("Rust/C++ dev"), ["rust", "c++", "dev"]);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultTokenizer;
+
+impl Tokenizer for DefaultTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        tokenize(text)
+    }
+}
+
+/// Tokenizer following Unicode word boundaries (UAX #29).
+///
+/// Keeps accented words whole however they are encoded, splits CJK text into
+/// individual words, and keeps apostrophes inside words (`don't`,
+/// `l'équipe`). Runs of `+` or `#` directly after a word are attached to it.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::{Tokenizer, UnicodeTokenizer};
+///
+/// // "é" written as "e" plus a combining acute accent
+/// let tokens = UnicodeTokenizer.tokenize("Inge\u{301}nieur C++");
+/// assert_eq!(tokens, ["inge\u{301}nieur", "c++"]);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnicodeTokenizer;
+
+impl Tokenizer for UnicodeTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        let mut tokens: Vec<String> = Vec::new();
+        let mut after_word = false;
+        for segment in text.split_word_bounds() {
+            if segment.chars().any(char::is_alphanumeric) {
+                tokens.push(segment.to_lowercase());
+                after_word = true;
+            } else if after_word && segment.chars().all(|c| c == '+' || c == '#') {
+                if let Some(last) = tokens.last_mut() {
+                    last.push_str(segment);
+                }
+            } else {
+                after_word = false;
+            }
+        }
+        tokens
+    }
+}
+
+/// Extract the set of lowercased keywords from text using the built-in stopword list.
+///
+/// Text is split on any character that is not alphanumeric, `+` or `#`, so
+/// `C++` and `C#` survive as tokens. Tokens of two characters or fewer are
+/// dropped unless they are well-known technical terms (`go`, `ai`, `ml`, ...).
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::extract_keywords;
+///
+/// let keywords = extract_keywords("Rust developer with C++ and Go; 5 yrs in ML");
+/// assert!(keywords.contains("rust"));
+/// assert!(keywords.contains("c++"));
+/// assert!(keywords.contains("go"));
+/// assert!(keywords.contains("ml"));
+/// assert!(keywords.contains("yrs"));
+/// assert!(!keywords.contains("and")); // stopword
+/// assert!(!keywords.contains("5")); // too short
+/// ```
+pub fn extract_keywords(text: &str) -> HashSet<String> {
+    extract_keywords_with_stopwords(text, &default_stopwords())
+}
+
+/// Extract the set of lowercased keywords from text using a custom stopword set.
+///
+/// Stopwords must already be lowercased (as returned by
+/// [`load_stopwords`](crate::scoring::load_stopwords)).
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::{default_stopwords, extract_keywords_with_stopwords};
+///
+/// let mut stopwords = default_stopwords();
+/// stopwords.insert("various".to_string());
+///
+/// let keywords = extract_keywords_with_stopwords("Various Kubernetes clusters", &stopwords);
+/// assert!(!keywords.contains("various"));
+/// assert!(keywords.contains("kubernetes"));
+/// assert!(keywords.contains("clusters"));
+/// ```
+pub fn extract_keywords_with_stopwords<S: std::hash::BuildHasher>(
+    text: &str,
+    stopwords: &HashSet<String, S>,
+) -> HashSet<String> {
+    extract_keywords_with(text, stopwords, &DefaultTokenizer)
+}
+
+/// Extract the set of lowercased keywords from text with a custom stopword
+/// set and tokenizer.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::{default_stopwords, extract_keywords_with, UnicodeTokenizer};
+///
+/// let keywords =
+///     extract_keywords_with("Développeur à Montréal", &default_stopwords(), &UnicodeTokenizer);
+/// assert!(keywords.contains("développeur"));
+/// assert!(keywords.contains("montréal"));
+/// ```
+pub fn extract_keywords_with<S: std::hash::BuildHasher>(
+    text: &str,
+    stopwords: &HashSet<String, S>,
+    tokenizer: &dyn Tokenizer,
+) -> HashSet<String> {
+    let tokens = tokenizer.tokenize(text);
+
+    let mut keywords = HashSet::new();
+
+    for lower in tokens {
+        if stopwords.contains(&lower) {
+            continue;
+        }
+
+        // Filter out very short tokens except known technical terms
+        if lower.len() <= 2 {
+            if matches!(
+                lower.as_str(),
+                "c" | "go" | "ai" | "ml" | "ui" | "ux" | "qa" | "c#" | "c++"
+            ) {
+                keywords.insert(lower);
+            }
+            continue;
+        }
+
+        keywords.insert(lower);
+    }
+
+    keywords
+}
+
+/// Check whether a bullet starts with a recognized action verb.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::looks_like_action_bullet;
+///
+/// assert!(looks_like_action_bullet("  Led a team of five engineers"));
+/// assert!(looks_like_action_bullet("Migrated billing to Postgres"));
+/// assert!(!looks_like_action_bullet("Responsible for the billing system"));
+/// assert!(!looks_like_action_bullet(""));
+/// ```
+pub fn looks_like_action_bullet(bullet: &str) -> bool {
+    let b = bullet.trim().to_lowercase();
+    if b.is_empty() {
+        return false;
+    }
+
+    let first_word = b.split_whitespace().next().unwrap_or("");
+
+    if action_verbs().contains(&first_word) {
+        return true;
+    }
+
+    for verb in action_verbs() {
+        if b.starts_with(&format!("{verb} ")) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Check whether text contains any numeric character (a quantification signal).
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::contains_number;
+///
+/// assert!(contains_number("Cut p99 latency by 40%"));
+/// assert!(!contains_number("Cut latency significantly"));
+/// ```
+pub fn contains_number(s: &str) -> bool {
+    // Match digits with optional decimal and % sign
+    s.chars().any(char::is_numeric)
+}
+
+/// Check whether text mentions an outcome (e.g. "reduced", "revenue", "latency").
+///
+/// Matching is case-insensitive substring matching.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::contains_outcome_language;
+///
+/// assert!(contains_outcome_language("Reduced cloud COST for the team"));
+/// assert!(!contains_outcome_language("Attended weekly meetings"));
+/// ```
+pub fn contains_outcome_language(s: &str) -> bool {
+    let lower = s.to_lowercase();
+    outcome_markers()
+        .iter()
+        .any(|&marker| lower.contains(marker))
+}
+
+/// Check whether a string has the shape of an email address.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::is_valid_email;
+///
+/// assert!(is_valid_email("john.doe@example.com"));
+/// assert!(!is_valid_email("john at example"));
+/// assert!(!is_valid_email("see LinkedIn"));
+/// ```
+pub fn is_valid_email(s: &str) -> bool {
+    EMAIL_RE.is_match(s.trim())
+}
+
+/// Check whether a string looks like a phone number: only digits and common
+/// separators, with 7 to 15 digits.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::is_valid_phone;
+///
+/// assert!(is_valid_phone("+1 (555) 123-4567"));
+/// assert!(!is_valid_phone("555-12"));
+/// assert!(!is_valid_phone("call me"));
+/// ```
+pub fn is_valid_phone(s: &str) -> bool {
+    let s = s.trim();
+    let allowed = |c: char| c.is_ascii_digit() || " +-().x/".contains(c);
+    let digits = s.chars().filter(char::is_ascii_digit).count();
+    s.chars().all(allowed) && (MIN_PHONE_DIGITS..=MAX_PHONE_DIGITS).contains(&digits)
+}
+
+/// Check whether text contains a linkedin.com or github.com profile URL.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::contains_profile_url;
+///
+/// assert!(contains_profile_url("linkedin.com/in/johndoe"));
+/// assert!(contains_profile_url("https://github.com/johndoe"));
+/// assert!(!contains_profile_url("https://example.com/johndoe"));
+/// ```
+pub fn contains_profile_url(s: &str) -> bool {
+    PROFILE_URL_RE.is_match(s)
+}
+
+/// Career level implied by a job title or years of experience, lowest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SeniorityLevel {
+    /// Interns, graduates and junior roles.
+    Junior,
+    /// Titles without a level keyword.
+    Mid,
+    /// Senior roles.
+    Senior,
+    /// Staff and lead roles.
+    Lead,
+    /// Principal, architect and director roles.
+    Principal,
+}
+
+impl SeniorityLevel {
+    /// Level typical for the given years of experience.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ats_checker::scoring::text::SeniorityLevel;
+    ///
+    /// assert_eq!(SeniorityLevel::from_years(1), SeniorityLevel::Junior);
+    /// assert_eq!(SeniorityLevel::from_years(6), SeniorityLevel::Senior);
+    /// ```
+    pub fn from_years(years: u32) -> Self {
+        match years {
+            0..=1 => Self::Junior,
+            2..=4 => Self::Mid,
+            5..=7 => Self::Senior,
+            8..=11 => Self::Lead,
+            _ => Self::Principal,
+        }
+    }
+
+    /// Number of levels between `self` and `other`.
+    pub fn gap(self, other: Self) -> u32 {
+        (self as u32).abs_diff(other as u32)
+    }
+
+    /// Lowercase name of the level.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Junior => "junior",
+            Self::Mid => "mid",
+            Self::Senior => "senior",
+            Self::Lead => "lead",
+            Self::Principal => "principal",
+        }
+    }
+}
+
+impl std::fmt::Display for SeniorityLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Infer the seniority level named in a job title.
+///
+/// Returns the highest level whose keyword appears in the title ("Senior
+/// Staff Engineer" is [`Lead`](SeniorityLevel::Lead)), or `None` if the title
+/// has no level keyword.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::{seniority_from_title, SeniorityLevel};
+///
+/// assert_eq!(seniority_from_title("Sr. Backend Engineer"), Some(SeniorityLevel::Senior));
+/// assert_eq!(seniority_from_title("Junior Developer"), Some(SeniorityLevel::Junior));
+/// assert_eq!(seniority_from_title("Software Engineer"), None);
+/// ```
+pub fn seniority_from_title(title: &str) -> Option<SeniorityLevel> {
+    tokenize(title)
+        .iter()
+        .filter_map(|token| {
+            seniority_keywords()
+                .iter()
+                .find(|(keyword, _)| keyword == token)
+                .map(|&(_, level)| level)
+        })
+        .max()
+}
+
+/// Extract the largest "N years" / "N+ yrs" figure mentioned in text.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::years_of_experience;
+///
+/// assert_eq!(years_of_experience("Engineer with 7+ years of experience"), Some(7));
+/// assert_eq!(years_of_experience("3 yrs Rust, 5 years Go"), Some(5));
+/// assert_eq!(years_of_experience("Recent graduate"), None);
+/// ```
+pub fn years_of_experience(text: &str) -> Option<u32> {
+    YEARS_RE
+        .captures_iter(text)
+        .filter_map(|caps| caps[1].parse().ok())
+        .max()
+}
+
+/// The built-in stopword set used by [`extract_keywords`].
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::default_stopwords;
+///
+/// let stopwords = default_stopwords();
+/// assert!(stopwords.contains("the"));
+/// assert!(!stopwords.contains("rust"));
+/// ```
+pub fn default_stopwords() -> HashSet<String> {
+    stopwords().iter().map(|&sw| sw.to_string()).collect()
+}
+
+/// Find filler phrases (clichés such as "team player") in text.
+///
+/// Matching is case-insensitive on whole tokens, so "Team-player" matches
+/// `team player` but "steam player" does not. Each occurrence is returned,
+/// in the order the phrases are given.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::text::{default_filler_phrases, find_filler_phrases};
+///
+/// let found = find_filler_phrases(
+///     "Detail-oriented team player and hard worker",
+///     &default_filler_phrases(),
+/// );
+/// assert_eq!(found, vec!["team player", "detail-oriented", "hard worker"]);
+/// ```
+pub fn find_filler_phrases<S: AsRef<str>>(text: &str, phrases: &[S]) -> Vec<String> {
+    let tokens = tokenize(text);
+    let mut found = Vec::new();
+
+    for phrase in phrases {
+        let phrase = phrase.as_ref();
+        let needle = tokenize(phrase);
+        if needle.is_empty() || needle.len() > tokens.len() {
+            continue;
+        }
+        let occurrences = tokens
+            .windows(needle.len())
+            .filter(|w| *w == needle.as_slice())
+            .count();
+        found.extend(std::iter::repeat_n(phrase.to_string(), occurrences));
+    }
+
+    found
+}
+
+/// The built-in filler phrase list used by resume impact scoring.
+pub fn default_filler_phrases() -> Vec<String> {
+    filler_phrases().iter().map(|&p| p.to_string()).collect()
+}
+
+fn stopwords() -> &'static [&'static str] {
+    &[
+        "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "has", "have", "he",
+        "in", "is", "it", "its", "of", "on", "or", "that", "the", "their", "they", "this", "to",
+        "was", "were", "will", "with", "you", "your", "we", "our", "us",
+    ]
+}
+
+fn filler_phrases() -> &'static [&'static str] {
+    &[
+        "team player",
+        "detail-oriented",
+        "hard worker",
+        "hardworking",
+        "self-starter",
+        "go-getter",
+        "results-driven",
+        "think outside the box",
+        "synergy",
+        "dynamic individual",
+        "highly motivated",
+        "strong work ethic",
+        "proven track record",
+        "excellent communication skills",
+        "fast learner",
+        "passionate about",
+    ]
+}
+
+fn seniority_keywords() -> &'static [(&'static str, SeniorityLevel)] {
+    use SeniorityLevel::{Junior, Lead, Mid, Principal, Senior};
+    &[
+        ("intern", Junior),
+        ("junior", Junior),
+        ("jr", Junior),
+        ("entry", Junior),
+        ("graduate", Junior),
+        ("trainee", Junior),
+        ("apprentice", Junior),
+        ("mid", Mid),
+        ("intermediate", Mid),
+        ("senior", Senior),
+        ("sr", Senior),
+        ("staff", Lead),
+        ("lead", Lead),
+        ("principal", Principal),
+        ("distinguished", Principal),
+        ("architect", Principal),
+        ("director", Principal),
+        ("head", Principal),
+    ]
+}
+
+fn action_verbs() -> &'static [&'static str] {
+    &[
+        "built",
+        "created",
+        "designed",
+        "developed",
+        "delivered",
+        "implemented",
+        "improved",
+        "increased",
+        "reduced",
+        "optimized",
+        "automated",
+        "led",
+        "managed",
+        "owned",
+        "shipped",
+        "launched",
+        "migrated",
+        "refactored",
+        "collaborated",
+        "analyzed",
+        "architected",
+        "tested",
+        "deployed",
+    ]
+}
+
+fn outcome_markers() -> &'static [&'static str] {
+    &[
+        "improved",
+        "increased",
+        "reduced",
+        "decreased",
+        "accelerated",
+        "saved",
+        "cut",
+        "boosted",
+        "grew",
+        "optimized",
+        "revenue",
+        "cost",
+        "latency",
+        "throughput",
+        "uptime",
+        "performance",
+        "efficiency",
+        "scalability",
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_keywords() {
+        let text = "Rust developer with C++ and Python experience";
+        let keywords = extract_keywords(text);
+
+        assert!(keywords.contains("rust"));
+        assert!(keywords.contains("developer"));
+        assert!(keywords.contains("c++"));
+        assert!(keywords.contains("python"));
+        assert!(keywords.contains("experience"));
+        assert!(!keywords.contains("with")); // stopword
+    }
+
+    #[test]
+    fn test_looks_like_action_bullet() {
+        assert!(looks_like_action_bullet("Built a scalable system"));
+        assert!(looks_like_action_bullet("Implemented new features"));
+        assert!(!looks_like_action_bullet("Responsible for the project"));
+    }
+
+    #[test]
+    fn test_contains_number() {
+        assert!(contains_number("Improved performance by 50%"));
+        assert!(contains_number("Processed 1000 requests"));
+        assert!(!contains_number("No numbers here"));
+    }
+
+    #[test]
+    fn test_seniority_from_title() {
+        assert_eq!(
+            seniority_from_title("Senior Staff Engineer"),
+            Some(SeniorityLevel::Lead)
+        );
+        assert_eq!(
+            seniority_from_title("Tech Lead, Payments"),
+            Some(SeniorityLevel::Lead)
+        );
+        assert_eq!(
+            seniority_from_title("Principal Architect"),
+            Some(SeniorityLevel::Principal)
+        );
+        // Whole tokens only: "seniority" is not "senior"
+        assert_eq!(seniority_from_title("Seniority Analyst"), None);
+        assert_eq!(SeniorityLevel::Junior.gap(SeniorityLevel::Lead), 3);
+    }
+
+    #[test]
+    fn test_tokenize_keeps_plus_and_hash() {
+        assert_eq!(tokenize("C#/C++, Go!"), vec!["c#", "c++", "go"]);
+    }
+}
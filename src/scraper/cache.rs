@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
 use crate::error::{AtsError, Result};
-use crate::scraper::{JobPosting, JobScraper, SearchFilters};
+use crate::scraper::{JobPage, JobPosting, JobScraper, SearchFilters};
 
 /// Configuration for cache behavior.
 #[derive(Debug, Clone)]
@@ -278,6 +278,17 @@ impl<S: JobScraper + Send + Sync> JobScraper for CacheWrapper<S> {
         Ok(jobs)
     }
 
+    async fn search_jobs_paged(
+        &self,
+        filters: &SearchFilters,
+        page: u32,
+        per_page: i32,
+    ) -> Result<JobPage> {
+        // Pages are not cached; a cached full search can't say whether
+        // more pages exist
+        self.inner.search_jobs_paged(filters, page, per_page).await
+    }
+
     async fn get_job_details(&self, job_url: &str) -> Result<Option<JobPosting>> {
         // For now, don't cache individual job details
         // This could be added later if needed
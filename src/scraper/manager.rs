@@ -1,10 +1,19 @@
 //! Job scraper manager implementation.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 
+use serde::{Deserialize, Serialize};
+
 use crate::error::{AtsError, Result};
-use crate::scraper::{JobPosting, JobScraper, SearchFilters};
+use crate::scraper::rate_limit::RateLimiter;
+use crate::scraper::sanitize::sanitize_posting;
+use crate::scraper::types::NEW_POSTING_KEY;
+use crate::scraper::{
+    JobPage, JobPosting, JobScraper, RateLimit, ResultsFormat, SavedSearch, SavedSearchManager,
+    SearchFilters, SearchMetadata, SearchResults,
+};
+use crate::toml_io;
 
 /// Manages job scraping across multiple sources.
 ///
@@ -17,8 +26,16 @@ pub struct JobScraperManager {
     saved_searches_path: PathBuf,
     /// Registered scrapers.
     scrapers: HashMap<String, Box<dyn JobScraper>>,
+    /// Optional request pacing.
+    rate_limiter: Option<RateLimiter>,
+    /// Descriptions shorter than this many characters are enriched.
+    enrich_min_chars: usize,
 }
 
+/// Default description length, in characters, below which
+/// [`JobScraperManager::enrich_jobs`] fetches the full posting.
+pub const DEFAULT_ENRICH_MIN_CHARS: usize = 500;
+
 impl JobScraperManager {
     /// Create a new job scraper manager.
     ///
@@ -49,9 +66,33 @@ impl JobScraperManager {
             results_folder,
             saved_searches_path,
             scrapers: HashMap::new(),
+            rate_limiter: None,
+            enrich_min_chars: DEFAULT_ENRICH_MIN_CHARS,
         })
     }
 
+    /// Set the description length below which
+    /// [`enrich_jobs`](Self::enrich_jobs) fetches the full posting.
+    #[must_use]
+    pub fn with_enrich_threshold(mut self, min_chars: usize) -> Self {
+        self.enrich_min_chars = min_chars;
+        self
+    }
+
+    /// Pace requests to sources with a token-bucket rate limit.
+    #[must_use]
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limiter = Some(RateLimiter::new(rate_limit));
+        self
+    }
+
+    /// Wait for the rate limiter, if any, before a request to `source`.
+    async fn pace(&self, source: &str) {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire(source).await;
+        }
+    }
+
     /// Register a job scraper.
     pub fn register_scraper(&mut self, scraper: Box<dyn JobScraper>) {
         let name = scraper.name().to_string();
@@ -73,7 +114,9 @@ impl JobScraperManager {
     ///
     /// # Returns
     ///
-    /// A vector of job postings, deduplicated by URL.
+    /// A vector of job postings, cleaned with [`sanitize_posting`] and
+    /// deduplicated by URL. Postings left without a title or description are
+    /// dropped.
     ///
     /// # Errors
     ///
@@ -84,37 +127,254 @@ impl JobScraperManager {
         sources: &[&str],
         max_results: i32,
     ) -> Result<Vec<JobPosting>> {
+        let mut by_source = self
+            .search_jobs_by_source(filters, sources, max_results)
+            .await;
         let mut all_jobs = Vec::new();
         let mut seen_urls = std::collections::HashSet::new();
 
         for source in sources {
-            if let Some(scraper) = self.scrapers.get(*source) {
-                match scraper.search_jobs(filters, max_results).await {
-                    Ok(jobs) => {
-                        for job in jobs {
-                            if seen_urls.insert(job.url.clone()) {
-                                all_jobs.push(job);
-                            }
+            match by_source.remove(*source) {
+                Some(Ok(jobs)) => {
+                    for job in jobs {
+                        if seen_urls.insert(job.url.clone()) {
+                            all_jobs.push(job);
                         }
                     }
-                    Err(e) => {
-                        log::warn!("Failed to search {source}: {e}");
-                    }
                 }
-            } else {
-                log::warn!("Unknown job source: {source}");
+                Some(Err(e)) => {
+                    log::warn!("Failed to search {source}: {e}");
+                }
+                // Repeated source name, already merged
+                None => {}
             }
         }
 
         Ok(all_jobs)
     }
 
+    /// Search several sources concurrently, keeping each source's results
+    /// separate.
+    ///
+    /// Every source is searched at the same time (still subject to the rate
+    /// limit). Postings are cleaned with [`sanitize_posting`] and filtered by
+    /// posting date, but not deduplicated across sources. A failing or
+    /// unknown source only affects its own entry in the map.
+    ///
+    /// # Returns
+    ///
+    /// A map from source name to that source's postings or error, with one
+    /// entry per distinct name in `sources`.
+    pub async fn search_jobs_by_source(
+        &self,
+        filters: &SearchFilters,
+        sources: &[&str],
+        max_results: i32,
+    ) -> HashMap<String, Result<Vec<JobPosting>>> {
+        let names: BTreeSet<&str> = sources.iter().copied().collect();
+        let searches = names.into_iter().map(|source| async move {
+            let result = match self.scrapers.get(source) {
+                Some(scraper) => {
+                    self.pace(source).await;
+                    scraper
+                        .search_jobs(filters, max_results)
+                        .await
+                        .map(|mut jobs| {
+                            jobs.retain_mut(sanitize_posting);
+                            retain_date_posted(&mut jobs, filters, scraper.as_ref());
+                            jobs
+                        })
+                }
+                None => Err(AtsError::Scraper {
+                    message: format!("Unknown job source: {source}"),
+                    source_name: Some(source.to_string()),
+                }),
+            };
+            (source.to_string(), result)
+        });
+
+        futures::future::join_all(searches)
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Collect results from one source page by page.
+    ///
+    /// Pages are requested from the source's
+    /// [`JobScraper::search_jobs_paged`] until `max_results` postings have
+    /// been collected, the source reports no more pages, or the source stops
+    /// making progress: a page comes back empty or with no new URLs, or the
+    /// next page is one already requested. Postings are deduplicated by URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source is not registered or a page request fails.
+    pub async fn search_jobs_paged(
+        &self,
+        filters: &SearchFilters,
+        source: &str,
+        max_results: i32,
+        per_page: i32,
+    ) -> Result<Vec<JobPosting>> {
+        let scraper = self.scrapers.get(source).ok_or_else(|| AtsError::Scraper {
+            message: format!("Unknown job source: {source}"),
+            source_name: Some(source.to_string()),
+        })?;
+
+        let limit = usize::try_from(max_results).unwrap_or(0);
+        let mut jobs = Vec::new();
+        let mut seen_urls = std::collections::HashSet::new();
+        let mut requested_pages = std::collections::HashSet::new();
+        let mut page = 1;
+
+        while jobs.len() < limit {
+            requested_pages.insert(page);
+            self.pace(source).await;
+            let JobPage {
+                jobs: mut page_jobs,
+                has_more,
+                next_page,
+            } = scraper.search_jobs_paged(filters, page, per_page).await?;
+            log::debug!("{source}: page {page} returned {} jobs", page_jobs.len());
+
+            if page_jobs.is_empty() {
+                break;
+            }
+            page_jobs.retain_mut(sanitize_posting);
+            page_jobs.retain(|job| seen_urls.insert(job.url.clone()));
+            if page_jobs.is_empty() {
+                log::warn!("{source}: page {page} returned no new jobs, stopping");
+                break;
+            }
+            retain_date_posted(&mut page_jobs, filters, scraper.as_ref());
+            jobs.extend(page_jobs);
+
+            if !has_more {
+                break;
+            }
+            page = next_page.unwrap_or(page + 1);
+            if requested_pages.contains(&page) {
+                log::warn!("{source}: next page {page} was already requested, stopping");
+                break;
+            }
+        }
+
+        jobs.truncate(limit);
+        Ok(jobs)
+    }
+
+    /// Run every saved search and group the results by search.
+    ///
+    /// Searches run in name order, each with its own filters, sources and
+    /// `max_results`. Postings whose URL was not returned by any earlier run
+    /// are flagged (see [`JobPosting::is_new`]); seen URLs are tracked in
+    /// [`SEEN_JOBS_FILE`] in the results folder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the seen-jobs state file cannot be read or written.
+    pub async fn run_saved_searches(
+        &self,
+        saved: &SavedSearchManager,
+    ) -> Result<Vec<(SavedSearch, Vec<JobPosting>)>> {
+        let state_path = self.results_folder.join(SEEN_JOBS_FILE);
+        let mut state: SeenJobs = if state_path.exists() {
+            toml_io::load_as(&state_path)?
+        } else {
+            SeenJobs::default()
+        };
+
+        let mut names = saved.list();
+        names.sort_unstable();
+
+        let mut grouped = Vec::with_capacity(names.len());
+        let mut found = BTreeSet::new();
+        for search in names.into_iter().filter_map(|name| saved.get(name)) {
+            let sources: Vec<&str> = search.sources.iter().map(String::as_str).collect();
+            let jobs = self
+                .search_jobs(&search.filters, &sources, search.max_results)
+                .await?
+                .into_iter()
+                .map(|job| {
+                    let is_new = !state.urls.contains(&job.url);
+                    found.insert(job.url.clone());
+                    job.with_metadata(NEW_POSTING_KEY, serde_json::Value::Bool(is_new))
+                })
+                .collect();
+            grouped.push((search.clone(), jobs));
+        }
+
+        state.urls.extend(found);
+        toml_io::dump_as(&state, &state_path)?;
+
+        Ok(grouped)
+    }
+
+    /// Fetch full descriptions for postings whose description looks
+    /// truncated.
+    ///
+    /// Each posting with a description shorter than the enrich threshold (see
+    /// [`with_enrich_threshold`](Self::with_enrich_threshold)) is looked up
+    /// with its source scraper's [`JobScraper::get_job_details`]. A longer
+    /// description replaces the short one, and details the posting lacks
+    /// (salary, posted date, job type, experience level) are filled in.
+    ///
+    /// Detail requests are paced by the manager's rate limit, or by
+    /// [`RateLimit::default`] when none is set. Failed lookups are logged and
+    /// leave the posting unchanged. Returns the number of postings enriched.
+    pub async fn enrich_jobs(&self, jobs: &mut [JobPosting]) -> usize {
+        let fallback;
+        let limiter = if let Some(limiter) = &self.rate_limiter {
+            limiter
+        } else {
+            fallback = RateLimiter::new(RateLimit::default());
+            &fallback
+        };
+
+        let mut enriched = 0;
+        for job in jobs.iter_mut() {
+            if job.url.is_empty() || job.description.chars().count() >= self.enrich_min_chars {
+                continue;
+            }
+            let Some(scraper) = self.scrapers.get(&job.source) else {
+                continue;
+            };
+
+            limiter.acquire(&job.source).await;
+            let details = match scraper.get_job_details(&job.url).await {
+                Ok(Some(details)) => details,
+                Ok(None) => continue,
+                Err(e) => {
+                    log::warn!("Failed to fetch details for {}: {e}", job.url);
+                    continue;
+                }
+            };
+
+            if details.description.chars().count() > job.description.chars().count() {
+                job.description = details.description;
+                enriched += 1;
+            }
+            job.salary = job.salary.take().or(details.salary);
+            job.posted_date = job.posted_date.take().or(details.posted_date);
+            job.job_type = job.job_type.take().or(details.job_type);
+            job.experience_level = job.experience_level.take().or(details.experience_level);
+        }
+
+        enriched
+    }
+
     /// Save job search results to a file.
     ///
+    /// The file extension is set to match `format`, so the saved file can be
+    /// read back with [`load_results`](Self::load_results).
+    ///
     /// # Arguments
     ///
     /// * `results` - Job postings to save
     /// * `filename` - Name of the output file (relative to results folder)
+    /// * `format` - Output format
+    /// * `metadata` - Description of the search that produced the results
     ///
     /// # Returns
     ///
@@ -123,25 +383,29 @@ impl JobScraperManager {
     /// # Errors
     ///
     /// Returns an error if the file cannot be written or serialization fails.
-    pub fn save_results(&self, results: &[JobPosting], filename: &str) -> Result<PathBuf> {
-        let path = self.results_folder.join(filename);
+    pub fn save_results(
+        &self,
+        results: &[JobPosting],
+        filename: &str,
+        format: ResultsFormat,
+        metadata: &SearchMetadata,
+    ) -> Result<PathBuf> {
+        let path = self
+            .results_folder
+            .join(filename)
+            .with_extension(format.extension());
 
-        // Determine format from extension
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("toml");
+        let file = SearchResults {
+            metadata: Some(metadata.clone()),
+            jobs: results.to_vec(),
+        };
 
-        if ext == "json" {
-            let json = serde_json::to_string_pretty(results)?;
-            std::fs::write(&path, json)?;
-        } else {
-            // Default to TOML
-            let wrapper = ResultsWrapper {
-                jobs: results.to_vec(),
-            };
-            let toml_str = toml::to_string_pretty(&wrapper).map_err(|e| AtsError::TomlParse {
-                message: e.to_string(),
-                source: None,
-            })?;
-            std::fs::write(&path, toml_str)?;
+        match format {
+            ResultsFormat::Json => {
+                let json = serde_json::to_string_pretty(&file)?;
+                std::fs::write(&path, json)?;
+            }
+            ResultsFormat::Toml => toml_io::dump_as(&file, &path)?,
         }
 
         Ok(path)
@@ -149,22 +413,14 @@ impl JobScraperManager {
 
     /// Load job search results from a file.
     ///
+    /// The format is inferred from the file extension. Use
+    /// [`load_search_results`] to also read the search metadata.
+    ///
     /// # Errors
     ///
     /// Returns an error if the file cannot be read or deserialization fails.
     pub fn load_results(&self, path: impl AsRef<Path>) -> Result<Vec<JobPosting>> {
-        let path = path.as_ref();
-        let content = std::fs::read_to_string(path)?;
-
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("toml");
-
-        if ext == "json" {
-            let jobs: Vec<JobPosting> = serde_json::from_str(&content)?;
-            Ok(jobs)
-        } else {
-            let wrapper: ResultsWrapper = toml::from_str(&content)?;
-            Ok(wrapper.jobs)
-        }
+        Ok(load_search_results(path)?.jobs)
     }
 
     /// Rank jobs in a results file by job score.
@@ -275,13 +531,44 @@ impl JobScraperManager {
     }
 }
 
-/// Wrapper for TOML serialization of job results.
-#[derive(Debug, Serialize, Deserialize)]
-struct ResultsWrapper {
-    jobs: Vec<JobPosting>,
+/// File in the results folder recording posting URLs seen by saved-search runs.
+pub const SEEN_JOBS_FILE: &str = "seen_jobs.toml";
+
+/// Posting URLs returned by earlier saved-search runs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SeenJobs {
+    #[serde(default)]
+    urls: BTreeSet<String>,
 }
 
-use serde::{Deserialize, Serialize};
+/// Load job search results and their search metadata from a file.
+///
+/// The format is inferred from the file extension (`.json` for JSON,
+/// anything else for TOML). JSON files holding a bare array of postings are
+/// also accepted.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or deserialization fails.
+pub fn load_search_results(path: impl AsRef<Path>) -> Result<SearchResults> {
+    let path = path.as_ref();
+
+    match ResultsFormat::from_path(path) {
+        ResultsFormat::Json => {
+            let content = std::fs::read_to_string(path)?;
+            let value: serde_json::Value = serde_json::from_str(&content)?;
+            if value.is_array() {
+                Ok(SearchResults {
+                    metadata: None,
+                    jobs: serde_json::from_value(value)?,
+                })
+            } else {
+                Ok(serde_json::from_value(value)?)
+            }
+        }
+        ResultsFormat::Toml => toml_io::load_as(path),
+    }
+}
 
 /// A ranked job entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -294,11 +581,198 @@ pub struct RankedJob {
     pub job: JobPosting,
 }
 
+/// Drop postings older than the `date_posted` filter, unless `scraper`
+/// already filtered by date itself.
+fn retain_date_posted(
+    jobs: &mut Vec<JobPosting>,
+    filters: &SearchFilters,
+    scraper: &dyn JobScraper,
+) {
+    if scraper.filters_date_posted() {
+        return;
+    }
+    let Some(filter) = filters.date_posted_filter() else {
+        return;
+    };
+
+    let now = chrono::Utc::now();
+    let before = jobs.len();
+    jobs.retain(|job| filter.accepts(job, now));
+    if jobs.len() < before {
+        log::debug!(
+            "{}: dropped {} postings outside date filter {filter}",
+            scraper.name(),
+            before - jobs.len()
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scraper::DatePosted;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
     use tempfile::tempdir;
 
+    /// Serves `pages` pages of `per_page` jobs each.
+    struct PagedScraper {
+        pages: u32,
+        requests: Arc<AtomicU32>,
+    }
+
+    #[async_trait]
+    impl JobScraper for PagedScraper {
+        fn name(&self) -> &'static str {
+            "paged"
+        }
+
+        async fn search_jobs(
+            &self,
+            _filters: &SearchFilters,
+            _max_results: i32,
+        ) -> Result<Vec<JobPosting>> {
+            unreachable!("pagination should not fall back to search_jobs")
+        }
+
+        async fn search_jobs_paged(
+            &self,
+            _filters: &SearchFilters,
+            page: u32,
+            per_page: i32,
+        ) -> Result<JobPage> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            let jobs = (0..per_page)
+                .map(|i| {
+                    let url = format!("https://jobs.example.com/{page}/{i}");
+                    JobPosting::new("Engineer", "Co", "SF", "Desc", url, "paged")
+                })
+                .collect();
+            Ok(JobPage {
+                jobs,
+                has_more: page < self.pages,
+                next_page: (page < self.pages).then_some(page + 1),
+            })
+        }
+
+        async fn get_job_details(&self, _job_url: &str) -> Result<Option<JobPosting>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_jobs_paged_walks_pages() {
+        let dir = tempdir().unwrap();
+        let mut manager =
+            JobScraperManager::new(dir.path().join("results"), dir.path().join("saved.toml"))
+                .unwrap();
+        let requests = Arc::new(AtomicU32::new(0));
+        manager.register_scraper(Box::new(PagedScraper {
+            pages: 2,
+            requests: Arc::clone(&requests),
+        }));
+        let filters = SearchFilters::new();
+
+        // Stops when the source runs out of pages
+        let jobs = manager
+            .search_jobs_paged(&filters, "paged", 50, 3)
+            .await
+            .unwrap();
+        assert_eq!(jobs.len(), 6);
+        assert_eq!(jobs[3].url, "https://jobs.example.com/2/0");
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        // Stops once max_results is reached
+        requests.store(0, Ordering::SeqCst);
+        let jobs = manager
+            .search_jobs_paged(&filters, "paged", 2, 3)
+            .await
+            .unwrap();
+        assert_eq!(jobs.len(), 2);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert!(manager
+            .search_jobs_paged(&filters, "missing", 10, 3)
+            .await
+            .is_err());
+    }
+
+    /// Claims more pages forever but keeps serving the same jobs, optionally
+    /// always pointing back at page 1.
+    struct StuckScraper {
+        repeat_page: bool,
+        requests: Arc<AtomicU32>,
+    }
+
+    #[async_trait]
+    impl JobScraper for StuckScraper {
+        fn name(&self) -> &'static str {
+            "stuck"
+        }
+
+        async fn search_jobs(
+            &self,
+            _filters: &SearchFilters,
+            _max_results: i32,
+        ) -> Result<Vec<JobPosting>> {
+            unreachable!("pagination should not fall back to search_jobs")
+        }
+
+        async fn search_jobs_paged(
+            &self,
+            _filters: &SearchFilters,
+            page: u32,
+            _per_page: i32,
+        ) -> Result<JobPage> {
+            let request = self.requests.fetch_add(1, Ordering::SeqCst);
+            // A repeated page number gets fresh URLs, so only the page check stops it
+            let url = if self.repeat_page {
+                format!("https://jobs.example.com/{request}")
+            } else {
+                "https://jobs.example.com/same".to_string()
+            };
+            Ok(JobPage {
+                jobs: vec![JobPosting::new(
+                    "Engineer", "Co", "SF", "Desc", url, "stuck",
+                )],
+                has_more: true,
+                next_page: Some(if self.repeat_page { 1 } else { page + 1 }),
+            })
+        }
+
+        async fn get_job_details(&self, _job_url: &str) -> Result<Option<JobPosting>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_jobs_paged_stops_without_progress() {
+        let dir = tempdir().unwrap();
+        let filters = SearchFilters::new();
+
+        for (repeat_page, expected_requests) in [(false, 2), (true, 1)] {
+            let mut manager =
+                JobScraperManager::new(dir.path().join("results"), dir.path().join("saved.toml"))
+                    .unwrap();
+            let requests = Arc::new(AtomicU32::new(0));
+            manager.register_scraper(Box::new(StuckScraper {
+                repeat_page,
+                requests: Arc::clone(&requests),
+            }));
+
+            let jobs = manager
+                .search_jobs_paged(&filters, "stuck", 50, 10)
+                .await
+                .unwrap();
+
+            assert_eq!(jobs.len(), 1);
+            assert_eq!(requests.load(Ordering::SeqCst), expected_requests);
+        }
+    }
+
     #[test]
     fn test_manager_creation() {
         let dir = tempdir().unwrap();
@@ -322,13 +796,49 @@ mod tests {
             JobPosting::new("Developer", "Inc", "NY", "Desc2", "url2", "indeed"),
         ];
 
-        let path = manager.save_results(&jobs, "test.toml").unwrap();
+        let metadata = SearchMetadata::new(SearchFilters::default(), vec!["linkedin".into()]);
+        let path = manager
+            .save_results(&jobs, "test.toml", ResultsFormat::Toml, &metadata)
+            .unwrap();
         let loaded = manager.load_results(&path).unwrap();
 
         assert_eq!(loaded.len(), 2);
         assert_eq!(loaded[0].title, "Engineer");
     }
 
+    #[test]
+    fn test_save_results_sets_format_extension() {
+        let dir = tempdir().unwrap();
+        let manager =
+            JobScraperManager::new(dir.path().join("results"), dir.path().join("saved.toml"))
+                .unwrap();
+
+        let jobs = vec![JobPosting::new(
+            "Engineer", "Co", "SF", "Desc", "url1", "linkedin",
+        )];
+        let metadata = SearchMetadata::new(SearchFilters::default(), Vec::new());
+
+        let path = manager
+            .save_results(&jobs, "results.toml", ResultsFormat::Json, &metadata)
+            .unwrap();
+        assert_eq!(path.extension().unwrap(), "json");
+        assert_eq!(manager.load_results(&path).unwrap(), jobs);
+    }
+
+    #[test]
+    fn test_load_legacy_json_array() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("legacy.json");
+        let jobs = vec![JobPosting::new(
+            "Engineer", "Co", "SF", "Desc", "url1", "indeed",
+        )];
+        std::fs::write(&path, serde_json::to_string(&jobs).unwrap()).unwrap();
+
+        let loaded = load_search_results(&path).unwrap();
+        assert!(loaded.metadata.is_none());
+        assert_eq!(loaded.jobs, jobs);
+    }
+
     #[test]
     fn test_rank_jobs() {
         let dir = tempdir().unwrap();
@@ -342,7 +852,10 @@ mod tests {
             JobPosting::new("C", "Co", "SF", "Desc", "url3", "src").with_score(65.0),
         ];
 
-        let path = manager.save_results(&jobs, "rank_test.toml").unwrap();
+        let metadata = SearchMetadata::new(SearchFilters::default(), Vec::new());
+        let path = manager
+            .save_results(&jobs, "rank_test.toml", ResultsFormat::Toml, &metadata)
+            .unwrap();
         let ranked = manager.rank_jobs_in_results(&path, 2, false).unwrap();
 
         assert_eq!(ranked.len(), 2);
@@ -350,4 +863,461 @@ mod tests {
         assert_eq!(ranked[0].rank, 1);
         assert_eq!(ranked[1].job.title, "C"); // Second highest
     }
+
+    /// Records the (tokio) time of every request.
+    struct TimedScraper {
+        name: &'static str,
+        calls: Arc<std::sync::Mutex<Vec<(&'static str, tokio::time::Instant)>>>,
+    }
+
+    #[async_trait]
+    impl JobScraper for TimedScraper {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn search_jobs(
+            &self,
+            _filters: &SearchFilters,
+            _max_results: i32,
+        ) -> Result<Vec<JobPosting>> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((self.name, tokio::time::Instant::now()));
+            Ok(Vec::new())
+        }
+
+        async fn get_job_details(&self, _job_url: &str) -> Result<Option<JobPosting>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limit_spaces_requests() {
+        let dir = tempdir().unwrap();
+        let mut manager =
+            JobScraperManager::new(dir.path().join("results"), dir.path().join("saved.toml"))
+                .unwrap()
+                .with_rate_limit(RateLimit::per_minute(20));
+        let calls = Arc::new(std::sync::Mutex::new(Vec::new()));
+        for name in ["a", "b"] {
+            manager.register_scraper(Box::new(TimedScraper {
+                name,
+                calls: Arc::clone(&calls),
+            }));
+        }
+        let filters = SearchFilters::new();
+
+        for _ in 0..3 {
+            manager
+                .search_jobs(&filters, &["a", "b"], 10)
+                .await
+                .unwrap();
+        }
+
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls.len(), 6);
+        let interval = Duration::from_secs(3);
+        for source in ["a", "b"] {
+            let times: Vec<_> = calls
+                .iter()
+                .filter(|(name, _)| *name == source)
+                .map(|(_, at)| *at)
+                .collect();
+            for pair in times.windows(2) {
+                assert!(pair[1] - pair[0] >= interval);
+            }
+        }
+        // Per-source buckets: "b" does not wait behind "a"
+        assert!(calls[1].1 - calls[0].1 < interval);
+    }
+
+    /// Returns short summaries from search and the full text from details.
+    struct SummaryScraper {
+        detail_calls: Arc<std::sync::Mutex<Vec<(String, tokio::time::Instant)>>>,
+    }
+
+    const FULL_DESCRIPTION: &str = "We are hiring a backend engineer to build and operate \
+        Rust services for our billing platform, working with PostgreSQL and Kafka.";
+
+    #[async_trait]
+    impl JobScraper for SummaryScraper {
+        fn name(&self) -> &'static str {
+            "summary"
+        }
+
+        async fn search_jobs(
+            &self,
+            _filters: &SearchFilters,
+            _max_results: i32,
+        ) -> Result<Vec<JobPosting>> {
+            Ok(vec![
+                JobPosting::new(
+                    "Backend Engineer",
+                    "Acme",
+                    "Remote",
+                    "We are hiring a backend engineer...",
+                    "https://jobs.example.com/1",
+                    "summary",
+                ),
+                JobPosting::new(
+                    "Platform Engineer",
+                    "Acme",
+                    "Remote",
+                    FULL_DESCRIPTION,
+                    "https://jobs.example.com/2",
+                    "summary",
+                ),
+            ])
+        }
+
+        async fn get_job_details(&self, job_url: &str) -> Result<Option<JobPosting>> {
+            self.detail_calls
+                .lock()
+                .unwrap()
+                .push((job_url.to_string(), tokio::time::Instant::now()));
+            let mut job = JobPosting::new(
+                "Backend Engineer",
+                "Acme",
+                "Remote",
+                FULL_DESCRIPTION,
+                job_url,
+                "summary",
+            );
+            job.salary = Some("$150k".to_string());
+            Ok(Some(job))
+        }
+    }
+
+    /// Returns postings with markup, tracking URLs and an empty title.
+    struct MessyScraper;
+
+    #[async_trait]
+    impl JobScraper for MessyScraper {
+        fn name(&self) -> &'static str {
+            "messy"
+        }
+
+        async fn search_jobs(
+            &self,
+            _filters: &SearchFilters,
+            _max_results: i32,
+        ) -> Result<Vec<JobPosting>> {
+            Ok(vec![
+                JobPosting::new(
+                    "Data &amp; ML Engineer",
+                    "Acme",
+                    "Remote",
+                    "<p>Own our <b>data</b> pipelines &amp; models.</p><p>Show more</p>",
+                    "https://jobs.example.com/1?utm_campaign=feed",
+                    "messy",
+                ),
+                // Same posting without tracking: a duplicate once normalized
+                JobPosting::new(
+                    "Data & ML Engineer",
+                    "Acme",
+                    "Remote",
+                    "Own our data pipelines & models.",
+                    "https://jobs.example.com/1",
+                    "messy",
+                ),
+                JobPosting::new(
+                    "  ",
+                    "Acme",
+                    "Remote",
+                    "Navigation only",
+                    "https://jobs.example.com/2",
+                    "messy",
+                ),
+            ])
+        }
+
+        async fn get_job_details(&self, _job_url: &str) -> Result<Option<JobPosting>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_jobs_sanitizes_postings() {
+        let dir = tempdir().unwrap();
+        let mut manager =
+            JobScraperManager::new(dir.path().join("results"), dir.path().join("saved.toml"))
+                .unwrap();
+        manager.register_scraper(Box::new(MessyScraper));
+
+        let jobs = manager
+            .search_jobs(&SearchFilters::new(), &["messy"], 10)
+            .await
+            .unwrap();
+
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].title, "Data & ML Engineer");
+        assert_eq!(jobs[0].description, "Own our data pipelines & models.");
+        assert_eq!(jobs[0].url, "https://jobs.example.com/1");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_enrich_jobs_fetches_full_descriptions() {
+        let dir = tempdir().unwrap();
+        let mut manager =
+            JobScraperManager::new(dir.path().join("results"), dir.path().join("saved.toml"))
+                .unwrap()
+                .with_rate_limit(RateLimit::per_minute(20))
+                .with_enrich_threshold(100);
+        let detail_calls = Arc::new(std::sync::Mutex::new(Vec::new()));
+        manager.register_scraper(Box::new(SummaryScraper {
+            detail_calls: Arc::clone(&detail_calls),
+        }));
+
+        let mut jobs = manager
+            .search_jobs(&SearchFilters::new(), &["summary"], 10)
+            .await
+            .unwrap();
+        jobs.push(jobs[0].clone());
+        jobs[2].url = "https://jobs.example.com/3".to_string();
+
+        let enriched = manager.enrich_jobs(&mut jobs).await;
+
+        assert_eq!(enriched, 2);
+        assert_eq!(jobs[0].description, FULL_DESCRIPTION);
+        assert_eq!(jobs[0].salary.as_deref(), Some("$150k"));
+        // Already complete: no detail request
+        assert!(jobs[1].salary.is_none());
+
+        let calls = detail_calls.lock().unwrap();
+        let urls: Vec<&str> = calls.iter().map(|(url, _)| url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec!["https://jobs.example.com/1", "https://jobs.example.com/3"]
+        );
+        // Detail requests are paced like searches
+        assert!(calls[1].1 - calls[0].1 >= Duration::from_secs(3));
+    }
+
+    /// Returns a fixed list of postings.
+    struct FixedScraper {
+        name: &'static str,
+        urls: Vec<&'static str>,
+    }
+
+    #[async_trait]
+    impl JobScraper for FixedScraper {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn search_jobs(
+            &self,
+            _filters: &SearchFilters,
+            _max_results: i32,
+        ) -> Result<Vec<JobPosting>> {
+            Ok(self
+                .urls
+                .iter()
+                .map(|url| JobPosting::new("Engineer", "Co", "SF", "Desc", *url, self.name))
+                .collect())
+        }
+
+        async fn get_job_details(&self, _job_url: &str) -> Result<Option<JobPosting>> {
+            Ok(None)
+        }
+    }
+
+    /// Fails every search.
+    struct FailingScraper;
+
+    #[async_trait]
+    impl JobScraper for FailingScraper {
+        fn name(&self) -> &'static str {
+            "failing"
+        }
+
+        async fn search_jobs(
+            &self,
+            _filters: &SearchFilters,
+            _max_results: i32,
+        ) -> Result<Vec<JobPosting>> {
+            Err(AtsError::Scraper {
+                message: "board unavailable".to_string(),
+                source_name: Some("failing".to_string()),
+            })
+        }
+
+        async fn get_job_details(&self, _job_url: &str) -> Result<Option<JobPosting>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_jobs_by_source_isolates_failures() {
+        let dir = tempdir().unwrap();
+        let mut manager =
+            JobScraperManager::new(dir.path().join("results"), dir.path().join("saved.toml"))
+                .unwrap();
+        manager.register_scraper(Box::new(FixedScraper {
+            name: "fixed",
+            urls: vec!["https://jobs.example.com/1", "https://jobs.example.com/2"],
+        }));
+        manager.register_scraper(Box::new(FailingScraper));
+        let filters = SearchFilters::new();
+
+        let results = manager
+            .search_jobs_by_source(&filters, &["fixed", "failing"], 10)
+            .await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results["fixed"].as_ref().unwrap().len(), 2);
+        assert!(matches!(results["failing"], Err(AtsError::Scraper { .. })));
+
+        // The merged search keeps the working source's postings
+        let merged = manager
+            .search_jobs(&filters, &["failing", "fixed"], 10)
+            .await
+            .unwrap();
+        assert_eq!(merged.len(), 2);
+    }
+
+    /// Returns postings with fixed posting dates.
+    struct DatedScraper {
+        name: &'static str,
+        native_date_filter: bool,
+    }
+
+    #[async_trait]
+    impl JobScraper for DatedScraper {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn filters_date_posted(&self) -> bool {
+            self.native_date_filter
+        }
+
+        async fn search_jobs(
+            &self,
+            _filters: &SearchFilters,
+            _max_results: i32,
+        ) -> Result<Vec<JobPosting>> {
+            let job = |id: &str| {
+                JobPosting::new(
+                    "Engineer",
+                    "Co",
+                    "SF",
+                    "Desc",
+                    format!("https://{}.example.com/{id}", self.name),
+                    self.name,
+                )
+            };
+            Ok(vec![
+                job("old").with_posted_date("2024-02-20"),
+                job("boundary").with_posted_date("2024-03-01"),
+                job("recent").with_posted_date("2024-03-10T09:30:00Z"),
+                job("undated"),
+            ])
+        }
+
+        async fn get_job_details(&self, _job_url: &str) -> Result<Option<JobPosting>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_jobs_post_filters_by_since_date() {
+        let dir = tempdir().unwrap();
+        let mut manager =
+            JobScraperManager::new(dir.path(), dir.path().join("saved.toml")).unwrap();
+        manager.register_scraper(Box::new(DatedScraper {
+            name: "plain",
+            native_date_filter: false,
+        }));
+        manager.register_scraper(Box::new(DatedScraper {
+            name: "native",
+            native_date_filter: true,
+        }));
+
+        let since = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let filters = SearchFilters::builder()
+            .since(DatePosted::SinceDate(since))
+            .build();
+
+        let jobs = manager.search_jobs(&filters, &["plain"], 10).await.unwrap();
+        let urls: Vec<_> = jobs.iter().map(|job| job.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://plain.example.com/boundary",
+                "https://plain.example.com/recent",
+                "https://plain.example.com/undated",
+            ]
+        );
+
+        // Scrapers that filter natively are trusted as-is
+        let jobs = manager
+            .search_jobs(&filters, &["native"], 10)
+            .await
+            .unwrap();
+        assert_eq!(jobs.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_run_saved_searches_flags_new_postings() {
+        let dir = tempdir().unwrap();
+        let saved_path = dir.path().join("saved.toml");
+        let mut manager = JobScraperManager::new(dir.path().join("results"), &saved_path).unwrap();
+        manager.register_scraper(Box::new(FixedScraper {
+            name: "alpha",
+            urls: vec!["https://a.example.com/1", "https://a.example.com/2"],
+        }));
+        manager.register_scraper(Box::new(FixedScraper {
+            name: "beta",
+            urls: vec!["https://b.example.com/1"],
+        }));
+
+        let mut saved = SavedSearchManager::new(&saved_path).unwrap();
+        saved
+            .save(SavedSearch::new(
+                "rust",
+                SearchFilters::builder().keywords("rust").build(),
+                vec!["alpha".into()],
+            ))
+            .unwrap();
+        saved
+            .save(SavedSearch::new(
+                "go",
+                SearchFilters::builder().keywords("go").build(),
+                vec!["beta".into()],
+            ))
+            .unwrap();
+
+        let first = manager.run_saved_searches(&saved).await.unwrap();
+        let names: Vec<_> = first.iter().map(|(s, _)| s.name.as_str()).collect();
+        assert_eq!(names, ["go", "rust"]);
+        assert_eq!(first[0].1.len(), 1);
+        assert_eq!(first[1].1.len(), 2);
+        assert!(first
+            .iter()
+            .flat_map(|(_, jobs)| jobs)
+            .all(JobPosting::is_new));
+
+        // A new posting appears for one search between runs
+        manager.register_scraper(Box::new(FixedScraper {
+            name: "alpha",
+            urls: vec!["https://a.example.com/1", "https://a.example.com/3"],
+        }));
+        let second = manager.run_saved_searches(&saved).await.unwrap();
+        assert!(!second[0].1[0].is_new());
+        let rust: Vec<_> = second[1]
+            .1
+            .iter()
+            .map(|job| (job.url.as_str(), job.is_new()))
+            .collect();
+        assert_eq!(
+            rust,
+            [
+                ("https://a.example.com/1", false),
+                ("https://a.example.com/3", true)
+            ]
+        );
+    }
 }
@@ -7,6 +7,7 @@
 //!
 //! - [`JobPosting`] - Represents a scraped job posting
 //! - [`SearchFilters`] - Filters for job searches
+//! - [`SalaryRange`] - A posting's salary parsed by [`parse_salary`]
 //! - [`SavedSearch`] - A saved job search configuration
 //! - [`JobScraperManager`] - Manages job scraping across multiple sources
 //!
@@ -34,17 +35,30 @@
 pub mod cache;
 pub mod jobspy;
 mod manager;
+mod ranking;
+pub mod rate_limit;
 pub mod retry;
+mod salary;
+mod sanitize;
 mod saved_search;
 pub mod setup;
 mod types;
 
 pub use cache::{CacheConfig, CacheWrapper};
-pub use manager::JobScraperManager;
+pub use manager::{
+    load_search_results, JobScraperManager, DEFAULT_ENRICH_MIN_CHARS, SEEN_JOBS_FILE,
+};
+pub use ranking::rank_jobs_for_resume;
+pub use rate_limit::RateLimit;
 pub use retry::{RetryConfig, RetryWrapper};
+pub use salary::{parse_salary, SalaryPeriod, SalaryRange};
+pub use sanitize::sanitize_posting;
 pub use saved_search::SavedSearchManager;
 pub use setup::{run_auto_setup, show_dependency_status, DependencyCheck};
-pub use types::{JobPosting, JobSource, SavedSearch, SearchFilters};
+pub use types::{
+    DatePosted, JobPage, JobPosting, JobSource, ResultsFormat, SavedSearch, SearchFilters,
+    SearchMetadata, SearchResults,
+};
 
 use crate::error::Result;
 use async_trait::async_trait;
@@ -64,6 +78,37 @@ pub trait JobScraper: Send + Sync {
         max_results: i32,
     ) -> Result<Vec<JobPosting>>;
 
+    /// Fetch a single page of results, numbered from 1.
+    ///
+    /// The default implementation serves everything from one
+    /// [`search_jobs`](Self::search_jobs) call as page 1 and reports no
+    /// further pages. Scrapers that fetch results page by page should
+    /// override it.
+    async fn search_jobs_paged(
+        &self,
+        filters: &SearchFilters,
+        page: u32,
+        per_page: i32,
+    ) -> Result<JobPage> {
+        if page > 1 {
+            return Ok(JobPage::default());
+        }
+
+        Ok(JobPage {
+            jobs: self.search_jobs(filters, per_page).await?,
+            has_more: false,
+            next_page: None,
+        })
+    }
+
+    /// Whether this scraper applies [`SearchFilters::date_posted`] itself.
+    ///
+    /// When `false` (the default), [`JobScraperManager`] filters the
+    /// scraper's results by [`JobPosting::posted_date`] instead.
+    fn filters_date_posted(&self) -> bool {
+        false
+    }
+
     /// Get detailed information about a specific job.
     async fn get_job_details(&self, job_url: &str) -> Result<Option<JobPosting>>;
 }
@@ -0,0 +1,63 @@
+//! Ranking scraped job postings against a resume.
+
+use crate::error::Result;
+use crate::scoring::{
+    score_job_with_options, score_match_with_options, ScoreReport, ScoringOptions,
+};
+use crate::scraper::JobPosting;
+
+/// Rank job postings by how well they match a resume.
+///
+/// Each job is scored with [`score_match_with_options`] and the results are
+/// sorted by match total, highest first. Ties are broken by the job's own
+/// `completeness` score from [`score_job_with_options`], so better-described
+/// postings come first. Pass [`Config::scoring_options`] so rankings use the
+/// same profile as the rest of the pipeline.
+///
+/// [`Config::scoring_options`]: crate::config::Config::scoring_options
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scoring::ScoringOptions;
+/// use ats_checker::scraper::{rank_jobs_for_resume, JobPosting};
+/// use serde_json::json;
+///
+/// let resume = json!({"skills": ["Rust", "Kubernetes"]});
+/// let jobs = vec![
+///     JobPosting::new("Accountant", "Ledger Co", "NYC", "Bookkeeping and audits", "https://a", "indeed"),
+///     JobPosting::new("Rust Engineer", "Acme", "Remote", "Rust services on Kubernetes", "https://b", "indeed"),
+/// ];
+///
+/// let ranked = rank_jobs_for_resume(&resume, &jobs, None, &ScoringOptions::default()).unwrap();
+/// assert_eq!(ranked[0].0.title, "Rust Engineer");
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a job cannot be scored.
+pub fn rank_jobs_for_resume(
+    resume: &serde_json::Value,
+    jobs: &[JobPosting],
+    weights_path: Option<&str>,
+    options: &ScoringOptions,
+) -> Result<Vec<(JobPosting, ScoreReport)>> {
+    let mut scored = Vec::with_capacity(jobs.len());
+    for job in jobs {
+        let job_json = job.to_scoring_json();
+        let report = score_match_with_options(resume, &job_json, weights_path, options)?;
+        let completeness = score_job_with_options(&job_json, weights_path, options)?
+            .categories
+            .iter()
+            .find(|c| c.name == "completeness")
+            .map_or(0.0, |c| c.score);
+        scored.push((job.clone(), report, completeness));
+    }
+
+    scored.sort_by(|a, b| b.1.total.total_cmp(&a.1.total).then(b.2.total_cmp(&a.2)));
+
+    Ok(scored
+        .into_iter()
+        .map(|(job, report, _)| (job, report))
+        .collect())
+}
@@ -0,0 +1,136 @@
+//! Rate limiting for job scrapers.
+//!
+//! Where [`RetryWrapper`](crate::scraper::RetryWrapper) reacts to failures,
+//! this module paces requests up front so sources are not hit back-to-back.
+//! Each bucket holds a single token that refills once per interval, so
+//! consecutive requests through the same bucket are spaced at least
+//! `60s / requests_per_minute` apart.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// Configuration for request pacing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Maximum number of requests per minute. Zero disables rate limiting.
+    pub requests_per_minute: u32,
+    /// Whether each source gets its own budget, rather than sharing one
+    /// across all sources.
+    pub per_source: bool,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            requests_per_minute: 30,
+            per_source: true,
+        }
+    }
+}
+
+impl RateLimit {
+    /// Create a rate limit of `requests_per_minute`, applied per source.
+    pub fn per_minute(requests_per_minute: u32) -> Self {
+        Self {
+            requests_per_minute,
+            per_source: true,
+        }
+    }
+
+    /// Share one budget across all sources.
+    #[must_use]
+    pub fn shared(mut self) -> Self {
+        self.per_source = false;
+        self
+    }
+
+    /// Minimum spacing between requests through one bucket.
+    ///
+    /// Returns `None` when rate limiting is disabled.
+    pub fn interval(&self) -> Option<Duration> {
+        (self.requests_per_minute > 0).then(|| Duration::from_mins(1) / self.requests_per_minute)
+    }
+}
+
+/// Token buckets keyed by source (or a single shared bucket).
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    config: RateLimit,
+    /// Instant at which each bucket next has a token available.
+    buckets: Mutex<HashMap<String, Instant>>,
+}
+
+impl RateLimiter {
+    /// Create a rate limiter with the given configuration.
+    pub(crate) fn new(config: RateLimit) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait until a request to `source` is allowed, then consume the token.
+    pub(crate) async fn acquire(&self, source: &str) {
+        let wait = self.reserve(source, Instant::now());
+        if !wait.is_zero() {
+            log::debug!("Rate limiting {source}: waiting {wait:?}");
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Reserve the next token for `source` and return how long to wait for it.
+    ///
+    /// Reservations are made under the lock, so concurrent callers queue up
+    /// one interval apart instead of all waking at once.
+    fn reserve(&self, source: &str, now: Instant) -> Duration {
+        let Some(interval) = self.config.interval() else {
+            return Duration::ZERO;
+        };
+        let key = if self.config.per_source { source } else { "" };
+
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let available_at = buckets.entry(key.to_string()).or_insert(now);
+        let start = (*available_at).max(now);
+        *available_at = start + interval;
+        start - now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval() {
+        assert_eq!(
+            RateLimit::per_minute(30).interval(),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(RateLimit::per_minute(0).interval(), None);
+    }
+
+    #[test]
+    fn test_reserve_per_source_and_shared() {
+        let now = Instant::now();
+
+        let limiter = RateLimiter::new(RateLimit::per_minute(60));
+        assert_eq!(limiter.reserve("a", now), Duration::ZERO);
+        assert_eq!(limiter.reserve("a", now), Duration::from_secs(1));
+        assert_eq!(limiter.reserve("a", now), Duration::from_secs(2));
+        assert_eq!(limiter.reserve("b", now), Duration::ZERO);
+
+        let shared = RateLimiter::new(RateLimit::per_minute(60).shared());
+        assert_eq!(shared.reserve("a", now), Duration::ZERO);
+        assert_eq!(shared.reserve("b", now), Duration::from_secs(1));
+
+        // A token that refilled while idle is available immediately
+        let later = now + Duration::from_secs(5);
+        assert_eq!(shared.reserve("a", later), Duration::ZERO);
+    }
+}
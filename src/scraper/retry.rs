@@ -8,7 +8,7 @@ use std::time::Duration;
 use async_trait::async_trait;
 
 use crate::error::Result;
-use crate::scraper::{JobPosting, JobScraper, SearchFilters};
+use crate::scraper::{JobPage, JobPosting, JobScraper, SearchFilters};
 
 /// Configuration for retry behavior.
 #[derive(Debug, Clone)]
@@ -135,6 +135,16 @@ impl<S: JobScraper + Send + Sync> JobScraper for RetryWrapper<S> {
             .await
     }
 
+    async fn search_jobs_paged(
+        &self,
+        filters: &SearchFilters,
+        page: u32,
+        per_page: i32,
+    ) -> Result<JobPage> {
+        self.retry_with_backoff(|| self.inner.search_jobs_paged(filters, page, per_page))
+            .await
+    }
+
     async fn get_job_details(&self, job_url: &str) -> Result<Option<JobPosting>> {
         self.retry_with_backoff(|| self.inner.get_job_details(job_url))
             .await
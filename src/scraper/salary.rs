@@ -0,0 +1,244 @@
+//! Salary parsing for job postings.
+//!
+//! Job boards report pay as free text ("$120k–$150k", "£60,000 per annum",
+//! "80 USD/hour", "Competitive"). [`parse_salary`] extracts the figures,
+//! currency and pay period and normalizes the figures to annual amounts so
+//! postings can be compared.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// Amounts such as `120,000`, `60k`, `1.2m` or `85.50`.
+static AMOUNT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(\d{1,3}(?:,\d{3})+|\d+(?:\.\d+)?)\s*([km])?\b").unwrap());
+
+/// Currency codes recognised as words, checked before symbols so that
+/// e.g. "CAD $90k" is not read as US dollars.
+const CURRENCY_CODES: &[&str] = &["usd", "gbp", "eur", "cad", "aud", "inr", "chf", "jpy"];
+
+/// Currency symbols, longest first so prefixed dollars win over `$`.
+const CURRENCY_SYMBOLS: &[(&str, &str)] = &[
+    ("ca$", "CAD"),
+    ("c$", "CAD"),
+    ("a$", "AUD"),
+    ("$", "USD"),
+    ("£", "GBP"),
+    ("€", "EUR"),
+    ("₹", "INR"),
+    ("¥", "JPY"),
+];
+
+/// Figures below this with no stated period are taken to be hourly rates.
+const HOURLY_THRESHOLD: f64 = 500.0;
+
+/// Period a salary is quoted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SalaryPeriod {
+    /// Per hour.
+    Hourly,
+    /// Per working day.
+    Daily,
+    /// Per week.
+    Weekly,
+    /// Per month.
+    Monthly,
+    /// Per year.
+    Annual,
+}
+
+impl SalaryPeriod {
+    /// Number of periods in a year, assuming full-time hours (40h weeks,
+    /// 52 weeks).
+    pub fn per_year(self) -> f64 {
+        match self {
+            SalaryPeriod::Hourly => 2080.0,
+            SalaryPeriod::Daily => 260.0,
+            SalaryPeriod::Weekly => 52.0,
+            SalaryPeriod::Monthly => 12.0,
+            SalaryPeriod::Annual => 1.0,
+        }
+    }
+}
+
+/// A salary range parsed from a posting, normalized to annual amounts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SalaryRange {
+    /// Lower bound per year.
+    pub min: f64,
+    /// Upper bound per year; equal to `min` for a single figure.
+    pub max: f64,
+    /// ISO 4217 currency code, if one was recognised.
+    pub currency: Option<String>,
+    /// Period the figures were quoted in before normalization.
+    pub period: SalaryPeriod,
+}
+
+impl SalaryRange {
+    /// Whether the salary gives distinct lower and upper bounds.
+    pub fn is_range(&self) -> bool {
+        self.max > self.min
+    }
+}
+
+/// Parse a free-text salary into an annual [`SalaryRange`].
+///
+/// The first two amounts in the text are used as the range bounds; `k` and
+/// `m` suffixes are expanded, and a suffix on the upper bound also applies to
+/// a bare lower bound ("80-100k"). The pay period is read from words such as
+/// "hour", "/hr", "month" or "per annum"; without one, figures under 500 are
+/// assumed to be hourly and anything else annual.
+///
+/// Returns `None` if the text contains no positive amount.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scraper::{parse_salary, SalaryPeriod};
+///
+/// let range = parse_salary("$120,000 - $150,000/yr").unwrap();
+/// assert_eq!((range.min, range.max), (120_000.0, 150_000.0));
+/// assert_eq!(range.currency.as_deref(), Some("USD"));
+/// assert_eq!(range.period, SalaryPeriod::Annual);
+///
+/// assert!(parse_salary("Competitive").is_none());
+/// ```
+pub fn parse_salary(s: &str) -> Option<SalaryRange> {
+    let text = s.to_lowercase();
+
+    let mut amounts: Vec<(f64, Option<f64>)> = AMOUNT_RE
+        .captures_iter(&text)
+        .filter_map(|caps| {
+            let value: f64 = caps[1].replace(',', "").parse().ok()?;
+            let multiplier = caps.get(2).map(|m| match m.as_str() {
+                "m" => 1_000_000.0,
+                _ => 1_000.0,
+            });
+            Some((value, multiplier))
+        })
+        .filter(|(value, _)| *value > 0.0)
+        .take(2)
+        .collect();
+
+    // "80-100k": the upper bound's suffix applies to a bare lower bound
+    if let [(low, None), (high, Some(multiplier))] = amounts[..] {
+        if low < high {
+            amounts[0].1 = Some(multiplier);
+        }
+    }
+
+    let mut figures: Vec<f64> = amounts
+        .into_iter()
+        .map(|(value, multiplier)| value * multiplier.unwrap_or(1.0))
+        .collect();
+    figures.sort_by(f64::total_cmp);
+    let (&min, &max) = (figures.first()?, figures.last()?);
+
+    let period = parse_period(&text).unwrap_or(if max < HOURLY_THRESHOLD {
+        SalaryPeriod::Hourly
+    } else {
+        SalaryPeriod::Annual
+    });
+
+    Some(SalaryRange {
+        min: min * period.per_year(),
+        max: max * period.per_year(),
+        currency: parse_currency(&text),
+        period,
+    })
+}
+
+/// Pay period named in lowercase salary text.
+fn parse_period(text: &str) -> Option<SalaryPeriod> {
+    let words: Vec<&str> = text
+        .split(|c: char| !c.is_alphanumeric() && c != '.')
+        .filter(|w| !w.is_empty())
+        .collect();
+    let has = |candidates: &[&str]| words.iter().any(|w| candidates.contains(w));
+
+    if has(&["hour", "hours", "hourly", "hr", "hrs", "h", "ph"]) {
+        Some(SalaryPeriod::Hourly)
+    } else if has(&["day", "daily", "diem"]) {
+        Some(SalaryPeriod::Daily)
+    } else if has(&["week", "weekly", "wk", "pw"]) {
+        Some(SalaryPeriod::Weekly)
+    } else if has(&["month", "monthly", "mo", "pcm", "pm"]) {
+        Some(SalaryPeriod::Monthly)
+    } else if has(&[
+        "year", "yearly", "yr", "annual", "annually", "annum", "pa", "p.a.", "p.a",
+    ]) {
+        Some(SalaryPeriod::Annual)
+    } else {
+        None
+    }
+}
+
+/// ISO currency code from a code word or symbol in lowercase salary text.
+fn parse_currency(text: &str) -> Option<String> {
+    let code = text
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .find(|word| CURRENCY_CODES.contains(word));
+    if let Some(code) = code {
+        return Some(code.to_uppercase());
+    }
+
+    CURRENCY_SYMBOLS
+        .iter()
+        .find(|(symbol, _)| text.contains(symbol))
+        .map(|(_, code)| (*code).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_annual_range() {
+        let range = parse_salary("$120,000 - $150,000/yr").unwrap();
+        assert_eq!(range.min, 120_000.0);
+        assert_eq!(range.max, 150_000.0);
+        assert_eq!(range.currency.as_deref(), Some("USD"));
+        assert_eq!(range.period, SalaryPeriod::Annual);
+        assert!(range.is_range());
+
+        let range = parse_salary("$120k–$150k").unwrap();
+        assert_eq!((range.min, range.max), (120_000.0, 150_000.0));
+
+        let range = parse_salary("80-100k EUR").unwrap();
+        assert_eq!((range.min, range.max), (80_000.0, 100_000.0));
+        assert_eq!(range.currency.as_deref(), Some("EUR"));
+    }
+
+    #[test]
+    fn test_parse_single_figure() {
+        let range = parse_salary("£60k").unwrap();
+        assert_eq!((range.min, range.max), (60_000.0, 60_000.0));
+        assert_eq!(range.currency.as_deref(), Some("GBP"));
+        assert_eq!(range.period, SalaryPeriod::Annual);
+        assert!(!range.is_range());
+    }
+
+    #[test]
+    fn test_parse_hourly_normalized_to_annual() {
+        let range = parse_salary("80 USD/hour").unwrap();
+        assert_eq!(range.period, SalaryPeriod::Hourly);
+        assert_eq!(range.currency.as_deref(), Some("USD"));
+        assert_eq!(range.min, 80.0 * 2080.0);
+        assert_eq!(range.max, range.min);
+
+        // Small figures without a period are assumed hourly
+        assert_eq!(parse_salary("$45").unwrap().period, SalaryPeriod::Hourly);
+
+        let monthly = parse_salary("€5,000 per month").unwrap();
+        assert_eq!(monthly.min, 60_000.0);
+    }
+
+    #[test]
+    fn test_parse_without_figures() {
+        assert!(parse_salary("competitive").is_none());
+        assert!(parse_salary("Competitive, DOE").is_none());
+        assert!(parse_salary("").is_none());
+        assert!(parse_salary("$0").is_none());
+    }
+}
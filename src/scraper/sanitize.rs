@@ -0,0 +1,218 @@
+//! Cleanup of scraped job postings.
+//!
+//! Job boards hand back descriptions with HTML markup, entities such as
+//! `&amp;`, and page chrome ("Skip to main content", "Show more"). Left in,
+//! these pollute keyword extraction and rankings. [`sanitize_posting`]
+//! reduces a posting to plain text and rejects postings with nothing usable
+//! left.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+use crate::scraper::JobPosting;
+
+/// `<script>` and `<style>` elements, whose content is never visible text.
+static HIDDEN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?is)<(script|style)\b[^>]*>.*?</(script|style)\s*>").unwrap());
+
+/// Tags that end a line of visible text.
+static BREAK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)<(br\s*/?|/p|/div|/li|/h[1-6]|/tr|/ul|/ol)\s*>|<li\b[^>]*>").unwrap()
+});
+
+/// Any remaining tag or comment.
+static TAG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<!--.*?-->|<[^>]*>").unwrap());
+
+/// Named, decimal and hex character references.
+static ENTITY_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"&(#[0-9]{1,7}|#[xX][0-9a-fA-F]{1,6}|[a-zA-Z]+);").unwrap());
+
+/// Lines that are page navigation rather than part of the posting, compared
+/// case-insensitively.
+const BOILERPLATE_LINES: &[&str] = &[
+    "skip to main content",
+    "sign in",
+    "join now",
+    "apply now",
+    "easy apply",
+    "save job",
+    "save",
+    "share",
+    "show more",
+    "show less",
+    "see more",
+    "report this job",
+    "back to search results",
+];
+
+/// Query parameters that only track where a click came from.
+const TRACKING_PARAMS: &[&str] = &["trk", "trackingid", "refid", "ref", "src", "from"];
+
+/// Clean a scraped posting in place.
+///
+/// Strips HTML tags and decodes entities in the title and description,
+/// removes navigation boilerplate lines from the description, collapses
+/// whitespace, and normalizes the URL (fragment and tracking parameters such
+/// as `utm_*` removed). Returns `false` if the title or description is empty
+/// afterwards, meaning the posting should be dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::scraper::{sanitize_posting, JobPosting};
+///
+/// let mut job = JobPosting::new(
+///     "Rust &amp; Go Engineer",
+///     "Acme",
+///     "Remote",
+///     "<p>Build APIs &amp; tools.</p>",
+///     "https://jobs.example.com/1?utm_source=feed#apply",
+///     "example",
+/// );
+///
+/// assert!(sanitize_posting(&mut job));
+/// assert_eq!(job.title, "Rust & Go Engineer");
+/// assert_eq!(job.description, "Build APIs & tools.");
+/// assert_eq!(job.url, "https://jobs.example.com/1");
+/// ```
+pub fn sanitize_posting(job: &mut JobPosting) -> bool {
+    job.title = collapse_whitespace(&html_to_text(&job.title));
+    job.company = collapse_whitespace(&html_to_text(&job.company));
+    job.location = collapse_whitespace(&html_to_text(&job.location));
+    job.description = clean_description(&job.description);
+    job.url = normalize_url(&job.url);
+
+    !job.title.is_empty() && !job.description.is_empty()
+}
+
+/// Plain-text description: one trimmed line per block, without blank or
+/// boilerplate lines.
+fn clean_description(description: &str) -> String {
+    html_to_text(description)
+        .lines()
+        .map(collapse_whitespace)
+        .filter(|line| {
+            !line.is_empty()
+                && !BOILERPLATE_LINES
+                    .iter()
+                    .any(|b| line.eq_ignore_ascii_case(b))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Strip tags and decode character references, keeping block boundaries as
+/// line breaks.
+fn html_to_text(html: &str) -> String {
+    let text = HIDDEN_RE.replace_all(html, "");
+    let text = BREAK_RE.replace_all(&text, "\n");
+    let text = TAG_RE.replace_all(&text, "");
+    decode_entities(&text)
+}
+
+/// Decode HTML character references. Unknown named references are kept as is.
+fn decode_entities(text: &str) -> String {
+    ENTITY_RE
+        .replace_all(text, |caps: &regex::Captures| {
+            let name = &caps[1];
+            let decoded =
+                if let Some(hex) = name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+                } else if let Some(decimal) = name.strip_prefix('#') {
+                    decimal.parse().ok().and_then(char::from_u32)
+                } else {
+                    match name {
+                        "amp" => Some('&'),
+                        "lt" => Some('<'),
+                        "gt" => Some('>'),
+                        "quot" => Some('"'),
+                        "apos" => Some('\''),
+                        "nbsp" => Some(' '),
+                        "ndash" => Some('–'),
+                        "mdash" => Some('—'),
+                        "bull" => Some('•'),
+                        "hellip" => Some('…'),
+                        "rsquo" => Some('’'),
+                        "lsquo" => Some('‘'),
+                        "rdquo" => Some('”'),
+                        "ldquo" => Some('“'),
+                        _ => None,
+                    }
+                };
+            decoded.map_or_else(|| caps[0].to_string(), String::from)
+        })
+        .into_owned()
+}
+
+/// Join whitespace-separated words with single spaces.
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Trim the URL and drop its fragment and tracking parameters. URLs that
+/// don't parse are only trimmed.
+fn normalize_url(raw: &str) -> String {
+    let raw = raw.trim();
+    let Ok(mut url) = url::Url::parse(raw) else {
+        return raw.to_string();
+    };
+
+    url.set_fragment(None);
+    let kept: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| {
+            let key = key.to_lowercase();
+            !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_str())
+        })
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    if kept.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(kept);
+    }
+
+    url.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn posting(title: &str, description: &str, url: &str) -> JobPosting {
+        JobPosting::new(title, "Acme", "Remote", description, url, "test")
+    }
+
+    #[test]
+    fn test_sanitize_posting_strips_tags_and_entities() {
+        let mut job = posting(
+            " Senior  Engineer ",
+            "Skip to main content\n<div><p>Build R&amp;D tools in <b>Rust</b>.</p>\
+             <ul><li>5+ years</li><li>Q&#38;A &lt;3</li></ul>\
+             <script>track()</script><p>Show more</p></div>",
+            " https://jobs.example.com/view/42?utm_source=x&trk=abc&id=7#top ",
+        );
+
+        assert!(sanitize_posting(&mut job));
+        assert_eq!(job.title, "Senior Engineer");
+        assert_eq!(
+            job.description,
+            "Build R&D tools in Rust.\n5+ years\nQ&A <3"
+        );
+        assert_eq!(job.url, "https://jobs.example.com/view/42?id=7");
+    }
+
+    #[test]
+    fn test_sanitize_posting_rejects_empty_fields() {
+        let mut no_title = posting("<span> </span>", "<p>Real description</p>", "");
+        assert!(!sanitize_posting(&mut no_title));
+
+        let mut no_description = posting("Engineer", "<p>&nbsp;</p><p>Apply now</p>", "");
+        assert!(!sanitize_posting(&mut no_description));
+    }
+
+    #[test]
+    fn test_decode_entities_keeps_unknown_references() {
+        assert_eq!(decode_entities("&copy; &#x41;&#66;"), "&copy; AB");
+    }
+}
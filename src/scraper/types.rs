@@ -1,6 +1,8 @@
 //! Core types for job scraping.
 
-use chrono::Utc;
+use crate::config::Profile;
+use crate::scoring::parse_posting_age_days;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -66,6 +68,7 @@ pub struct JobPosting {
     pub experience_level: Option<String>,
 
     /// Timestamp when the job was scraped.
+    #[serde(default)]
     pub scraped_at: String,
 
     /// Optional job score (computed separately).
@@ -77,6 +80,9 @@ pub struct JobPosting {
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
+/// Metadata key marking a posting not seen in a previous saved-search run.
+pub(crate) const NEW_POSTING_KEY: &str = "is_new";
+
 impl JobPosting {
     /// Create a new job posting with required fields.
     pub fn new(
@@ -154,11 +160,67 @@ impl JobPosting {
         self
     }
 
+    /// Whether this posting was flagged as unseen by
+    /// [`JobScraperManager::run_saved_searches`](crate::scraper::JobScraperManager::run_saved_searches).
+    pub fn is_new(&self) -> bool {
+        self.metadata
+            .get(NEW_POSTING_KEY)
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+    }
+
+    /// Parse [`salary`](Self::salary) into an annual range; see
+    /// [`parse_salary`](crate::scraper::parse_salary).
+    pub fn salary_range(&self) -> Option<crate::scraper::SalaryRange> {
+        self.salary
+            .as_deref()
+            .and_then(crate::scraper::parse_salary)
+    }
+
     /// Check if this job is remote.
     pub fn is_remote(&self) -> bool {
         self.remote.unwrap_or(false)
     }
 
+    /// Job JSON in the shape [`score_match`](crate::scoring::score_match)
+    /// and [`score_job`](crate::scoring::score_job) read.
+    ///
+    /// Always has `title`, `description`, `company`, `location`, `url` and
+    /// `salary` (empty when unknown); `experience_level` and `date_posted`
+    /// (from [`posted_date`](Self::posted_date), for the recency category) are
+    /// added when set.
+    /// Scraper-only fields such as `source` and `metadata` are left out.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ats_checker::scraper::JobPosting;
+    ///
+    /// let job = JobPosting::new("Rust Engineer", "Acme", "Remote", "Build services", "", "test")
+    ///     .with_salary("$120k - $150k");
+    /// let json = job.to_scoring_json();
+    ///
+    /// assert_eq!(json["title"], "Rust Engineer");
+    /// assert_eq!(json["salary"], "$120k - $150k");
+    /// ```
+    pub fn to_scoring_json(&self) -> serde_json::Value {
+        let mut json = serde_json::json!({
+            "title": self.title,
+            "description": self.description,
+            "company": self.company,
+            "location": self.location,
+            "url": self.url,
+            "salary": self.salary.as_deref().unwrap_or_default(),
+        });
+        if let Some(level) = &self.experience_level {
+            json["experience_level"] = serde_json::json!(level);
+        }
+        if let Some(date) = &self.posted_date {
+            json["date_posted"] = serde_json::json!(date);
+        }
+        json
+    }
+
     /// Get a unique identifier for this job (based on URL hash).
     pub fn id(&self) -> String {
         use sha2::{Digest, Sha256};
@@ -214,7 +276,8 @@ pub struct SearchFilters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub salary_min: Option<i32>,
 
-    /// Maximum age of job postings (e.g., "24h", "7d", "30d").
+    /// Maximum age of job postings (e.g., "24h", "7d", "30d") or the
+    /// earliest posting date ("2024-03-01"); see [`DatePosted`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub date_posted: Option<String>,
 
@@ -238,6 +301,56 @@ impl SearchFilters {
         SearchFiltersBuilder::default()
     }
 
+    /// Seed filters from a candidate profile.
+    ///
+    /// Keywords are the desired titles joined with `OR`, or the first few
+    /// skills when no titles are given. Location and the remote-only flag
+    /// come from the profile's preferences.
+    ///
+    /// ```rust
+    /// use ats_checker::config::Profile;
+    /// use ats_checker::scraper::SearchFilters;
+    ///
+    /// let profile = Profile {
+    ///     desired_titles: vec!["Backend Engineer".into(), "SRE".into()],
+    ///     remote_only: true,
+    ///     ..Profile::default()
+    /// };
+    /// let filters = SearchFilters::from_profile(&profile);
+    /// assert_eq!(filters.keywords.as_deref(), Some("Backend Engineer OR SRE"));
+    /// assert!(filters.remote_only);
+    /// ```
+    pub fn from_profile(profile: &Profile) -> Self {
+        let non_empty = |values: &[String]| -> Vec<String> {
+            values
+                .iter()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+                .collect()
+        };
+
+        let titles = non_empty(&profile.desired_titles);
+        let keywords = if titles.is_empty() {
+            let skills = non_empty(&profile.skills);
+            let top: Vec<String> = skills.into_iter().take(PROFILE_KEYWORD_SKILLS).collect();
+            top.join(" ")
+        } else {
+            titles.join(" OR ")
+        };
+
+        Self {
+            keywords: (!keywords.is_empty()).then_some(keywords),
+            location: profile
+                .location
+                .as_deref()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(String::from),
+            remote_only: profile.remote_only,
+            ..Self::default()
+        }
+    }
+
     /// Check if any filters are set.
     pub fn is_empty(&self) -> bool {
         self.keywords.is_none()
@@ -248,8 +361,26 @@ impl SearchFilters {
             && self.salary_min.is_none()
             && self.date_posted.is_none()
     }
+
+    /// The `date_posted` filter parsed into a [`DatePosted`].
+    ///
+    /// Returns `None` if no date filter is set, or if it cannot be parsed (a
+    /// warning is logged and the filter is ignored).
+    pub fn date_posted_filter(&self) -> Option<DatePosted> {
+        let raw = self.date_posted.as_deref()?;
+        match raw.parse() {
+            Ok(filter) => Some(filter),
+            Err(e) => {
+                log::warn!("Ignoring date filter: {e}");
+                None
+            }
+        }
+    }
 }
 
+/// Skills used as search keywords when a profile names no desired titles.
+const PROFILE_KEYWORD_SKILLS: usize = 3;
+
 /// Builder for [`SearchFilters`].
 #[derive(Debug, Clone, Default)]
 pub struct SearchFiltersBuilder {
@@ -299,6 +430,11 @@ impl SearchFiltersBuilder {
         self
     }
 
+    /// Set the date posted filter from a typed [`DatePosted`].
+    pub fn since(self, date_posted: DatePosted) -> Self {
+        self.date_posted(date_posted.to_string())
+    }
+
     /// Set the country code.
     pub fn country(mut self, country: impl Into<String>) -> Self {
         self.filters.country = Some(country.into());
@@ -317,6 +453,183 @@ impl SearchFiltersBuilder {
     }
 }
 
+/// How recently a job must have been posted.
+///
+/// Parses from the keyword forms `24h` (also `1d`, `day`, `today`,
+/// `past_24_hours`), `7d` (`week`, `past_week`), `30d` (`month`,
+/// `past_month`) or an ISO date such as `2024-03-01`:
+///
+/// ```rust
+/// use ats_checker::scraper::DatePosted;
+/// use chrono::NaiveDate;
+///
+/// assert_eq!("7d".parse::<DatePosted>().unwrap(), DatePosted::PastWeek);
+/// assert_eq!(
+///     "2024-03-01".parse::<DatePosted>().unwrap(),
+///     DatePosted::SinceDate(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePosted {
+    /// Posted within the last 24 hours.
+    Past24Hours,
+    /// Posted within the last 7 days.
+    PastWeek,
+    /// Posted within the last 30 days.
+    PastMonth,
+    /// Posted on or after the given date.
+    SinceDate(NaiveDate),
+}
+
+impl DatePosted {
+    /// Whether `job` passes this filter at `now`.
+    ///
+    /// The posting's `posted_date` may be an RFC 3339 timestamp, a
+    /// `YYYY-MM-DD` date or a relative phrase such as "3 days ago". Postings
+    /// whose date is missing or cannot be parsed are kept, since their age is
+    /// unknown.
+    pub fn accepts(&self, job: &JobPosting, now: DateTime<Utc>) -> bool {
+        let Some(age_days) = job
+            .posted_date
+            .as_deref()
+            .and_then(|raw| parse_posting_age_days(raw, now))
+        else {
+            return true;
+        };
+
+        match self {
+            DatePosted::Past24Hours => age_days <= 1.0,
+            DatePosted::PastWeek => age_days <= 7.0,
+            DatePosted::PastMonth => age_days <= 30.0,
+            DatePosted::SinceDate(since) => {
+                let posted = now - chrono::Duration::seconds((age_days * 86_400.0) as i64);
+                posted.date_naive() >= *since
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for DatePosted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DatePosted::Past24Hours => write!(f, "24h"),
+            DatePosted::PastWeek => write!(f, "7d"),
+            DatePosted::PastMonth => write!(f, "30d"),
+            DatePosted::SinceDate(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+        }
+    }
+}
+
+impl std::str::FromStr for DatePosted {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "24h" | "1d" | "day" | "today" | "past_24_hours" => Ok(DatePosted::Past24Hours),
+            "7d" | "1w" | "week" | "past_week" => Ok(DatePosted::PastWeek),
+            "30d" | "1m" | "month" | "past_month" => Ok(DatePosted::PastMonth),
+            other => NaiveDate::parse_from_str(other, "%Y-%m-%d")
+                .map(DatePosted::SinceDate)
+                .map_err(|_| {
+                    format!("Invalid date filter: {s} (expected 24h, 7d, 30d or YYYY-MM-DD)")
+                }),
+        }
+    }
+}
+
+/// One page of job search results.
+///
+/// Pages are numbered from 1.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobPage {
+    /// Job postings on this page.
+    pub jobs: Vec<JobPosting>,
+
+    /// Whether more results are available after this page.
+    pub has_more: bool,
+
+    /// Number of the next page, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<u32>,
+}
+
+/// Metadata describing the search that produced a set of saved results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchMetadata {
+    /// When the search was run (RFC 3339).
+    pub searched_at: String,
+
+    /// Filters used for the search.
+    #[serde(default)]
+    pub filters: SearchFilters,
+
+    /// Sources that were searched.
+    #[serde(default)]
+    pub sources: Vec<String>,
+}
+
+impl SearchMetadata {
+    /// Create metadata for a search run now.
+    pub fn new(filters: SearchFilters, sources: Vec<String>) -> Self {
+        Self {
+            searched_at: Utc::now().to_rfc3339(),
+            filters,
+            sources,
+        }
+    }
+}
+
+/// Job search results as stored on disk.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchResults {
+    /// Search metadata; absent in files written before metadata was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<SearchMetadata>,
+
+    /// Job postings.
+    #[serde(default)]
+    pub jobs: Vec<JobPosting>,
+}
+
+/// File format for saved job search results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultsFormat {
+    /// TOML with a `[metadata]` table and a `[[jobs]]` array.
+    Toml,
+    /// JSON object with `metadata` and `jobs` keys.
+    Json,
+}
+
+impl ResultsFormat {
+    /// Infer the format from a file extension, defaulting to TOML.
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Self {
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ResultsFormat::Json,
+            _ => ResultsFormat::Toml,
+        }
+    }
+
+    /// File extension for this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ResultsFormat::Toml => "toml",
+            ResultsFormat::Json => "json",
+        }
+    }
+}
+
+impl std::str::FromStr for ResultsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "toml" => Ok(ResultsFormat::Toml),
+            "json" => Ok(ResultsFormat::Json),
+            _ => Err(format!("Unknown results format: {s}")),
+        }
+    }
+}
+
 /// A saved job search configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SavedSearch {
@@ -511,6 +824,55 @@ mod tests {
         assert!(search.last_run.is_none());
     }
 
+    #[test]
+    fn test_date_posted_parsing() {
+        for (input, expected) in [
+            ("24h", DatePosted::Past24Hours),
+            ("past_24_hours", DatePosted::Past24Hours),
+            ("7d", DatePosted::PastWeek),
+            ("Week", DatePosted::PastWeek),
+            ("30d", DatePosted::PastMonth),
+            ("past_month", DatePosted::PastMonth),
+            (
+                "2024-03-01",
+                DatePosted::SinceDate(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()),
+            ),
+        ] {
+            let parsed: DatePosted = input.parse().unwrap();
+            assert_eq!(parsed, expected, "{input}");
+            assert_eq!(parsed.to_string().parse::<DatePosted>().unwrap(), parsed);
+        }
+
+        assert!("yesterday-ish".parse::<DatePosted>().is_err());
+        assert!("2024-13-01".parse::<DatePosted>().is_err());
+
+        let filters = SearchFilters::builder().since(DatePosted::PastWeek).build();
+        assert_eq!(filters.date_posted.as_deref(), Some("7d"));
+        assert_eq!(filters.date_posted_filter(), Some(DatePosted::PastWeek));
+    }
+
+    #[test]
+    fn test_date_posted_accepts() {
+        let now = DateTime::parse_from_rfc3339("2024-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let job = |date: &str| JobPosting::default().with_posted_date(date);
+
+        assert!(DatePosted::Past24Hours.accepts(&job("2024-03-10T02:00:00Z"), now));
+        assert!(!DatePosted::Past24Hours.accepts(&job("3 days ago"), now));
+        assert!(DatePosted::PastWeek.accepts(&job("3 days ago"), now));
+        assert!(!DatePosted::PastMonth.accepts(&job("2024-01-15"), now));
+
+        let since = DatePosted::SinceDate(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap());
+        assert!(since.accepts(&job("2024-03-05"), now));
+        assert!(since.accepts(&job("2024-03-05T08:00:00Z"), now));
+        assert!(!since.accepts(&job("2024-03-04"), now));
+
+        // Unknown age is not filtered out
+        assert!(since.accepts(&JobPosting::default(), now));
+        assert!(since.accepts(&job("recently"), now));
+    }
+
     #[test]
     fn test_job_posting_serialization() {
         let job = JobPosting::new("Title", "Company", "Location", "Desc", "url", "source");
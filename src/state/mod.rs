@@ -4,6 +4,7 @@
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 use serde::{Deserialize, Serialize};
 
@@ -149,6 +150,70 @@ impl StateManager {
     }
 }
 
+// -------------------------
+// Thread-Safe State
+// -------------------------
+
+/// Thread-safe state manager.
+///
+/// Wraps `StateManager` with `Arc<Mutex<>>` so concurrent tasks can record
+/// processed resumes through `&self`. Each update saves while holding the
+/// lock, so writes to the state file are serialized and every save contains
+/// all earlier updates.
+#[derive(Clone)]
+pub struct SyncStateManager {
+    inner: Arc<Mutex<StateManager>>,
+}
+
+impl SyncStateManager {
+    /// Create a new thread-safe state manager.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state file exists but cannot be loaded.
+    pub fn new(state_filepath: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::from(StateManager::new(state_filepath)?))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StateManager> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Check if a resume has been processed (thread-safe).
+    pub fn is_processed(&self, file_hash: &str) -> bool {
+        self.lock().is_processed(file_hash)
+    }
+
+    /// Update state for a resume and save to disk (thread-safe).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state file cannot be written.
+    pub fn update_resume_state(&self, file_hash: &str, output_path: &str) -> Result<()> {
+        self.lock().update_resume_state(file_hash, output_path)
+    }
+
+    /// Get the number of processed resumes (thread-safe).
+    pub fn count(&self) -> usize {
+        self.lock().count()
+    }
+
+    /// Run a closure with access to the underlying state manager.
+    ///
+    /// The lock is held for the duration of the closure.
+    pub fn read<R>(&self, f: impl FnOnce(&StateManager) -> R) -> R {
+        f(&self.lock())
+    }
+}
+
+impl From<StateManager> for SyncStateManager {
+    fn from(manager: StateManager) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(manager)),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct StateWrapper {
     #[serde(default)]
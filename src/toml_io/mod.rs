@@ -163,11 +163,26 @@ pub fn dumps_as<T: Serialize>(data: &T) -> Result<String> {
     Ok(s)
 }
 
+/// How [`merge_toml_with`] combines an array in the overlay with an array in
+/// the base.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArrayMergeMode {
+    /// The overlay array replaces the base array.
+    #[default]
+    Replace,
+    /// Overlay elements are appended after the base elements.
+    Append,
+    /// Like `Append`, but elements already present are skipped, so the
+    /// result has no duplicates (first occurrence wins).
+    Union,
+}
+
 /// Deep merge two TOML values.
 ///
 /// Merges `overlay` into `base`, with `overlay` values taking precedence.
 /// For objects/structs, fields are recursively merged.
 /// For arrays and primitive values, overlay completely replaces base.
+/// Use [`merge_toml_with`] to append to or union arrays instead.
 ///
 /// # Arguments
 ///
@@ -206,13 +221,44 @@ pub fn dumps_as<T: Serialize>(data: &T) -> Result<String> {
 /// assert_eq!(merged["extra"], "value");
 /// ```
 pub fn merge_toml(base: &serde_json::Value, overlay: &serde_json::Value) -> serde_json::Value {
+    merge_toml_with(base, overlay, ArrayMergeMode::Replace)
+}
+
+/// Deep merge two TOML values, choosing how arrays combine.
+///
+/// Precedence rules, applied recursively:
+/// - Objects are merged key by key; keys only in one side are kept.
+/// - A `null` overlay value keeps the base value.
+/// - When both sides are arrays, `mode` decides: `Replace` takes the
+///   overlay, `Append` concatenates base then overlay, and `Union`
+///   concatenates while dropping elements already present. Array elements
+///   are compared whole and never merged into each other.
+/// - In every other case (scalars, or mismatched types) the overlay wins.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::toml_io::{merge_toml_with, ArrayMergeMode};
+/// use serde_json::json;
+///
+/// let base = json!({"sources": ["linkedin", "indeed"]});
+/// let overlay = json!({"sources": ["indeed", "glassdoor"]});
+///
+/// let merged = merge_toml_with(&base, &overlay, ArrayMergeMode::Union);
+/// assert_eq!(merged["sources"], json!(["linkedin", "indeed", "glassdoor"]));
+/// ```
+pub fn merge_toml_with(
+    base: &serde_json::Value,
+    overlay: &serde_json::Value,
+    mode: ArrayMergeMode,
+) -> serde_json::Value {
     match (base, overlay) {
         // Both are objects - recursively merge
         (serde_json::Value::Object(base_map), serde_json::Value::Object(overlay_map)) => {
             let mut result = base_map.clone();
             for (key, overlay_value) in overlay_map {
                 let merged_value = if let Some(base_value) = result.get(key) {
-                    merge_toml(base_value, overlay_value)
+                    merge_toml_with(base_value, overlay_value, mode)
                 } else {
                     overlay_value.clone()
                 };
@@ -220,6 +266,24 @@ pub fn merge_toml(base: &serde_json::Value, overlay: &serde_json::Value) -> serd
             }
             serde_json::Value::Object(result)
         }
+        // Both are arrays - combine per mode
+        (serde_json::Value::Array(base_items), serde_json::Value::Array(overlay_items)) => {
+            match mode {
+                ArrayMergeMode::Replace => overlay.clone(),
+                ArrayMergeMode::Append => serde_json::Value::Array(
+                    base_items.iter().chain(overlay_items).cloned().collect(),
+                ),
+                ArrayMergeMode::Union => {
+                    let mut result: Vec<serde_json::Value> = Vec::new();
+                    for item in base_items.iter().chain(overlay_items) {
+                        if !result.contains(item) {
+                            result.push(item.clone());
+                        }
+                    }
+                    serde_json::Value::Array(result)
+                }
+            }
+        }
         // Overlay is null - keep base
         (_, serde_json::Value::Null) => base.clone(),
         // Otherwise overlay takes precedence
@@ -345,6 +409,45 @@ timeout = 30
         assert_eq!(merged["c"], 4);
     }
 
+    #[test]
+    fn test_merge_toml_array_modes() {
+        let base = json!({"job_search_default_sources": ["linkedin", "indeed"], "n": 1});
+        let overlay = json!({"job_search_default_sources": ["indeed", "glassdoor"]});
+        let sources =
+            |mode| merge_toml_with(&base, &overlay, mode)["job_search_default_sources"].clone();
+
+        assert_eq!(
+            sources(ArrayMergeMode::Replace),
+            json!(["indeed", "glassdoor"])
+        );
+        assert_eq!(
+            sources(ArrayMergeMode::Append),
+            json!(["linkedin", "indeed", "indeed", "glassdoor"])
+        );
+        assert_eq!(
+            sources(ArrayMergeMode::Union),
+            json!(["linkedin", "indeed", "glassdoor"])
+        );
+
+        // merge_toml keeps replacing arrays, and other keys are unaffected
+        assert_eq!(
+            merge_toml(&base, &overlay),
+            merge_toml_with(&base, &overlay, ArrayMergeMode::Replace)
+        );
+        assert_eq!(
+            merge_toml_with(&base, &overlay, ArrayMergeMode::Union)["n"],
+            1
+        );
+
+        // A non-array overlay still replaces the array
+        let merged = merge_toml_with(
+            &base,
+            &json!({"job_search_default_sources": "google"}),
+            ArrayMergeMode::Append,
+        );
+        assert_eq!(merged["job_search_default_sources"], "google");
+    }
+
     #[test]
     fn test_merge_toml_nested() {
         let base = json!({
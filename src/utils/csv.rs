@@ -0,0 +1,100 @@
+//! Minimal CSV writing utilities.
+//!
+//! Produces RFC 4180 output: fields containing commas, quotes or line breaks
+//! (or leading/trailing whitespace) are wrapped in double quotes, with
+//! embedded quotes doubled. Records are terminated with CRLF.
+
+use crate::error::Result;
+use std::io::Write;
+
+/// Writes CSV records to an underlying writer.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::utils::csv::CsvWriter;
+///
+/// let mut writer = CsvWriter::new(Vec::new());
+/// writer.write_record(["name", "score"]).unwrap();
+/// writer.write_record(["Doe, John", "85"]).unwrap();
+///
+/// let csv = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(csv, "name,score\r\n\"Doe, John\",85\r\n");
+/// ```
+#[derive(Debug)]
+pub struct CsvWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Create a new CSV writer.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Write a single record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying writer fails.
+    pub fn write_record<I, S>(&mut self, fields: I) -> Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let line = fields
+            .into_iter()
+            .map(|field| escape_field(field.as_ref()))
+            .collect::<Vec<_>>()
+            .join(",");
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.write_all(b"\r\n")?;
+        Ok(())
+    }
+
+    /// Return the underlying writer.
+    ///
+    /// Records go straight to the writer, so nothing is held back here; a
+    /// buffered writer still needs flushing by the caller.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Quote a field if it would otherwise be ambiguous.
+fn escape_field(field: &str) -> String {
+    let needs_quotes = field.contains([',', '"', '\n', '\r'])
+        || field.starts_with(char::is_whitespace)
+        || field.ends_with(char::is_whitespace);
+
+    if needs_quotes {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_field() {
+        assert_eq!(escape_field("plain"), "plain");
+        assert_eq!(escape_field(""), "");
+        assert_eq!(escape_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(escape_field(" padded"), "\" padded\"");
+    }
+
+    #[test]
+    fn test_write_records() {
+        let mut writer = CsvWriter::new(Vec::new());
+        writer.write_record(["a", "b,c", ""]).unwrap();
+        writer.write_record(vec![String::from("1")]).unwrap();
+
+        let csv = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(csv, "a,\"b,c\",\r\n1\r\n");
+    }
+}
@@ -4,6 +4,9 @@
 //! - Plain text files (.txt, .md, .tex)
 //! - PDF documents (.pdf)
 //! - Microsoft Word documents (.docx)
+//! - Rich Text Format documents (.rtf)
+//!
+//! [`extract_text_cached`] adds an on-disk cache keyed by file content hash.
 //!
 //! # Example
 //!
@@ -15,9 +18,11 @@
 //! ```
 
 use crate::error::{AtsError, Result};
+use crate::utils::file::{atomic_write, ensure_directory};
+use crate::utils::hash::calculate_file_hash;
 use std::path::Path;
 
-/// Extract text from a file (TXT, PDF, DOCX, etc.).
+/// Extract text from a file (TXT, PDF, DOCX, RTF, etc.).
 ///
 /// Automatically detects the file type based on extension and uses the appropriate
 /// extraction method.
@@ -27,6 +32,7 @@ use std::path::Path;
 /// - `.txt`, `.md`, `.tex` - Plain text files
 /// - `.pdf` - PDF documents
 /// - `.docx` - Microsoft Word documents
+/// - `.rtf` - Rich Text Format documents (see [`rtf_to_text`])
 ///
 /// # Errors
 ///
@@ -51,10 +57,57 @@ pub fn extract_text_from_file(path: impl AsRef<Path>) -> Result<String> {
         "txt" | "md" | "tex" => extract_text_file(path),
         "pdf" => extract_pdf(path),
         "docx" => extract_docx(path),
+        "rtf" => extract_rtf(path),
         _ => extract_text_file(path), // Fallback to plain text
     }
 }
 
+/// Extract text from a file, reusing earlier extractions stored in `cache_dir`.
+///
+/// Cache entries are keyed by the file's SHA256 content hash (see
+/// [`calculate_file_hash`]) and extension, so an unchanged file is only parsed
+/// once while any edit to it produces a new key and a fresh extraction. The
+/// cache directory is created if needed; entries that cannot be read are
+/// treated as misses.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be hashed or extracted, or the cache
+/// entry cannot be written.
+///
+/// # Example
+///
+/// ```no_run
+/// use ats_checker::utils::extract::extract_text_cached;
+///
+/// // Parses the PDF on the first call, reads the cache afterwards
+/// let text = extract_text_cached("resume.pdf", ".cache/extract")?;
+/// # Ok::<(), ats_checker::error::AtsError>(())
+/// ```
+pub fn extract_text_cached(path: impl AsRef<Path>, cache_dir: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let cache_dir = cache_dir.as_ref();
+
+    let hash = calculate_file_hash(path)?;
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    let entry = cache_dir.join(format!("{hash}.{ext}.txt"));
+
+    if let Ok(text) = std::fs::read_to_string(&entry) {
+        log::debug!("Extraction cache hit for {}", path.display());
+        return Ok(text);
+    }
+
+    let text = extract_text_from_file(path)?;
+    ensure_directory(cache_dir)?;
+    atomic_write(&entry, &text)?;
+
+    Ok(text)
+}
+
 /// Extract text from plain text files.
 fn extract_text_file(path: &Path) -> Result<String> {
     std::fs::read_to_string(path).map_err(|e| AtsError::Io {
@@ -205,3 +258,225 @@ fn extract_docx(path: &Path) -> Result<String> {
 
     Ok(text)
 }
+
+/// RTF destinations whose content is never body text.
+const SKIPPED_RTF_DESTINATIONS: &[&str] = &[
+    "fonttbl",
+    "colortbl",
+    "stylesheet",
+    "info",
+    "pict",
+    "object",
+    "themedata",
+    "colorschememapping",
+    "datastore",
+    "latentstyles",
+    "listtable",
+    "listoverridetable",
+    "revtbl",
+    "rsidtbl",
+    "filetbl",
+    "generator",
+    "xmlnstbl",
+    "mmathPr",
+    "fldinst",
+];
+
+/// Extract text from RTF files.
+///
+/// # Errors
+///
+/// Returns an error if the RTF file cannot be read.
+fn extract_rtf(path: &Path) -> Result<String> {
+    let bytes = std::fs::read(path).map_err(|e| AtsError::Io {
+        message: format!("Failed to read RTF file: {}", path.display()),
+        source: e,
+    })?;
+
+    Ok(rtf_to_text(&String::from_utf8_lossy(&bytes)))
+}
+
+/// Convert RTF markup to plain text.
+///
+/// Control words and non-text groups (font and color tables, stylesheets,
+/// document info, pictures, `{\*...}` destinations) are dropped, paragraph
+/// and line breaks become newlines, and `\'hh` and `\uN` escapes are decoded
+/// (hex escapes as Windows-1252). Formatting is discarded; the result is
+/// passed through [`normalize_text`].
+///
+/// # Example
+///
+/// ```
+/// use ats_checker::utils::extract::rtf_to_text;
+///
+/// let rtf = r"{\rtf1\ansi{\fonttbl{\f0 Arial;}}\f0\b Jane Doe\b0\par Caf\'e9 owner}";
+/// assert_eq!(rtf_to_text(rtf), "Jane Doe\nCafé owner");
+/// ```
+#[must_use]
+pub fn rtf_to_text(rtf: &str) -> String {
+    let mut reader = RtfReader::default();
+    let mut chars = rtf.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => reader.groups.push((reader.skip, reader.unicode_skip)),
+            '}' => {
+                if let Some((skip, unicode_skip)) = reader.groups.pop() {
+                    reader.skip = skip;
+                    reader.unicode_skip = unicode_skip;
+                }
+                reader.pending_fallback = 0;
+            }
+            '\\' => match chars.peek().copied() {
+                Some(next) if next.is_ascii_alphabetic() => {
+                    let mut word = String::new();
+                    while let Some(&c) = chars.peek().filter(|c| c.is_ascii_alphabetic()) {
+                        word.push(c);
+                        chars.next();
+                    }
+                    let mut param = String::new();
+                    if chars.peek() == Some(&'-') {
+                        param.push('-');
+                        chars.next();
+                    }
+                    while let Some(&c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+                        param.push(c);
+                        chars.next();
+                    }
+                    // A single space delimits the control word
+                    if chars.peek() == Some(&' ') {
+                        chars.next();
+                    }
+                    let param: Option<i32> = param.parse().ok();
+                    if word == "bin" {
+                        let len = param.and_then(|n| usize::try_from(n).ok()).unwrap_or(0);
+                        chars.by_ref().take(len).for_each(drop);
+                    } else {
+                        reader.control_word(&word, param);
+                    }
+                }
+                Some('\'') => {
+                    chars.next();
+                    let hex: String = chars.by_ref().take(2).collect();
+                    if let Ok(byte) = u8::from_str_radix(&hex, 16) {
+                        reader.emit(decode_cp1252(byte));
+                    }
+                }
+                Some('*') => {
+                    chars.next();
+                    reader.skip = true;
+                }
+                Some(literal @ ('\\' | '{' | '}')) => {
+                    chars.next();
+                    reader.emit(literal);
+                }
+                Some('~') => {
+                    chars.next();
+                    reader.emit(' ');
+                }
+                Some('_') => {
+                    chars.next();
+                    reader.emit('-');
+                }
+                Some('\n' | '\r') => {
+                    chars.next();
+                    reader.newline();
+                }
+                Some(_) => {
+                    chars.next();
+                }
+                None => {}
+            },
+            '\n' | '\r' => {}
+            _ => reader.emit(c),
+        }
+    }
+
+    normalize_text(&reader.text)
+}
+
+/// Parser state for [`rtf_to_text`].
+struct RtfReader {
+    text: String,
+    /// Whether the current group's content is dropped.
+    skip: bool,
+    /// Fallback characters following each `\uN` escape (set by `\ucN`).
+    unicode_skip: usize,
+    /// Fallback characters still to drop after the last `\uN` escape.
+    pending_fallback: usize,
+    /// `skip` and `unicode_skip` of the enclosing groups.
+    groups: Vec<(bool, usize)>,
+}
+
+impl Default for RtfReader {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            skip: false,
+            unicode_skip: 1,
+            pending_fallback: 0,
+            groups: Vec::new(),
+        }
+    }
+}
+
+impl RtfReader {
+    fn emit(&mut self, c: char) {
+        if self.pending_fallback > 0 {
+            self.pending_fallback -= 1;
+        } else if !self.skip {
+            self.text.push(c);
+        }
+    }
+
+    fn newline(&mut self) {
+        if !self.skip {
+            self.text.push('\n');
+        }
+    }
+
+    fn control_word(&mut self, word: &str, param: Option<i32>) {
+        match word {
+            "par" | "line" | "sect" | "page" | "row" => self.newline(),
+            "tab" | "cell" | "emspace" | "enspace" | "qmspace" => self.emit(' '),
+            "emdash" => self.emit('\u{2014}'),
+            "endash" => self.emit('\u{2013}'),
+            "bullet" => self.emit('\u{2022}'),
+            "lquote" => self.emit('\u{2018}'),
+            "rquote" => self.emit('\u{2019}'),
+            "ldblquote" => self.emit('\u{201c}'),
+            "rdblquote" => self.emit('\u{201d}'),
+            "uc" => self.unicode_skip = param.and_then(|n| usize::try_from(n).ok()).unwrap_or(1),
+            "u" => {
+                // Code points above 32767 are written as negative numbers
+                let code = param.map(|n| if n < 0 { n + 65_536 } else { n });
+                if let Some(c) = code
+                    .and_then(|n| u32::try_from(n).ok())
+                    .and_then(char::from_u32)
+                {
+                    self.emit(c);
+                }
+                self.pending_fallback = self.unicode_skip;
+            }
+            _ if SKIPPED_RTF_DESTINATIONS.contains(&word) => self.skip = true,
+            _ => {}
+        }
+    }
+}
+
+/// Decode a Windows-1252 byte, the usual code page of `\'hh` escapes.
+fn decode_cp1252(byte: u8) -> char {
+    match byte {
+        0x80 => '\u{20ac}',
+        0x85 => '\u{2026}',
+        0x91 => '\u{2018}',
+        0x92 => '\u{2019}',
+        0x93 => '\u{201c}',
+        0x94 => '\u{201d}',
+        0x95 => '\u{2022}',
+        0x96 => '\u{2013}',
+        0x97 => '\u{2014}',
+        0x99 => '\u{2122}',
+        _ => char::from(byte),
+    }
+}
@@ -25,6 +25,15 @@ pub fn ensure_directory(path: impl AsRef<Path>) -> Result<()> {
 ///
 /// Returns an error if the temporary file cannot be written or renamed.
 pub fn atomic_write(path: impl AsRef<Path>, content: &str) -> Result<()> {
+    atomic_write_bytes(path, content.as_bytes())
+}
+
+/// Atomic write of binary content to a file.
+///
+/// # Errors
+///
+/// Returns an error if the temporary file cannot be written or renamed.
+pub fn atomic_write_bytes(path: impl AsRef<Path>, content: &[u8]) -> Result<()> {
     let path = path.as_ref();
     let temp_path = path.with_extension("tmp");
     std::fs::write(&temp_path, content)?;
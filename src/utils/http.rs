@@ -0,0 +1,247 @@
+//! Shared HTTP client construction.
+//!
+//! The LLM clients build their `reqwest::Client` through [`build_client`] so
+//! timeout and proxy handling live in one place, validate base URL
+//! overrides with [`normalize_base_url`], and read rate-limit backoff hints
+//! with [`retry_after`].
+
+use crate::error::{AtsError, Result};
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Client, NoProxy, Proxy};
+use std::time::Duration;
+
+/// Environment variables consulted for a proxy URL, in order.
+const PROXY_ENV_VARS: [&str; 2] = ["HTTPS_PROXY", "https_proxy"];
+
+/// Options for building an HTTP client.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::utils::http::{build_client, ClientOptions};
+/// use std::time::Duration;
+///
+/// let options = ClientOptions::default().with_proxy("http://proxy.example.com:8080");
+/// let client = build_client(&options, Duration::from_secs(30)).unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientOptions {
+    /// Request timeout; the caller's default is used when unset.
+    pub timeout: Option<Duration>,
+    /// Proxy URL for all HTTP and HTTPS traffic.
+    pub proxy: Option<String>,
+    /// Proxy URL for HTTPS traffic, read from the environment. Ignored when
+    /// [`proxy`](Self::proxy) is set.
+    pub https_proxy: Option<String>,
+}
+
+impl ClientOptions {
+    /// Read options from the environment (`HTTPS_PROXY` / `https_proxy`).
+    pub fn from_env() -> Self {
+        let https_proxy = PROXY_ENV_VARS
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.trim().is_empty());
+
+        Self {
+            timeout: None,
+            proxy: None,
+            https_proxy,
+        }
+    }
+
+    /// Set the request timeout.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Route all traffic through the given proxy.
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+}
+
+/// Build an HTTP client from `options`.
+///
+/// Hosts listed in `NO_PROXY` bypass the proxy, so local services such as
+/// Ollama keep working behind a corporate proxy. An invalid
+/// [`https_proxy`](ClientOptions::https_proxy) from the environment is
+/// skipped with a warning rather than failing every client.
+///
+/// # Errors
+///
+/// Returns [`AtsError::ApiRequest`] if the explicit proxy URL is invalid or
+/// the client cannot be built.
+pub fn build_client(options: &ClientOptions, default_timeout: Duration) -> Result<Client> {
+    let mut builder = Client::builder().timeout(options.timeout.unwrap_or(default_timeout));
+
+    if let Some(url) = &options.proxy {
+        let proxy = Proxy::all(url)
+            .map_err(|e| AtsError::ApiRequest {
+                message: format!("Invalid proxy URL '{url}': {e}"),
+                source: Some(e),
+            })?
+            .no_proxy(NoProxy::from_env());
+        builder = builder.proxy(proxy);
+    } else if let Some(url) = &options.https_proxy {
+        match Proxy::https(url) {
+            Ok(proxy) => builder = builder.proxy(proxy.no_proxy(NoProxy::from_env())),
+            Err(e) => log::warn!("Ignoring invalid HTTPS_PROXY '{url}': {e}"),
+        }
+    }
+
+    builder.build().map_err(|e| AtsError::ApiRequest {
+        message: format!("Failed to build HTTP client: {e}"),
+        source: Some(e),
+    })
+}
+
+/// Validate an API base URL and strip trailing slashes.
+///
+/// # Errors
+///
+/// Returns [`AtsError::ConfigInvalidValue`] (naming `field`) if the URL does
+/// not parse or is not `http`/`https`.
+pub fn normalize_base_url(field: &str, url: &str) -> Result<String> {
+    let invalid = |message: String| AtsError::ConfigInvalidValue {
+        field: field.to_string(),
+        message,
+    };
+
+    let trimmed = url.trim().trim_end_matches('/');
+    let parsed =
+        reqwest::Url::parse(trimmed).map_err(|e| invalid(format!("invalid URL '{url}': {e}")))?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err(invalid(format!("'{url}' is not an http(s) URL")));
+    }
+
+    Ok(trimmed.to_string())
+}
+
+/// Read a base URL override from environment variable `name`.
+///
+/// Returns `None` when the variable is unset or empty.
+///
+/// # Errors
+///
+/// Returns an error if the variable is set to an invalid URL.
+pub fn base_url_from_env(name: &str) -> Result<Option<String>> {
+    match std::env::var(name) {
+        Ok(value) if !value.trim().is_empty() => normalize_base_url(name, &value).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Seconds to wait before retrying, from a response's `Retry-After` header.
+///
+/// Returns `None` if the header is missing or unparseable; see
+/// [`parse_retry_after`].
+pub fn retry_after(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| parse_retry_after(v, Utc::now()))
+}
+
+/// Parse a `Retry-After` value, given either as delay seconds or as an
+/// HTTP-date, into seconds from `now`.
+///
+/// Dates in the past yield 0.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::utils::http::parse_retry_after;
+/// use chrono::{TimeZone, Utc};
+///
+/// let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 27, 30).unwrap();
+/// assert_eq!(parse_retry_after("30", now), Some(30));
+/// assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now), Some(30));
+/// assert_eq!(parse_retry_after("soon", now), None);
+/// ```
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(seconds);
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let seconds = date
+        .with_timezone(&Utc)
+        .signed_duration_since(now)
+        .num_seconds();
+    Some(u64::try_from(seconds).unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_client_with_proxy() {
+        let options = ClientOptions::default()
+            .with_proxy("http://proxy.example.com:8080")
+            .with_timeout(Duration::from_secs(5));
+        assert!(build_client(&options, Duration::from_secs(30)).is_ok());
+        assert!(build_client(&ClientOptions::default(), Duration::from_secs(30)).is_ok());
+    }
+
+    #[test]
+    fn test_build_client_invalid_proxy() {
+        let options = ClientOptions::default().with_proxy("http://bad host:8080");
+        let err = build_client(&options, Duration::from_secs(30)).unwrap_err();
+        assert!(matches!(err, AtsError::ApiRequest { .. }));
+        assert!(err.to_string().contains("Invalid proxy URL"));
+
+        // The same value from the environment is skipped instead
+        let options = ClientOptions {
+            https_proxy: Some("http://bad host:8080".to_string()),
+            ..ClientOptions::default()
+        };
+        assert!(build_client(&options, Duration::from_secs(30)).is_ok());
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(parse_retry_after(" 120 ", now), Some(120));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:30:00 GMT", now),
+            Some(120)
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(0)
+        );
+        assert_eq!(parse_retry_after("-5", now), None);
+        assert_eq!(parse_retry_after("", now), None);
+    }
+
+    #[test]
+    fn test_normalize_base_url() {
+        assert_eq!(
+            normalize_base_url("base_url", "http://localhost:1234/v1//").unwrap(),
+            "http://localhost:1234/v1"
+        );
+        assert_eq!(
+            normalize_base_url("base_url", " https://api.example.com ").unwrap(),
+            "https://api.example.com"
+        );
+
+        for bad in ["localhost:1234", "ftp://example.com", "not a url", ""] {
+            let err = normalize_base_url("OPENAI_BASE_URL", bad).unwrap_err();
+            assert!(
+                matches!(err, AtsError::ConfigInvalidValue { ref field, .. } if field == "OPENAI_BASE_URL"),
+                "{bad}: {err}"
+            );
+        }
+    }
+}
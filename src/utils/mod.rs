@@ -1,9 +1,14 @@
 //! Utility functions module.
 
+pub mod csv;
 pub mod extract;
 pub mod file;
 pub mod hash;
+pub mod http;
 pub mod ocr;
+pub mod redact;
+pub mod stream;
+pub mod text;
 pub mod validation;
 
 pub use extract::extract_text_from_file;
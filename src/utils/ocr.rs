@@ -1,6 +1,8 @@
 //! OCR (Optical Character Recognition) utilities.
 //!
-//! Provides text extraction from images using Tesseract OCR.
+//! Provides text extraction from images using Tesseract OCR, with optional
+//! image preprocessing (grayscale, upscaling and Otsu binarization) to
+//! improve accuracy on low-quality scans.
 //!
 //! # Requirements
 //!
@@ -22,21 +24,75 @@
 //! brew install tesseract
 //! ```
 
+use crate::config::Config;
 use crate::error::{AtsError, Result};
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, GrayImage, ImageFormat};
+use std::collections::HashSet;
 use std::path::Path;
 use std::process::Command;
 
+/// Smallest image height, in pixels, handed to Tesseract when preprocessing.
+///
+/// Text in smaller scans is only a few pixels tall, which Tesseract
+/// recognises poorly.
+const MIN_OCR_HEIGHT: u32 = 1000;
+
+/// Largest factor small images are upscaled by when preprocessing.
+const MAX_UPSCALE: u32 = 4;
+
+/// Options for [`extract_text_with_options`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OcrOptions {
+    /// Language codes joined with `+` (e.g. "eng+deu"). Tesseract's default
+    /// ("eng") is used when `None`.
+    pub language: Option<String>,
+    /// Clean up the image with [`preprocess_image`] before running OCR.
+    pub preprocess: bool,
+}
+
+impl OcrOptions {
+    /// Create options using Tesseract's defaults and no preprocessing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Options from the `tesseract_lang` and `ocr_preprocess` config fields.
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            language: config.tesseract_lang.clone(),
+            preprocess: config.ocr_preprocess,
+        }
+    }
+
+    /// Set the OCR language codes (e.g. "eng+deu").
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Enable or disable image preprocessing.
+    #[must_use]
+    pub fn with_preprocess(mut self, preprocess: bool) -> Self {
+        self.preprocess = preprocess;
+        self
+    }
+}
+
 /// Extract text from an image file using OCR.
 ///
 /// # Arguments
 ///
 /// * `path` - Path to the image file (PNG, JPG, TIFF, etc.)
-/// * `language` - Optional language code (e.g., "eng", "spa"). Defaults to "eng"
+/// * `language` - Optional language codes joined with `+` (e.g., "eng", "eng+deu").
+///   Defaults to Tesseract's own default ("eng")
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Tesseract is not installed or not in PATH
+/// - A requested language pack is not installed
 /// - The image file cannot be read
 /// - OCR processing fails
 ///
@@ -50,6 +106,33 @@ use std::process::Command;
 /// # Ok::<(), ats_checker::error::AtsError>(())
 /// ```
 pub fn extract_text_from_image(path: impl AsRef<Path>, language: Option<&str>) -> Result<String> {
+    let options = OcrOptions {
+        language: language.map(str::to_string),
+        preprocess: false,
+    };
+    extract_text_with_options(path, &options)
+}
+
+/// Extract text from an image file using OCR with the given options.
+///
+/// With `preprocess` enabled the image is cleaned up by [`preprocess_image`]
+/// and written to a temporary PNG, which is what Tesseract reads.
+///
+/// # Errors
+///
+/// Returns the errors of [`extract_text_from_image`], and an error if
+/// preprocessing is enabled and the image cannot be decoded.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ats_checker::utils::ocr::{extract_text_with_options, OcrOptions};
+///
+/// let options = OcrOptions::new().with_language("eng+deu").with_preprocess(true);
+/// let text = extract_text_with_options("resume_scan.png", &options)?;
+/// # Ok::<(), ats_checker::error::AtsError>(())
+/// ```
+pub fn extract_text_with_options(path: impl AsRef<Path>, options: &OcrOptions) -> Result<String> {
     let path = path.as_ref();
 
     // Verify file exists
@@ -62,23 +145,40 @@ pub fn extract_text_from_image(path: impl AsRef<Path>, language: Option<&str>) -
     // Verify Tesseract is installed
     check_tesseract_installed()?;
 
-    // Build tesseract command
-    let mut cmd = Command::new("tesseract");
-    cmd.arg(path.as_os_str());
-    cmd.arg("stdout"); // Output to stdout instead of file
-
-    // Set language
+    // Verify the language packs before running a full OCR pass
+    let language = options
+        .language
+        .as_deref()
+        .map(str::trim)
+        .filter(|lang| !lang.is_empty());
     if let Some(lang) = language {
-        cmd.arg("-l").arg(lang);
+        check_language_installed(lang)?;
     }
 
+    // Keep the preprocessed copy alive until Tesseract has read it
+    let preprocessed = if options.preprocess {
+        Some(preprocess_image_file(path)?)
+    } else {
+        None
+    };
+    let input = preprocessed.as_ref().map_or(path, |file| file.path());
+
     // Execute tesseract
-    let output = cmd.output().map_err(|e| AtsError::Ocr {
-        message: format!("Failed to execute Tesseract: {e}"),
-    })?;
+    let output = tesseract_command(input, language)
+        .output()
+        .map_err(|e| AtsError::Ocr {
+            message: format!("Failed to execute Tesseract: {e}"),
+        })?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
+        if let Some(lang) = language {
+            if reports_missing_language(&stderr) {
+                return Err(missing_language_error(
+                    &language_codes(lang).unwrap_or_default(),
+                ));
+            }
+        }
         return Err(AtsError::Ocr {
             message: format!("Tesseract failed: {stderr}"),
         });
@@ -89,6 +189,205 @@ pub fn extract_text_from_image(path: impl AsRef<Path>, language: Option<&str>) -
     Ok(text.trim().to_string())
 }
 
+/// Prepare a scanned image for OCR.
+///
+/// The image is converted to grayscale, upscaled when it is shorter than
+/// Tesseract reads reliably, and binarized with [`otsu_threshold`], which
+/// removes low contrast and coloured backgrounds. Light text on a dark
+/// background is inverted so the result is always dark text on white.
+pub fn preprocess_image(image: &DynamicImage) -> GrayImage {
+    let mut gray = image.to_luma8();
+
+    let (width, height) = gray.dimensions();
+    if height > 0 && height < MIN_OCR_HEIGHT {
+        let factor = MIN_OCR_HEIGHT.div_ceil(height).min(MAX_UPSCALE);
+        if factor > 1 {
+            gray = imageops::resize(&gray, width * factor, height * factor, FilterType::Triangle);
+        }
+    }
+
+    let threshold = otsu_threshold(&gray);
+    let mut dark_pixels = 0usize;
+    for pixel in gray.pixels_mut() {
+        if pixel.0[0] > threshold {
+            pixel.0[0] = u8::MAX;
+        } else {
+            pixel.0[0] = 0;
+            dark_pixels += 1;
+        }
+    }
+
+    // Text covers less of the page than background, so mostly-dark means inverted
+    if dark_pixels * 2 > gray.pixels().len() {
+        imageops::invert(&mut gray);
+    }
+
+    gray
+}
+
+/// Compute Otsu's binarization threshold for a grayscale image.
+///
+/// Returns the intensity that best separates the histogram into two classes
+/// (maximising between-class variance); pixels at or below it are dark.
+pub fn otsu_threshold(image: &GrayImage) -> u8 {
+    let mut histogram = [0u64; 256];
+    for pixel in image.pixels() {
+        histogram[usize::from(pixel.0[0])] += 1;
+    }
+
+    let total: u64 = histogram.iter().sum();
+    let weighted_total: f64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(level, &count)| level as f64 * count as f64)
+        .sum();
+
+    let mut background_weight = 0u64;
+    let mut background_sum = 0.0;
+    let mut best_threshold = 0u8;
+    let mut best_variance = -1.0;
+
+    for (level, &count) in histogram.iter().enumerate() {
+        background_weight += count;
+        if background_weight == 0 {
+            continue;
+        }
+        let foreground_weight = total - background_weight;
+        if foreground_weight == 0 {
+            break;
+        }
+
+        background_sum += level as f64 * count as f64;
+        let background_mean = background_sum / background_weight as f64;
+        let foreground_mean = (weighted_total - background_sum) / foreground_weight as f64;
+        let variance = background_weight as f64
+            * foreground_weight as f64
+            * (background_mean - foreground_mean).powi(2);
+
+        if variance > best_variance {
+            best_variance = variance;
+            best_threshold = level as u8;
+        }
+    }
+
+    best_threshold
+}
+
+/// Preprocess the image at `path` into a temporary PNG for Tesseract.
+fn preprocess_image_file(path: &Path) -> Result<tempfile::NamedTempFile> {
+    let image = image::open(path).map_err(|e| AtsError::Ocr {
+        message: format!("Failed to read image {}: {e}", path.display()),
+    })?;
+    let cleaned = preprocess_image(&image);
+
+    let file = tempfile::Builder::new()
+        .prefix("ocr-")
+        .suffix(".png")
+        .tempfile()?;
+    cleaned
+        .save_with_format(file.path(), ImageFormat::Png)
+        .map_err(|e| AtsError::Ocr {
+            message: format!("Failed to write preprocessed image: {e}"),
+        })?;
+
+    Ok(file)
+}
+
+/// Build the Tesseract invocation that writes the text of `path` to stdout.
+fn tesseract_command(path: &Path, language: Option<&str>) -> Command {
+    let mut cmd = Command::new("tesseract");
+    cmd.arg(path.as_os_str());
+    cmd.arg("stdout"); // Output to stdout instead of file
+
+    if let Some(lang) = language {
+        cmd.arg("-l").arg(lang);
+    }
+
+    cmd
+}
+
+/// Split a Tesseract language spec such as `"eng+deu"` into its codes.
+///
+/// Returns `None` if the spec is empty or any code contains characters other
+/// than ASCII letters, digits and underscores (e.g. `chi_sim`).
+pub(crate) fn language_codes(spec: &str) -> Option<Vec<&str>> {
+    let codes: Vec<&str> = spec.split('+').map(str::trim).collect();
+    let valid = codes.iter().all(|code| {
+        !code.is_empty() && code.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    });
+    valid.then_some(codes)
+}
+
+/// Check that every language in `language` (e.g. `"eng+deu"`) has an
+/// installed Tesseract language pack.
+///
+/// # Errors
+///
+/// Returns an error if the language spec is malformed, Tesseract cannot list
+/// its languages, or any requested language pack is missing.
+pub fn check_language_installed(language: &str) -> Result<()> {
+    let codes = language_codes(language).ok_or_else(|| AtsError::Ocr {
+        message: format!(
+            "Invalid Tesseract language '{language}': expected codes such as \"eng\" or \"eng+deu\""
+        ),
+    })?;
+
+    let output = Command::new("tesseract")
+        .arg("--list-langs")
+        .output()
+        .map_err(|e| AtsError::Ocr {
+            message: format!("Failed to list Tesseract languages: {e}"),
+        })?;
+
+    // Older Tesseract versions print the list to stderr
+    let listing = format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let installed = installed_languages(&listing);
+
+    let missing: Vec<&str> = codes
+        .into_iter()
+        .filter(|code| !installed.contains(code))
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(missing_language_error(&missing))
+    }
+}
+
+/// Parse the output of `tesseract --list-langs`.
+fn installed_languages(listing: &str) -> HashSet<&str> {
+    listing
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("List of available languages"))
+        .collect()
+}
+
+/// Whether Tesseract's stderr says a language pack could not be loaded.
+fn reports_missing_language(stderr: &str) -> bool {
+    stderr.contains("Failed loading language") || stderr.contains("Error opening data file")
+}
+
+fn missing_language_error(codes: &[&str]) -> AtsError {
+    let packages: Vec<String> = codes
+        .iter()
+        .map(|code| format!("tesseract-ocr-{}", code.replace('_', "-").to_lowercase()))
+        .collect();
+    AtsError::Ocr {
+        message: format!(
+            "Tesseract language pack not installed: {}. Install the matching \
+             traineddata (e.g. `sudo apt-get install {}`) or change `tesseract_lang` \
+             in the configuration.",
+            codes.join(", "),
+            packages.join(" ")
+        ),
+    }
+}
+
 /// Check if Tesseract is installed and accessible.
 ///
 /// # Errors
@@ -184,6 +483,164 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tesseract_command_includes_language() {
+        let cmd = tesseract_command(Path::new("scan.png"), Some("eng+deu"));
+        let args: Vec<_> = cmd.get_args().collect();
+
+        assert_eq!(cmd.get_program(), "tesseract");
+        assert_eq!(args, ["scan.png", "stdout", "-l", "eng+deu"]);
+
+        let cmd = tesseract_command(Path::new("scan.png"), None);
+        assert!(!cmd.get_args().any(|arg| arg == "-l"));
+    }
+
+    #[test]
+    fn test_language_codes() {
+        assert_eq!(language_codes("eng"), Some(vec!["eng"]));
+        assert_eq!(
+            language_codes("eng+deu+chi_sim"),
+            Some(vec!["eng", "deu", "chi_sim"])
+        );
+        assert_eq!(language_codes(""), None);
+        assert_eq!(language_codes("eng+"), None);
+        assert_eq!(language_codes("eng -psm 3"), None);
+    }
+
+    #[test]
+    fn test_missing_language_detection() {
+        let listing = "List of available languages in \"/usr/share/tessdata/\" (2):\neng\nosd\n";
+        let installed = installed_languages(listing);
+        assert!(installed.contains("eng"));
+        assert!(installed.contains("osd"));
+        assert!(!installed.contains("deu"));
+
+        assert!(reports_missing_language(
+            "Error opening data file /usr/share/tessdata/deu.traineddata\n\
+             Failed loading language 'deu'"
+        ));
+        assert!(!reports_missing_language("Error in pixReadStream"));
+
+        let message = missing_language_error(&["deu", "chi_sim"]).to_string();
+        assert!(message.contains("deu, chi_sim"));
+        assert!(message.contains("tesseract-ocr-deu tesseract-ocr-chi-sim"));
+    }
+
+    /// 5x7 bitmap glyphs for rendering synthetic scans.
+    fn glyph(c: char) -> [&'static str; 7] {
+        match c {
+            'H' => [
+                "#...#", "#...#", "#...#", "#####", "#...#", "#...#", "#...#",
+            ],
+            'E' => [
+                "#####", "#....", "#....", "####.", "#....", "#....", "#####",
+            ],
+            'L' => [
+                "#....", "#....", "#....", "#....", "#....", "#....", "#####",
+            ],
+            'O' => [
+                ".###.", "#...#", "#...#", "#...#", "#...#", "#...#", ".###.",
+            ],
+            _ => ["....."; 7],
+        }
+    }
+
+    /// Render `text` as a low-contrast RGB "scan": grey-blue ink on a
+    /// slightly lighter grey-blue background, at `cell` pixels per glyph dot.
+    fn low_contrast_scan(text: &str, cell: u32) -> DynamicImage {
+        let margin = cell * 4;
+        let width = margin * 2 + text.chars().count() as u32 * 6 * cell;
+        let height = margin * 2 + 7 * cell;
+        let mut image = image::RgbImage::from_pixel(width, height, image::Rgb([150, 155, 170]));
+
+        for (i, c) in text.chars().enumerate() {
+            for (row, line) in glyph(c).iter().enumerate() {
+                for (col, dot) in line.chars().enumerate() {
+                    if dot != '#' {
+                        continue;
+                    }
+                    let x0 = margin + (i as u32 * 6 + col as u32) * cell;
+                    let y0 = margin + row as u32 * cell;
+                    for y in y0..y0 + cell {
+                        for x in x0..x0 + cell {
+                            image.put_pixel(x, y, image::Rgb([120, 125, 140]));
+                        }
+                    }
+                }
+            }
+        }
+        DynamicImage::ImageRgb8(image)
+    }
+
+    #[test]
+    fn test_preprocess_binarizes_low_contrast_scan() {
+        let scan = low_contrast_scan("HELLO", 2);
+        let (width, height) = (scan.width(), scan.height());
+
+        let cleaned = preprocess_image(&scan);
+
+        // Small scans are upscaled, up to the maximum factor
+        assert_eq!(
+            cleaned.dimensions(),
+            (width * MAX_UPSCALE, height * MAX_UPSCALE)
+        );
+
+        // Only pure black and white remain, with dark text on a white background
+        assert!(cleaned.pixels().all(|p| p.0[0] == 0 || p.0[0] == u8::MAX));
+        let dark = cleaned.pixels().filter(|p| p.0[0] == 0).count();
+        assert!(dark > 0);
+        assert!(dark * 2 < cleaned.pixels().len());
+        assert_eq!(cleaned.get_pixel(0, 0).0[0], u8::MAX);
+    }
+
+    #[test]
+    fn test_preprocess_inverts_light_text_on_dark_background() {
+        let mut image = GrayImage::from_pixel(40, 20, image::Luma([30]));
+        for x in 10..30 {
+            image.put_pixel(x, 10, image::Luma([60]));
+        }
+
+        let cleaned = preprocess_image(&DynamicImage::ImageLuma8(image));
+
+        assert_eq!(cleaned.get_pixel(0, 0).0[0], u8::MAX);
+        assert_eq!(
+            cleaned.get_pixel(20 * MAX_UPSCALE, 10 * MAX_UPSCALE + 1).0[0],
+            0
+        );
+    }
+
+    #[test]
+    fn test_otsu_threshold_separates_classes() {
+        let mut image = GrayImage::from_pixel(10, 10, image::Luma([200]));
+        for x in 0..10 {
+            image.put_pixel(x, 0, image::Luma([40]));
+        }
+        let threshold = otsu_threshold(&image);
+        assert!((40..200).contains(&threshold));
+    }
+
+    #[test]
+    #[ignore = "Only run when Tesseract is installed"]
+    fn test_preprocessing_cleans_low_contrast_ocr() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scan.png");
+        low_contrast_scan("HELLO HELLO", 3).save(&path).unwrap();
+
+        let garbage = |text: &str| {
+            text.chars()
+                .filter(|c| !c.is_ascii_alphanumeric() && !c.is_whitespace())
+                .count()
+        };
+
+        let raw = extract_text_from_image(&path, None).unwrap();
+        let cleaned =
+            extract_text_with_options(&path, &OcrOptions::new().with_preprocess(true)).unwrap();
+
+        assert!(!cleaned.is_empty());
+        assert!(cleaned.contains("HELLO"));
+        assert!(garbage(&cleaned) <= garbage(&raw));
+    }
+
     #[test]
     #[ignore = "Only run when Tesseract is installed"]
     fn test_get_tesseract_version() {
@@ -0,0 +1,354 @@
+//! Resume anonymization.
+//!
+//! Replaces contact details with placeholders so a resume can be shared
+//! without exposing personal information. The placeholders are still
+//! well-formed, so completeness scoring treats an anonymized resume the same
+//! as the original.
+//!
+//! Redacted are the name, email, phone, address, location and profile or
+//! website links (`linkedin`, `github`, `website`, `portfolio`, `url`,
+//! `links`) found at the top level or in `personal_info`, `contact` or
+//! `contact_info`. The same values are also replaced wherever they appear
+//! verbatim as whole words elsewhere in the resume, and [`redact_text`] does
+//! this for free text such as a cover letter.
+//!
+//! Left as is: employers, schools, job titles and locations inside
+//! `experience` or `education`, partial mentions in free text (a first name
+//! on its own, or an email written differently from the resume's), and
+//! values shorter than three characters (a location such as "CA") unless
+//! they make up the whole string.
+
+use serde_json::Value;
+
+use crate::utils::hash::calculate_string_hash;
+
+/// Placeholder for the candidate's name and address.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Placeholder email; passes the completeness check's email validation.
+pub const REDACTED_EMAIL: &str = "redacted@example.com";
+
+/// Placeholder phone number; passes the completeness check's phone validation.
+pub const REDACTED_PHONE: &str = "000-000-0000";
+
+/// Placeholder `LinkedIn` profile; keeps the completeness profile-link bonus.
+pub const REDACTED_LINKEDIN: &str = "https://www.linkedin.com/in/redacted";
+
+/// Placeholder GitHub profile; keeps the completeness profile-link bonus.
+pub const REDACTED_GITHUB: &str = "https://github.com/redacted";
+
+/// Placeholder for any other website link.
+pub const REDACTED_URL: &str = "https://example.com";
+
+/// Objects that hold contact details, besides the resume root.
+const CONTACT_SECTIONS: &[&str] = &["personal_info", "contact", "contact_info"];
+
+/// Shortest contact value replaced inside longer text; shorter ones are
+/// only replaced when they are the whole string.
+const MIN_MENTION_CHARS: usize = 3;
+
+/// Replace the name, email, phone, address, location and links in a
+/// structured resume.
+///
+/// Looks in `personal_info`, `contact` and `contact_info` as well as the top
+/// level. Only fields that are present are replaced; elsewhere (summary,
+/// experience, skills, ...) only verbatim mentions of those values are.
+///
+/// # Examples
+///
+/// ```
+/// use ats_checker::utils::redact::{anonymize_resume, REDACTED_EMAIL};
+/// use serde_json::json;
+///
+/// let resume = json!({
+///     "personal_info": {"name": "Jane Doe", "email": "jane@example.org"},
+///     "skills": ["Rust"]
+/// });
+/// let anonymized = anonymize_resume(&resume);
+///
+/// assert_eq!(anonymized["personal_info"]["email"], REDACTED_EMAIL);
+/// assert_eq!(anonymized["skills"], resume["skills"]);
+/// ```
+#[must_use]
+pub fn anonymize_resume(resume: &Value) -> Value {
+    let mut anonymized = resume.clone();
+    redact_strings(&mut anonymized, resume);
+    redact_contact_fields(&mut anonymized);
+    for section in CONTACT_SECTIONS {
+        if let Some(contact) = anonymized.get_mut(*section) {
+            redact_contact_fields(contact);
+        }
+    }
+    anonymized
+}
+
+/// Replace every contact value of `resume` found verbatim in `text`.
+///
+/// Values are the ones [`anonymize_resume`] redacts; each is replaced by the
+/// same placeholder. Only whole words match, so a location of "CA" leaves
+/// "CAD" alone, and values under three characters are replaced only when
+/// they are all of `text`. Use this for generated text that quotes the
+/// resume, such as a cover letter.
+#[must_use]
+pub fn redact_text(text: &str, resume: &Value) -> String {
+    let mut redacted = text.to_string();
+    for (value, placeholder) in contact_values(resume) {
+        if redacted.trim() == value {
+            return placeholder.to_string();
+        }
+        if value.chars().count() >= MIN_MENTION_CHARS {
+            redacted = replace_words(&redacted, &value, placeholder);
+        }
+    }
+    redacted
+}
+
+/// Replace occurrences of `value` in `text` that don't run into a
+/// neighbouring letter or digit.
+fn replace_words(text: &str, value: &str, placeholder: &str) -> String {
+    let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    // Only an alphanumeric edge of the value needs a boundary next to it
+    let check_start = is_word(value.chars().next());
+    let check_end = is_word(value.chars().next_back());
+
+    let mut redacted = String::with_capacity(text.len());
+    let mut last = 0;
+    for (start, _) in text.match_indices(value) {
+        let end = start + value.len();
+        if (check_start && is_word(text[..start].chars().next_back()))
+            || (check_end && is_word(text[end..].chars().next()))
+        {
+            continue;
+        }
+        redacted.push_str(&text[last..start]);
+        redacted.push_str(placeholder);
+        last = end;
+    }
+    redacted.push_str(&text[last..]);
+    redacted
+}
+
+/// [`redact_text`] applied to every string inside `value`.
+pub fn redact_strings(value: &mut Value, resume: &Value) {
+    match value {
+        Value::String(s) => *s = redact_text(s, resume),
+        Value::Array(items) => items.iter_mut().for_each(|v| redact_strings(v, resume)),
+        Value::Object(fields) => fields.values_mut().for_each(|v| redact_strings(v, resume)),
+        _ => {}
+    }
+}
+
+/// A stable alias for a resume name, used in place of the file name in
+/// output paths and reports.
+///
+/// ```
+/// use ats_checker::utils::redact::resume_alias;
+///
+/// assert_eq!(resume_alias("jane_doe"), resume_alias("jane_doe"));
+/// assert!(!resume_alias("jane_doe").contains("jane"));
+/// ```
+#[must_use]
+pub fn resume_alias(resume_name: &str) -> String {
+    format!("resume_{}", &calculate_string_hash(resume_name)[..12])
+}
+
+/// The placeholder for contact field `key` holding `value`, if it is one.
+fn placeholder(key: &str, value: &str) -> Option<&'static str> {
+    match key {
+        "name" | "full_name" | "address" | "location" => Some(REDACTED),
+        "email" => Some(REDACTED_EMAIL),
+        "phone" => Some(REDACTED_PHONE),
+        "linkedin" | "github" | "website" | "portfolio" | "url" | "links" => {
+            let lower = value.to_lowercase();
+            Some(if lower.contains("linkedin.com") {
+                REDACTED_LINKEDIN
+            } else if lower.contains("github.com") {
+                REDACTED_GITHUB
+            } else {
+                REDACTED_URL
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Non-blank contact values of `resume` with their placeholders, longest
+/// first so a full name is replaced before anything it contains.
+fn contact_values(resume: &Value) -> Vec<(String, &'static str)> {
+    let objects = std::iter::once(resume)
+        .chain(CONTACT_SECTIONS.iter().filter_map(|s| resume.get(*s)))
+        .filter_map(Value::as_object);
+
+    let mut values = Vec::new();
+    for fields in objects {
+        for (key, field) in fields {
+            let strings = match field {
+                Value::Array(items) => items.iter().filter_map(Value::as_str).collect(),
+                other => other.as_str().into_iter().collect::<Vec<_>>(),
+            };
+            for value in strings.into_iter().map(str::trim).filter(|s| !s.is_empty()) {
+                if let Some(placeholder) = placeholder(key, value) {
+                    values.push((value.to_string(), placeholder));
+                }
+            }
+        }
+    }
+
+    values.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+    values.dedup_by(|a, b| a.0 == b.0);
+    values
+}
+
+fn redact_contact_fields(value: &mut Value) {
+    let Some(fields) = value.as_object_mut() else {
+        return;
+    };
+    // Blank values stay blank so redaction never adds completeness points
+    let redact = |key: &str, field: &mut Value| match field.as_str() {
+        Some(s) if s.trim().is_empty() => {}
+        Some(s) => {
+            if let Some(placeholder) = placeholder(key, s) {
+                *field = Value::String(placeholder.to_string());
+            }
+        }
+        None => {
+            if !field.is_null() {
+                if let Some(placeholder) = placeholder(key, "") {
+                    *field = Value::String(placeholder.to_string());
+                }
+            }
+        }
+    };
+    for (key, field) in fields.iter_mut() {
+        if let Value::Array(items) = field {
+            if placeholder(key, "").is_some() {
+                for item in items {
+                    redact(key, item);
+                }
+            }
+        } else {
+            redact(key, field);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_anonymize_resume_redacts_contact_details() {
+        let resume = json!({
+            "personal_info": {
+                "name": "Jane Doe",
+                "email": "jane.doe@example.org",
+                "phone": "+1 (555) 123-4567",
+                "address": "12 Main St, Springfield",
+                "location": "Springfield, IL",
+                "linkedin": "linkedin.com/in/janedoe",
+                "links": ["https://github.com/janedoe", "https://janedoe.dev"]
+            },
+            "summary": "Backend engineer",
+            "experience": [{
+                "title": "Engineer",
+                "company": "Acme",
+                "description": ["Built Rust services", "Cut latency by 40%"]
+            }],
+            "skills": ["Rust", "PostgreSQL"]
+        });
+
+        let anonymized = anonymize_resume(&resume);
+        let personal = &anonymized["personal_info"];
+
+        assert_eq!(personal["name"], REDACTED);
+        assert_eq!(personal["email"], REDACTED_EMAIL);
+        assert_eq!(personal["phone"], REDACTED_PHONE);
+        assert_eq!(personal["address"], REDACTED);
+        assert_eq!(personal["location"], REDACTED);
+        assert_eq!(personal["linkedin"], REDACTED_LINKEDIN);
+        assert_eq!(personal["links"], json!([REDACTED_GITHUB, REDACTED_URL]));
+        assert!(!anonymized.to_string().contains("jane.doe@example.org"));
+        assert!(!anonymized.to_string().contains("123-4567"));
+
+        assert_eq!(anonymized["skills"], resume["skills"]);
+        assert_eq!(anonymized["experience"], resume["experience"]);
+        assert_eq!(anonymized["summary"], resume["summary"]);
+    }
+
+    #[test]
+    fn test_anonymize_resume_keeps_completeness_score() {
+        let resume = json!({
+            "personal_info": {
+                "name": "Jane Doe",
+                "email": "jane@example.org",
+                "phone": "555-123-4567"
+            },
+            "experience": [{"title": "Engineer"}]
+        });
+
+        let original = crate::scoring::score_resume(&resume, None).unwrap();
+        let anonymized = crate::scoring::score_resume(&anonymize_resume(&resume), None).unwrap();
+
+        assert!((anonymized.total - original.total).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_redact_text_replaces_contact_values() {
+        let resume = json!({
+            "personal_info": {
+                "name": "Jane Doe",
+                "email": "jane@example.org",
+                "github": "https://github.com/janedoe"
+            }
+        });
+        let letter =
+            "Code at https://github.com/janedoe.\n\nSincerely,\nJane Doe (jane@example.org)";
+
+        let redacted = redact_text(letter, &resume);
+
+        assert_eq!(
+            redacted,
+            format!("Code at {REDACTED_GITHUB}.\n\nSincerely,\n{REDACTED} ({REDACTED_EMAIL})")
+        );
+
+        let mut details = json!({"weak_bullets": ["Led Jane Doe's team"], "count": 1});
+        redact_strings(&mut details, &resume);
+        assert_eq!(
+            details,
+            json!({"weak_bullets": ["Led [REDACTED]'s team"], "count": 1})
+        );
+    }
+
+    #[test]
+    fn test_short_values_do_not_corrupt_other_words() {
+        let resume = json!({
+            "personal_info": {"name": "Al", "location": "CA", "address": "Remote"},
+            "skills": ["CAD", "CANopen", "Remote sensing", "Alerting"],
+            "experience": [{"title": "Engineer", "location": "CA"}]
+        });
+
+        let anonymized = anonymize_resume(&resume);
+
+        assert_eq!(
+            anonymized["skills"],
+            json!(["CAD", "CANopen", "[REDACTED] sensing", "Alerting"])
+        );
+        // A whole-string match is still redacted, however short
+        assert_eq!(anonymized["experience"][0]["location"], REDACTED);
+        assert_eq!(
+            redact_text("Remote-first; see RemoteOK", &resume),
+            "[REDACTED]-first; see RemoteOK"
+        );
+    }
+
+    #[test]
+    fn test_anonymize_resume_leaves_missing_fields_absent() {
+        let resume = json!({"personal_info": {"name": "Jane Doe", "phone": ""}});
+
+        let anonymized = anonymize_resume(&resume);
+
+        assert_eq!(anonymized["personal_info"]["phone"], "");
+        assert!(anonymized["personal_info"].get("email").is_none());
+    }
+}
@@ -0,0 +1,104 @@
+//! Helpers for consuming streamed HTTP responses.
+//!
+//! LLM providers stream output either as server-sent events (`OpenAI`, Gemini)
+//! or as newline-delimited JSON (Ollama). Both are line oriented, so the
+//! clients share [`response_lines`] and only differ in how each line is parsed.
+
+use crate::error::{AtsError, Result};
+use futures::stream::{self, Stream};
+use std::collections::VecDeque;
+
+/// Split a streaming HTTP response body into text lines.
+///
+/// Lines are yielded without their trailing `\r\n` / `\n`. A final line that
+/// is not newline-terminated is yielded when the body ends. A transport error
+/// is yielded once and terminates the stream.
+pub fn response_lines(response: reqwest::Response) -> impl Stream<Item = Result<String>> + Send {
+    struct State {
+        response: reqwest::Response,
+        buffer: Vec<u8>,
+        pending: VecDeque<String>,
+        done: bool,
+    }
+
+    let state = State {
+        response,
+        buffer: Vec::new(),
+        pending: VecDeque::new(),
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(line) = state.pending.pop_front() {
+                return Some((Ok(line), state));
+            }
+
+            if state.done {
+                if state.buffer.is_empty() {
+                    return None;
+                }
+                let rest = std::mem::take(&mut state.buffer);
+                return Some((Ok(decode_line(&rest)), state));
+            }
+
+            match state.response.chunk().await {
+                Ok(Some(bytes)) => {
+                    state.buffer.extend_from_slice(&bytes);
+                    while let Some(pos) = state.buffer.iter().position(|&b| b == b'\n') {
+                        let line: Vec<u8> = state.buffer.drain(..=pos).collect();
+                        state.pending.push_back(decode_line(&line[..pos]));
+                    }
+                }
+                Ok(None) => state.done = true,
+                Err(e) => {
+                    state.done = true;
+                    state.buffer.clear();
+                    let err = AtsError::ApiRequest {
+                        message: format!("Failed to read streamed response: {e}"),
+                        source: Some(e),
+                    };
+                    return Some((Err(err), state));
+                }
+            }
+        }
+    })
+}
+
+/// Extract the payload of a server-sent event `data:` line.
+///
+/// Returns `None` for comments, other SSE fields, blank keep-alive lines and
+/// the `OpenAI` `[DONE]` terminator.
+pub fn sse_data(line: &str) -> Option<&str> {
+    let data = line.strip_prefix("data:")?.trim();
+    if data.is_empty() || data == "[DONE]" {
+        return None;
+    }
+    Some(data)
+}
+
+fn decode_line(bytes: &[u8]) -> String {
+    let line = String::from_utf8_lossy(bytes);
+    line.strip_suffix('\r').unwrap_or(&line).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sse_data() {
+        assert_eq!(sse_data("data: {\"a\":1}"), Some("{\"a\":1}"));
+        assert_eq!(sse_data("data:{}"), Some("{}"));
+        assert_eq!(sse_data("data: [DONE]"), None);
+        assert_eq!(sse_data(": keep-alive"), None);
+        assert_eq!(sse_data("event: message"), None);
+        assert_eq!(sse_data(""), None);
+    }
+
+    #[test]
+    fn test_decode_line_strips_carriage_return() {
+        assert_eq!(decode_line(b"hello\r"), "hello");
+        assert_eq!(decode_line(b"hello"), "hello");
+    }
+}
@@ -0,0 +1,424 @@
+//! Text clean-up helpers for model output and prompts, plus a lightweight
+//! language check for resume text.
+
+/// A Markdown code fence: the fence character and how many times it repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Fence {
+    ch: char,
+    len: usize,
+}
+
+/// Strip Markdown code fences from model output.
+///
+/// Handles backtick and `~~~` fences, language tags (` ```json `), fences
+/// longer than three characters (so the content may itself contain fences),
+/// and fenced blocks surrounded by commentary. A block that is never closed
+/// (truncated output) yields everything after the opening fence. Text without
+/// a fence is returned trimmed but otherwise unchanged, even if it contains
+/// backticks.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::utils::text::strip_markdown_fences;
+///
+/// assert_eq!(strip_markdown_fences("```json\n{\"a\": 1}\n```\n"), "{\"a\": 1}");
+/// assert_eq!(strip_markdown_fences("~~~\n[1, 2]\n~~~"), "[1, 2]");
+/// assert_eq!(strip_markdown_fences("plain text"), "plain text");
+/// ```
+pub fn strip_markdown_fences(text: &str) -> String {
+    let trimmed = text.trim();
+    let lines: Vec<&str> = trimmed.lines().collect();
+
+    if let [line] = lines.as_slice() {
+        return strip_inline_fence(line).unwrap_or(line).to_string();
+    }
+
+    let Some((open, fence)) = lines
+        .iter()
+        .enumerate()
+        .find_map(|(i, line)| opening_fence(line).map(|fence| (i, fence)))
+    else {
+        return trimmed.to_string();
+    };
+
+    // The last matching closing line wins, so shorter fences nested inside the
+    // block are kept as content.
+    let body = &lines[open + 1..];
+    let end = body
+        .iter()
+        .rposition(|line| is_closing_fence(line, fence))
+        .unwrap_or(body.len());
+
+    body[..end].join("\n").trim().to_string()
+}
+
+/// Leading run of fence characters on `line`, if it is at least three long.
+fn fence_run(line: &str) -> Option<Fence> {
+    let ch = line.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = line.chars().take_while(|&c| c == ch).count();
+    (len >= 3).then_some(Fence { ch, len })
+}
+
+/// Parse an opening fence line (fence plus optional info string).
+fn opening_fence(line: &str) -> Option<Fence> {
+    let line = line.trim_start();
+    let fence = fence_run(line)?;
+    let info = &line[fence.len..];
+    // A backtick fence's info string cannot contain backticks
+    (fence.ch == '~' || !info.contains('`')).then_some(fence)
+}
+
+/// Whether `line` closes a block opened with `fence`.
+fn is_closing_fence(line: &str, fence: Fence) -> bool {
+    let line = line.trim();
+    !line.is_empty() && line.len() >= fence.len && line.chars().all(|c| c == fence.ch)
+}
+
+/// Strip a fence opened and closed on the same line, e.g. ` ```{"a": 1}``` `.
+fn strip_inline_fence(line: &str) -> Option<&str> {
+    let fence = fence_run(line)?;
+    let marker = fence.ch.to_string().repeat(fence.len);
+    let inner = line[fence.len..].strip_suffix(marker.as_str())?;
+    Some(inner.trim())
+}
+
+/// Marker appended to text cut short by [`truncate_to_budget`].
+pub const TRUNCATION_MARKER: &str = "\n[...]";
+
+/// Trim `text` to at most `max_chars` characters, keeping its beginning.
+///
+/// Whole lines are kept while they fit; the first line that doesn't is cut
+/// at a word boundary. Cut text ends with [`TRUNCATION_MARKER`], which counts
+/// towards the budget. Resumes conventionally open with the name, summary and
+/// most recent experience, so keeping the head keeps the most relevant parts.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::utils::text::truncate_to_budget;
+///
+/// let text = "Jane Doe\nSummary: engineer\nOlder roles...";
+/// assert_eq!(truncate_to_budget(text, 100), text);
+/// assert_eq!(truncate_to_budget(text, 33), "Jane Doe\nSummary: engineer\n[...]");
+/// ```
+pub fn truncate_to_budget(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let marker_len = TRUNCATION_MARKER.chars().count();
+    if max_chars <= marker_len {
+        return text.chars().take(max_chars).collect();
+    }
+
+    let budget = max_chars - marker_len;
+    let mut kept = String::new();
+    let mut used = 0;
+    for line in text.split_inclusive('\n') {
+        let len = line.chars().count();
+        if used + len <= budget {
+            kept.push_str(line);
+            used += len;
+            continue;
+        }
+        let head: String = line.chars().take(budget - used).collect();
+        let head = match head.rfind(char::is_whitespace) {
+            Some(end) if end > 0 => &head[..end],
+            _ => head.as_str(),
+        };
+        kept.push_str(head);
+        break;
+    }
+
+    let mut out = kept.trim_end().to_string();
+    out.push_str(TRUNCATION_MARKER);
+    out
+}
+
+/// Languages [`detect_language`] can recognise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lang {
+    /// English.
+    English,
+    /// Spanish.
+    Spanish,
+    /// French.
+    French,
+    /// German.
+    German,
+    /// Portuguese.
+    Portuguese,
+    /// Italian.
+    Italian,
+    /// Dutch.
+    Dutch,
+}
+
+impl Lang {
+    /// ISO 639-1 code, e.g. `"es"`.
+    #[must_use]
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Spanish => "es",
+            Self::French => "fr",
+            Self::German => "de",
+            Self::Portuguese => "pt",
+            Self::Italian => "it",
+            Self::Dutch => "nl",
+        }
+    }
+
+    /// English name of the language.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Spanish => "Spanish",
+            Self::French => "French",
+            Self::German => "German",
+            Self::Portuguese => "Portuguese",
+            Self::Italian => "Italian",
+            Self::Dutch => "Dutch",
+        }
+    }
+
+    /// Frequent function words, which show up even in terse bullet points.
+    fn function_words(self) -> &'static [&'static str] {
+        match self {
+            Self::English => &[
+                "the", "and", "of", "to", "with", "for", "was", "on", "as", "by", "at", "from",
+                "my", "i", "have", "our", "an", "this", "that", "across",
+            ],
+            Self::Spanish => &[
+                "el", "los", "las", "del", "y", "con", "para", "por", "que", "una", "un", "se",
+                "su", "al", "como", "sus", "más", "años", "mediante", "según",
+            ],
+            Self::French => &[
+                "le", "les", "des", "du", "et", "pour", "avec", "dans", "sur", "une", "est", "au",
+                "aux", "par", "qui", "ce", "ans", "plus", "été", "leur",
+            ],
+            Self::German => &[
+                "der", "die", "das", "und", "mit", "für", "von", "im", "zu", "den", "dem", "ein",
+                "eine", "ist", "auf", "bei", "als", "auch", "sowie", "jahre",
+            ],
+            Self::Portuguese => &[
+                "o", "os", "do", "da", "dos", "das", "e", "em", "com", "para", "um", "uma", "no",
+                "na", "nos", "como", "anos", "pela", "pelo", "não",
+            ],
+            Self::Italian => &[
+                "il", "lo", "gli", "di", "della", "e", "con", "per", "un", "una", "che", "nel",
+                "nella", "sono", "ho", "anni", "degli", "delle", "dei", "alla",
+            ],
+            Self::Dutch => &[
+                "het", "een", "en", "van", "met", "voor", "op", "te", "bij", "aan", "zijn", "ook",
+                "door", "naar", "om", "dat", "jaar", "heb", "binnen", "wij",
+            ],
+        }
+    }
+}
+
+/// Every language [`detect_language`] scores.
+const LANGUAGES: [Lang; 7] = [
+    Lang::English,
+    Lang::Spanish,
+    Lang::French,
+    Lang::German,
+    Lang::Portuguese,
+    Lang::Italian,
+    Lang::Dutch,
+];
+
+/// Function-word hits a language needs before it is reported.
+const MIN_LANGUAGE_HITS: usize = 3;
+
+/// Guess the language of `text` from its most frequent function words.
+///
+/// Returns `None` when the text is too short to tell, or two languages score
+/// the same.
+///
+/// # Example
+///
+/// ```rust
+/// use ats_checker::utils::text::{detect_language, Lang};
+///
+/// let text = "Desarrollé servicios para el equipo de pagos y lideré la migración de los datos";
+/// assert_eq!(detect_language(text), Some(Lang::Spanish));
+/// assert_eq!(detect_language("Rust"), None);
+/// ```
+#[must_use]
+pub fn detect_language(text: &str) -> Option<Lang> {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    let mut hits: Vec<(Lang, usize)> = LANGUAGES
+        .iter()
+        .map(|&lang| {
+            let vocabulary = lang.function_words();
+            let count = words
+                .iter()
+                .filter(|w| vocabulary.contains(&w.as_str()))
+                .count();
+            (lang, count)
+        })
+        .collect();
+    hits.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+
+    match hits.as_slice() {
+        [(best, count), (_, runner_up), ..] if *count >= MIN_LANGUAGE_HITS && count > runner_up => {
+            Some(*best)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON: &str = "{\"key\": \"value\"}";
+
+    #[test]
+    fn test_basic_fences() {
+        assert_eq!(
+            strip_markdown_fences("```json\n{\"key\": \"value\"}\n```"),
+            JSON
+        );
+        assert_eq!(
+            strip_markdown_fences("```\n{\"key\": \"value\"}\n```"),
+            JSON
+        );
+        assert_eq!(strip_markdown_fences(JSON), JSON);
+        assert_eq!(strip_markdown_fences("plain text"), "plain text");
+        assert_eq!(strip_markdown_fences(""), "");
+    }
+
+    #[test]
+    fn test_closing_fence_whitespace() {
+        assert_eq!(
+            strip_markdown_fences("```json\n{\"key\": \"value\"}\n```\n"),
+            JSON
+        );
+        assert_eq!(
+            strip_markdown_fences("```json\n{\"key\": \"value\"}\n```   \n\n"),
+            JSON
+        );
+        assert_eq!(
+            strip_markdown_fences("```json\r\n{\"key\": \"value\"}\r\n```\r\n"),
+            JSON
+        );
+        assert_eq!(
+            strip_markdown_fences("  ```json\n{\"key\": \"value\"}\n  ```"),
+            JSON
+        );
+    }
+
+    #[test]
+    fn test_tilde_fences_and_language_tags() {
+        assert_eq!(
+            strip_markdown_fences("~~~\n{\"key\": \"value\"}\n~~~"),
+            JSON
+        );
+        assert_eq!(
+            strip_markdown_fences("~~~json\n{\"key\": \"value\"}\n~~~"),
+            JSON
+        );
+        assert_eq!(
+            strip_markdown_fences("``` JSON \n{\"key\": \"value\"}\n```"),
+            JSON
+        );
+        assert_eq!(
+            strip_markdown_fences("```jsonc title=\"resume\"\n{\"key\": \"value\"}\n```"),
+            JSON
+        );
+    }
+
+    #[test]
+    fn test_nested_fences() {
+        let input = "````markdown\n# Notes\n```rust\nfn main() {}\n```\n````";
+        assert_eq!(
+            strip_markdown_fences(input),
+            "# Notes\n```rust\nfn main() {}\n```"
+        );
+
+        // A tilde block is not closed by backticks
+        let input = "~~~\n```\ninner\n```\n~~~";
+        assert_eq!(strip_markdown_fences(input), "```\ninner\n```");
+    }
+
+    #[test]
+    fn test_content_containing_backticks() {
+        let input = "{\"snippet\": \"use ```code``` blocks\"}";
+        assert_eq!(strip_markdown_fences(input), input);
+
+        let input = "```json\n{\"snippet\": \"wrap in ``` fences\"}\n```";
+        assert_eq!(
+            strip_markdown_fences(input),
+            "{\"snippet\": \"wrap in ``` fences\"}"
+        );
+
+        assert_eq!(strip_markdown_fences("`inline` code"), "`inline` code");
+    }
+
+    #[test]
+    fn test_surrounding_commentary() {
+        let input = "Here is the JSON:\n```json\n{\"key\": \"value\"}\n```\nLet me know!";
+        assert_eq!(strip_markdown_fences(input), JSON);
+    }
+
+    #[test]
+    fn test_unterminated_fence() {
+        assert_eq!(strip_markdown_fences("```json\n{\"key\": \"value\"}"), JSON);
+    }
+
+    #[test]
+    fn test_single_line_fence() {
+        assert_eq!(strip_markdown_fences("```{\"key\": \"value\"}```"), JSON);
+        assert_eq!(strip_markdown_fences("~~~ [1, 2] ~~~"), "[1, 2]");
+    }
+
+    #[test]
+    fn test_multiline_content_preserved() {
+        let input = "```json\n{\n  \"a\": [\n    1\n  ]\n}\n```";
+        assert_eq!(strip_markdown_fences(input), "{\n  \"a\": [\n    1\n  ]\n}");
+    }
+
+    #[test]
+    fn test_detect_language_spanish_resume() {
+        let resume = "Ingeniera de software con 6 años de experiencia en el desarrollo de \
+                      servicios web. Diseñé una plataforma de pagos para los clientes del banco \
+                      y reduje los tiempos de respuesta un 40% mediante la migración a Rust.";
+
+        let lang = detect_language(resume).unwrap();
+        assert_eq!(lang, Lang::Spanish);
+        assert_ne!(lang.code(), "en");
+    }
+
+    #[test]
+    fn test_detect_language_english_and_unknown() {
+        let resume = "Software engineer with 6 years of experience. Designed a payments \
+                      platform for the bank and cut response times by 40% across the stack.";
+        assert_eq!(detect_language(resume), Some(Lang::English));
+        assert_eq!(detect_language("Rust, Go, SQL"), None);
+    }
+
+    #[test]
+    fn test_truncate_to_budget() {
+        let text = "Jane Doe\nSummary: engineer\nOlder roles";
+        assert_eq!(truncate_to_budget(text, 200), text);
+
+        let cut = truncate_to_budget(text, 33);
+        assert_eq!(cut, "Jane Doe\nSummary: engineer\n[...]");
+        assert!(cut.chars().count() <= 33);
+
+        // A line that doesn't fit is cut at a word boundary
+        let cut = truncate_to_budget("alpha beta gamma delta", 18);
+        assert_eq!(cut, "alpha beta\n[...]");
+
+        // Budgets smaller than the marker cut hard, counting characters
+        assert_eq!(truncate_to_budget("ééééé", 3), "ééé");
+    }
+}
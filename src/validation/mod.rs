@@ -3,6 +3,7 @@
 use crate::error::{AtsError, Result};
 use jsonschema::Validator;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 
 /// Validation result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +12,9 @@ pub struct ValidationResult {
     pub ok: bool,
     /// Validation errors.
     pub errors: Vec<String>,
+    /// Structured validation errors, one per entry in `errors`.
+    #[serde(default)]
+    pub detailed_errors: Vec<SchemaError>,
     /// Summary message.
     pub summary: String,
 }
@@ -21,6 +25,7 @@ impl ValidationResult {
         Self {
             ok: true,
             errors: vec![],
+            detailed_errors: vec![],
             summary: "Valid".to_string(),
         }
     }
@@ -30,9 +35,71 @@ impl ValidationResult {
         Self {
             ok: false,
             errors,
+            detailed_errors: vec![],
             summary: "Validation failed".to_string(),
         }
     }
+
+    /// Create a failed validation result from structured errors.
+    pub fn from_schema_errors(detailed_errors: Vec<SchemaError>) -> Self {
+        let errors = detailed_errors
+            .iter()
+            .map(|e| format!("{}: {}", e.instance_path, e.message))
+            .collect();
+        Self {
+            detailed_errors,
+            ..Self::failure(errors)
+        }
+    }
+
+    /// Instance paths of all failed values, e.g. `/personal_info/email`.
+    pub fn failed_paths(&self) -> Vec<&str> {
+        self.detailed_errors
+            .iter()
+            .map(|e| e.instance_path.as_str())
+            .collect()
+    }
+}
+
+/// A single schema violation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaError {
+    /// JSON pointer to the failing value. For `required` violations this
+    /// points at the missing property itself rather than its parent object.
+    pub instance_path: String,
+    /// JSON pointer to the schema keyword that failed.
+    pub schema_path: String,
+    /// Human-readable description of the failure.
+    pub message: String,
+    /// The failing schema keyword (e.g. `required`, `type`).
+    pub keyword: String,
+}
+
+impl SchemaError {
+    fn from_validation_error(error: &jsonschema::ValidationError<'_>) -> Self {
+        let mut instance_path = error.instance_path.to_string();
+        if let jsonschema::error::ValidationErrorKind::Required { property } = &error.kind {
+            if let Some(property) = property.as_str() {
+                instance_path.push('/');
+                instance_path.push_str(&property.replace('~', "~0").replace('/', "~1"));
+            }
+        }
+
+        // The keyword is the last schema path segment that isn't an array index
+        let schema_path = error.schema_path.to_string();
+        let keyword = schema_path
+            .rsplit('/')
+            .find(|segment| !segment.is_empty() && !segment.bytes().all(|b| b.is_ascii_digit()))
+            .unwrap_or_default()
+            .to_string();
+
+        Self {
+            instance_path,
+            schema_path,
+            message: error.to_string(),
+            keyword,
+        }
+    }
 }
 
 /// Check if schema validation is available.
@@ -54,18 +121,134 @@ pub fn validate_json(
         .map_err(|e| AtsError::internal(format!("Failed to compile JSON schema: {e}")))?;
 
     // Validate the instance
-    if validator.is_valid(instance) {
-        Ok(ValidationResult::success())
-    } else {
-        // Collect all validation errors
-        let errors = validator.validate(instance);
-        let error_messages: Vec<String> = match errors {
-            Ok(()) => vec![], // This shouldn't happen since is_valid returned false
-            Err(error_iter) => error_iter
-                .map(|e| format!("{}: {}", e.instance_path, e))
+    let result = match validator.validate(instance) {
+        Ok(()) => ValidationResult::success(),
+        Err(error_iter) => ValidationResult::from_schema_errors(
+            error_iter
+                .map(|e| SchemaError::from_validation_error(&e))
                 .collect(),
-        };
+        ),
+    };
+    Ok(result)
+}
 
-        Ok(ValidationResult::failure(error_messages))
-    }
+// -------------------------
+// Built-in Schemas
+// -------------------------
+
+/// Canonical JSON schema for the structured resume read by the scorer.
+///
+/// Describes `personal_info`, `summary`, `experience[]`, `education[]`,
+/// `skills` and `projects[]`. Entries allow extra fields, and `skills` may be
+/// a flat list or a map of category to list. Used when no
+/// `resume_schema_path` file is present.
+pub fn resume_schema() -> Value {
+    // Bullets may be a list or a newline-separated string
+    let text_or_list = json!({
+        "oneOf": [
+            { "type": "string" },
+            { "type": "array", "items": { "type": "string" } },
+            { "type": "null" }
+        ]
+    });
+    let entry = |fields: &[&str]| {
+        let mut properties = serde_json::Map::new();
+        for field in fields {
+            properties.insert((*field).to_string(), json!({ "type": ["string", "null"] }));
+        }
+        for field in [
+            "description",
+            "bullets",
+            "achievements",
+            "responsibilities",
+            "highlights",
+        ] {
+            properties.insert(field.to_string(), text_or_list.clone());
+        }
+        json!({
+            "type": "object",
+            "additionalProperties": true,
+            "properties": properties
+        })
+    };
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "ATS Checker Structured Resume",
+        "type": "object",
+        "additionalProperties": true,
+        "required": ["personal_info", "experience", "education", "skills"],
+        "properties": {
+            "personal_info": {
+                "type": "object",
+                "additionalProperties": true,
+                "properties": {
+                    "name": { "type": "string" },
+                    "email": { "type": "string" },
+                    "phone": { "type": "string" },
+                    "headline": { "type": "string" },
+                    "title": { "type": "string" },
+                    "location": { "type": "string" },
+                    "linkedin": { "type": "string" },
+                    "github": { "type": "string" },
+                    "portfolio": { "type": "string" }
+                }
+            },
+            "summary": text_or_list,
+            "experience": {
+                "type": ["array", "null"],
+                "items": entry(&[
+                    "title", "company", "location", "start_date", "end_date", "dates", "duration"
+                ])
+            },
+            "education": {
+                "type": ["array", "null"],
+                "items": entry(&[
+                    "degree", "institution", "location", "graduation_date", "year", "gpa"
+                ])
+            },
+            "skills": {
+                "oneOf": [
+                    { "type": "array", "items": { "type": "string" } },
+                    {
+                        "type": "object",
+                        "additionalProperties": { "type": "array", "items": { "type": "string" } }
+                    },
+                    { "type": "null" }
+                ]
+            },
+            "projects": {
+                "type": ["array", "null"],
+                "items": entry(&["name", "link", "role", "technologies"])
+            }
+        }
+    })
+}
+
+/// JSON schema for a serialized [`crate::scoring::ScoreReport`].
+pub fn score_report_schema() -> Value {
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "ATS Checker Score Report",
+        "type": "object",
+        "required": ["kind", "total", "categories"],
+        "properties": {
+            "kind": { "type": "string" },
+            "total": { "type": "number", "minimum": 0, "maximum": 100 },
+            "categories": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["name", "score", "weight"],
+                    "properties": {
+                        "name": { "type": "string" },
+                        "score": { "type": "number", "minimum": 0, "maximum": 100 },
+                        "weight": { "type": "number" },
+                        "details": { "type": "object" }
+                    }
+                }
+            },
+            "meta": { "type": "object" }
+        }
+    })
 }
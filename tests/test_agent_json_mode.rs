@@ -0,0 +1,171 @@
+//! Tests for native JSON response modes when `require_json` is set.
+
+use ats_checker::agents::{Agent, AgentConfig, GeminiAgent, LlamaAgent, OpenAiAgent};
+use ats_checker::gemini::GeminiClient;
+use ats_checker::openai::OpenAiClient;
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn config(provider: &str, model: &str, require_json: bool) -> AgentConfig {
+    AgentConfig::builder()
+        .name("enhancer")
+        .provider(provider)
+        .model_name(model)
+        .require_json(require_json)
+        .build()
+}
+
+/// Body of the single request the mock server received.
+async fn request_body(server: &MockServer) -> Value {
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    serde_json::from_slice(&requests[0].body).unwrap()
+}
+
+#[tokio::test]
+async fn test_openai_sends_response_format() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(body_partial_json(json!({
+            "response_format": {"type": "json_object"}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"role": "assistant", "content": "{\"name\": \"Jane\"}"}}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let agent = OpenAiAgent::new("test-key", config("openai", "gpt-4", true))
+        .unwrap()
+        .with_base_url(server.uri());
+
+    let value = agent.generate_json("Extract the name").await.unwrap();
+    assert_eq!(value, json!({"name": "Jane"}));
+}
+
+#[tokio::test]
+async fn test_gemini_sends_response_mime_type() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/gemini-1.5-flash:generateContent"))
+        .and(body_partial_json(json!({
+            "generation_config": {"responseMimeType": "application/json"}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "candidates": [{"content": {"parts": [{"text": "{\"name\": \"Jane\"}"}]}}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let agent = GeminiAgent::new("test-key", config("gemini", "gemini-1.5-flash", true))
+        .unwrap()
+        .with_base_url(server.uri());
+
+    let value = agent.generate_json("Extract the name").await.unwrap();
+    assert_eq!(value, json!({"name": "Jane"}));
+
+    // Native mode replaces the prompt instruction
+    let body = request_body(&server).await;
+    assert_eq!(body["contents"][0]["parts"][0]["text"], "Extract the name");
+}
+
+#[tokio::test]
+async fn test_json_mode_off_without_require_json() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"role": "assistant", "content": "plain"}}]
+        })))
+        .mount(&server)
+        .await;
+
+    let agent = OpenAiAgent::new("test-key", config("openai", "gpt-4", false))
+        .unwrap()
+        .with_base_url(server.uri());
+    agent.generate_text("Say something").await.unwrap();
+
+    assert!(request_body(&server).await.get("response_format").is_none());
+}
+
+#[tokio::test]
+async fn test_llama_falls_back_to_prompt_instruction() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/generate"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "response": "```json\n{\"name\": \"Jane\"}\n```",
+            "done": true
+        })))
+        .mount(&server)
+        .await;
+
+    let agent = LlamaAgent::with_host(config("llama", "llama3.2", true), server.uri()).unwrap();
+    let value = agent.generate_json("Extract the name").await.unwrap();
+    assert_eq!(value, json!({"name": "Jane"}));
+
+    let prompt = request_body(&server).await["prompt"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(prompt.starts_with("Extract the name"));
+    assert!(prompt.contains("raw JSON object only"));
+}
+
+#[tokio::test]
+async fn test_openai_json_mode_only_applies_to_json_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"role": "assistant", "content": "Dear Hiring Manager,"}}]
+        })))
+        .mount(&server)
+        .await;
+
+    let client = OpenAiClient::new("test-key", "gpt-4")
+        .unwrap()
+        .with_base_url(server.uri())
+        .with_json_mode(true);
+    let text = client
+        .generate_content("Write a cover letter")
+        .await
+        .unwrap();
+    assert_eq!(text, "Dear Hiring Manager,");
+
+    assert!(request_body(&server).await.get("response_format").is_none());
+}
+
+#[tokio::test]
+async fn test_gemini_json_mode_only_applies_to_json_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/gemini-1.5-flash:generateContent"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "candidates": [{"content": {"parts": [{"text": "Dear Hiring Manager,"}]}}]
+        })))
+        .mount(&server)
+        .await;
+
+    let client = GeminiClient::new("test-key", "gemini-1.5-flash")
+        .unwrap()
+        .with_base_url(server.uri())
+        .with_json_mode(true);
+    client
+        .generate_content("Write a cover letter")
+        .await
+        .unwrap();
+
+    let body = request_body(&server).await;
+    assert!(body["generation_config"].get("responseMimeType").is_none());
+}
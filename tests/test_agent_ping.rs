@@ -0,0 +1,266 @@
+//! Tests for provider health checks (`ping`) and `AgentRegistry::verify_all`.
+
+use async_trait::async_trait;
+use ats_checker::agents::{
+    Agent, AgentConfig, AgentRegistry, AnthropicAgent, GeminiAgent, LlamaAgent, OpenAiAgent,
+};
+use ats_checker::anthropic::AnthropicClient;
+use ats_checker::error::{AtsError, Result};
+use ats_checker::gemini::GeminiClient;
+use ats_checker::llama::LlamaClient;
+use ats_checker::openai::OpenAiClient;
+use ats_checker::utils::http::ClientOptions;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, header, method, path, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn config(provider: &str, model: &str) -> AgentConfig {
+    AgentConfig::builder()
+        .name(provider)
+        .provider(provider)
+        .model_name(model)
+        .max_retries(0)
+        .build()
+}
+
+async fn mount_tags(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "models": [{"name": "llama3.2:latest"}, {"name": "mistral:7b"}]
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_ping_success() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(body_partial_json(
+            json!({"model": "gpt-4", "max_tokens": 1}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"role": "assistant", "content": "p"}}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .and(body_partial_json(json!({"max_tokens": 1})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "content": [{"type": "text", "text": "p"}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"^/gemini-pro:generateContent$"))
+        .and(body_partial_json(
+            json!({"generation_config": {"max_output_tokens": 1}}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "candidates": [{"content": {"parts": [{"text": "p"}]}}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_tags(&server).await;
+
+    let openai = OpenAiClient::new("key", "gpt-4")
+        .unwrap()
+        .with_base_url(server.uri());
+    openai.ping().await.unwrap();
+
+    let anthropic = AnthropicClient::new("key", "claude")
+        .unwrap()
+        .with_base_url(server.uri());
+    anthropic.ping().await.unwrap();
+
+    let gemini = GeminiClient::new("key", "gemini-pro")
+        .unwrap()
+        .with_base_url(server.uri());
+    gemini.ping().await.unwrap();
+
+    let llama = LlamaClient::new("llama3.2")
+        .unwrap()
+        .with_host(server.uri());
+    llama.ping().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_ping_auth_failure() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(401).set_body_string("invalid api key"))
+        .mount(&server)
+        .await;
+
+    let openai = OpenAiClient::new("bad-key", "gpt-4")
+        .unwrap()
+        .with_base_url(server.uri());
+    assert!(matches!(openai.ping().await, Err(AtsError::ApiAuth { .. })));
+
+    let anthropic = AnthropicClient::new("bad-key", "claude")
+        .unwrap()
+        .with_base_url(server.uri());
+    assert!(matches!(
+        anthropic.ping().await,
+        Err(AtsError::ApiAuth { .. })
+    ));
+
+    let gemini = GeminiClient::new("bad-key", "gemini-pro")
+        .unwrap()
+        .with_base_url(server.uri());
+    assert!(matches!(gemini.ping().await, Err(AtsError::ApiAuth { .. })));
+}
+
+#[tokio::test]
+async fn test_ollama_ping_requires_pulled_model() {
+    let server = MockServer::start().await;
+    mount_tags(&server).await;
+
+    let pulled = LlamaClient::new("mistral:7b")
+        .unwrap()
+        .with_host(server.uri());
+    pulled.ping().await.unwrap();
+
+    let missing = LlamaClient::new("codellama")
+        .unwrap()
+        .with_host(server.uri());
+    let err = missing.ping().await.unwrap_err();
+    assert!(matches!(err, AtsError::AgentConfig { .. }));
+    assert!(err.to_string().contains("ollama pull codellama"));
+}
+
+#[tokio::test]
+async fn test_registry_verify_all() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(header("authorization", "Bearer bad-key"))
+        .respond_with(ResponseTemplate::new(401).set_body_string("invalid api key"))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "content": [{"type": "text", "text": "p"}]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"^/gemini-pro:generateContent$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "candidates": [{"content": {"parts": [{"text": "p"}]}}]
+        })))
+        .mount(&server)
+        .await;
+    mount_tags(&server).await;
+
+    let mut registry = AgentRegistry::new();
+    registry.register(
+        "writer",
+        Box::new(
+            OpenAiAgent::new("bad-key", config("openai", "gpt-4"))
+                .unwrap()
+                .with_base_url(server.uri()),
+        ),
+    );
+    registry.register(
+        "reviewer",
+        Box::new(
+            AnthropicAgent::new("key", config("anthropic", "claude"))
+                .unwrap()
+                .with_base_url(server.uri()),
+        ),
+    );
+    registry.register(
+        "scorer",
+        Box::new(
+            GeminiAgent::new("key", config("gemini", "gemini-pro"))
+                .unwrap()
+                .with_base_url(server.uri()),
+        ),
+    );
+    registry.register(
+        "local",
+        Box::new(LlamaAgent::with_host(config("llama", "llama3.2"), server.uri()).unwrap()),
+    );
+
+    let results = registry.verify_all().await;
+    let names: Vec<&str> = results.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["local", "reviewer", "scorer", "writer"]);
+
+    for (name, result) in &results {
+        if name == "writer" {
+            assert!(matches!(result, Err(AtsError::ApiAuth { .. })), "{name}");
+        } else {
+            assert!(result.is_ok(), "{name}: {result:?}");
+        }
+    }
+}
+
+/// Agent whose probe either answers immediately or never answers.
+struct ProbeAgent {
+    config: AgentConfig,
+    hangs: bool,
+}
+
+#[async_trait]
+impl Agent for ProbeAgent {
+    fn config(&self) -> &AgentConfig {
+        &self.config
+    }
+
+    async fn generate_text(&self, _prompt: &str) -> Result<String> {
+        if self.hangs {
+            std::future::pending::<()>().await;
+        }
+        Ok("pong".to_string())
+    }
+
+    async fn generate_json(&self, _prompt: &str) -> Result<serde_json::Value> {
+        Ok(json!({}))
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_verify_all_with_times_out_hung_probe() {
+    let mut registry = AgentRegistry::new();
+    registry.register(
+        "fast",
+        Box::new(ProbeAgent {
+            config: config("openai", "gpt-4"),
+            hangs: false,
+        }),
+    );
+    registry.register(
+        "slow",
+        Box::new(ProbeAgent {
+            config: config("gemini", "gemini-pro"),
+            hangs: true,
+        }),
+    );
+
+    let options = ClientOptions::default().with_timeout(Duration::from_secs(5));
+    let results = registry.verify_all_with(&options, 2).await;
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].0, "fast");
+    assert!(results[0].1.is_ok(), "{:?}", results[0].1);
+    assert_eq!(results[1].0, "slow");
+    assert!(
+        matches!(
+            &results[1].1,
+            Err(AtsError::Timeout { provider, elapsed })
+                if provider == "gemini" && *elapsed == Duration::from_secs(5)
+        ),
+        "{:?}",
+        results[1].1
+    );
+}
@@ -1,6 +1,11 @@
-use ats_checker::agents::{AgentConfig, AgentDefaults, AgentRegistry, SyncAgentRegistry};
-use ats_checker::error::Result;
+use async_trait::async_trait;
+use ats_checker::agents::{
+    Agent, AgentConfig, AgentDefaults, AgentRegistry, RetryPolicy, SyncAgentRegistry,
+};
+use ats_checker::error::{AtsError, Result};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
 
 mod common;
 
@@ -58,7 +63,10 @@ fn test_agent_registry_from_config_with_agents() -> Result<()> {
             max_output_tokens: 2048,
             max_retries: 3,
             retry_on_empty: true,
+            retry_policy: RetryPolicy::default(),
             require_json: true,
+            system_prompt: None,
+            request_timeout_secs: None,
             extras: HashMap::new(),
         },
     );
@@ -76,7 +84,10 @@ fn test_agent_registry_from_config_with_agents() -> Result<()> {
             max_output_tokens: 2048,
             max_retries: 3,
             retry_on_empty: true,
+            retry_policy: RetryPolicy::default(),
             require_json: true,
+            system_prompt: None,
+            request_timeout_secs: None,
             extras: HashMap::new(),
         },
     );
@@ -115,7 +126,10 @@ fn test_agent_registry_from_config_unsupported_provider() {
             max_output_tokens: 2048,
             max_retries: 3,
             retry_on_empty: true,
+            retry_policy: RetryPolicy::default(),
             require_json: false,
+            system_prompt: None,
+            request_timeout_secs: None,
             extras: HashMap::new(),
         },
     );
@@ -158,7 +172,10 @@ fn test_agent_config_clone() {
         max_output_tokens: 1024,
         max_retries: 5,
         retry_on_empty: false,
+        retry_policy: RetryPolicy::default(),
         require_json: true,
+        system_prompt: None,
+        request_timeout_secs: None,
         extras: HashMap::new(),
     };
 
@@ -243,7 +260,10 @@ fn test_agent_defaults_apply_to() {
         max_output_tokens: 0,      // Zero - should be filled
         max_retries: 0,            // Zero - should be filled
         retry_on_empty: false,
+        retry_policy: RetryPolicy::default(),
         require_json: true,
+        system_prompt: None,
+        request_timeout_secs: None,
         extras: HashMap::new(),
     };
 
@@ -272,7 +292,10 @@ fn test_agent_defaults_apply_to_preserves_existing() {
         max_output_tokens: 2048,         // Non-zero - should NOT be overwritten
         max_retries: 5,                  // Non-zero - should NOT be overwritten
         retry_on_empty: false,
+        retry_policy: RetryPolicy::default(),
         require_json: true,
+        system_prompt: None,
+        request_timeout_secs: None,
         extras: HashMap::new(),
     };
 
@@ -317,3 +340,194 @@ fn test_sync_agent_registry_clone() {
     assert_eq!(registry1.list().len(), 0);
     assert_eq!(registry2.list().len(), 0);
 }
+
+/// Stub agent that returns a canned result and counts calls.
+struct StubAgent {
+    config: AgentConfig,
+    outcome: fn() -> Result<serde_json::Value>,
+    calls: Arc<AtomicU32>,
+}
+
+impl StubAgent {
+    fn boxed(
+        provider: &str,
+        outcome: fn() -> Result<serde_json::Value>,
+        calls: &Arc<AtomicU32>,
+    ) -> Box<dyn Agent> {
+        Box::new(Self {
+            config: AgentConfig::builder()
+                .name(provider)
+                .provider(provider)
+                .build(),
+            outcome,
+            calls: Arc::clone(calls),
+        })
+    }
+}
+
+#[async_trait]
+impl Agent for StubAgent {
+    fn config(&self) -> &AgentConfig {
+        &self.config
+    }
+
+    async fn generate_text(&self, _prompt: &str) -> Result<String> {
+        self.generate_json("").await.map(|v| v.to_string())
+    }
+
+    async fn generate_json(&self, _prompt: &str) -> Result<serde_json::Value> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        (self.outcome)()
+    }
+}
+
+fn quota_exceeded() -> Result<serde_json::Value> {
+    Err(AtsError::ApiRateLimit {
+        message: "quota exceeded".to_string(),
+        retry_after: None,
+    })
+}
+
+fn unparseable() -> Result<serde_json::Value> {
+    Err(AtsError::JsonParse {
+        message: "expected value".to_string(),
+        source: None,
+    })
+}
+
+fn timed_out() -> Result<serde_json::Value> {
+    Err(AtsError::timeout(
+        "Gemini",
+        std::time::Duration::from_secs(60),
+    ))
+}
+
+fn unreachable() -> Result<serde_json::Value> {
+    Err(AtsError::ApiRequest {
+        message: "connection refused".to_string(),
+        source: None,
+    })
+}
+
+fn success() -> Result<serde_json::Value> {
+    Ok(serde_json::json!({"ok": true}))
+}
+
+#[tokio::test]
+async fn test_fallback_uses_second_agent_when_first_fails() -> Result<()> {
+    let primary_calls = Arc::new(AtomicU32::new(0));
+    let backup_calls = Arc::new(AtomicU32::new(0));
+
+    let mut registry = AgentRegistry::new();
+    registry.register_with_fallbacks(
+        "enhancer",
+        vec![
+            StubAgent::boxed("gemini", quota_exceeded, &primary_calls),
+            StubAgent::boxed("openai", success, &backup_calls),
+        ],
+    )?;
+
+    let (value, provider) = registry
+        .generate_json_with_fallback("enhancer", "prompt")
+        .await?;
+
+    assert_eq!(value, serde_json::json!({"ok": true}));
+    assert_eq!(provider, "openai");
+    assert_eq!(primary_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(backup_calls.load(Ordering::SeqCst), 1);
+
+    // `get` returns the chain itself, so plain calls fall back too
+    registry.get("enhancer")?.generate_json("prompt").await?;
+    assert_eq!(primary_calls.load(Ordering::SeqCst), 2);
+    assert_eq!(backup_calls.load(Ordering::SeqCst), 2);
+
+    // Traced calls report the agent that answered each request
+    let generation = registry
+        .get("enhancer")?
+        .generate_text_traced("prompt")
+        .await?;
+    assert_eq!(generation.agent, "openai");
+    assert_eq!(generation.provider, "openai");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fallback_skips_hung_or_unreachable_provider() -> Result<()> {
+    for outcome in [timed_out, unreachable] {
+        let primary_calls = Arc::new(AtomicU32::new(0));
+        let backup_calls = Arc::new(AtomicU32::new(0));
+
+        let mut registry = AgentRegistry::new();
+        registry.register_with_fallbacks(
+            "enhancer",
+            vec![
+                StubAgent::boxed("gemini", outcome, &primary_calls),
+                StubAgent::boxed("openai", success, &backup_calls),
+            ],
+        )?;
+
+        let (_, provider) = registry
+            .generate_json_with_fallback("enhancer", "prompt")
+            .await?;
+
+        assert_eq!(provider, "openai");
+        assert_eq!(primary_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(backup_calls.load(Ordering::SeqCst), 1);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fallback_does_not_skip_parse_errors() -> Result<()> {
+    let primary_calls = Arc::new(AtomicU32::new(0));
+    let backup_calls = Arc::new(AtomicU32::new(0));
+
+    let mut registry = AgentRegistry::new();
+    registry.register_with_fallbacks(
+        "enhancer",
+        vec![
+            StubAgent::boxed("gemini", unparseable, &primary_calls),
+            StubAgent::boxed("openai", success, &backup_calls),
+        ],
+    )?;
+
+    let result = registry
+        .generate_json_with_fallback("enhancer", "prompt")
+        .await;
+
+    assert!(matches!(result, Err(AtsError::JsonParse { .. })));
+    assert_eq!(backup_calls.load(Ordering::SeqCst), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fallback_returns_last_error_when_all_fail() {
+    let calls = Arc::new(AtomicU32::new(0));
+
+    let mut registry = AgentRegistry::new();
+    registry
+        .register_with_fallbacks(
+            "enhancer",
+            vec![
+                StubAgent::boxed("gemini", quota_exceeded, &calls),
+                StubAgent::boxed("openai", quota_exceeded, &calls),
+            ],
+        )
+        .unwrap();
+
+    let result = registry
+        .generate_json_with_fallback("enhancer", "prompt")
+        .await;
+
+    assert!(matches!(result, Err(AtsError::ApiRateLimit { .. })));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_register_with_fallbacks_rejects_empty_chain() {
+    let mut registry = AgentRegistry::new();
+    assert!(registry
+        .register_with_fallbacks("empty", Vec::new())
+        .is_err());
+    assert!(registry.list().is_empty());
+}
@@ -0,0 +1,162 @@
+//! Integration tests for streaming text generation against mocked provider APIs.
+
+use ats_checker::agents::{Agent, AgentConfig, GeminiAgent, LlamaAgent, OpenAiAgent};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const FULL_TEXT: &str = "Led a team of five engineers.";
+
+fn config(provider: &str, model: &str) -> AgentConfig {
+    AgentConfig::builder()
+        .name("streamer")
+        .provider(provider)
+        .model_name(model)
+        .build()
+}
+
+async fn collect(agent: &dyn Agent, prompt: &str) -> (Vec<String>, String) {
+    let mut stream = agent.generate_stream(prompt).await.unwrap();
+    let mut chunks = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        chunks.push(chunk.unwrap());
+    }
+    let joined = chunks.concat();
+    (chunks, joined)
+}
+
+#[tokio::test]
+async fn test_openai_stream_matches_generate_text() {
+    let server = MockServer::start().await;
+
+    let sse_body = [
+        r#"data: {"choices":[{"delta":{"role":"assistant"}}]}"#,
+        r#"data: {"choices":[{"delta":{"content":"Led a team"}}]}"#,
+        r#"data: {"choices":[{"delta":{"content":" of five"}}]}"#,
+        r#"data: {"choices":[{"delta":{"content":" engineers."}}]}"#,
+        "data: [DONE]",
+    ]
+    .join("\n\n");
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(body_partial_json(json!({"stream": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(sse_body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"role": "assistant", "content": FULL_TEXT}}]
+        })))
+        .mount(&server)
+        .await;
+
+    let agent = OpenAiAgent::new("test-key", config("openai", "gpt-4"))
+        .unwrap()
+        .with_base_url(server.uri());
+
+    let (chunks, joined) = collect(&agent, "Rewrite this bullet").await;
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(
+        joined,
+        agent.generate_text("Rewrite this bullet").await.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_gemini_stream_matches_generate_text() {
+    let server = MockServer::start().await;
+
+    let sse_body = [
+        r#"data: {"candidates":[{"content":{"parts":[{"text":"Led a team of"}]}}]}"#,
+        r#"data: {"candidates":[{"content":{"parts":[{"text":" five engineers."}]}}]}"#,
+    ]
+    .join("\r\n\r\n");
+
+    Mock::given(method("POST"))
+        .and(path("/gemini-1.5-flash:streamGenerateContent"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(sse_body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/gemini-1.5-flash:generateContent"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "candidates": [{"content": {"parts": [{"text": FULL_TEXT}]}}]
+        })))
+        .mount(&server)
+        .await;
+
+    let agent = GeminiAgent::new("test-key", config("gemini", "gemini-1.5-flash"))
+        .unwrap()
+        .with_base_url(server.uri());
+
+    let (chunks, joined) = collect(&agent, "Rewrite this bullet").await;
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(
+        joined,
+        agent.generate_text("Rewrite this bullet").await.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_llama_stream_matches_generate_text() {
+    let server = MockServer::start().await;
+
+    let ndjson_body = [
+        r#"{"response":"Led a team","done":false}"#,
+        r#"{"response":" of five engineers.","done":false}"#,
+        r#"{"response":"","done":true}"#,
+    ]
+    .join("\n");
+
+    Mock::given(method("POST"))
+        .and(path("/api/generate"))
+        .and(body_partial_json(json!({"stream": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(ndjson_body, "application/x-ndjson"))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/generate"))
+        .and(body_partial_json(json!({"stream": false})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "response": FULL_TEXT,
+            "done": true
+        })))
+        .mount(&server)
+        .await;
+
+    let agent = LlamaAgent::with_host(config("llama", "llama3.2"), server.uri()).unwrap();
+
+    let (chunks, joined) = collect(&agent, "Rewrite this bullet").await;
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(
+        joined,
+        agent.generate_text("Rewrite this bullet").await.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_stream_surfaces_request_errors() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(429).set_body_string("slow down"))
+        .mount(&server)
+        .await;
+
+    let agent = OpenAiAgent::new("test-key", config("openai", "gpt-4"))
+        .unwrap()
+        .with_base_url(server.uri());
+
+    let result = agent.generate_stream("Rewrite this bullet").await;
+    assert!(matches!(
+        result,
+        Err(ats_checker::error::AtsError::ApiRateLimit { .. })
+    ));
+}
@@ -0,0 +1,115 @@
+//! Tests for per-agent system prompts.
+
+use ats_checker::agents::{Agent, AgentConfig, AnthropicAgent, GeminiAgent, OpenAiAgent};
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SYSTEM_PROMPT: &str = "Never fabricate employment dates.";
+
+fn config(provider: &str, model: &str) -> AgentConfig {
+    AgentConfig::builder()
+        .name("enhancer")
+        .provider(provider)
+        .model_name(model)
+        .system_prompt(SYSTEM_PROMPT)
+        .build()
+}
+
+#[test]
+fn test_system_prompt_toml_round_trip() {
+    let config = config("openai", "gpt-4");
+    assert_eq!(config.system_prompt.as_deref(), Some(SYSTEM_PROMPT));
+
+    let serialized = toml::to_string(&config).unwrap();
+    assert!(serialized.contains("system_prompt"));
+
+    let parsed: AgentConfig = toml::from_str(&serialized).unwrap();
+    assert_eq!(parsed.system_prompt.as_deref(), Some(SYSTEM_PROMPT));
+
+    let without: AgentConfig = toml::from_str("name = \"plain\"\nmodel_name = \"gpt-4\"").unwrap();
+    assert!(without.system_prompt.is_none());
+    assert!(!toml::to_string(&without).unwrap().contains("system_prompt"));
+}
+
+#[tokio::test]
+async fn test_openai_sends_system_message() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(body_partial_json(json!({
+            "messages": [
+                {"role": "system", "content": SYSTEM_PROMPT},
+                {"role": "user", "content": "Enhance this resume"}
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"role": "assistant", "content": "ok"}}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let agent = OpenAiAgent::new("test-key", config("openai", "gpt-4"))
+        .unwrap()
+        .with_base_url(server.uri());
+
+    assert_eq!(
+        agent.generate_text("Enhance this resume").await.unwrap(),
+        "ok"
+    );
+}
+
+#[tokio::test]
+async fn test_anthropic_sends_system_field() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .and(body_partial_json(json!({
+            "system": SYSTEM_PROMPT,
+            "messages": [{"role": "user", "content": "Enhance this resume"}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "content": [{"type": "text", "text": "ok"}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let agent = AnthropicAgent::new("test-key", config("anthropic", "claude-3-haiku"))
+        .unwrap()
+        .with_base_url(server.uri());
+
+    assert_eq!(
+        agent.generate_text("Enhance this resume").await.unwrap(),
+        "ok"
+    );
+}
+
+#[tokio::test]
+async fn test_gemini_prepends_system_prompt() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/gemini-1.5-flash:generateContent"))
+        .and(body_partial_json(json!({
+            "contents": [{"parts": [{"text": format!("{SYSTEM_PROMPT}\n\nEnhance this resume")}]}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "candidates": [{"content": {"parts": [{"text": "ok"}]}}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let agent = GeminiAgent::new("test-key", config("gemini", "gemini-1.5-flash"))
+        .unwrap()
+        .with_base_url(server.uri());
+
+    assert_eq!(
+        agent.generate_text("Enhance this resume").await.unwrap(),
+        "ok"
+    );
+}
@@ -0,0 +1,101 @@
+//! Tests for configurable HTTP request timeouts.
+
+use ats_checker::agents::{Agent, AgentConfig, OpenAiAgent};
+use ats_checker::error::AtsError;
+use ats_checker::openai::OpenAiClient;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Mount a chat completion that answers "ok" after `delay`.
+async fn slow_completion(server: &MockServer, delay: Duration) {
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({
+                    "choices": [{"message": {"role": "assistant", "content": "ok"}}]
+                }))
+                .set_delay(delay),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_client_timeout_applies_to_requests() {
+    let server = MockServer::start().await;
+    slow_completion(&server, Duration::from_millis(500)).await;
+
+    let client = |timeout| {
+        OpenAiClient::new("test-key", "gpt-4")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_timeout(timeout)
+    };
+
+    let impatient = client(Duration::from_millis(100));
+    assert!(impatient.generate_content("hello").await.is_err());
+
+    let patient = client(Duration::from_secs(5));
+    assert_eq!(patient.generate_content("hello").await.unwrap(), "ok");
+}
+
+#[tokio::test]
+async fn test_client_timeout_maps_to_timeout_error() {
+    let server = MockServer::start().await;
+    slow_completion(&server, Duration::from_millis(500)).await;
+
+    let client = OpenAiClient::new("test-key", "gpt-4")
+        .unwrap()
+        .with_base_url(server.uri())
+        .with_timeout(Duration::from_millis(100));
+
+    let err = client.generate_content("hello").await.unwrap_err();
+    match &err {
+        AtsError::Timeout { provider, elapsed } => {
+            assert_eq!(provider, "OpenAI");
+            assert!(*elapsed >= Duration::from_millis(100));
+            assert!(*elapsed < Duration::from_millis(500));
+        }
+        other => panic!("expected Timeout, got {other:?}"),
+    }
+    assert!(err.is_retryable());
+    assert!(err
+        .to_string()
+        .starts_with("OpenAI request timed out after"));
+}
+
+#[tokio::test]
+async fn test_agent_config_request_timeout() {
+    let server = MockServer::start().await;
+    slow_completion(&server, Duration::from_secs(3)).await;
+
+    let config = AgentConfig::builder()
+        .name("enhancer")
+        .provider("openai")
+        .model_name("gpt-4")
+        .request_timeout_secs(1)
+        .build();
+    assert_eq!(config.request_timeout(), Some(Duration::from_secs(1)));
+
+    let agent = OpenAiAgent::new("test-key", config)
+        .unwrap()
+        .with_base_url(server.uri());
+    assert!(agent.generate_text("hello").await.is_err());
+}
+
+#[test]
+fn test_request_timeout_toml() {
+    let config: AgentConfig =
+        toml::from_str("name = \"slow\"\nmodel_name = \"llama3\"\nrequest_timeout_secs = 300")
+            .unwrap();
+    assert_eq!(config.request_timeout(), Some(Duration::from_secs(300)));
+
+    let unset: AgentConfig = toml::from_str("name = \"plain\"\nmodel_name = \"gpt-4\"").unwrap();
+    assert_eq!(unset.request_timeout(), None);
+    assert!(!toml::to_string(&unset)
+        .unwrap()
+        .contains("request_timeout_secs"));
+}
@@ -0,0 +1,45 @@
+//! Tests for per-request token usage reporting.
+
+use ats_checker::agents::{Agent, AgentConfig, OpenAiAgent, TokenUsage};
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_failed_request_reports_no_usage() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"role": "assistant", "content": "Hello"}}],
+            "usage": {"prompt_tokens": 12, "completion_tokens": 3, "total_tokens": 15}
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("bad request"))
+        .mount(&server)
+        .await;
+
+    let config = AgentConfig::builder()
+        .name("writer")
+        .provider("openai")
+        .model_name("gpt-4")
+        .max_retries(0)
+        .build();
+    let agent = OpenAiAgent::new("test-key", config)
+        .unwrap()
+        .with_base_url(server.uri());
+
+    let generation = agent.generate_text_traced("Say hello").await.unwrap();
+    assert_eq!(generation.value, "Hello");
+    assert_eq!(generation.agent, "writer");
+    assert_eq!(generation.provider, "openai");
+    assert_eq!(generation.usage, Some(TokenUsage::new(12, 3)));
+    assert_eq!(agent.last_usage(), Some(TokenUsage::new(12, 3)));
+
+    assert!(agent.generate_text_traced("Say hello").await.is_err());
+    assert_eq!(agent.last_usage(), None);
+}
@@ -0,0 +1,126 @@
+//! Integration tests for the batch processing CSV summary.
+
+mod common;
+
+use ats_checker::processor::{export_summary_csv, ProcessingResult};
+use ats_checker::scoring::{ScoreCategoryResult, ScoreReport};
+use common::*;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Parse RFC 4180 CSV text into records.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => in_quotes = !in_quotes,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+
+    records
+}
+
+fn category(name: &str, score: f64) -> ScoreCategoryResult {
+    ScoreCategoryResult {
+        name: name.to_string(),
+        score,
+        weight: 0.5,
+        details: HashMap::new(),
+    }
+}
+
+#[test]
+fn test_export_summary_csv_round_trip() {
+    let temp_dir = create_temp_dir();
+    let csv_path = temp_dir.path().join("summary.csv");
+
+    let results = vec![
+        ProcessingResult {
+            index: 0,
+            resume_name: "Doe, John".to_string(),
+            success: true,
+            output_dir: Some(PathBuf::from("workspace/output/john")),
+            scores: Some(ScoreReport {
+                kind: "resume".to_string(),
+                total: 82.5,
+                categories: vec![category("completeness", 90.0), category("keywords", 75.0)],
+                meta: HashMap::new(),
+            }),
+            enhanced_resume: None,
+            recommendations: vec![],
+            iterations: 2,
+            iteration_history: vec![],
+            run_report: None,
+            enhancement_confidence: 0.9,
+            detected_language: None,
+            error: None,
+        },
+        ProcessingResult {
+            index: 1,
+            resume_name: "jane \"JR\" roe".to_string(),
+            success: false,
+            output_dir: None,
+            scores: None,
+            enhanced_resume: None,
+            recommendations: vec![],
+            iterations: 0,
+            iteration_history: vec![],
+            run_report: None,
+            enhancement_confidence: 0.0,
+            detected_language: None,
+            error: Some("enhancer failed".to_string()),
+        },
+    ];
+
+    export_summary_csv(&results, &csv_path).expect("Failed to export CSV");
+
+    let records = parse_csv(&std::fs::read_to_string(&csv_path).unwrap());
+    assert_eq!(records.len(), 3);
+    assert_eq!(
+        records[0],
+        [
+            "resume_name",
+            "total_score",
+            "completeness",
+            "keywords",
+            "iterations",
+            "output_dir",
+            "success"
+        ]
+    );
+    assert!(records.iter().all(|r| r.len() == records[0].len()));
+
+    assert_eq!(
+        records[1],
+        [
+            "Doe, John",
+            "82.50",
+            "90.00",
+            "75.00",
+            "2",
+            "workspace/output/john",
+            "true"
+        ]
+    );
+    // Missing scores are empty cells, not zeros
+    assert_eq!(
+        records[2],
+        ["jane \"JR\" roe", "", "", "", "0", "", "false"]
+    );
+}
@@ -2,7 +2,8 @@
 
 mod common;
 
-use ats_checker::cli::{Cli, Commands};
+use ats_checker::cli::{Cli, Commands, OutputFormat};
+use ats_checker::scraper::DatePosted;
 use clap::Parser;
 
 #[test]
@@ -51,6 +52,41 @@ fn test_cli_score_match_command() {
     }
 }
 
+#[test]
+fn test_cli_score_job_command() {
+    let args = vec!["ats-checker", "score-job", "--job", "job.json"];
+
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        Some(Commands::ScoreJob { job, weights }) => {
+            assert_eq!(job, "job.json");
+            assert_eq!(weights, None);
+        }
+        _ => panic!("Expected ScoreJob command"),
+    }
+}
+
+#[test]
+fn test_cli_format_flag() {
+    let cli = Cli::try_parse_from(["ats-checker", "score-resume", "--resume", "r.json"]).unwrap();
+    assert_eq!(cli.format, OutputFormat::Text);
+
+    // Global, so it is accepted after the subcommand too
+    let args = [
+        "ats-checker",
+        "score-resume",
+        "--resume",
+        "r.json",
+        "--format",
+        "json",
+    ];
+    let cli = Cli::try_parse_from(args).unwrap();
+    assert_eq!(cli.format, OutputFormat::Json);
+
+    assert!(Cli::try_parse_from(["ats-checker", "--format", "yaml"]).is_err());
+}
+
 #[test]
 fn test_cli_rank_jobs_command() {
     let args = vec![
@@ -73,6 +109,46 @@ fn test_cli_rank_jobs_command() {
     }
 }
 
+#[test]
+fn test_cli_run_saved_searches_command() {
+    let cli = Cli::try_parse_from(["ats-checker", "run-saved-searches"]).unwrap();
+    assert!(matches!(cli.command, Some(Commands::RunSavedSearches)));
+}
+
+#[test]
+fn test_cli_job_search_since() {
+    let cli = Cli::try_parse_from([
+        "ats-checker",
+        "job-search",
+        "--keywords",
+        "rust",
+        "--since",
+        "7d",
+    ])
+    .unwrap();
+    match cli.command {
+        Some(Commands::JobSearch { since, .. }) => assert_eq!(since, Some(DatePosted::PastWeek)),
+        other => panic!("Expected JobSearch, got: {other:?}"),
+    }
+
+    // Keywords are optional; the candidate profile fills them in
+    let cli = Cli::try_parse_from(["ats-checker", "job-search"]).unwrap();
+    match cli.command {
+        Some(Commands::JobSearch { keywords, .. }) => assert_eq!(keywords, None),
+        other => panic!("Expected JobSearch, got: {other:?}"),
+    }
+
+    let args = [
+        "ats-checker",
+        "job-search",
+        "--keywords",
+        "rust",
+        "--since",
+        "last tuesday",
+    ];
+    assert!(Cli::try_parse_from(args).is_err());
+}
+
 #[test]
 fn test_cli_default_config_path() {
     let args = vec!["ats-checker"];
@@ -110,6 +186,18 @@ fn test_cli_quiet_flag() {
     assert!(cli.quiet);
 }
 
+#[test]
+fn test_cli_force_flag() {
+    let cli = Cli::try_parse_from(["ats-checker"]).unwrap();
+    assert!(!cli.force);
+
+    let cli = Cli::try_parse_from(["ats-checker", "--force"]).unwrap();
+    assert!(cli.force);
+
+    let cli = Cli::try_parse_from(["ats-checker", "interactive", "--force"]).unwrap();
+    assert!(cli.force);
+}
+
 #[test]
 fn test_cli_no_command_is_interactive() {
     let args = vec!["ats-checker"];
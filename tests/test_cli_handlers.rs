@@ -2,7 +2,10 @@
 
 mod common;
 
-use ats_checker::cli::handlers::{handle_rank_jobs, handle_score_match, handle_score_resume};
+use ats_checker::cli::handlers::{
+    handle_rank_jobs, handle_score_job, handle_score_match, handle_score_resume,
+};
+use ats_checker::cli::OutputFormat;
 use ats_checker::config::Config;
 use common::{
     create_temp_dir, create_test_file, sample_config_toml, sample_job_description,
@@ -30,6 +33,7 @@ fn test_handle_score_resume_with_json() {
     let result = handle_score_resume(
         resume_path.to_str().unwrap(),
         Some(weights_path.to_str().unwrap()),
+        OutputFormat::Text,
         &config,
     );
 
@@ -62,6 +66,7 @@ fn test_handle_score_resume_with_toml() {
     let result = handle_score_resume(
         resume_path.to_str().unwrap(),
         Some(weights_path.to_str().unwrap()),
+        OutputFormat::Text,
         &config,
     );
 
@@ -76,7 +81,7 @@ fn test_handle_score_resume_with_invalid_file() {
     let config = Config::load(config_path.to_str().unwrap()).unwrap();
 
     // Test with non-existent file
-    let result = handle_score_resume("nonexistent.json", None, &config);
+    let result = handle_score_resume("nonexistent.json", None, OutputFormat::Text, &config);
 
     assert!(result.is_err());
 }
@@ -92,7 +97,12 @@ fn test_handle_score_resume_with_invalid_format() {
     let config = Config::load(config_path.to_str().unwrap()).unwrap();
 
     // Test scoring with unsupported file format
-    let result = handle_score_resume(resume_path.to_str().unwrap(), None, &config);
+    let result = handle_score_resume(
+        resume_path.to_str().unwrap(),
+        None,
+        OutputFormat::Text,
+        &config,
+    );
 
     assert!(result.is_err());
 }
@@ -121,6 +131,7 @@ fn test_handle_score_match() {
         resume_path.to_str().unwrap(),
         job_path.to_str().unwrap(),
         Some(weights_path.to_str().unwrap()),
+        OutputFormat::Text,
         &config,
     );
 
@@ -147,12 +158,57 @@ fn test_handle_score_match_with_missing_job() {
         resume_path.to_str().unwrap(),
         "nonexistent_job.txt",
         None,
+        OutputFormat::Text,
         &config,
     );
 
     assert!(result.is_err());
 }
 
+#[test]
+fn test_handle_score_job() {
+    let temp_dir = create_temp_dir();
+
+    let job = serde_json::json!({
+        "title": "Senior Software Engineer",
+        "company": "Tech Corp",
+        "location": "Remote",
+        "description": sample_job_description(),
+        "salary": "$150,000 - $180,000",
+        "url": "https://example.com/jobs/123",
+        "posted_date": chrono::Utc::now().format("%Y-%m-%d").to_string()
+    });
+    let job_path = create_test_file(
+        temp_dir.path(),
+        "job.json",
+        &serde_json::to_string_pretty(&job).unwrap(),
+    );
+    let text_job_path = create_test_file(temp_dir.path(), "job.txt", sample_job_description());
+
+    let weights_path = create_test_file(temp_dir.path(), "weights.toml", sample_scoring_weights());
+    let config_path = create_test_file(temp_dir.path(), "config.toml", sample_config_toml());
+    let config = Config::load(config_path.to_str().unwrap()).unwrap();
+
+    let result = handle_score_job(
+        job_path.to_str().unwrap(),
+        Some(weights_path.to_str().unwrap()),
+        OutputFormat::Text,
+        &config,
+    );
+    assert_eq!(result.unwrap(), 0);
+
+    // Plain text postings are scored too
+    let result = handle_score_job(
+        text_job_path.to_str().unwrap(),
+        None,
+        OutputFormat::Text,
+        &config,
+    );
+    assert_eq!(result.unwrap(), 0);
+
+    assert!(handle_score_job("nonexistent_job.json", None, OutputFormat::Text, &config).is_err());
+}
+
 #[test]
 fn test_handle_rank_jobs() {
     let temp_dir = create_temp_dir();
@@ -197,7 +253,12 @@ source = "Glassdoor"
     config.scoring_weights_file = weights_path;
 
     // Test ranking
-    let result = handle_rank_jobs(results_path.to_str().unwrap(), 10, &config);
+    let result = handle_rank_jobs(
+        results_path.to_str().unwrap(),
+        10,
+        OutputFormat::Text,
+        &config,
+    );
 
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), 0);
@@ -223,7 +284,12 @@ jobs = []
     config.scoring_weights_file = weights_path;
 
     // Test ranking with empty jobs
-    let result = handle_rank_jobs(results_path.to_str().unwrap(), 10, &config);
+    let result = handle_rank_jobs(
+        results_path.to_str().unwrap(),
+        10,
+        OutputFormat::Text,
+        &config,
+    );
 
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), 0);
@@ -287,7 +353,12 @@ source = "Indeed"
     config.scoring_weights_file = weights_path;
 
     // Test ranking with top=2 (should show only 2 jobs)
-    let result = handle_rank_jobs(results_path.to_str().unwrap(), 2, &config);
+    let result = handle_rank_jobs(
+        results_path.to_str().unwrap(),
+        2,
+        OutputFormat::Text,
+        &config,
+    );
 
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), 0);
@@ -300,7 +371,60 @@ fn test_handle_rank_jobs_with_invalid_file() {
     let config = Config::load(config_path.to_str().unwrap()).unwrap();
 
     // Test with non-existent file
-    let result = handle_rank_jobs("nonexistent.toml", 10, &config);
+    let result = handle_rank_jobs("nonexistent.toml", 10, OutputFormat::Text, &config);
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_json_format_prints_score_reports_to_stdout() {
+    let temp_dir = create_temp_dir();
+    let resume_path = create_test_file(
+        temp_dir.path(),
+        "resume.json",
+        &serde_json::to_string_pretty(&sample_resume_json()).unwrap(),
+    );
+    let job_path = create_test_file(temp_dir.path(), "job.txt", sample_job_description());
+    create_test_file(temp_dir.path(), "config.toml", sample_config_toml());
+
+    let run = |args: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_ats-checker"))
+            .current_dir(temp_dir.path())
+            .args(["--config", "config.toml", "--format", "json"])
+            .args(args)
+            .output()
+            .expect("Failed to run ats-checker");
+        assert!(output.status.success(), "{output:?}");
+        serde_json::from_slice::<serde_json::Value>(&output.stdout)
+            .expect("stdout should be valid JSON")
+    };
+
+    let report = run(&["score-resume", "--resume", resume_path.to_str().unwrap()]);
+    assert_eq!(report["kind"], "resume");
+    assert!(report["total"].is_f64());
+    assert!(report["categories"]
+        .as_array()
+        .is_some_and(|c| !c.is_empty()));
+
+    let report = run(&[
+        "score-match",
+        "--resume",
+        resume_path.to_str().unwrap(),
+        "--job",
+        job_path.to_str().unwrap(),
+    ]);
+    assert!(report["total"].is_f64());
+    assert_eq!(report["resume"]["kind"], "resume");
+    assert_eq!(report["match"]["kind"], "match");
+
+    // Errors still go to stderr
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_ats-checker"))
+        .current_dir(temp_dir.path())
+        .args(["--config", "config.toml", "--format", "json"])
+        .args(["score-resume", "--resume", "missing.json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Error scoring resume"));
+}
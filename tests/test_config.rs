@@ -29,6 +29,75 @@ fn test_config_from_toml_string() {
     assert_eq!(config.iteration_strategy, "best_of");
 }
 
+#[test]
+fn test_config_load_sectioned_layout() {
+    let temp_dir = create_temp_dir();
+    let config_path = create_test_file(temp_dir.path(), "config.toml", sample_config_toml());
+
+    let config = Config::load(&config_path).unwrap();
+
+    // Values come from the [paths], [processing] and [ai] tables rather than
+    // the defaults
+    assert_eq!(config.structured_output_format, "json");
+    assert_ne!(
+        config.structured_output_format,
+        Config::default().structured_output_format
+    );
+    assert_eq!(
+        config.state_file,
+        temp_dir.path().join("data/test_state.toml")
+    );
+    assert_eq!(config.default_model_name, "gemini-1.5-flash");
+
+    // [ai] aliases map onto the default_* fields and ai_agents
+    let config_path = create_test_file(
+        temp_dir.path(),
+        "aliased.toml",
+        r#"
+[ai]
+model_name = "gpt-4o-mini"
+temperature = 0.2
+
+[ai.agents.enhancer]
+provider = "openai"
+model_name = "gpt-4o-mini"
+
+[processing]
+max_iterations = 7
+"#,
+    );
+    let config = Config::load(&config_path).unwrap();
+    assert_eq!(config.default_model_name, "gpt-4o-mini");
+    assert!((config.default_temperature - 0.2).abs() < f64::EPSILON);
+    assert_eq!(config.ai_agents["enhancer"].provider, "openai");
+    assert_eq!(config.max_iterations, 7);
+}
+
+#[test]
+fn test_shipped_config_and_profiles_validate() {
+    let config = Config::load("config/config.toml").expect("Failed to load config/config.toml");
+    config
+        .validate()
+        .expect("config/config.toml should validate");
+
+    // Paths are relative, so the sample works on any machine
+    for path in [
+        &config.input_resumes_folder,
+        &config.output_folder,
+        &config.state_file,
+        &config.scoring_weights_file,
+    ] {
+        assert!(path.is_relative(), "{}", path.display());
+    }
+
+    for entry in std::fs::read_dir("config/profiles").unwrap() {
+        let path = entry.unwrap().path();
+        Config::load(&path)
+            .and_then(|profile| profile.validate())
+            .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    }
+}
+
 #[test]
 fn test_config_default() {
     let config = Config::default();
@@ -78,3 +147,116 @@ fn test_config_with_custom_paths() {
         .contains("resumes"));
     assert!(config.output_folder.to_str().unwrap().contains("output"));
 }
+
+#[test]
+fn test_config_load_applies_profile_overlay() {
+    let temp_dir = create_temp_dir();
+
+    let profile_path = create_test_file(
+        temp_dir.path(),
+        "profile.toml",
+        r#"
+default_model_name = "gemini-1.5-pro"
+
+[ai_agents.enhancer]
+temperature = 0.2
+
+[job_portals.indeed]
+enabled = false
+"#,
+    );
+
+    let base = format!(
+        r#"
+default_model_name = "gemini-1.5-flash"
+target_score = 85.0
+profile_file = "{}"
+
+[ai_agents.enhancer]
+provider = "gemini"
+model_name = "gemini-1.5-flash"
+temperature = 0.7
+require_json = true
+
+[ai_agents.reviser]
+temperature = 0.8
+
+[job_portals.linkedin]
+default_location = "Seattle, WA"
+
+[job_portals.indeed]
+default_country = "USA"
+"#,
+        profile_path.display().to_string().replace('\\', "/")
+    );
+    let config_path = create_test_file(temp_dir.path(), "config.toml", &base);
+
+    let config = Config::load(&config_path).expect("Failed to load config with profile");
+
+    // Overridden by the profile
+    assert_eq!(config.default_model_name, "gemini-1.5-pro");
+    let enhancer = &config.ai_agents["enhancer"];
+    assert_eq!(enhancer.temperature, 0.2);
+    assert!(!config.job_portals["indeed"].enabled);
+
+    // Untouched base values survive the overlay
+    assert_eq!(config.target_score, 85.0);
+    assert_eq!(enhancer.model_name, "gemini-1.5-flash");
+    assert!(enhancer.require_json);
+    assert_eq!(config.ai_agents["reviser"].temperature, 0.8);
+    assert_eq!(
+        config.job_portals["indeed"].default_country.as_deref(),
+        Some("USA")
+    );
+    assert_eq!(
+        config.job_portals["linkedin"].default_location.as_deref(),
+        Some("Seattle, WA")
+    );
+    assert!(config.job_portals["linkedin"].enabled);
+}
+
+#[test]
+fn test_config_merge_adds_new_map_entries() {
+    let base: Config = toml::from_str("[ai_agents.enhancer]\ntemperature = 0.5").unwrap();
+    let overlay: Config =
+        toml::from_str("max_iterations = 7\n\n[ai_agents.summarizer]\nprovider = \"openai\"")
+            .unwrap();
+
+    let merged = base.merge(overlay);
+
+    assert_eq!(merged.max_iterations, 7);
+    assert_eq!(merged.ai_agents["enhancer"].temperature, 0.5);
+    assert_eq!(merged.ai_agents["summarizer"].provider, "openai");
+    assert_eq!(merged.ai_agents["summarizer"].temperature, 0.7);
+}
+
+#[test]
+fn test_config_save_roundtrip() {
+    let temp_dir = create_temp_dir();
+    let config_path = temp_dir.path().join("config.toml");
+    std::fs::copy(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("config/config.toml"),
+        &config_path,
+    )
+    .unwrap();
+
+    let mut config = Config::load(&config_path).expect("Failed to load sample config");
+    let original_target = config.target_score;
+    config.target_score = original_target - 5.0;
+    config.save(&config_path).expect("Failed to save config");
+
+    // Paths are written relative to the config file, not as expanded
+    let saved = std::fs::read_to_string(&config_path).unwrap();
+    assert!(!saved.contains(&*temp_dir.path().to_string_lossy()));
+
+    let reloaded = Config::load(&config_path).expect("Failed to reload config");
+    assert_eq!(reloaded.target_score, original_target - 5.0);
+    assert_eq!(reloaded.output_folder, config.output_folder);
+    assert_eq!(reloaded.state_file, config.state_file);
+
+    // Every other field survives the round trip
+    assert_eq!(
+        serde_json::to_value(&reloaded).unwrap(),
+        serde_json::to_value(&config).unwrap()
+    );
+}
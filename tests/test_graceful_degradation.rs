@@ -160,6 +160,8 @@ fn test_output_generator_with_invalid_directory() {
         enhanced_resume: json!({"personal_info": {"name": "John Doe"}}),
         scores: None,
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -1,6 +1,11 @@
 //! Integration tests for job scraping types and basic functionality.
 
-use ats_checker::scraper::{JobPosting, JobSource, SearchFilters};
+mod common;
+
+use ats_checker::config::{Config, Profile};
+use ats_checker::scoring::ScoringOptions;
+use ats_checker::scraper::{rank_jobs_for_resume, JobPosting, JobSource, SearchFilters};
+use common::{create_temp_dir, create_test_file, sample_resume_json};
 use std::str::FromStr;
 
 #[tokio::test]
@@ -165,6 +170,65 @@ fn test_job_posting_remote_check() {
     assert!(!unspecified_job.is_remote()); // Defaults to false
 }
 
+#[test]
+fn test_job_posting_to_scoring_json() {
+    let job = JobPosting::new(
+        "Senior Rust Engineer",
+        "Acme",
+        "Berlin",
+        "Build Rust services. Requirements: 5+ years.",
+        "https://example.com/jobs/1",
+        "linkedin",
+    )
+    .with_salary("$120,000 - $150,000")
+    .with_experience_level("senior")
+    .with_posted_date(chrono::Utc::now().format("%Y-%m-%d").to_string())
+    .with_metadata("is_new", serde_json::json!(true));
+
+    let json = job.to_scoring_json();
+
+    assert_eq!(json["title"], "Senior Rust Engineer");
+    assert_eq!(
+        json["description"],
+        "Build Rust services. Requirements: 5+ years."
+    );
+    assert_eq!(json["company"], "Acme");
+    assert_eq!(json["location"], "Berlin");
+    assert_eq!(json["url"], "https://example.com/jobs/1");
+    assert_eq!(json["salary"], "$120,000 - $150,000");
+    assert_eq!(json["experience_level"], "senior");
+    assert!(json.get("source").is_none());
+    assert!(json.get("metadata").is_none());
+
+    let report = ats_checker::score_job(&json, None).unwrap();
+    let completeness = report
+        .categories
+        .iter()
+        .find(|c| c.name == "completeness")
+        .unwrap();
+    for key in ["has_title", "has_company", "has_location", "has_url"] {
+        assert_eq!(completeness.details[key], true, "{key}");
+    }
+    let recency = |report: &ats_checker::scoring::ScoreReport| {
+        report
+            .categories
+            .iter()
+            .find(|c| c.name == "recency")
+            .unwrap()
+            .clone()
+    };
+    assert_eq!(recency(&report).score, 100.0);
+    assert_eq!(recency(&report).details["age_days"], 0.0);
+
+    let bare = JobPosting::new("Engineer", "Acme", "Remote", "Build things", "", "test");
+    assert_eq!(bare.to_scoring_json()["salary"], "");
+    assert!(bare.to_scoring_json().get("experience_level").is_none());
+    assert!(bare.to_scoring_json().get("date_posted").is_none());
+    let report = ats_checker::score_job(&bare.to_scoring_json(), None).unwrap();
+    assert_eq!(recency(&report).score, 50.0);
+    assert_eq!(recency(&report).details["reason"], "missing_posting_date");
+}
+
 #[tokio::test]
 async fn test_job_posting_metadata() {
     let job = JobPosting::new(
@@ -223,3 +287,103 @@ async fn test_multiple_job_postings() {
     assert_ne!(jobs[1].id(), jobs[2].id());
     assert_ne!(jobs[0].id(), jobs[2].id());
 }
+
+#[test]
+fn test_rank_jobs_for_resume_orders_by_overlap() {
+    let jobs = vec![
+        JobPosting::new(
+            "Pastry Chef",
+            "Sweet Bakery",
+            "Paris",
+            "Bake croissants and laminated dough each morning.",
+            "https://example.com/jobs/chef",
+            "indeed",
+        ),
+        JobPosting::new(
+            "Backend Software Engineer",
+            "Tech Corp",
+            "Remote",
+            "Build microservices in Python and Rust with Django and Flask, \
+             deployed on Docker and Kubernetes in AWS.",
+            "https://example.com/jobs/backend",
+            "linkedin",
+        ),
+        JobPosting::new(
+            "Data Analyst",
+            "Numbers Inc",
+            "Austin",
+            "Write SQL reports and dashboards for the finance team.",
+            "https://example.com/jobs/analyst",
+            "indeed",
+        ),
+    ];
+
+    let ranked = rank_jobs_for_resume(
+        &sample_resume_json(),
+        &jobs,
+        None,
+        &ScoringOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!(ranked.len(), 3);
+    assert_eq!(ranked[0].0.title, "Backend Software Engineer");
+    assert_eq!(ranked[2].0.title, "Pastry Chef");
+    assert!(ranked
+        .windows(2)
+        .all(|pair| pair[0].1.total >= pair[1].1.total));
+}
+
+#[test]
+fn test_search_filters_from_profile() {
+    let temp_dir = create_temp_dir();
+    let profile_path = create_test_file(
+        temp_dir.path(),
+        "profile.toml",
+        r#"
+max_iterations = 4
+
+[candidate]
+name = "Jane Doe"
+location = "Austin, TX"
+desired_titles = ["Backend Engineer", "Platform Engineer"]
+skills = ["Rust", "Kubernetes", "PostgreSQL", "AWS"]
+remote_only = true
+"#,
+    );
+
+    let profile = Profile::load(&profile_path).unwrap();
+    assert_eq!(profile.name.as_deref(), Some("Jane Doe"));
+
+    let filters = SearchFilters::from_profile(&profile);
+    let keywords = filters.keywords.as_deref().unwrap();
+    assert!(keywords.contains("Backend Engineer"));
+    assert!(keywords.contains("Platform Engineer"));
+    assert_eq!(filters.location.as_deref(), Some("Austin, TX"));
+    assert!(filters.remote_only);
+
+    // Without titles, the top skills become the keywords
+    let skills_only = Profile {
+        desired_titles: vec![],
+        ..profile
+    };
+    let filters = SearchFilters::from_profile(&skills_only);
+    assert_eq!(
+        filters.keywords.as_deref(),
+        Some("Rust Kubernetes PostgreSQL")
+    );
+
+    // The same file serves as the config's profile overlay
+    let config_path = create_test_file(
+        temp_dir.path(),
+        "config.toml",
+        &format!("profile_file = {:?}\n", profile_path.to_str().unwrap()),
+    );
+    let config = Config::load(&config_path).unwrap();
+    assert_eq!(config.max_iterations, 4);
+    assert_eq!(
+        config.candidate_profile().unwrap().unwrap().desired_titles,
+        ["Backend Engineer", "Platform Engineer"]
+    );
+    assert!(Config::default().candidate_profile().unwrap().is_none());
+}
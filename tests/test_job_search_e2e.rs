@@ -2,8 +2,8 @@
 
 use ats_checker::config::Config;
 use ats_checker::scraper::{
-    jobspy::JobSpyScraper, CacheConfig, CacheWrapper, JobScraper, JobScraperManager, RetryConfig,
-    RetryWrapper, SearchFilters,
+    jobspy::JobSpyScraper, load_search_results, CacheConfig, CacheWrapper, JobScraper,
+    JobScraperManager, ResultsFormat, RetryConfig, RetryWrapper, SearchFilters, SearchMetadata,
 };
 use std::time::Duration;
 use tempfile::TempDir;
@@ -148,15 +148,35 @@ async fn test_job_results_save_and_load() {
         .with_remote(true),
     ];
 
-    // Save results
-    let path = manager.save_results(&jobs, "test_jobs.toml").unwrap();
-    assert!(path.exists());
-
-    // Load results
-    let loaded_jobs = manager.load_results(&path).unwrap();
-    assert_eq!(loaded_jobs.len(), 2);
-    assert_eq!(loaded_jobs[0].title, "Software Engineer");
-    assert_eq!(loaded_jobs[1].title, "Rust Developer");
+    let filters = SearchFilters::builder()
+        .keywords("rust")
+        .location("Remote")
+        .remote_only(true)
+        .build();
+    let metadata = SearchMetadata::new(
+        filters.clone(),
+        vec!["linkedin".to_string(), "indeed".to_string()],
+    );
+
+    for format in [ResultsFormat::Toml, ResultsFormat::Json] {
+        // Save results
+        let path = manager
+            .save_results(&jobs, "test_jobs", format, &metadata)
+            .unwrap();
+        assert!(path.exists());
+        assert_eq!(ResultsFormat::from_path(&path), format);
+
+        // Load results
+        let loaded_jobs = manager.load_results(&path).unwrap();
+        assert_eq!(loaded_jobs, jobs);
+
+        // Metadata survives the round trip
+        let loaded = load_search_results(&path).unwrap();
+        let loaded_metadata = loaded.metadata.unwrap();
+        assert_eq!(loaded_metadata, metadata);
+        assert_eq!(loaded_metadata.filters, filters);
+        assert_eq!(loaded_metadata.sources, vec!["linkedin", "indeed"]);
+    }
 }
 
 #[tokio::test]
@@ -177,7 +197,10 @@ async fn test_job_ranking() {
         JobPosting::new("C", "Co", "Loc", "Desc", "url3", "src").with_score(70.0),
     ];
 
-    let path = manager.save_results(&jobs, "ranked_jobs.toml").unwrap();
+    let metadata = SearchMetadata::new(SearchFilters::default(), vec!["src".to_string()]);
+    let path = manager
+        .save_results(&jobs, "ranked_jobs.toml", ResultsFormat::Toml, &metadata)
+        .unwrap();
 
     // Rank jobs
     let ranked = manager.rank_jobs_in_results(&path, 2, false).unwrap();
@@ -0,0 +1,157 @@
+//! Tests for pulling missing Ollama models before use.
+
+use ats_checker::agents::{Agent, AgentConfig, LlamaAgent};
+use ats_checker::error::AtsError;
+use ats_checker::llama::LlamaClient;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const PULL_PROGRESS: &str = concat!(
+    r#"{"status":"pulling manifest"}"#,
+    "\n",
+    r#"{"status":"pulling 6a0746a1ec1a","digest":"sha256:6a07","total":2000,"completed":1000}"#,
+    "\n",
+    r#"{"status":"pulling 6a0746a1ec1a","digest":"sha256:6a07","total":2000,"completed":2000}"#,
+    "\n",
+    r#"{"status":"verifying sha256 digest"}"#,
+    "\n",
+    r#"{"status":"success"}"#,
+    "\n",
+);
+
+/// Mount `/api/tags` reporting the model missing once, then pulled.
+async fn mount_missing_then_pulled(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"models": []})))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "models": [{"name": "llama3.2:latest"}]
+        })))
+        .mount(server)
+        .await;
+}
+
+async fn mount_pull(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/api/pull"))
+        .and(body_partial_json(
+            json!({"model": "llama3.2", "stream": true}),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(PULL_PROGRESS, "application/x-ndjson"),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_ensure_model_pulls_missing_model() {
+    let server = MockServer::start().await;
+    mount_missing_then_pulled(&server).await;
+    mount_pull(&server).await;
+
+    let client = LlamaClient::new("llama3.2")
+        .unwrap()
+        .with_host(server.uri())
+        .with_auto_pull(true);
+
+    client.ensure_model().await.unwrap();
+    client.ping().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_ensure_model_without_auto_pull() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"models": []})))
+        .mount(&server)
+        .await;
+
+    let client = LlamaClient::new("llama3.2")
+        .unwrap()
+        .with_host(server.uri());
+
+    let err = client.ensure_model().await.unwrap_err();
+    assert!(matches!(err, AtsError::AgentConfig { .. }));
+    assert!(err.to_string().contains("ollama pull llama3.2"));
+}
+
+#[tokio::test]
+async fn test_ensure_model_reports_pull_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"models": []})))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/pull"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            "{\"status\":\"pulling manifest\"}\n{\"error\":\"pull model manifest: file does not exist\"}\n",
+            "application/x-ndjson",
+        ))
+        .mount(&server)
+        .await;
+
+    let client = LlamaClient::new("llama3.2")
+        .unwrap()
+        .with_host(server.uri())
+        .with_auto_pull(true);
+
+    let err = client.ensure_model().await.unwrap_err();
+    assert!(err.to_string().contains("file does not exist"), "{err}");
+}
+
+#[tokio::test]
+async fn test_unreachable_host_error() {
+    // Nothing listens on the discard port
+    let client = LlamaClient::new("llama3.2")
+        .unwrap()
+        .with_host("http://127.0.0.1:9")
+        .with_auto_pull(true);
+
+    let err = client.ensure_model().await.unwrap_err();
+    assert!(matches!(err, AtsError::ApiRequest { .. }));
+    assert!(err.to_string().contains("not reachable"), "{err}");
+}
+
+#[tokio::test]
+async fn test_agent_auto_pulls_before_first_request() {
+    let server = MockServer::start().await;
+    mount_missing_then_pulled(&server).await;
+    mount_pull(&server).await;
+    Mock::given(method("POST"))
+        .and(path("/api/generate"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "response": "Hello",
+            "done": true
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let mut config = AgentConfig::builder()
+        .name("local")
+        .provider("llama")
+        .model_name("llama3.2")
+        .build();
+    config.extras.insert("auto_pull".to_string(), json!(true));
+    let agent = LlamaAgent::with_host(config, server.uri()).unwrap();
+
+    assert_eq!(agent.generate_text("hi").await.unwrap(), "Hello");
+    assert_eq!(agent.generate_text("hi again").await.unwrap(), "Hello");
+
+    // The model is only checked before the first request
+    let requests = server.received_requests().await.unwrap();
+    let tag_checks = requests.iter().filter(|r| r.url.path() == "/api/tags");
+    assert_eq!(tag_checks.count(), 1);
+}
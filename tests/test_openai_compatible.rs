@@ -0,0 +1,88 @@
+//! Tests for pointing the `OpenAI` client at OpenAI-compatible servers.
+
+use ats_checker::error::AtsError;
+use ats_checker::openai::OpenAiClient;
+use serde_json::json;
+use wiremock::matchers::{header, header_exists, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_completion(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(header("authorization", "Bearer local-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"role": "assistant", "content": "from local server"}}]
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_with_base_url_routes_requests() {
+    let server = MockServer::start().await;
+    mount_completion(&server).await;
+
+    let client = OpenAiClient::new("local-key", "local-model")
+        .unwrap()
+        .with_base_url(format!("{}/v1/", server.uri()));
+
+    assert_eq!(
+        client.generate_content("hello").await.unwrap(),
+        "from local server"
+    );
+}
+
+#[tokio::test]
+async fn test_azure_mode_uses_deployment_url_and_api_key_header() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/openai/deployments/resume-gpt/chat/completions"))
+        .and(query_param("api-version", "2024-10-21"))
+        .and(header("api-key", "azure-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"role": "assistant", "content": "from azure"}}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // Azure rejects bearer auth, so it must not be sent
+    Mock::given(header_exists("authorization"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = OpenAiClient::azure(
+        format!("{}/", server.uri()),
+        "resume-gpt",
+        "2024-10-21",
+        "azure-key",
+    )
+    .unwrap();
+
+    assert_eq!(
+        client.generate_content("hello").await.unwrap(),
+        "from azure"
+    );
+}
+
+// The only test in this binary that touches the environment.
+#[tokio::test]
+async fn test_from_env_respects_base_url_env() {
+    let server = MockServer::start().await;
+    mount_completion(&server).await;
+
+    std::env::set_var("OPENAI_API_KEY", "local-key");
+
+    std::env::set_var("OPENAI_BASE_URL", "not a url");
+    let err = OpenAiClient::from_env().unwrap_err();
+    assert!(matches!(err, AtsError::ConfigInvalidValue { .. }));
+
+    std::env::set_var("OPENAI_BASE_URL", format!("{}/v1//", server.uri()));
+    let client = OpenAiClient::from_env_with_model("local-model").unwrap();
+    assert_eq!(
+        client.generate_content("hello").await.unwrap(),
+        "from local server"
+    );
+}
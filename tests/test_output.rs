@@ -2,7 +2,8 @@
 
 mod common;
 
-use ats_checker::output::{OutputData, OutputGenerator};
+use ats_checker::config::Config;
+use ats_checker::output::{OutputData, OutputGenerator, OutputIndex, OutputManifest, INDEX_FILE};
 use ats_checker::scoring::ScoreReport;
 use common::*;
 use serde_json::json;
@@ -45,6 +46,8 @@ fn test_generate_outputs_json_format() {
         enhanced_resume: resume,
         scores: Some(score_report),
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -94,6 +97,8 @@ fn test_generate_outputs_toml_format() {
         enhanced_resume: resume,
         scores: Some(score_report),
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -140,6 +145,8 @@ fn test_generate_outputs_both_formats() {
         enhanced_resume: resume,
         scores: Some(score_report),
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -184,6 +191,8 @@ fn test_output_path_with_all_placeholders() {
         enhanced_resume: resume,
         scores: None,
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -216,6 +225,8 @@ fn test_output_path_without_job_title() {
         enhanced_resume: resume,
         scores: None,
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -223,10 +234,78 @@ fn test_output_path_without_job_title() {
     assert!(result.is_ok());
     let output_path = result.unwrap();
 
-    // Should use "no_job" placeholder
-    let path_str = output_path.to_string_lossy();
-    assert!(path_str.contains("Bob_Williams"));
-    assert!(path_str.contains("no_job"));
+    // The empty job title level is omitted rather than left blank
+    assert_eq!(output_path, temp_dir.path().join("Bob_Williams"));
+}
+
+#[test]
+fn test_output_path_omits_missing_job_title_level() {
+    let temp_dir = create_temp_dir();
+    let generator = OutputGenerator::new(
+        temp_dir.path().to_path_buf(),
+        "json".to_string(),
+        "{resume_name}/{job_title}/{date}".to_string(),
+    );
+
+    let output_data = OutputData {
+        resume_name: "Bob_Williams".to_string(),
+        job_title: None,
+        enhanced_resume: sample_resume_json(),
+        scores: None,
+        recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
+        metadata: HashMap::new(),
+    };
+
+    let output_path = generator.generate(&output_data).unwrap();
+    let relative = output_path.strip_prefix(temp_dir.path()).unwrap();
+
+    // Two levels (resume, date), with no empty one between them
+    let levels: Vec<_> = relative.components().collect();
+    assert_eq!(levels.len(), 2);
+    assert_eq!(levels[0].as_os_str(), "Bob_Williams");
+    assert!(!relative.to_string_lossy().contains("//"));
+}
+
+#[test]
+fn test_output_path_renders_score_and_provider() {
+    let temp_dir = create_temp_dir();
+    let generator = OutputGenerator::new(
+        temp_dir.path().to_path_buf(),
+        "json".to_string(),
+        "{resume_name}/{provider}/score_{score}".to_string(),
+    )
+    .with_provider("gemini");
+
+    let output_data = OutputData {
+        resume_name: "Carol/Diaz".to_string(),
+        job_title: None,
+        enhanced_resume: sample_resume_json(),
+        scores: Some(ats_checker::ScoreReport {
+            kind: "resume".to_string(),
+            total: 81.6,
+            categories: vec![],
+            meta: HashMap::new(),
+        }),
+        recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
+        metadata: HashMap::new(),
+    };
+
+    let output_path = generator.generate(&output_data).unwrap();
+
+    // The slash in the name is sanitized instead of adding a level
+    assert_eq!(
+        output_path,
+        temp_dir
+            .path()
+            .join("Carol_Diaz")
+            .join("gemini")
+            .join("score_82")
+    );
+    assert!(output_path.is_dir());
 }
 
 #[test]
@@ -254,6 +333,8 @@ fn test_output_with_unicode_characters() {
         enhanced_resume: resume,
         scores: None,
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -297,6 +378,8 @@ fn test_output_with_empty_sections() {
         enhanced_resume: resume,
         scores: None,
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -342,6 +425,8 @@ fn test_manifest_generation() {
         enhanced_resume: resume,
         scores: Some(score_report),
         recommendations,
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -361,6 +446,104 @@ fn test_manifest_generation() {
     assert!(manifest_content.contains("recommendations_count"));
 }
 
+#[test]
+fn test_index_records_each_generation_in_order() {
+    let temp_dir = create_temp_dir();
+    let generator = OutputGenerator::new(
+        temp_dir.path().to_path_buf(),
+        "json".to_string(),
+        "{resume_name}/{job_title}".to_string(),
+    );
+
+    let output_data = |name: &str, job_title: Option<&str>, total: Option<f64>| OutputData {
+        resume_name: name.to_string(),
+        job_title: job_title.map(str::to_string),
+        enhanced_resume: sample_resume_json(),
+        scores: total.map(|total| ScoreReport {
+            kind: "resume".to_string(),
+            total,
+            categories: vec![],
+            meta: HashMap::new(),
+        }),
+        recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
+        metadata: HashMap::new(),
+    };
+
+    let first = generator
+        .generate(&output_data("Alice", Some("Backend Engineer"), Some(81.0)))
+        .unwrap();
+    let second = generator.generate(&output_data("Bob", None, None)).unwrap();
+
+    assert!(temp_dir.path().join(INDEX_FILE).exists());
+    let index = OutputIndex::load(temp_dir.path()).unwrap();
+    assert_eq!(index.runs.len(), 2);
+
+    let (a, b) = (&index.runs[0], &index.runs[1]);
+    assert_eq!(a.resume_name, "Alice");
+    assert_eq!(a.job_title.as_deref(), Some("Backend Engineer"));
+    assert_eq!(a.score, Some(81.0));
+    assert_eq!(temp_dir.path().join(&a.path), first);
+    assert_eq!(b.resume_name, "Bob");
+    assert_eq!(b.job_title, None);
+    assert_eq!(b.score, None);
+    assert_eq!(temp_dir.path().join(&b.path), second);
+    assert!(a.timestamp <= b.timestamp);
+}
+
+#[test]
+fn test_manifest_reports_staleness_after_weights_change() {
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(
+        temp_dir.path(),
+        "scoring_weights.toml",
+        "[resume]\nkeywords = 0.5\n",
+    );
+    let config = Config {
+        scoring_weights_file: weights_path.clone(),
+        ..Config::default()
+    };
+
+    let generator = OutputGenerator::new(
+        temp_dir.path().join("output"),
+        "json".to_string(),
+        "{resume_name}".to_string(),
+    )
+    .with_fingerprint(&config);
+
+    let output_data = OutputData {
+        resume_name: "Stale_Test".to_string(),
+        job_title: None,
+        enhanced_resume: json!({"name": "Test"}),
+        scores: None,
+        recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
+        metadata: HashMap::new(),
+    };
+    let output_dir = generator.generate(&output_data).unwrap();
+
+    let manifest = OutputManifest::load(&output_dir).unwrap();
+    assert_eq!(manifest.version, ats_checker::VERSION);
+    assert!(manifest.config_hash.is_some());
+    assert!(manifest.weights_hash.is_some());
+    assert!(!manifest.is_stale(&config, &weights_path));
+
+    // Changing the weights file content makes the outputs stale
+    std::fs::write(&weights_path, "[resume]\nkeywords = 0.6\n").unwrap();
+    assert!(manifest.is_stale(&config, &weights_path));
+
+    // So does changing the config
+    std::fs::write(&weights_path, "[resume]\nkeywords = 0.5\n").unwrap();
+    let changed = Config {
+        target_score: config.target_score + 1.0,
+        ..config.clone()
+    };
+    assert!(!manifest.is_stale(&config, &weights_path));
+    assert!(manifest.is_stale(&changed, &weights_path));
+}
+
 #[test]
 fn test_output_with_complex_nested_data() {
     let temp_dir = create_temp_dir();
@@ -422,6 +605,8 @@ fn test_output_with_complex_nested_data() {
         enhanced_resume: resume,
         scores: None,
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata,
     };
 
@@ -485,6 +670,8 @@ fn test_txt_format_contains_all_sections() {
         enhanced_resume: resume,
         scores: None,
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -552,6 +739,8 @@ fn test_scores_file_format() {
         enhanced_resume: resume,
         scores: Some(score_report),
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -572,3 +761,316 @@ fn test_scores_file_format() {
     assert!(content.contains("90"));
     assert!(content.contains("80"));
 }
+
+#[test]
+fn test_generate_pdf_from_sample_resume() {
+    let temp_dir = create_temp_dir();
+
+    let generator = OutputGenerator::new(
+        temp_dir.path().to_path_buf(),
+        "pdf".to_string(),
+        "{resume_name}".to_string(),
+    );
+
+    let output_data = OutputData {
+        resume_name: "John_Doe".to_string(),
+        job_title: Some("SWE".to_string()),
+        enhanced_resume: sample_resume_json(),
+        scores: None,
+        recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
+        metadata: HashMap::new(),
+    };
+
+    let pdf_path = generator
+        .generate_pdf(&output_data)
+        .expect("PDF generation failed");
+    assert_eq!(
+        pdf_path.file_name().unwrap().to_str().unwrap(),
+        "John_Doe_SWE_enhanced.pdf"
+    );
+
+    let bytes = std::fs::read(&pdf_path).unwrap();
+    assert!(bytes.starts_with(b"%PDF-"));
+
+    let text = pdf_extract::extract_text_from_mem(&bytes).expect("PDF should be readable");
+    for expected in [
+        "John Doe",
+        "SUMMARY",
+        "EXPERIENCE",
+        "Senior Software Engineer",
+        "Reduced system latency",
+        "SKILLS",
+        "EDUCATION",
+    ] {
+        assert!(text.contains(expected), "missing {expected:?} in:\n{text}");
+    }
+}
+
+#[test]
+fn test_generate_pdf_format_skips_missing_sections() {
+    let temp_dir = create_temp_dir();
+
+    let generator = OutputGenerator::new(
+        temp_dir.path().to_path_buf(),
+        "pdf".to_string(),
+        "{resume_name}".to_string(),
+    );
+
+    let output_data = OutputData {
+        resume_name: "Minimal".to_string(),
+        job_title: None,
+        enhanced_resume: json!({"personal_info": {"name": "Jane Roe"}}),
+        scores: None,
+        recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
+        metadata: HashMap::new(),
+    };
+
+    let output_dir = generator.generate(&output_data).unwrap();
+
+    let pdf_file = output_dir.join("Minimal_no_job_enhanced.pdf");
+    assert!(pdf_file.exists());
+    assert!(!output_dir.join("Minimal_no_job_enhanced.json").exists());
+
+    let text = pdf_extract::extract_text(&pdf_file).unwrap();
+    assert!(text.contains("Jane Roe"));
+    assert!(!text.contains("EXPERIENCE"));
+}
+
+#[test]
+fn test_generate_docx_from_sample_resume() {
+    use std::io::Read;
+
+    let temp_dir = create_temp_dir();
+
+    let generator = OutputGenerator::new(
+        temp_dir.path().to_path_buf(),
+        "docx".to_string(),
+        "{resume_name}".to_string(),
+    );
+
+    let output_data = OutputData {
+        resume_name: "John_Doe".to_string(),
+        job_title: Some("SWE".to_string()),
+        enhanced_resume: sample_resume_json(),
+        scores: None,
+        recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
+        metadata: HashMap::new(),
+    };
+
+    let output_dir = generator
+        .generate(&output_data)
+        .expect("DOCX generation failed");
+    let docx_path = output_dir.join("John_Doe_SWE_enhanced.docx");
+    assert!(docx_path.exists());
+
+    let file = std::fs::File::open(&docx_path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).expect("DOCX should be a zip archive");
+    for part in [
+        "[Content_Types].xml",
+        "word/styles.xml",
+        "word/numbering.xml",
+    ] {
+        assert!(archive.by_name(part).is_ok(), "missing part {part}");
+    }
+
+    let mut document = String::new();
+    archive
+        .by_name("word/document.xml")
+        .expect("missing word/document.xml")
+        .read_to_string(&mut document)
+        .unwrap();
+
+    // Well-formed XML
+    for event in xml::reader::EventReader::from_str(&document) {
+        event.expect("document.xml should be well-formed");
+    }
+
+    for expected in [
+        "John Doe",
+        "Senior Software Engineer",
+        "Tech Corp",
+        "Led team of 5 engineers in agile development",
+        r#"<w:pStyle w:val="Heading1" />"#,
+        r#"<w:pStyle w:val="Title" />"#,
+        "<w:numPr>",
+    ] {
+        assert!(
+            document.contains(expected),
+            "missing {expected:?} in document.xml"
+        );
+    }
+
+    // The document also reads back through a DOCX parser
+    let bytes = std::fs::read(&docx_path).unwrap();
+    assert!(docx_rs::read_docx(&bytes).is_ok());
+}
+
+#[test]
+fn test_generate_markdown_from_sample_resume() {
+    let temp_dir = create_temp_dir();
+
+    let generator = OutputGenerator::new(
+        temp_dir.path().to_path_buf(),
+        "markdown".to_string(),
+        "{resume_name}".to_string(),
+    );
+
+    let output_data = OutputData {
+        resume_name: "John_Doe".to_string(),
+        job_title: Some("SWE".to_string()),
+        enhanced_resume: sample_resume_json(),
+        scores: Some(ScoreReport {
+            kind: "resume".to_string(),
+            total: 85.0,
+            categories: vec![],
+            meta: HashMap::new(),
+        }),
+        recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
+        metadata: HashMap::new(),
+    };
+
+    let md_path = generator
+        .generate_markdown(&output_data)
+        .expect("Markdown generation failed");
+    assert_eq!(
+        md_path.file_name().unwrap().to_str().unwrap(),
+        "John_Doe_SWE_enhanced.md"
+    );
+
+    let markdown = std::fs::read_to_string(&md_path).unwrap();
+    for expected in [
+        "# John Doe\n",
+        "## Summary\n",
+        "## Experience\n",
+        "**Senior Software Engineer** | Tech Corp | 2020-Present",
+        "- Led team of 5 engineers in agile development\n",
+        "## Skills\n",
+        "- **Languages:** Python, Rust, JavaScript, SQL",
+        "## Education\n",
+        "## Score Summary\n",
+        "| **Total** | **85.0** | |",
+    ] {
+        assert!(
+            markdown.contains(expected),
+            "missing {expected:?} in:\n{markdown}"
+        );
+    }
+}
+
+#[test]
+fn test_generate_outputs_md_format() {
+    let temp_dir = create_temp_dir();
+
+    let generator = OutputGenerator::new(
+        temp_dir.path().to_path_buf(),
+        "md".to_string(),
+        "{resume_name}".to_string(),
+    );
+
+    let output_data = OutputData {
+        resume_name: "John_Doe".to_string(),
+        job_title: None,
+        enhanced_resume: sample_resume_json(),
+        scores: None,
+        recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
+        metadata: HashMap::new(),
+    };
+
+    let output_dir = generator.generate(&output_data).unwrap();
+
+    let markdown = std::fs::read_to_string(output_dir.join("John_Doe_no_job_enhanced.md")).unwrap();
+    assert!(markdown.starts_with("# John Doe\n"));
+    assert!(!markdown.contains("## Score Summary"));
+    assert!(!output_dir.join("John_Doe_no_job_enhanced.json").exists());
+}
+
+#[test]
+fn test_generate_html_report() {
+    let temp_dir = create_temp_dir();
+
+    let generator = OutputGenerator::new(
+        temp_dir.path().to_path_buf(),
+        "html".to_string(),
+        "{resume_name}".to_string(),
+    );
+
+    let mut resume = sample_resume_json();
+    resume["summary"] = json!("Ships <fast> & reliable \"R&D\" systems");
+
+    let category = |name: &str, score: f64| ats_checker::scoring::ScoreCategoryResult {
+        name: name.to_string(),
+        score,
+        weight: 0.25,
+        details: HashMap::new(),
+    };
+    let output_data = OutputData {
+        resume_name: "John_Doe".to_string(),
+        job_title: Some("SWE".to_string()),
+        enhanced_resume: resume,
+        scores: Some(ScoreReport {
+            kind: "resume".to_string(),
+            total: 66.0,
+            categories: vec![
+                category("completeness", 92.0),
+                category("skills_quality", 61.5),
+                category("experience_quality", 35.0),
+            ],
+            meta: HashMap::new(),
+        }),
+        recommendations: vec![ats_checker::recommendations::Recommendation::new(
+            "Quantify achievements",
+        )
+        .with_reason("Only 1 of 6 bullets includes a metric")],
+        keyword_gap: Some(ats_checker::scoring::KeywordGap {
+            present: vec!["rust".to_string()],
+            missing: vec!["kubernetes".to_string(), "terraform".to_string()],
+            missing_by_importance: vec![
+                ("kubernetes".to_string(), 0.4),
+                ("terraform".to_string(), 0.2),
+            ],
+        }),
+        cover_letter: None,
+        metadata: HashMap::new(),
+    };
+
+    let html_path = generator
+        .generate_html(&output_data)
+        .expect("HTML generation failed");
+    let html = std::fs::read_to_string(&html_path).unwrap();
+
+    // Well-formed: every element closes and nests properly
+    let body = html.trim_start_matches("<!DOCTYPE html>");
+    let mut depth = 0usize;
+    for event in xml::reader::EventReader::from_str(body) {
+        match event.expect("HTML output should be well-formed") {
+            xml::reader::XmlEvent::StartElement { .. } => depth += 1,
+            xml::reader::XmlEvent::EndElement { .. } => depth -= 1,
+            _ => {}
+        }
+    }
+    assert_eq!(depth, 0);
+
+    assert!(!html.contains("<link") && !html.contains("src=\""));
+    for name in ["Completeness", "Skills quality", "Experience quality"] {
+        assert!(html.contains(name), "missing category {name:?}");
+    }
+    assert!(html.contains("band-green\" style=\"width: 92.0%"));
+    assert!(html.contains("band-amber\" style=\"width: 61.5%"));
+    assert!(html.contains("band-red\" style=\"width: 35.0%"));
+    assert!(html.contains("Ships &lt;fast&gt; &amp; reliable &quot;R&amp;D&quot; systems"));
+    assert!(html.contains("<ol class=\"recommendations\">"));
+    assert!(html.contains("Only 1 of 6 bullets includes a metric"));
+    assert!(html.contains("<h2>Keyword Gap</h2>"));
+    assert!(html.contains("1 of 3 job keywords found"));
+    assert!(html.contains("<li>kubernetes<span class=\"weight\">40%</span></li>"));
+}
@@ -11,7 +11,8 @@
 mod common;
 
 use ats_checker::{
-    config::Config, input::InputHandler, output::OutputGenerator, scoring, state::StateManager,
+    config::Config, input::InputHandler, output::OutputGenerator,
+    processor::parse_resume_heuristic, scoring, state::StateManager,
     utils::hash::calculate_file_hash,
 };
 use common::{
@@ -147,6 +148,8 @@ fn test_scoring_and_output_integration() {
         enhanced_resume: resume_json.clone(),
         scores: Some(resume_score.clone()),
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata,
     };
 
@@ -238,7 +241,7 @@ fn test_full_component_integration() {
         scoring::score_resume(&enhanced_resume, config.scoring_weights_file.to_str()).unwrap();
 
     assert!(resume_score.total >= 0.0);
-    assert_eq!(resume_score.categories.len(), 4); // completeness, skills_quality, experience_quality, impact
+    assert_eq!(resume_score.categories.len(), 6); // completeness, skills_quality, experience_quality, impact, length, tenure
 
     // 9. Generate output
     let output_generator = OutputGenerator::new(
@@ -253,6 +256,8 @@ fn test_full_component_integration() {
         enhanced_resume: enhanced_resume.clone(),
         scores: Some(resume_score.clone()),
         recommendations: vec![],
+        keyword_gap: None,
+        cover_letter: None,
         metadata: HashMap::new(),
     };
 
@@ -402,3 +407,52 @@ fn test_error_handling_integration() {
     // Either creates directories or fails gracefully
     let _ = result;
 }
+
+#[test]
+fn test_heuristic_parser_feeds_scoring() {
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(temp_dir.path(), "weights.toml", sample_scoring_weights());
+
+    let resume = parse_resume_heuristic(sample_resume_text());
+
+    assert_eq!(resume["personal_info"]["name"], "John Doe");
+    assert_eq!(resume["personal_info"]["email"], "john.doe@example.com");
+    assert_eq!(resume["personal_info"]["phone"], "(555) 123-4567");
+    assert!(resume["summary"]
+        .as_str()
+        .unwrap()
+        .starts_with("Experienced software engineer"));
+
+    let experience = resume["experience"].as_array().unwrap();
+    assert!(experience.len() >= 2);
+    assert_eq!(experience[0]["title"], "Senior Software Engineer");
+    assert_eq!(experience[0]["company"], "Tech Corp");
+    assert_eq!(experience[0]["end_date"], "Present");
+    assert_eq!(experience[1]["description"].as_array().unwrap().len(), 3);
+
+    let education = resume["education"].as_array().unwrap();
+    assert_eq!(education.len(), 1);
+    assert_eq!(education[0]["institution"], "University of Technology");
+    assert_eq!(education[0]["gpa"], "3.8/4.0");
+
+    let skills: Vec<&str> = resume["skills"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|s| s.as_str())
+        .collect();
+    assert!(skills.contains(&"Rust"));
+    assert!(skills.contains(&"Kubernetes"));
+    assert!(!skills.iter().any(|s| s.contains(':')));
+
+    // The parsed resume scores without any further conversion
+    let report = scoring::score_resume(&resume, weights_path.to_str()).unwrap();
+    let completeness = report
+        .categories
+        .iter()
+        .find(|c| c.name == "completeness")
+        .unwrap();
+    assert_eq!(completeness.details["has_experience"], true);
+    assert_eq!(completeness.details["has_skills"], true);
+    assert!(report.total > 0.0);
+}
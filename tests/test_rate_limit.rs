@@ -0,0 +1,82 @@
+//! Tests that each provider client surfaces `Retry-After` on HTTP 429.
+
+use ats_checker::anthropic::AnthropicClient;
+use ats_checker::error::{AtsError, Result};
+use ats_checker::gemini::GeminiClient;
+use ats_checker::llama::LlamaClient;
+use ats_checker::openai::OpenAiClient;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn rate_limited_server(retry_after: &str) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", retry_after)
+                .set_body_string("slow down"),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+fn retry_after(result: Result<String>) -> Option<u64> {
+    match result {
+        Err(AtsError::ApiRateLimit { retry_after, .. }) => retry_after,
+        other => panic!("expected a rate limit error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_all_clients_carry_retry_after_seconds() {
+    let server = rate_limited_server("30").await;
+
+    let openai = OpenAiClient::new("key", "gpt-4")
+        .unwrap()
+        .with_base_url(server.uri());
+    assert_eq!(retry_after(openai.generate_content("hi").await), Some(30));
+
+    let anthropic = AnthropicClient::new("key", "claude")
+        .unwrap()
+        .with_base_url(server.uri());
+    assert_eq!(
+        retry_after(anthropic.generate_content("hi").await),
+        Some(30)
+    );
+
+    let gemini = GeminiClient::new("key", "gemini-pro")
+        .unwrap()
+        .with_base_url(server.uri());
+    assert_eq!(retry_after(gemini.generate_content("hi").await), Some(30));
+
+    let llama = LlamaClient::new("llama3.2")
+        .unwrap()
+        .with_host(server.uri());
+    assert_eq!(retry_after(llama.generate_content("hi").await), Some(30));
+}
+
+#[tokio::test]
+async fn test_retry_after_http_date() {
+    let server = rate_limited_server("Wed, 21 Oct 2015 07:28:00 GMT").await;
+
+    // A date in the past means "retry now"
+    let client = OpenAiClient::new("key", "gpt-4")
+        .unwrap()
+        .with_base_url(server.uri());
+    assert_eq!(retry_after(client.generate_content("hi").await), Some(0));
+}
+
+#[tokio::test]
+async fn test_missing_retry_after() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(429))
+        .mount(&server)
+        .await;
+
+    let client = OpenAiClient::new("key", "gpt-4")
+        .unwrap()
+        .with_base_url(server.uri());
+    assert_eq!(retry_after(client.generate_content("hi").await), None);
+}
@@ -2,7 +2,8 @@
 
 mod common;
 
-use ats_checker::recommendations::generate_recommendations;
+use ats_checker::processor::detect_section_order;
+use ats_checker::recommendations::{generate_recommendations, section_recommendations};
 use serde_json::json;
 
 #[test]
@@ -47,6 +48,95 @@ fn test_generate_recommendations_for_low_scores() {
     }
 }
 
+#[test]
+fn test_generate_recommendations_quotes_action_ratio() {
+    let resume = json!({
+        "experience": [{
+            "title": "Engineer",
+            "description": [
+                "Led the migration of 12 services to Kubernetes",
+                "Responsible for the billing service handling 2M payments",
+                "Worked on internal tooling used by 40 engineers",
+                "Was part of the on-call rotation"
+            ]
+        }]
+    });
+    let report = ats_checker::scoring::score_resume(&resume, None).unwrap();
+    let scoring = serde_json::to_value(&report).unwrap();
+
+    let recommendations = generate_recommendations(&scoring, 10);
+
+    let experience = recommendations
+        .iter()
+        .find(|r| r.message.contains("action verbs"))
+        .expect("experience recommendation");
+    let reason = experience.reason.as_deref().unwrap();
+    assert!(reason.contains("25%"), "{reason}");
+    assert!(reason.contains("(1 of 4)"), "{reason}");
+    assert!(reason.contains("\"Responsible for the billing service handling 2M payments\""));
+    assert!(!reason.contains("Led the migration"));
+}
+
+#[test]
+fn test_experience_recommendation_names_the_weakest_component() {
+    let recommend = |description: serde_json::Value| {
+        let resume = json!({"experience": [{"title": "Engineer", "description": description}]});
+        let report = ats_checker::scoring::score_resume(&resume, None).unwrap();
+        let scoring = serde_json::to_value(&report).unwrap();
+        generate_recommendations(&scoring, 10)
+    };
+
+    // Every bullet opens with an action verb, but none has a number
+    let recommendations = recommend(json!([
+        "Led the migration to Kubernetes",
+        "Built the billing service",
+        "Designed internal tooling",
+        "Mentored new engineers"
+    ]));
+    let experience = recommendations
+        .iter()
+        .find(|r| r.message == "Quantify your experience bullets")
+        .expect("quantification recommendation");
+    assert!(experience.reason.as_deref().unwrap().contains("(0 of 4)"));
+    assert!(!recommendations
+        .iter()
+        .any(|r| r.message.contains("action verbs")));
+
+    // Too few bullets costs more than their wording
+    let recommendations = recommend(json!([
+        "Led the migration of 12 services to Kubernetes",
+        "Responsible for the billing service"
+    ]));
+    let experience = recommendations
+        .iter()
+        .find(|r| r.message == "Add more experience bullets")
+        .expect("bullet count recommendation");
+    assert!(experience
+        .reason
+        .as_deref()
+        .unwrap()
+        .contains("has 2 bullets;"));
+}
+
+#[test]
+fn test_generate_recommendations_cites_too_long_skills() {
+    let scoring = json!({
+        "total": 80.0,
+        "categories": [{
+            "name": "skills_quality",
+            "score": 20.0,
+            "weight": 0.2,
+            "details": {"unique_skill_count": 4, "too_long_skills": 2}
+        }]
+    });
+
+    let recommendations = generate_recommendations(&scoring, 5);
+
+    let reason = recommendations[0].reason.as_deref().unwrap();
+    assert!(reason.starts_with("2 of your skills"));
+    assert!(reason.contains("4 distinct skills"));
+}
+
 #[test]
 fn test_generate_recommendations_for_high_score() {
     let scoring = json!({
@@ -181,3 +271,81 @@ fn test_recommendations_with_missing_categories() {
     // Should handle gracefully (may return empty or generic recommendations)
     assert!(recommendations.len() <= 5);
 }
+
+#[test]
+fn test_section_recommendations_reorder_education_first() {
+    let order = detect_section_order(
+        "Jane Doe\n\nEducation\nBSc Computer Science\n\nWork History\nSenior Engineer, Acme\n\nSkills\nRust\n",
+    );
+    let resume = json!({
+        "summary": "Backend engineer with 8 years of experience",
+        "experience": [
+            {"title": "Senior Engineer", "company": "Acme"},
+            {"title": "Engineer", "company": "Globex"}
+        ],
+        "education": [{"degree": "BSc Computer Science"}],
+        "_meta": {"section_order": order}
+    });
+
+    let recommendations = section_recommendations(&resume);
+    let messages: Vec<&str> = recommendations.iter().map(|r| r.message.as_str()).collect();
+
+    assert_eq!(
+        messages,
+        [
+            "Reorder your sections: Experience, Skills, Education",
+            "Rename the \"Work History\" heading to \"Experience\"",
+        ]
+    );
+    assert!(recommendations[0]
+        .reason
+        .as_deref()
+        .unwrap()
+        .contains("experience should come before education"));
+}
+
+#[test]
+fn test_section_recommendations_accept_conventional_layout() {
+    // A recent graduate may lead with education
+    let graduate = json!({
+        "experience": [{"title": "Intern", "company": "Acme"}],
+        "_meta": {"section_order": detect_section_order("Summary\nEducation\nExperience\nSkills\n")}
+    });
+    assert!(section_recommendations(&graduate).is_empty());
+
+    // Without a recorded order there is nothing to check
+    assert!(section_recommendations(&json!({"experience": []})).is_empty());
+}
+
+#[test]
+fn test_generate_recommendations_flags_employment_gaps() {
+    let scoring = json!({
+        "total": 85.0,
+        "categories": [
+            {
+                "name": "tenure",
+                "score": 80.0,
+                "weight": 0.1,
+                "details": {
+                    "gaps": [{"after": "2021-06", "before": "2022-01", "months": 6}]
+                }
+            }
+        ]
+    });
+
+    let recommendations = generate_recommendations(&scoring, 5);
+    assert_eq!(recommendations.len(), 1);
+    assert!(recommendations[0].message.contains("gaps"));
+    assert!(recommendations[0]
+        .reason
+        .as_deref()
+        .unwrap()
+        .contains("6 months between 2021-06 and 2022-01"));
+
+    // No gaps, no recommendation
+    let scoring = json!({
+        "total": 85.0,
+        "categories": [{"name": "tenure", "score": 90.0, "weight": 0.1, "details": {"gaps": []}}]
+    });
+    assert!(generate_recommendations(&scoring, 5).is_empty());
+}
@@ -2,7 +2,15 @@
 
 mod common;
 
-use ats_checker::scoring::{score_match, score_resume};
+use ats_checker::scoring::tenure::{
+    entry_range, parse_range, resume_tenure, total_months, YearMonth,
+};
+use ats_checker::scoring::text::{DefaultTokenizer, Tokenizer, UnicodeTokenizer};
+use ats_checker::scoring::{
+    load_scoring_weights_profile, score_job, score_match, score_match_multi,
+    score_match_with_options, score_resume, score_resume_with_options, ScoreCategoryResult,
+    ScoringOptions, SynonymMap, DEFAULT_ABBREVIATIONS,
+};
 use common::*;
 
 #[test]
@@ -67,6 +75,39 @@ fn test_score_match_basic() {
     assert!(!report.categories.is_empty());
 }
 
+#[test]
+fn test_score_match_multi_weights_shared_terms() {
+    let jobs = [
+        serde_json::json!({
+            "title": "Backend Engineer",
+            "description": "Build Kubernetes services. Terraform experience required."
+        }),
+        serde_json::json!({
+            "title": "Platform Engineer",
+            "description": "Operate Kubernetes clusters with Ansible."
+        }),
+    ];
+    let resume_with = |skill: &str| {
+        serde_json::json!({
+            "summary": format!("Engineer working with {skill}"),
+            "skills": [skill]
+        })
+    };
+
+    let shared = score_match_multi(&resume_with("Kubernetes"), &jobs, None).unwrap();
+    let single = score_match_multi(&resume_with("Terraform"), &jobs, None).unwrap();
+
+    let weights = &category(&shared, "keyword_overlap").details["term_weights"];
+    assert_eq!(weights["kubernetes"], 2);
+    assert_eq!(weights["terraform"], 1);
+    assert!(
+        category(&shared, "keyword_overlap").score > category(&single, "keyword_overlap").score
+    );
+    assert_eq!(shared.meta["job_count"], 2);
+
+    assert!(score_match_multi(&resume_with("Rust"), &[], None).is_err());
+}
+
 #[test]
 fn test_score_resume_with_missing_fields() {
     let resume = serde_json::json!({
@@ -99,3 +140,899 @@ fn test_score_resume_completeness() {
 
     assert!(complete_score.total > incomplete_score.total);
 }
+
+#[test]
+fn test_score_match_custom_stopwords_remove_overlap() {
+    let temp_dir = create_temp_dir();
+    let stopwords_path = create_test_file(
+        temp_dir.path(),
+        "stopwords.toml",
+        "words = [\" Kubernetes \"]",
+    );
+
+    let resume = serde_json::json!({
+        "skills": ["Kubernetes"],
+        "summary": "Kubernetes operator"
+    });
+    let job = serde_json::json!({
+        "title": "Platform Engineer",
+        "description": "Kubernetes"
+    });
+
+    let keyword_overlap = |report: &ats_checker::ScoreReport| {
+        category(report, "keyword_overlap").details["overlap_count"]
+            .as_u64()
+            .unwrap_or(0)
+    };
+
+    let baseline = score_match(&resume, &job, None).unwrap();
+    assert_eq!(keyword_overlap(&baseline), 1);
+
+    let options = ScoringOptions {
+        stopwords_path: Some(stopwords_path),
+        ..Default::default()
+    };
+    let filtered = score_match_with_options(&resume, &job, None, &options).unwrap();
+    assert_eq!(keyword_overlap(&filtered), 0);
+    assert!(filtered.total < baseline.total);
+}
+
+fn category<'a>(report: &'a ats_checker::ScoreReport, name: &str) -> &'a ScoreCategoryResult {
+    report
+        .categories
+        .iter()
+        .find(|c| c.name == name)
+        .expect("category should be present")
+}
+
+fn synonym_options(dir: &std::path::Path) -> ScoringOptions {
+    let synonyms_path = create_test_file(
+        dir,
+        "synonyms.toml",
+        r#"
+[synonyms]
+javascript = ["js", "node"]
+"machine learning" = ["ml"]
+"#,
+    );
+    ScoringOptions {
+        synonyms_path: Some(synonyms_path),
+        ..no_abbreviations()
+    }
+}
+
+/// Options with abbreviation expansion off, so only synonyms apply.
+fn no_abbreviations() -> ScoringOptions {
+    ScoringOptions {
+        abbreviations: Some(SynonymMap::default()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_score_match_single_token_alias() {
+    let temp_dir = create_temp_dir();
+    let options = synonym_options(temp_dir.path());
+
+    let resume = serde_json::json!({ "skills": ["JS"] });
+    let job = serde_json::json!({
+        "title": "Frontend Developer",
+        "description": "Strong JavaScript required"
+    });
+
+    let baseline = score_match_with_options(&resume, &job, None, &no_abbreviations()).unwrap();
+    assert_eq!(category(&baseline, "skills_overlap").score, 0.0);
+
+    let report = score_match_with_options(&resume, &job, None, &options).unwrap();
+    let skills = category(&report, "skills_overlap");
+    assert_eq!(skills.score, 100.0);
+    assert_eq!(skills.details["alias_match_count"], 1);
+    assert_eq!(
+        skills.details["alias_matched_skills"],
+        serde_json::json!(["js"])
+    );
+
+    let keywords = category(&report, "keyword_overlap");
+    assert_eq!(
+        keywords.details["alias_matches"],
+        serde_json::json!(["javascript"])
+    );
+    assert!(report.total > baseline.total);
+}
+
+#[test]
+fn test_score_match_multi_token_alias() {
+    let temp_dir = create_temp_dir();
+    let options = synonym_options(temp_dir.path());
+
+    let resume = serde_json::json!({ "skills": ["Machine Learning"] });
+    let job = serde_json::json!({
+        "title": "ML Engineer",
+        "description": "Build ML models"
+    });
+
+    let baseline = score_match_with_options(&resume, &job, None, &no_abbreviations()).unwrap();
+    assert_eq!(category(&baseline, "skills_overlap").score, 0.0);
+
+    let report = score_match_with_options(&resume, &job, None, &options).unwrap();
+    let skills = category(&report, "skills_overlap");
+    assert_eq!(skills.score, 100.0);
+    assert_eq!(
+        skills.details["alias_matched_skills"],
+        serde_json::json!(["machine learning"])
+    );
+
+    let keywords = category(&report, "keyword_overlap");
+    assert_eq!(keywords.details["alias_match_count"], 2);
+    assert_eq!(
+        keywords.details["alias_matches"],
+        serde_json::json!(["learning", "machine"])
+    );
+}
+
+#[test]
+fn test_excluded_categories_are_dropped_and_weights_renormalized() {
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(
+        temp_dir.path(),
+        "weights.toml",
+        r#"
+[job]
+exclude = ["compensation_transparency"]
+
+[job.weights]
+completeness = 0.35
+clarity = 0.35
+compensation_transparency = 0.15
+link_quality = 0.15
+recency = 0.10
+
+[resume]
+exclude = ["impact", "experience_quality"]
+"#,
+    );
+    let weights_path = weights_path.to_str();
+
+    let job = serde_json::json!({
+        "title": "Backend Engineer",
+        "company": "Acme",
+        "description": "Build services in Rust.",
+        "url": "https://example.com/jobs/1"
+    });
+    let report = score_job(&job, weights_path).unwrap();
+    let names: Vec<&str> = report.categories.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names.len(), 4);
+    assert!(!names.contains(&"compensation_transparency"));
+    let weight_sum: f64 = report.categories.iter().map(|c| c.weight).sum();
+    assert!((weight_sum - 1.0).abs() < 1e-9);
+    // completeness keeps its share relative to the remaining raw weights
+    let completeness = category(&report, "completeness");
+    assert!((completeness.weight - 0.35 / 0.95).abs() < 1e-9);
+
+    let report = score_resume(&sample_resume_json(), weights_path).unwrap();
+    let names: Vec<&str> = report.categories.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["completeness", "skills_quality", "length", "tenure"]
+    );
+    let weight_sum: f64 = report.categories.iter().map(|c| c.weight).sum();
+    assert!((weight_sum - 1.0).abs() < 1e-9);
+
+    // Groups without an exclude list are unaffected
+    let report = score_match(&sample_resume_json(), &job, weights_path).unwrap();
+    assert_eq!(report.categories.len(), 4);
+}
+
+#[test]
+fn test_filler_phrases_lower_impact_score() {
+    let clean = serde_json::json!({
+        "personal_info": {"name": "Jane Doe", "email": "jane@example.com"},
+        "summary": "Backend engineer building payment systems in Rust.",
+        "experience": [{
+            "title": "Engineer",
+            "company": "Acme",
+            "description": [
+                "Reduced checkout latency by 35% across 4 services",
+                "Built a fraud scoring pipeline processing 2M events/day"
+            ]
+        }]
+    });
+    let mut stuffed = clean.clone();
+    stuffed["summary"] = serde_json::json!(
+        "Detail-oriented team player and hard worker building payment systems in Rust."
+    );
+
+    let impact = |resume: &serde_json::Value| {
+        category(&score_resume(resume, None).unwrap(), "impact").clone()
+    };
+
+    let clean_impact = impact(&clean);
+    let stuffed_impact = impact(&stuffed);
+
+    assert!(stuffed_impact.score < clean_impact.score - 20.0);
+    assert_eq!(
+        clean_impact.details["filler_phrases"],
+        serde_json::json!([])
+    );
+    assert_eq!(
+        stuffed_impact.details["filler_phrases"],
+        serde_json::json!(["team player", "detail-oriented", "hard worker"])
+    );
+    assert_eq!(stuffed_impact.details["filler_penalty"], 24.0);
+}
+
+#[test]
+fn test_filler_phrases_configurable_in_weights_file() {
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(
+        temp_dir.path(),
+        "weights.toml",
+        r#"
+[resume.filler]
+mode = "replace"
+phrases = ["rockstar"]
+"#,
+    );
+
+    let resume = serde_json::json!({
+        "summary": "Rockstar engineer and team player.",
+        "experience": [{"title": "Engineer", "description": ["Reduced costs by 10%"]}]
+    });
+    let report = score_resume(&resume, weights_path.to_str()).unwrap();
+    let impact = category(&report, "impact");
+
+    assert_eq!(
+        impact.details["filler_phrases"],
+        serde_json::json!(["rockstar"])
+    );
+}
+
+const PROFILE_WEIGHTS: &str = r#"
+[resume.weights]
+completeness = 1.0
+skills_quality = 1.0
+
+[profiles.tech.resume.weights]
+skills_quality = 3.0
+
+[profiles.management.resume.weights]
+experience_quality = 4.0
+
+[profiles.management.match.weights]
+role_alignment = 2.0
+"#;
+
+#[test]
+fn test_load_scoring_weights_profile_layers_over_top_level() {
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(temp_dir.path(), "weights.toml", PROFILE_WEIGHTS);
+    let path = weights_path.to_str();
+
+    let tech = load_scoring_weights_profile(path, "tech");
+    assert_eq!(tech["resume"]["skills_quality"], 3.0);
+    // Unset in the profile: top-level value, then the built-in default
+    assert_eq!(tech["resume"]["completeness"], 1.0);
+    assert_eq!(tech["resume"]["experience_quality"], 0.30);
+
+    let management = load_scoring_weights_profile(path, "management");
+    assert_eq!(management["resume"]["experience_quality"], 4.0);
+    assert_eq!(management["resume"]["skills_quality"], 1.0);
+    assert_eq!(management["match"]["role_alignment"], 2.0);
+
+    let unknown = load_scoring_weights_profile(path, "design");
+    assert_eq!(unknown["resume"]["skills_quality"], 1.0);
+}
+
+#[test]
+fn test_score_resume_with_profile_applies_its_weights() {
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(temp_dir.path(), "weights.toml", PROFILE_WEIGHTS);
+    let path = weights_path.to_str();
+    let resume = sample_resume_json();
+
+    let weight_of = |profile: Option<&str>, name: &str| {
+        let options = ScoringOptions {
+            profile: profile.map(str::to_string),
+            ..ScoringOptions::default()
+        };
+        let report = score_resume_with_options(&resume, path, &options).unwrap();
+        category(&report, name).weight
+    };
+
+    let top_level = weight_of(None, "skills_quality");
+    let tech = weight_of(Some("tech"), "skills_quality");
+    let management = weight_of(Some("management"), "skills_quality");
+
+    assert!(tech > top_level);
+    assert!(management < top_level);
+    assert!(weight_of(Some("management"), "experience_quality") > 0.5);
+}
+
+#[test]
+fn test_readability_flags_misspellings_when_enabled() {
+    let resume = serde_json::json!({
+        "summary": "Engineer focused on reliable payment systems.",
+        "experience": [{
+            "title": "Engineer",
+            "description": [
+                "Recieved an award for managment of the seperate billing migration",
+                "Built Rust services handling 1M requests per day"
+            ]
+        }],
+        "skills": ["Rust", "Seperate Ledger"]
+    });
+
+    // Off by default
+    let report = score_resume(&resume, None).unwrap();
+    assert!(report.categories.iter().all(|c| c.name != "readability"));
+
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(
+        temp_dir.path(),
+        "weights.toml",
+        "[resume.weights]\ncompleteness = 0.5\nreadability = 0.5\n",
+    );
+    let report = score_resume(&resume, weights_path.to_str()).unwrap();
+    let readability = category(&report, "readability");
+
+    // Words from listed skills are never flagged
+    assert_eq!(readability.details["misspelled_count"], 2);
+    assert_eq!(
+        readability.details["misspellings"],
+        serde_json::json!([
+            {"word": "Recieved", "suggestion": "received"},
+            {"word": "managment", "suggestion": "management"}
+        ])
+    );
+    assert!(readability.score < 100.0);
+
+    let clean = serde_json::json!({"summary": "Received an award for management"});
+    let report = score_resume(&clean, weights_path.to_str()).unwrap();
+    let readability = category(&report, "readability");
+    assert_eq!(readability.score, 100.0);
+}
+
+#[test]
+fn test_grouped_skills_are_flattened() {
+    let resume = sample_resume_json();
+    let report = score_resume(&resume, None).unwrap();
+    let quality = category(&report, "skills_quality");
+    assert_eq!(quality.details["unique_skill_count"], 12);
+    assert!(quality.score > 0.0);
+
+    let job = serde_json::json!({
+        "title": "Software Engineer",
+        "description": sample_job_description()
+    });
+    let report = score_match(&resume, &job, None).unwrap();
+    let overlap = category(&report, "skills_overlap");
+    assert_eq!(overlap.details["resume_skill_count"], 12);
+    // Python, Docker and Kubernetes appear in the sample job
+    assert!(overlap.details["matched_skill_count"].as_u64().unwrap() >= 3);
+    assert!(overlap.score > 0.0);
+}
+
+#[test]
+fn test_experience_quality_counts_bullets_field() {
+    let report = score_resume(&sample_resume_json(), None).unwrap();
+    let experience = category(&report, "experience_quality");
+
+    assert_eq!(experience.details["total_bullets"], 6);
+    assert!(!experience.details.contains_key("reason"));
+    // Bullet-less experience scores a flat 15
+    assert!(experience.score > 15.0);
+}
+
+#[test]
+fn test_experience_quality_reads_achievements() {
+    let resume = serde_json::json!({
+        "experience": [{
+            "title": "Engineer",
+            "description": "Owned the billing service",
+            "achievements": ["Cut invoice errors by 30%"]
+        }]
+    });
+    let report = score_resume(&resume, None).unwrap();
+    let experience = category(&report, "experience_quality");
+
+    assert_eq!(experience.details["total_bullets"], 2);
+    assert_eq!(experience.details["quantified_bullets"], 1);
+}
+
+#[test]
+fn test_length_category_prefers_ideal_word_count() {
+    // Ten identical-quality bullets, varying only in word count
+    let resume_with_bullet_words = |words: usize| {
+        let bullet = format!(
+            "Reduced latency by 40% {}",
+            "across services ".repeat(words / 2)
+        );
+        serde_json::json!({
+            "personal_info": {"name": "Jane Doe", "email": "jane@example.com"},
+            "summary": "Backend engineer",
+            "skills": ["Rust", "Go"],
+            "experience": [{"title": "Engineer", "company": "Acme", "description": vec![bullet; 10]}]
+        })
+    };
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(
+        temp_dir.path(),
+        "weights.toml",
+        "[resume.weights]\nlength = 0.05\n",
+    );
+    let weights_path = weights_path.to_str();
+
+    let terse = score_resume(&resume_with_bullet_words(2), weights_path).unwrap();
+    let ideal = score_resume(&resume_with_bullet_words(60), weights_path).unwrap();
+    let bloated = score_resume(&resume_with_bullet_words(300), weights_path).unwrap();
+
+    assert_eq!(category(&terse, "length").details["band"], "too_short");
+    assert_eq!(category(&ideal, "length").details["band"], "ideal");
+    assert_eq!(category(&bloated, "length").details["band"], "too_long");
+    assert_eq!(category(&ideal, "length").score, 100.0);
+    assert!(
+        category(&ideal, "length").details["word_count"]
+            .as_u64()
+            .unwrap()
+            >= 400
+    );
+
+    assert!(ideal.total > terse.total);
+    assert!(ideal.total > bloated.total);
+
+    // Length is opt-in: it is reported but carries no weight by default
+    let default = score_resume(&resume_with_bullet_words(60), None).unwrap();
+    assert_eq!(category(&default, "length").weight, 0.0);
+    assert_eq!(category(&default, "length").score, 100.0);
+}
+
+#[test]
+fn test_score_match_rare_terms_outweigh_common_terms() {
+    let job = serde_json::json!({
+        "description": "Kubernetes operator. Team player, team lead, team culture, \
+                        team rituals. Collaboration with the team."
+    });
+    let rare = serde_json::json!({"skills": ["Kubernetes"]});
+    let common = serde_json::json!({"skills": ["Team"]});
+
+    let rare_report = score_match(&rare, &job, None).unwrap();
+    let common_report = score_match(&common, &job, None).unwrap();
+    let rare_overlap = category(&rare_report, "keyword_overlap");
+    let common_overlap = category(&common_report, "keyword_overlap");
+
+    // One matched term each, but the rarer one is worth more
+    assert_eq!(rare_overlap.details["overlap_count"], 1);
+    assert_eq!(common_overlap.details["overlap_count"], 1);
+    assert!(rare_overlap.score > common_overlap.score);
+
+    // The repeated term is the least valuable gap
+    let missing = rare_overlap.details["top_weighted_missing"]
+        .as_array()
+        .unwrap();
+    assert_eq!(missing.last().unwrap(), "team");
+}
+
+#[test]
+fn test_score_job_short_description_with_word_threshold() {
+    let job = serde_json::json!({
+        "title": "Backend Engineer",
+        "description": "Internal transfer: own the billing service, Rust and Postgres, on call monthly."
+    });
+    let has_description = |report: &ats_checker::ScoreReport| {
+        category(report, "completeness").details["has_description"].clone()
+    };
+
+    // 12 words falls short of the default
+    let report = score_job(&job, None).unwrap();
+    assert_eq!(has_description(&report), false);
+    assert_eq!(
+        category(&report, "completeness").details["description_words"],
+        12
+    );
+
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(
+        temp_dir.path(),
+        "weights.toml",
+        "[job.completeness]\nmin_description_words = 10\n",
+    );
+    let report = score_job(&job, weights_path.to_str()).unwrap();
+    assert_eq!(has_description(&report), true);
+}
+
+#[test]
+fn test_score_job_multibyte_description() {
+    // 40 ideographs: 120 bytes, but 40 words and 40 characters
+    let description =
+        "我们正在招聘后端工程师负责设计开发和维护高性能分布式系统需要熟悉数据库云平台经验";
+    assert_eq!(description.chars().count(), 40);
+    let job = serde_json::json!({ "title": "后端工程师", "description": description });
+
+    let report = score_job(&job, None).unwrap();
+    let details = &category(&report, "completeness").details;
+    assert_eq!(details["description_words"], 40);
+    assert_eq!(details["has_description"], true);
+
+    // A character threshold counts characters, not bytes
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(
+        temp_dir.path(),
+        "weights.toml",
+        "[job.completeness]\nmin_description_chars = 100\n",
+    );
+    let report = score_job(&job, weights_path.to_str()).unwrap();
+    assert_eq!(
+        category(&report, "completeness").details["has_description"],
+        false
+    );
+}
+
+#[test]
+fn test_score_match_expands_abbreviations() {
+    let resume = serde_json::json!({"skills": ["K8s", "Machine Learning"]});
+    let job = serde_json::json!({
+        "title": "Platform Engineer",
+        "description": "Run our Kubernetes clusters and ML pipelines"
+    });
+
+    let report = score_match(&resume, &job, None).unwrap();
+    let skills = category(&report, "skills_overlap");
+    assert_eq!(skills.details["matched_skill_count"], 2);
+    assert_eq!(
+        skills.details["abbreviation_expansions"],
+        serde_json::json!({"k8s": "kubernetes", "ml": "machine learning"})
+    );
+
+    // An empty table turns expansion off
+    let report = score_match_with_options(&resume, &job, None, &no_abbreviations()).unwrap();
+    let skills = category(&report, "skills_overlap");
+    assert_eq!(skills.details["matched_skill_count"], 0);
+    assert_eq!(
+        skills.details["abbreviation_expansions"],
+        serde_json::json!({})
+    );
+}
+
+#[test]
+fn test_score_match_custom_abbreviations() {
+    let resume = serde_json::json!({"skills": ["TF"]});
+    let job = serde_json::json!({"description": "Infrastructure as code with Terraform"});
+    let options = ScoringOptions {
+        abbreviations: Some(SynonymMap::new(
+            DEFAULT_ABBREVIATIONS
+                .iter()
+                .map(|(expansion, abbreviations)| {
+                    (
+                        (*expansion).to_string(),
+                        abbreviations.iter().map(|a| (*a).to_string()).collect(),
+                    )
+                })
+                .chain([("terraform".to_string(), vec!["tf".to_string()])]),
+        )),
+        ..Default::default()
+    };
+
+    let baseline = score_match(&resume, &job, None).unwrap();
+    let extended = score_match_with_options(&resume, &job, None, &options).unwrap();
+
+    assert_eq!(
+        category(&baseline, "skills_overlap").details["matched_skill_count"],
+        0
+    );
+    assert_eq!(
+        category(&extended, "skills_overlap").details["matched_skill_count"],
+        1
+    );
+}
+
+#[test]
+fn test_score_match_seniority_alignment() {
+    let resume_titled = |title: &str| {
+        serde_json::json!({
+            "personal_info": {"name": "Jane Doe"},
+            "summary": "Engineer building distributed systems in Rust",
+            "experience": [{"title": title, "company": "Acme", "description": ["Built Rust services"]}],
+            "skills": ["Rust", "Kubernetes"]
+        })
+    };
+    let job = serde_json::json!({
+        "title": "Staff Engineer",
+        "description": "Lead distributed systems work in Rust and Kubernetes"
+    });
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(
+        temp_dir.path(),
+        "weights.toml",
+        "[match.weights]\nseniority_alignment = 0.15\n",
+    );
+    let weights_path = weights_path.to_str();
+
+    let senior = score_match(&resume_titled("Senior Engineer"), &job, weights_path).unwrap();
+    let junior = score_match(&resume_titled("Junior Developer"), &job, weights_path).unwrap();
+
+    assert!(
+        category(&senior, "seniority_alignment").score
+            > category(&junior, "seniority_alignment").score
+    );
+    assert!(senior.total > junior.total);
+    // Seniority is opt-in: it is reported but carries no weight by default
+    let default = score_match(&resume_titled("Senior Engineer"), &job, None).unwrap();
+    assert_eq!(category(&default, "seniority_alignment").weight, 0.0);
+    assert_eq!(
+        category(&senior, "seniority_alignment").details["resume_level"],
+        "senior"
+    );
+    assert_eq!(
+        category(&junior, "seniority_alignment").details["resume_level"],
+        "junior"
+    );
+    assert_eq!(
+        category(&senior, "seniority_alignment").details["job_level"],
+        "lead"
+    );
+    assert_eq!(
+        category(&junior, "seniority_alignment").details["level_gap"],
+        3
+    );
+
+    // Without a level keyword, years of experience decide
+    let mut veteran = resume_titled("Software Engineer");
+    veteran["summary"] = serde_json::json!("Engineer with 10+ years of experience");
+    let veteran = score_match(&veteran, &job, None).unwrap();
+    assert_eq!(
+        category(&veteran, "seniority_alignment").details["resume_level_source"],
+        "years"
+    );
+    assert_eq!(category(&veteran, "seniority_alignment").score, 100.0);
+
+    // A job without a title gives no level to compare against
+    let untitled = serde_json::json!({"description": "Rust and Kubernetes"});
+    let report = score_match(&resume_titled("Senior Engineer"), &untitled, None).unwrap();
+    assert!(report
+        .categories
+        .iter()
+        .all(|c| c.name != "seniority_alignment"));
+}
+
+#[test]
+fn test_score_match_required_keywords_cap_total() {
+    let temp_dir = create_temp_dir();
+    let weights_path = create_test_file(
+        temp_dir.path(),
+        "weights.toml",
+        r#"
+[match.requirements]
+keywords = ["AWS"]
+cap = 40
+"#,
+    );
+    let weights_path = weights_path.to_str();
+
+    let job = serde_json::json!({
+        "title": "Backend Engineer",
+        "description": "Backend engineer building Rust services on AWS with PostgreSQL"
+    });
+    let resume_with = |skills: &[&str]| {
+        serde_json::json!({
+            "personal_info": {"name": "Jane Doe"},
+            "summary": "Backend engineer building Rust services with PostgreSQL",
+            "experience": [{"title": "Backend Engineer", "company": "Acme"}],
+            "skills": skills
+        })
+    };
+
+    let missing = score_match(&resume_with(&["Rust", "PostgreSQL"]), &job, weights_path).unwrap();
+    let present = score_match(
+        &resume_with(&["Rust", "PostgreSQL", "AWS"]),
+        &job,
+        weights_path,
+    )
+    .unwrap();
+
+    assert_eq!(missing.total, 40.0);
+    assert!(missing.meta["uncapped_total"].as_f64().unwrap() > 40.0);
+    assert_eq!(
+        category(&missing, "keyword_overlap").details["failed_requirements"],
+        serde_json::json!(["aws"])
+    );
+
+    assert!(present.total > 40.0);
+    assert_eq!(
+        category(&present, "keyword_overlap").details["failed_requirements"],
+        serde_json::json!([])
+    );
+    assert!(!present.meta.contains_key("uncapped_total"));
+
+    // Requirements can also be passed in, on top of the weights file
+    let options = ScoringOptions {
+        required_keywords: vec!["Kubernetes".to_string()],
+        ..Default::default()
+    };
+    let report = score_match_with_options(
+        &resume_with(&["Rust", "PostgreSQL", "AWS"]),
+        &job,
+        weights_path,
+        &options,
+    )
+    .unwrap();
+    assert_eq!(report.total, 40.0);
+    assert_eq!(
+        category(&report, "keyword_overlap").details["failed_requirements"],
+        serde_json::json!(["kubernetes"])
+    );
+
+    // Without requirements nothing is reported
+    let report = score_match(&resume_with(&["Rust"]), &job, None).unwrap();
+    assert!(!category(&report, "keyword_overlap")
+        .details
+        .contains_key("failed_requirements"));
+    assert!(!report.meta.contains_key("failed_requirements"));
+}
+
+#[test]
+fn test_tenure_parses_date_formats() {
+    let today = YearMonth::new(2024, 6);
+    let range = |text: &str| {
+        let r = parse_range(text, today).unwrap();
+        (r.start, r.end)
+    };
+
+    assert_eq!(
+        range("2020-Present"),
+        (YearMonth::new(2020, 1), YearMonth::new(2024, 6))
+    );
+    assert_eq!(
+        range("Jan 2019 – Mar 2021"),
+        (YearMonth::new(2019, 1), YearMonth::new(2021, 3))
+    );
+    assert_eq!(
+        range("2016 - 2018"),
+        (YearMonth::new(2016, 1), YearMonth::new(2018, 12))
+    );
+    assert_eq!(
+        range("09/2017 to 11/2018"),
+        (YearMonth::new(2017, 9), YearMonth::new(2018, 11))
+    );
+    assert_eq!(
+        range("2021-04 – current"),
+        (YearMonth::new(2021, 4), YearMonth::new(2024, 6))
+    );
+    assert_eq!(
+        range("September 2015 — June 2016"),
+        (YearMonth::new(2015, 9), YearMonth::new(2016, 6))
+    );
+    assert!(parse_range("Several years", today).is_none());
+
+    // Separate start/end fields; a missing end means the current role
+    let entry = serde_json::json!({"start_date": "Feb 2022"});
+    assert_eq!(entry_range(&entry, today).map(|r| r.months()), Some(29));
+    let entry = serde_json::json!({"start_date": "2018", "end_date": "2019"});
+    assert_eq!(entry_range(&entry, today).map(|r| r.months()), Some(24));
+}
+
+#[test]
+fn test_tenure_two_role_history() {
+    let today = YearMonth::new(2024, 6);
+    let resume = serde_json::json!({
+        "experience": [
+            {"title": "Senior Engineer", "duration": "Jan 2022 - Present"},
+            {"title": "Engineer", "start_date": "Mar 2018", "end_date": "Jun 2021"}
+        ]
+    });
+
+    let tenure = resume_tenure(&resume, today, 6);
+    assert_eq!(tenure.ranges.len(), 2);
+    // 30 months (Jan 2022 - Jun 2024) + 40 months (Mar 2018 - Jun 2021)
+    assert_eq!(tenure.total_months, 70);
+    assert_eq!(total_months(&tenure.ranges), 70);
+    assert!((tenure.average_months() - 35.0).abs() < f64::EPSILON);
+
+    // Jul - Dec 2021 without a role
+    assert_eq!(tenure.gaps.len(), 1);
+    assert_eq!(tenure.gaps[0].months, 6);
+    assert!(resume_tenure(&resume, today, 7).gaps.is_empty());
+
+    // The resume score reports tenure, with the gap in its details
+    let options = ScoringOptions {
+        today: Some(today),
+        ..ScoringOptions::default()
+    };
+    let report = score_resume_with_options(&resume, None, &options).unwrap();
+    let details = &category(&report, "tenure").details;
+    assert_eq!(details["dated_roles"], serde_json::json!(2));
+    assert_eq!(details["total_months"], serde_json::json!(70));
+    assert_eq!(
+        details["gaps"],
+        serde_json::json!([{"after": "2021-06", "before": "2022-01", "months": 6}])
+    );
+
+    // Undated experience leaves the category out
+    let undated = serde_json::json!({"experience": [{"title": "Engineer"}]});
+    let report = score_resume(&undated, None).unwrap();
+    assert!(report.categories.iter().all(|c| c.name != "tenure"));
+}
+
+#[test]
+fn test_tenure_current_role_runs_to_this_month() {
+    // The latest date written is the current role's start, long ago
+    let resume = serde_json::json!({
+        "experience": [
+            {"title": "Senior Engineer", "duration": "Jan 2020 - Present"},
+            {"title": "Engineer", "duration": "2018 - 2019"}
+        ]
+    });
+    let total_months = |report: &ats_checker::ScoreReport| {
+        category(report, "tenure").details["total_months"]
+            .as_u64()
+            .unwrap()
+    };
+
+    let options = ScoringOptions {
+        today: Some(YearMonth::new(2024, 6)),
+        ..ScoringOptions::default()
+    };
+    let pinned = score_resume_with_options(&resume, None, &options).unwrap();
+    // 24 months (2018 - 2019) + 54 months (Jan 2020 - Jun 2024)
+    assert_eq!(total_months(&pinned), 78);
+
+    // Unpinned, "Present" is the current month
+    let current = score_resume(&resume, None).unwrap();
+    let today = YearMonth::current();
+    let expected = 24 + YearMonth::new(2020, 1).months_until(today) + 1;
+    assert_eq!(i64::try_from(total_months(&current)).unwrap(), expected);
+    // Tenure is opt-in: it is reported but carries no weight by default
+    assert_eq!(category(&current, "tenure").weight, 0.0);
+}
+
+#[test]
+fn test_unicode_tokenizer_keeps_accented_words() {
+    // "Ingénieur réseau confirmé" with decomposed accents (e + U+0301), as
+    // produced by some PDF extractors and macOS file systems
+    let phrase = "Inge\u{301}nieur re\u{301}seau confirme\u{301}, C++";
+
+    assert_eq!(
+        DefaultTokenizer.tokenize(phrase),
+        ["inge", "nieur", "re", "seau", "confirme", "c++"]
+    );
+    assert_eq!(
+        UnicodeTokenizer.tokenize(phrase),
+        [
+            "inge\u{301}nieur",
+            "re\u{301}seau",
+            "confirme\u{301}",
+            "c++"
+        ]
+    );
+
+    // Precomposed accents tokenize the same either way
+    let phrase = "Développeur à Montréal";
+    assert_eq!(
+        DefaultTokenizer.tokenize(phrase),
+        UnicodeTokenizer.tokenize(phrase)
+    );
+}
+
+#[test]
+fn test_score_match_with_unicode_tokenizer() {
+    let resume = serde_json::json!({
+        "summary": "数据分析 Rust",
+        "experience": [{"title": "Engineer"}]
+    });
+    let job = serde_json::json!({"title": "Engineer", "description": "数据工程师 Rust"});
+    let overlap = |report: &ats_checker::scoring::ScoreReport| {
+        category(report, "keyword_overlap").details["overlap_count"].clone()
+    };
+
+    // The default tokenizer keeps each CJK run whole, so only "rust" and
+    // "engineer" overlap
+    let report = score_match(&resume, &job, None).unwrap();
+    assert_eq!(overlap(&report), serde_json::json!(2));
+
+    // Word boundaries split the run, so the shared 数据 counts too
+    let options = ScoringOptions {
+        tokenizer: Some(std::sync::Arc::new(UnicodeTokenizer)),
+        ..Default::default()
+    };
+    let unicode = score_match_with_options(&resume, &job, None, &options).unwrap();
+    assert_eq!(overlap(&unicode), serde_json::json!(4));
+}
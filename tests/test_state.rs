@@ -125,3 +125,45 @@ fn test_state_manager_clear_all() {
     assert!(!manager.is_processed("hash2"));
     assert!(!manager.is_processed("hash3"));
 }
+
+#[test]
+fn test_sync_state_manager_concurrent_updates() {
+    use ats_checker::state::SyncStateManager;
+
+    let temp_dir = create_temp_dir();
+    let state_file = temp_dir.path().join("state.toml");
+
+    let manager = SyncStateManager::new(&state_file).expect("Failed to create state manager");
+
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let manager = manager.clone();
+            std::thread::spawn(move || {
+                for j in 0..5 {
+                    let hash = format!("hash_{i}_{j}");
+                    manager
+                        .update_resume_state(&hash, &format!("/output/{i}/{j}"))
+                        .expect("Failed to update state");
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(manager.count(), 40);
+
+    // Every update survives on disk and the file is still valid TOML
+    let reloaded = StateManager::new(&state_file).expect("Failed to reload state");
+    assert_eq!(reloaded.count(), 40);
+    for i in 0..8 {
+        for j in 0..5 {
+            let state = reloaded
+                .get_resume_state(&format!("hash_{i}_{j}"))
+                .expect("Missing state entry");
+            assert_eq!(state.output_path, format!("/output/{i}/{j}"));
+        }
+    }
+}
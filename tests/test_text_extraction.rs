@@ -2,7 +2,7 @@
 
 mod common;
 
-use ats_checker::utils::extract::extract_text_from_file;
+use ats_checker::utils::extract::{extract_text_cached, extract_text_from_file};
 use common::*;
 
 #[test]
@@ -74,3 +74,130 @@ fn test_extract_text_handles_utf8() {
 
     assert_eq!(extracted, content);
 }
+
+// -------------------------
+// Remote resumes
+// -------------------------
+
+#[tokio::test]
+async fn test_load_resume_from_url_plain_text() {
+    use ats_checker::input::InputHandler;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/resumes/jane"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/plain; charset=utf-8")
+                .set_body_string(sample_resume_text()),
+        )
+        .mount(&server)
+        .await;
+
+    let handler = InputHandler::default();
+    let text = handler
+        .load_resume_from_url(&format!("{}/resumes/jane", server.uri()))
+        .await
+        .expect("Failed to load resume from URL");
+
+    assert_eq!(text, sample_resume_text());
+}
+
+#[tokio::test]
+async fn test_load_resume_from_url_errors() {
+    use ats_checker::error::AtsError;
+    use ats_checker::input::InputHandler;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/missing.txt"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/slow.txt"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string("late")
+                .set_delay(Duration::from_secs(5)),
+        )
+        .mount(&server)
+        .await;
+
+    let handler = InputHandler::default().with_download_timeout(Duration::from_millis(200));
+
+    let err = handler
+        .load_resume_from_url(&format!("{}/missing.txt", server.uri()))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        AtsError::Download {
+            status_code: Some(404),
+            ..
+        }
+    ));
+
+    let err = handler
+        .load_resume_from_url(&format!("{}/slow.txt", server.uri()))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AtsError::DownloadTimeout { .. }));
+}
+
+#[test]
+fn test_extract_text_cached_reads_cache_and_invalidates() {
+    let temp_dir = create_temp_dir();
+    let cache_dir = temp_dir.path().join("cache");
+    let file_path = create_test_file(temp_dir.path(), "resume.txt", "Original resume");
+
+    let first = extract_text_cached(&file_path, &cache_dir).expect("first extraction");
+    assert_eq!(first, "Original resume");
+
+    // Replace the cached entry: a second extraction must come from the cache
+    let entries: Vec<_> = std::fs::read_dir(&cache_dir)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .collect();
+    assert_eq!(entries.len(), 1);
+    std::fs::write(&entries[0], "From cache").unwrap();
+
+    let second = extract_text_cached(&file_path, &cache_dir).expect("cached extraction");
+    assert_eq!(second, "From cache");
+
+    // Changing the file changes its hash, so it is extracted afresh
+    std::fs::write(&file_path, "Updated resume").unwrap();
+    let third = extract_text_cached(&file_path, &cache_dir).expect("re-extraction");
+    assert_eq!(third, "Updated resume");
+    assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 2);
+}
+
+#[test]
+fn test_extract_text_from_rtf() {
+    let temp_dir = create_temp_dir();
+    let content = r"{\rtf1\ansi\deff0{\fonttbl{\f0\fswiss Arial;}}{\colortbl;\red0\green0\blue0;}
+{\*\generator Riched20 10.0;}{\info{\author Word}}\viewkind4\uc1\pard\f0\fs24
+{\b John Doe}\par
+Senior Software Engineer \endash  Caf\'e9 Corp\par
+\bullet\tab Built Rust services in Z\u252?rich\par
+}";
+
+    let file_path = create_test_file(temp_dir.path(), "resume.rtf", content);
+
+    let extracted = extract_text_from_file(&file_path).expect("Failed to extract text from RTF");
+
+    assert_eq!(
+        extracted,
+        "John Doe\nSenior Software Engineer \u{2013} Café Corp\n\u{2022} Built Rust services in Zürich"
+    );
+    for control in ['\\', '{', '}'] {
+        assert!(!extracted.contains(control));
+    }
+    assert!(!extracted.contains("Arial"));
+    assert!(!extracted.contains("Riched20"));
+}
@@ -2,7 +2,10 @@
 
 mod common;
 
-use ats_checker::validation::validate_json;
+use ats_checker::processor::parse_resume_heuristic;
+use ats_checker::scoring::score_resume;
+use ats_checker::validation::{resume_schema, score_report_schema, validate_json};
+use common::{sample_resume_json, sample_resume_text};
 use serde_json::json;
 
 #[test]
@@ -158,3 +161,63 @@ fn test_validate_resume_with_nested_structure() {
     let validation_result = result.unwrap();
     assert!(validation_result.ok);
 }
+
+#[test]
+fn test_builtin_resume_schema_accepts_known_shapes() {
+    let schema = resume_schema();
+
+    let result = validate_json(&sample_resume_json(), &schema).unwrap();
+    assert!(result.ok, "{:?}", result.errors);
+
+    // Output of the heuristic parser must stay valid as well
+    let parsed = parse_resume_heuristic(sample_resume_text());
+    let result = validate_json(&parsed, &schema).unwrap();
+    assert!(result.ok, "{:?}", result.errors);
+}
+
+#[test]
+fn test_builtin_resume_schema_rejects_wrong_types() {
+    let mut resume = sample_resume_json();
+    resume["experience"] = json!("Senior Engineer at Tech Corp");
+
+    let result = validate_json(&resume, &resume_schema()).unwrap();
+    assert!(!result.ok);
+    assert!(result.errors.iter().any(|e| e.starts_with("/experience")));
+}
+
+#[test]
+fn test_builtin_score_report_schema() {
+    let report = score_resume(&sample_resume_json(), None).unwrap();
+    let instance = serde_json::to_value(&report).unwrap();
+
+    let result = validate_json(&instance, &score_report_schema()).unwrap();
+    assert!(result.ok, "{:?}", result.errors);
+}
+
+#[test]
+fn test_validation_detailed_errors_point_at_missing_field() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "personal_info": {
+                "type": "object",
+                "properties": {
+                    "email": {"type": "string"}
+                },
+                "required": ["email"]
+            }
+        }
+    });
+    let resume = json!({"personal_info": {"name": "John Doe"}});
+
+    let result = validate_json(&resume, &schema).unwrap();
+    assert!(!result.ok);
+    assert_eq!(result.errors.len(), result.detailed_errors.len());
+
+    let error = &result.detailed_errors[0];
+    assert_eq!(error.instance_path, "/personal_info/email");
+    assert_eq!(error.keyword, "required");
+    assert_eq!(error.schema_path, "/properties/personal_info/required");
+    assert!(error.message.contains("email"));
+    assert_eq!(result.failed_paths(), vec!["/personal_info/email"]);
+}